@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+use indicato_rs::signals::{BollingerBands, HighLowClose, MaximumPeriod, SimpleMovingAverage};
+use indicato_rs::traits::{Apply, ApplyBatch};
+
+fn prices() -> Vec<f64> {
+    (0..10_000).map(|x| 100.0 + (x as f64 * 0.1).sin() * 10.0).collect()
+}
+
+fn criterion_benchmark(c: &mut Criterion) {
+    let prices = prices();
+    let bars = prices
+        .iter()
+        .map(|&price| HighLowClose { high: price + 1.0, low: price - 1.0, close: price })
+        .collect::<Vec<_>>();
+
+    let mut group = c.benchmark_group("SMA over 10k");
+    for period in [14, 200] {
+        group.bench_with_input(BenchmarkId::new("apply", period), &period, |b, &period| {
+            b.iter(|| {
+                let mut sma = SimpleMovingAverage::new(period).unwrap();
+                prices.iter().map(|&price| sma.apply(price)).collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("apply_slice", period), &period, |b, &period| {
+            b.iter(|| SimpleMovingAverage::new(period).unwrap().apply_slice(&prices))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("Maximum over 10k");
+    for period in [14, 200] {
+        group.bench_with_input(BenchmarkId::new("apply", period), &period, |b, &period| {
+            b.iter(|| {
+                let mut max = MaximumPeriod::new(period).unwrap();
+                prices.iter().map(|&price| max.apply(price)).collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("apply_slice", period), &period, |b, &period| {
+            b.iter(|| MaximumPeriod::new(period).unwrap().apply_slice(&prices))
+        });
+    }
+    group.finish();
+
+    let mut group = c.benchmark_group("Bollinger Bands over 10k");
+    for period in [20, 200] {
+        group.bench_with_input(BenchmarkId::new("apply", period), &period, |b, &period| {
+            b.iter(|| {
+                let mut bollinger = BollingerBands::new(period, 2.0).unwrap();
+                bars.iter().map(|&bar| bollinger.apply(bar)).collect::<Vec<_>>()
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("apply_slice", period), &period, |b, &period| {
+            b.iter(|| BollingerBands::new(period, 2.0).unwrap().apply_slice(&bars))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,360 @@
+use std::{cell::Cell, fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::Inspect,
+};
+
+/// The version of the snapshot format written by this crate.
+///
+/// The serialized state of every signal is covered by this version, it is bumped whenever a release changes the fields
+/// of any signal or the layout of the snapshot itself. Snapshots written with one version are only restored by
+/// releases with the same version, so a changed layout is reported as an
+/// [`IncompatibleVersion`](FinErrorType::IncompatibleVersion) error rather than being read into the wrong fields.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const FIELDS: &[&str] = &["version", "name", "state"];
+
+/// # Snapshot
+/// A versioned copy of the state of a signal
+///
+/// A snapshot holds the [`SNAPSHOT_VERSION`] it was written with, the name of the signal it was taken from and the
+/// state of the signal, in that order. The version is checked as soon as it is read, before the state, and the name is
+/// checked when the signal is taken back out of the snapshot, so a snapshot of one signal can't be restored as another
+/// with the same fields.
+///
+/// Snapshots can be embedded in larger serializable structures, [`Checkpoint`] writes and reads them on their own.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::checkpoint::{Snapshot, SNAPSHOT_VERSION};
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::traits::{Apply, Current};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// sma.apply(1.0);
+/// sma.apply(3.0);
+///
+/// // take a snapshot of the signal and write it out
+/// let snapshot = Snapshot::new(sma);
+/// assert_eq!(snapshot.version(), SNAPSHOT_VERSION);
+/// assert_eq!(snapshot.name(), "SimpleMovingAverage");
+/// let json = serde_json::to_string(&snapshot).unwrap();
+///
+/// // read it back in and carry on from where the signal left off
+/// let snapshot: Snapshot<SimpleMovingAverage<f64>> = serde_json::from_str(&json).unwrap();
+/// let mut sma = snapshot.into_signal().unwrap();
+/// assert_eq!(sma.current(), 2.0);
+/// assert_eq!(sma.apply(5.0), 4.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Snapshot<S> {
+    version: u32,
+    name: String,
+    state: S,
+}
+
+impl<S: Inspect> Snapshot<S> {
+    /// Create a new Snapshot of a signal
+    /// # Arguments
+    /// * `signal` - The signal to take a snapshot of
+    pub fn new(signal: S) -> Self {
+        Self {
+            version: SNAPSHOT_VERSION,
+            name: signal.name().to_string(),
+            state: signal,
+        }
+    }
+
+    /// Consumes the snapshot, returning the signal
+    ///
+    /// # Errors
+    /// Will return an error if the snapshot was taken from a different signal
+    /// ```
+    /// use indicato_rs::checkpoint::Snapshot;
+    /// use indicato_rs::signals::{MaximumPeriod, MinimumPeriod};
+    ///
+    /// let json = serde_json::to_string(&Snapshot::new(MaximumPeriod::new(3).unwrap())).unwrap();
+    /// let snapshot: Snapshot<MinimumPeriod> = serde_json::from_str(&json).unwrap();
+    ///
+    /// assert!(snapshot.into_signal().is_err());
+    /// ```
+    pub fn into_signal(self) -> Result<S, FinError> {
+        let expected = self.state.name();
+        match self.name == expected {
+            true => Ok(self.state),
+            false => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                &format!("Snapshot of {} can't be restored as {}", self.name, expected),
+            )),
+        }
+    }
+}
+
+impl<S> Snapshot<S> {
+    /// The snapshot format version the snapshot was written with
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// The name of the signal the snapshot was taken from
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<'de, S: Deserialize<'de>> Deserialize<'de> for Snapshot<S> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let version = Cell::new(None);
+        SnapshotSeed::new(&version).deserialize(deserializer)
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum Field {
+    Version,
+    Name,
+    State,
+}
+
+/// Reads a snapshot, checking its version before its state. The version read is kept so [`Checkpoint::restore`] can
+/// tell an incompatible version apart from a malformed snapshot.
+struct SnapshotSeed<'a, S> {
+    version: &'a Cell<Option<u32>>,
+    signal: PhantomData<S>,
+}
+
+impl<'a, S> SnapshotSeed<'a, S> {
+    fn new(version: &'a Cell<Option<u32>>) -> Self {
+        Self {
+            version,
+            signal: PhantomData,
+        }
+    }
+
+    fn check<E: de::Error>(&self, version: u32) -> Result<u32, E> {
+        self.version.set(Some(version));
+        match version == SNAPSHOT_VERSION {
+            true => Ok(version),
+            false => Err(E::custom(format!(
+                "Snapshot version {} is not supported, expected version {}",
+                version, SNAPSHOT_VERSION
+            ))),
+        }
+    }
+}
+
+impl<'de, S: Deserialize<'de>> DeserializeSeed<'de> for SnapshotSeed<'_, S> {
+    type Value = Snapshot<S>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_struct("Snapshot", FIELDS, self)
+    }
+}
+
+impl<'de, S: Deserialize<'de>> Visitor<'de> for SnapshotSeed<'_, S> {
+    type Value = Snapshot<S>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("a signal snapshot")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let version = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let version = self.check(version)?;
+        let name = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        let state = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(2, &self))?;
+        Ok(Snapshot { version, name, state })
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let (mut version, mut name, mut state) = (None, None, None);
+        while let Some(field) = map.next_key()? {
+            match field {
+                Field::Version => version = Some(self.check(map.next_value()?)?),
+                Field::Name => name = Some(map.next_value()?),
+                Field::State if version.is_none() => {
+                    return Err(de::Error::custom("Snapshot version must come before its state"))
+                }
+                Field::State => state = Some(map.next_value()?),
+            }
+        }
+        Ok(Snapshot {
+            version: version.ok_or_else(|| de::Error::missing_field("version"))?,
+            name: name.ok_or_else(|| de::Error::missing_field("name"))?,
+            state: state.ok_or_else(|| de::Error::missing_field("state"))?,
+        })
+    }
+}
+
+/// Writes signals to versioned snapshots and restores them, with any `serde` format.
+///
+/// Every serializable signal, including signals composed with [`Then`](crate::traits::Then) and the other
+/// serializable combinators, is checkpointed. Failures are reported as a [`FinError`], with an
+/// [`IncompatibleVersion`](FinErrorType::IncompatibleVersion) error when the snapshot was written with a different
+/// [`SNAPSHOT_VERSION`].
+///
+/// # Example
+/// ```
+/// use indicato_rs::checkpoint::Checkpoint;
+/// use indicato_rs::signals::{ExponentialMovingAverage, Momentum};
+/// use indicato_rs::traits::{Apply, Chain, Then};
+///
+/// let mut pipeline = Momentum::new(1)
+///     .unwrap()
+///     .then(ExponentialMovingAverage::new(3).unwrap());
+/// pipeline.apply(1.0);
+/// pipeline.apply(2.0);
+///
+/// // write the pipeline out, such as before a restart
+/// let mut bytes = Vec::new();
+/// pipeline.checkpoint(&mut serde_json::Serializer::new(&mut bytes)).unwrap();
+///
+/// // restore it and carry on without replaying any history
+/// let mut restored: Chain<Momentum, ExponentialMovingAverage<f64>> =
+///     Checkpoint::restore(&mut serde_json::Deserializer::from_slice(&bytes)).unwrap();
+/// assert_eq!(pipeline.apply(4.0), restored.apply(4.0));
+/// ```
+pub trait Checkpoint: Inspect + Serialize + DeserializeOwned {
+    /// Writes a snapshot of the signal to `serializer`
+    ///
+    /// # Errors
+    /// Will return an error if the serializer fails
+    fn checkpoint<Se: Serializer>(&self, serializer: Se) -> Result<Se::Ok, FinError> {
+        Snapshot {
+            version: SNAPSHOT_VERSION,
+            name: self.name().to_string(),
+            state: self,
+        }
+        .serialize(serializer)
+        .map_err(|error| {
+            FinError::new(
+                FinErrorType::InvalidOperation,
+                &format!("Snapshot could not be written: {}", error),
+            )
+        })
+    }
+
+    /// Reads a snapshot from `deserializer` and restores the signal it was taken from
+    ///
+    /// # Errors
+    /// Will return an error if the snapshot was written with a different [`SNAPSHOT_VERSION`], was taken from a
+    /// different signal or can't be read
+    /// ```
+    /// use indicato_rs::checkpoint::Checkpoint;
+    /// use indicato_rs::fin_error::FinErrorType;
+    /// use indicato_rs::signals::SimpleMovingAverage;
+    ///
+    /// let json = r#"{"version":0,"name":"SimpleMovingAverage","state":{"window":[]}}"#;
+    /// let error = SimpleMovingAverage::<f64>::restore(&mut serde_json::Deserializer::from_str(json)).unwrap_err();
+    ///
+    /// assert_eq!(error.error_type, FinErrorType::IncompatibleVersion);
+    /// ```
+    fn restore<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, FinError> {
+        let version = Cell::new(None);
+        SnapshotSeed::new(&version)
+            .deserialize(deserializer)
+            .map_err(|error| match version.get() {
+                Some(version) if version != SNAPSHOT_VERSION => FinError::new(
+                    FinErrorType::IncompatibleVersion,
+                    &format!(
+                        "Snapshot version {} can't be restored, expected version {}",
+                        version, SNAPSHOT_VERSION
+                    ),
+                ),
+                _ => FinError::new(
+                    FinErrorType::InvalidInput,
+                    &format!("Snapshot could not be read: {}", error),
+                ),
+            })?
+            .into_signal()
+    }
+}
+
+impl<S: Inspect + Serialize + DeserializeOwned> Checkpoint for S {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{MaximumPeriod, MinimumPeriod, RelativeStrengthIndex};
+    use crate::traits::{Apply, Current};
+
+    fn write<S: Checkpoint>(signal: &S) -> String {
+        let mut bytes = Vec::new();
+        signal
+            .checkpoint(&mut serde_json::Serializer::new(&mut bytes))
+            .unwrap();
+        String::from_utf8(bytes).unwrap()
+    }
+
+    fn read<S: Checkpoint>(json: &str) -> Result<S, FinError> {
+        S::restore(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut rsi = RelativeStrengthIndex::new(3, 0).unwrap();
+        [1.0, 2.0, 1.5, 3.0].iter().for_each(|value| {
+            rsi.apply(*value);
+        });
+        let json = write(&rsi);
+        assert!(json.starts_with(r#"{"version":1,"name":"RelativeStrengthIndex","state":"#));
+
+        let mut restored: RelativeStrengthIndex = read(&json).unwrap();
+        assert_eq!(restored.current(), rsi.current());
+        assert_eq!(restored.apply(2.5), rsi.apply(2.5));
+    }
+
+    #[test]
+    fn test_version_checked_before_state() {
+        let json = write(&MaximumPeriod::new(2).unwrap()).replace(r#""version":1"#, r#""version":2"#);
+        let error = read::<MaximumPeriod>(&json).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::IncompatibleVersion);
+
+        // the state is never read, so a changed layout still reports the version
+        let json = r#"{"version":2,"name":"MaximumPeriod","state":{"renamed":true}}"#;
+        let error = read::<MaximumPeriod>(json).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::IncompatibleVersion);
+    }
+
+    #[test]
+    fn test_wrong_signal() {
+        let json = write(&MaximumPeriod::new(2).unwrap());
+        let error = read::<MinimumPeriod>(&json).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::InvalidInput);
+        assert_eq!(error.message, "Snapshot of MaximumPeriod can't be restored as MinimumPeriod");
+    }
+
+    #[test]
+    fn test_malformed() {
+        let error = read::<MaximumPeriod>(r#"{"name":"MaximumPeriod"}"#).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::InvalidInput);
+        let error = read::<MaximumPeriod>(r#"{"state":{},"version":1}"#).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::InvalidInput);
+    }
+
+    #[test]
+    fn test_sequence_format() {
+        let snapshot = Snapshot::new(MaximumPeriod::new(2).unwrap());
+        let value = serde_json::to_value(&snapshot).unwrap();
+        let sequence = serde_json::json!([value["version"], value["name"], value["state"]]);
+        let restored: Snapshot<MaximumPeriod> = serde_json::from_value(sequence).unwrap();
+        assert_eq!(restored.name(), "MaximumPeriod");
+        assert_eq!(restored.into_signal().unwrap().apply(1.0), 1.0);
+
+        let sequence = serde_json::json!([3, value["name"], value["state"]]);
+        assert!(serde_json::from_value::<Snapshot<MaximumPeriod>>(sequence).is_err());
+    }
+}
@@ -1,43 +1,473 @@
-use std::collections::VecDeque;
-
-pub trait DequeMathExtF64 {
-    fn mean(&self) -> f64;
-    fn variance(&self) -> f64;
-    fn standard_deviation(&self) -> f64;
-    fn max(&self) -> f64;
-    fn min(&self) -> f64;
+use std::{
+    collections::{BTreeMap, VecDeque},
+    ops::Neg,
+};
+
+use num_traits::Num;
+
+use super::DequeMathExt;
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::Numeric,
+};
+
+/// The least squares line fitted to the values of a deque against their index, the front of the deque is index 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRegressionFit {
+    /// The change in value per index.
+    pub slope: f64,
+    /// The value of the line at index 0.
+    pub intercept: f64,
+    /// The coefficient of determination, 1 when the line fits the values exactly.
+    pub r_squared: f64,
+    /// The standard error of the estimate, the typical distance of the values from the line, 0 with 2 or fewer
+    /// values.
+    pub standard_error: f64,
+}
+
+/// A bin of the histogram of a deque, counting the values from its lower bound up to its upper bound.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistogramBin {
+    /// The inclusive lower bound of the bin.
+    pub lower: f64,
+    /// The upper bound of the bin, exclusive except for the last bin.
+    pub upper: f64,
+    /// The number of values in the bin.
+    pub count: usize,
+}
+
+/// The way a quantile falling between two values of a deque is resolved.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QuantileInterpolation {
+    /// The value closest to the quantile, rounding halfway quantiles up.
+    Nearest,
+    /// The linear interpolation between the values either side of the quantile.
+    Linear,
+}
+
+/// The statistics of `VecDeque<f64>` windows beyond the core statistics of [`DequeMathExt`], which it builds on, so
+/// `mean`, `variance`, `standard_deviation`, `max`, `min` and `median` are called through [`DequeMathExt`].
+pub trait DequeMathExtF64: DequeMathExt<f64> {
+    fn linear_regression(&self) -> LinearRegressionFit;
+    fn quantile(&self, quantile: f64, interpolation: QuantileInterpolation) -> f64;
+    fn percentile(&self, percentile: f64, interpolation: QuantileInterpolation) -> f64;
+    fn percent_rank(&self, value: f64) -> f64;
+    fn median_absolute_deviation(&self) -> f64;
+    fn skewness(&self, sample_corrected: bool) -> f64;
+    fn kurtosis(&self, sample_corrected: bool) -> f64;
+    /// The nth root of the product of the values, 0 if any value is 0 and NaN if any value is negative.
+    fn geometric_mean(&self) -> f64;
+    /// The reciprocal of the mean of the reciprocals, 0 if any value is 0 and NaN if any value is negative.
+    fn harmonic_mean(&self) -> f64;
+    /// The mean of the values weighted by the weight at the same index, 0 when empty.
+    fn weighted_mean(&self, weights: &[f64]) -> Result<f64, FinError>;
+    /// The sum of the values with compensation for the rounding error of each addition.
+    fn sum_compensated(&self) -> f64;
+    /// The index of the maximum value from the front, the most recent index when tied and `None` when empty.
+    fn argmax(&self) -> Option<usize>;
+    /// The index of the minimum value from the front, the most recent index when tied and `None` when empty.
+    fn argmin(&self) -> Option<usize>;
+    /// The number of values after the maximum value, 0 when the back is the maximum and `None` when empty.
+    fn argmax_age(&self) -> Option<usize>;
+    /// The number of values after the minimum value, 0 when the back is the minimum and `None` when empty.
+    fn argmin_age(&self) -> Option<usize>;
+    /// The number of population standard deviations the back is from the mean, 0 when empty or without variance.
+    fn zscore_last(&self) -> f64;
+    /// The square root of the mean of the squared values, 0 when empty.
+    fn root_mean_square(&self) -> f64;
+    /// The mean absolute distance of the values from their mean, 0 when empty.
+    fn mean_absolute_deviation(&self) -> f64;
+    /// The mean absolute distance of the values from their median, 0 when empty.
+    fn mean_absolute_deviation_from_median(&self) -> f64;
+    /// The mean weighting each value by `lambda` raised to its age from the back, 0 when empty.
+    fn ew_mean(&self, lambda: f64) -> Result<f64, FinError>;
+    /// The variance around the exponentially weighted mean using the same weights, 0 when empty.
+    fn ew_variance(&self, lambda: f64) -> Result<f64, FinError>;
+    /// The centre of the most populated bin of `bin_width` aligned to 0, the lowest bin when tied and 0 when empty.
+    fn mode(&self, bin_width: f64) -> Result<f64, FinError>;
+    /// The count of values in each of `bins` equal width bins spanning the minimum to the maximum, empty when empty.
+    fn histogram(&self, bins: usize) -> Result<Vec<HistogramBin>, FinError>;
+    /// The maximum less the minimum found in a single pass, 0 when empty.
+    ///
+    /// This is the statistical range of the values, it is not named `range` as the inherent
+    /// [`VecDeque::range`](std::collections::VecDeque::range), which returns an iterator over a range of indices,
+    /// would be called instead.
+    fn value_range(&self) -> f64;
+    /// The midpoint of the maximum and minimum found in a single pass, 0 when empty.
+    fn midrange(&self) -> f64;
+}
+
+/// The (minimum, maximum) of the values in a single pass, `None` when empty.
+fn extrema(values: &VecDeque<f64>) -> Option<(f64, f64)> {
+    values.iter().fold(None, |extrema, &x| match extrema {
+        Some((min, max)) => Some((f64::min(min, x), f64::max(max, x))),
+        None => Some((x, x)),
+    })
+}
+
+/// The weights of each value for an exponentially weighted statistic, the back has a weight of 1.
+fn exponential_weights(len: usize, lambda: f64) -> Result<Vec<f64>, FinError> {
+    match lambda > 0.0 && lambda <= 1.0 {
+        true => Ok((0..len).map(|index| lambda.powi((len - 1 - index) as i32)).collect()),
+        false => Err(FinError::new(
+            FinErrorType::InvalidInput,
+            "Lambda must be greater than 0 and at most 1",
+        )),
+    }
+}
+
+fn mean_distance(values: &VecDeque<f64>, center: f64) -> f64 {
+    values
+        .iter()
+        .map(|x| (x - center).abs())
+        .collect::<VecDeque<f64>>()
+        .mean()
+}
+
+/// The most recent index whose value is preferred over every earlier value by `replaces`.
+fn extreme_index(values: &VecDeque<f64>, replaces: impl Fn(f64, f64) -> bool) -> Option<usize> {
+    values
+        .iter()
+        .enumerate()
+        .fold(None, |best: Option<(usize, f64)>, (index, &x)| match best {
+            Some((_, extreme)) if !replaces(x, extreme) => best,
+            _ => Some((index, x)),
+        })
+        .map(|(index, _)| index)
+}
+
+/// Sums the values using Neumaier's improvement of Kahan summation, tracking the low order bits lost by each addition
+/// so the error does not grow with the number of values.
+pub(crate) fn compensated_sum<T>(values: impl Iterator<Item = T>) -> T
+where
+    T: Copy + PartialOrd + Num + Neg<Output = T>,
+{
+    let magnitude = |x: T| match x < T::zero() {
+        true => -x,
+        false => x,
+    };
+    let (sum, compensation) = values.fold((T::zero(), T::zero()), |(sum, compensation), x| {
+        let total = sum + x;
+        let lost = match magnitude(sum) >= magnitude(x) {
+            true => (sum - total) + x,
+            false => (x - total) + sum,
+        };
+        (total, compensation + lost)
+    });
+    sum + compensation
+}
+
+/// The compensated mean of the values, 0 when empty.
+pub(crate) fn compensated_mean<T: Numeric>(values: &VecDeque<T>) -> T {
+    match values.is_empty() {
+        true => T::zero(),
+        false => compensated_sum(values.iter().copied()) / T::from_count(values.len()),
+    }
+}
+
+/// Checks the values are suitable for a geometric or harmonic mean, returning the mean to use when they are not.
+fn positive_mean_fallback(values: &VecDeque<f64>) -> Option<f64> {
+    match values.iter().any(|x| *x < 0.0) {
+        true => Some(f64::NAN),
+        false if values.is_empty() || values.iter().any(|x| *x == 0.0) => Some(0.0),
+        false => None,
+    }
+}
+
+/// The second, third and fourth central moments of the values.
+fn central_moments(values: &VecDeque<f64>) -> (f64, f64, f64) {
+    let mean = values.mean();
+    let n = values.len() as f64;
+    let (m2, m3, m4) = values.iter().fold((0.0, 0.0, 0.0), |(m2, m3, m4), x| {
+        let deviation = x - mean;
+        let squared = deviation * deviation;
+        (m2 + squared, m3 + squared * deviation, m4 + squared * squared)
+    });
+    (m2 / n, m3 / n, m4 / n)
 }
 
 impl DequeMathExtF64 for VecDeque<f64> {
-    fn mean(&self) -> f64 {
+    fn linear_regression(&self) -> LinearRegressionFit {
+        let n = self.len() as f64;
+        if self.is_empty() {
+            return LinearRegressionFit {
+                slope: 0.0,
+                intercept: 0.0,
+                r_squared: 1.0,
+                standard_error: 0.0,
+            };
+        }
+        let mean_x = (n - 1.0) / 2.0;
+        let mean_y = self.mean();
+        let (sxy, sxx, syy) = self.iter().enumerate().fold(
+            (0.0, 0.0, 0.0),
+            |(sxy, sxx, syy), (x, &y)| {
+                let dx = x as f64 - mean_x;
+                let dy = y - mean_y;
+                (sxy + dx * dy, sxx + dx * dx, syy + dy * dy)
+            },
+        );
+        let slope = match sxx == 0.0 {
+            true => 0.0,
+            false => sxy / sxx,
+        };
+        let r_squared = match syy == 0.0 {
+            true => 1.0,
+            false => (slope * sxy) / syy,
+        };
+        let standard_error = match self.len() > 2 {
+            true => ((syy - slope * sxy).max(0.0) / (n - 2.0)).sqrt(),
+            false => 0.0,
+        };
+        LinearRegressionFit {
+            slope,
+            intercept: mean_y - slope * mean_x,
+            r_squared,
+            standard_error,
+        }
+    }
+
+    fn quantile(&self, quantile: f64, interpolation: QuantileInterpolation) -> f64 {
         if self.is_empty() {
             return 0.0;
         }
-        self.iter().sum::<f64>() / self.len() as f64
+        let mut sorted = self.iter().copied().collect::<Vec<f64>>();
+        sorted.sort_by(f64::total_cmp);
+        let rank = (sorted.len() - 1) as f64 * quantile.clamp(0.0, 1.0);
+        match interpolation {
+            QuantileInterpolation::Nearest => sorted[rank.round() as usize],
+            QuantileInterpolation::Linear => {
+                let lower = rank.floor() as usize;
+                let upper = rank.ceil() as usize;
+                sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+            }
+        }
     }
 
-    fn variance(&self) -> f64 {
-        let mean = self.iter().sum::<f64>() / self.len() as f64;
-        self.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / self.len() as f64
+    fn percentile(&self, percentile: f64, interpolation: QuantileInterpolation) -> f64 {
+        self.quantile(percentile / 100.0, interpolation)
     }
 
-    fn standard_deviation(&self) -> f64 {
-        self.variance().sqrt()
+    fn percent_rank(&self, value: f64) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        100.0 * self.iter().filter(|&&x| x < value).count() as f64 / self.len() as f64
     }
 
-    fn max(&self) -> f64 {
-        self.iter().fold(f64::MIN, |acc, &x| acc.max(x))
+    fn median_absolute_deviation(&self) -> f64 {
+        let median = self.median();
+        self.iter()
+            .map(|x| (x - median).abs())
+            .collect::<VecDeque<f64>>()
+            .median()
     }
 
-    fn min(&self) -> f64 {
-        self.iter().fold(f64::MAX, |acc, &x| acc.min(x))
+    fn skewness(&self, sample_corrected: bool) -> f64 {
+        let n = self.len() as f64;
+        if self.len() < 3 {
+            return 0.0;
+        }
+        let (m2, m3, _) = central_moments(self);
+        if m2 == 0.0 {
+            return 0.0;
+        }
+        let skewness = m3 / m2.powf(1.5);
+        match sample_corrected {
+            true => skewness * (n * (n - 1.0)).sqrt() / (n - 2.0),
+            false => skewness,
+        }
     }
-}
 
+    fn kurtosis(&self, sample_corrected: bool) -> f64 {
+        let n = self.len() as f64;
+        if self.len() < 2 || (sample_corrected && self.len() < 4) {
+            return 0.0;
+        }
+        let (m2, _, m4) = central_moments(self);
+        if m2 == 0.0 {
+            return 0.0;
+        }
+        let excess = m4 / (m2 * m2) - 3.0;
+        match sample_corrected {
+            true => ((n + 1.0) * excess + 6.0) * (n - 1.0) / ((n - 2.0) * (n - 3.0)),
+            false => excess,
+        }
+    }
+
+    fn geometric_mean(&self) -> f64 {
+        if let Some(fallback) = positive_mean_fallback(self) {
+            return fallback;
+        }
+        (self.iter().map(|x| x.ln()).sum::<f64>() / self.len() as f64).exp()
+    }
+
+    fn harmonic_mean(&self) -> f64 {
+        if let Some(fallback) = positive_mean_fallback(self) {
+            return fallback;
+        }
+        self.len() as f64 / self.iter().map(|x| 1.0 / x).sum::<f64>()
+    }
+
+    fn sum_compensated(&self) -> f64 {
+        compensated_sum(self.iter().copied())
+    }
+
+    fn argmax(&self) -> Option<usize> {
+        extreme_index(self, |x, extreme| x >= extreme)
+    }
+
+    fn argmin(&self) -> Option<usize> {
+        extreme_index(self, |x, extreme| x <= extreme)
+    }
+
+    fn argmax_age(&self) -> Option<usize> {
+        self.argmax().map(|index| self.len() - 1 - index)
+    }
+
+    fn argmin_age(&self) -> Option<usize> {
+        self.argmin().map(|index| self.len() - 1 - index)
+    }
+
+    fn zscore_last(&self) -> f64 {
+        let Some(&last) = self.back() else {
+            return 0.0;
+        };
+        // Welford's update gives the mean and squared deviations in a single pass
+        let (count, mean, m2) = self
+            .iter()
+            .fold((0.0, 0.0, 0.0), |(count, mean, m2), &x| {
+                let count = count + 1.0;
+                let delta = x - mean;
+                let mean = mean + delta / count;
+                (count, mean, m2 + delta * (x - mean))
+            });
+        match m2 == 0.0 {
+            true => 0.0,
+            false => (last - mean) / (m2 / count).sqrt(),
+        }
+    }
+
+    fn root_mean_square(&self) -> f64 {
+        self.iter()
+            .map(|x| x * x)
+            .collect::<VecDeque<f64>>()
+            .mean()
+            .sqrt()
+    }
+
+    fn mean_absolute_deviation(&self) -> f64 {
+        mean_distance(self, self.mean())
+    }
+
+    fn mean_absolute_deviation_from_median(&self) -> f64 {
+        mean_distance(self, self.median())
+    }
+
+    fn ew_mean(&self, lambda: f64) -> Result<f64, FinError> {
+        self.weighted_mean(&exponential_weights(self.len(), lambda)?)
+    }
+
+    fn ew_variance(&self, lambda: f64) -> Result<f64, FinError> {
+        let weights = exponential_weights(self.len(), lambda)?;
+        let mean = self.weighted_mean(&weights)?;
+        self.iter()
+            .map(|x| (x - mean).powi(2))
+            .collect::<VecDeque<f64>>()
+            .weighted_mean(&weights)
+    }
+
+    fn mode(&self, bin_width: f64) -> Result<f64, FinError> {
+        if bin_width <= 0.0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Bin width must be greater than 0",
+            ));
+        }
+        let mut counts = BTreeMap::new();
+        for x in self {
+            *counts.entry((x / bin_width).floor() as i64).or_insert(0_usize) += 1;
+        }
+        let mode = counts
+            .into_iter()
+            .fold(None, |best: Option<(i64, usize)>, (bin, count)| match best {
+                Some((_, most)) if most >= count => best,
+                _ => Some((bin, count)),
+            });
+        Ok(mode.map_or(0.0, |(bin, _)| (bin as f64 + 0.5) * bin_width))
+    }
+
+    fn histogram(&self, bins: usize) -> Result<Vec<HistogramBin>, FinError> {
+        if bins == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Bins must be greater than 0",
+            ));
+        }
+        if self.is_empty() {
+            return Ok(Vec::new());
+        }
+        let (min, max) = (self.min(), self.max());
+        let width = (max - min) / bins as f64;
+        let mut histogram = (0..bins)
+            .map(|bin| HistogramBin {
+                lower: min + width * bin as f64,
+                upper: min + width * (bin + 1) as f64,
+                count: 0,
+            })
+            .collect::<Vec<HistogramBin>>();
+        for x in self {
+            let bin = match width == 0.0 {
+                true => 0,
+                false => (((x - min) / width) as usize).min(bins - 1),
+            };
+            histogram[bin].count += 1;
+        }
+        Ok(histogram)
+    }
+
+    fn value_range(&self) -> f64 {
+        extrema(self).map_or(0.0, |(min, max)| max - min)
+    }
+
+    fn midrange(&self) -> f64 {
+        extrema(self).map_or(0.0, |(min, max)| (max + min) / 2.0)
+    }
+
+    fn weighted_mean(&self, weights: &[f64]) -> Result<f64, FinError> {
+        if self.len() != weights.len() {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Weights must be the same length as the values",
+            ));
+        }
+        if self.is_empty() {
+            return Ok(0.0);
+        }
+        let weight_sum = weights.iter().sum::<f64>();
+        match weight_sum == 0.0 {
+            true => Err(FinError::new(
+                FinErrorType::DivideByZero,
+                "Weights must not sum to 0",
+            )),
+            false => Ok(self
+                .iter()
+                .zip(weights.iter())
+                .map(|(x, weight)| x * weight)
+                .sum::<f64>()
+                / weight_sum),
+        }
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use approx::assert_abs_diff_eq;
 
     #[test]
     fn test_mean() {
@@ -50,7 +480,7 @@ mod tests {
 
     #[test]
     fn test_mean_empty() {
-        let values = VecDeque::new();
+        let values = VecDeque::<f64>::new();
         assert_eq!(values.mean(), 0.0);
     }
 
@@ -69,7 +499,7 @@ mod tests {
         values.push_back(1.0);
         values.push_back(2.0);
         values.push_back(3.0);
-        assert_eq!(values.standard_deviation(), (2.0/3.0 as f64).sqrt());
+        assert_eq!(values.standard_deviation(), (2.0_f64/3.0).sqrt());
     }
 
     #[test]
@@ -89,4 +519,324 @@ mod tests {
         values.push_back(3.0);
         assert_eq!(values.min(), 1.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_linear_regression() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 3.0, 5.0, 7.0]);
+        let fit = values.linear_regression();
+        assert_eq!(fit.slope, 2.0);
+        assert_eq!(fit.intercept, 1.0);
+        assert_eq!(fit.r_squared, 1.0);
+        assert_eq!(fit.standard_error, 0.0);
+
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 1.0, 2.0]);
+        let fit = values.linear_regression();
+        assert_eq!(fit.slope, 0.2);
+        assert_eq!(fit.intercept, 1.2);
+        assert_eq!(fit.r_squared, 0.2);
+        assert_abs_diff_eq!(fit.standard_error, 0.4_f64.sqrt(), epsilon = 10e-7);
+
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 3.0]);
+        assert_eq!(values.linear_regression().standard_error, 0.0);
+    }
+
+    #[test]
+    fn test_linear_regression_flat() {
+        let values: VecDeque<f64> = VecDeque::from(vec![4.0]);
+        let fit = values.linear_regression();
+        assert_eq!(fit.slope, 0.0);
+        assert_eq!(fit.intercept, 4.0);
+        assert_eq!(fit.r_squared, 1.0);
+
+        let fit = VecDeque::new().linear_regression();
+        assert_eq!(fit.slope, 0.0);
+        assert_eq!(fit.intercept, 0.0);
+    }
+
+    #[test]
+    fn test_quantile() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0, 4.0, 2.0, 5.0]);
+        let linear = QuantileInterpolation::Linear;
+        assert_eq!(values.quantile(0.0, linear), 1.0);
+        assert_eq!(values.quantile(0.5, linear), 3.0);
+        assert_eq!(values.quantile(1.0, linear), 5.0);
+        assert_eq!(values.quantile(0.1, linear), 1.4);
+        assert_eq!(values.quantile(0.875, linear), 4.5);
+    }
+
+    #[test]
+    fn test_quantile_nearest() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0, 4.0, 2.0, 5.0]);
+        let nearest = QuantileInterpolation::Nearest;
+        assert_eq!(values.quantile(0.0, nearest), 1.0);
+        assert_eq!(values.quantile(0.1, nearest), 1.0);
+        assert_eq!(values.quantile(0.125, nearest), 2.0);
+        assert_eq!(values.quantile(0.8, nearest), 4.0);
+        assert_eq!(values.quantile(1.0, nearest), 5.0);
+    }
+
+    #[test]
+    fn test_quantile_empty() {
+        assert_eq!(VecDeque::new().quantile(0.5, QuantileInterpolation::Linear), 0.0);
+        assert_eq!(VecDeque::new().quantile(0.5, QuantileInterpolation::Nearest), 0.0);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0, 4.0, 2.0, 5.0]);
+        assert_eq!(values.percentile(50.0, QuantileInterpolation::Linear), 3.0);
+        assert_eq!(values.percentile(87.5, QuantileInterpolation::Linear), 4.5);
+        assert_eq!(values.percentile(87.5, QuantileInterpolation::Nearest), 5.0);
+        assert_eq!(values.percentile(150.0, QuantileInterpolation::Nearest), 5.0);
+    }
+
+    #[test]
+    fn test_percent_rank() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0, 4.0, 2.0]);
+        assert_eq!(values.percent_rank(0.0), 0.0);
+        assert_eq!(values.percent_rank(2.5), 50.0);
+        assert_eq!(values.percent_rank(3.0), 50.0);
+        assert_eq!(values.percent_rank(5.0), 100.0);
+        assert_eq!(VecDeque::new().percent_rank(1.0), 0.0);
+    }
+
+    #[test]
+    fn test_median() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0, 100.0]);
+        assert_eq!(values.median(), 3.0);
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0, 100.0, 2.0]);
+        assert_eq!(values.median(), 2.5);
+        assert_eq!(VecDeque::<f64>::new().median(), 0.0);
+    }
+
+    #[test]
+    fn test_median_matches_mean() {
+        let values: VecDeque<f64> = VecDeque::from(vec![7.0]);
+        assert_eq!(values.median(), values.mean());
+        let values: VecDeque<f64> = VecDeque::from(vec![4.0, -2.0]);
+        assert_eq!(values.median(), values.mean());
+        let values: VecDeque<f64> = VecDeque::new();
+        assert_eq!(values.median(), values.mean());
+    }
+
+    #[test]
+    fn test_median_absolute_deviation() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]);
+        assert_eq!(values.median_absolute_deviation(), 1.0);
+        let values: VecDeque<f64> = VecDeque::from(vec![5.0, 5.0, 5.0]);
+        assert_eq!(values.median_absolute_deviation(), 0.0);
+        assert_eq!(VecDeque::new().median_absolute_deviation(), 0.0);
+    }
+
+    #[test]
+    fn test_skewness() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0, 4.0, 10.0]);
+        assert_abs_diff_eq!(values.skewness(false), 1.1384200, epsilon = 10e-7);
+        assert_abs_diff_eq!(values.skewness(true), 1.6970563, epsilon = 10e-7);
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        assert_eq!(values.skewness(false), 0.0);
+    }
+
+    #[test]
+    fn test_kurtosis() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0, 4.0, 10.0]);
+        assert_abs_diff_eq!(values.kurtosis(false), -0.212, epsilon = 10e-7);
+        assert_abs_diff_eq!(values.kurtosis(true), 3.152, epsilon = 10e-7);
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        assert_abs_diff_eq!(values.kurtosis(false), -1.5, epsilon = 10e-7);
+        assert_eq!(values.kurtosis(true), 0.0);
+    }
+
+    #[test]
+    fn test_shape_degenerate() {
+        let values: VecDeque<f64> = VecDeque::from(vec![2.0, 2.0, 2.0, 2.0]);
+        assert_eq!(values.skewness(true), 0.0);
+        assert_eq!(values.kurtosis(true), 0.0);
+        assert_eq!(VecDeque::new().skewness(false), 0.0);
+        assert_eq!(VecDeque::new().kurtosis(false), 0.0);
+    }
+
+    #[test]
+    fn test_geometric_mean() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 4.0]);
+        assert_abs_diff_eq!(values.geometric_mean(), 2.0, epsilon = 10e-7);
+        let values: VecDeque<f64> = VecDeque::from(vec![1.1, 0.9, 1.2]);
+        assert_abs_diff_eq!(values.geometric_mean(), 1.188_f64.cbrt(), epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_harmonic_mean() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 4.0]);
+        assert_abs_diff_eq!(values.harmonic_mean(), 12.0 / 7.0, epsilon = 10e-7);
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 3.0]);
+        assert_eq!(values.harmonic_mean(), 3.0);
+    }
+
+    #[test]
+    fn test_positive_means_fallback() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 0.0, 4.0]);
+        assert_eq!(values.geometric_mean(), 0.0);
+        assert_eq!(values.harmonic_mean(), 0.0);
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, -2.0, 0.0]);
+        assert!(values.geometric_mean().is_nan());
+        assert!(values.harmonic_mean().is_nan());
+        assert_eq!(VecDeque::new().geometric_mean(), 0.0);
+        assert_eq!(VecDeque::new().harmonic_mean(), 0.0);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        assert_eq!(values.weighted_mean(&[1.0, 2.0, 3.0]).unwrap(), 14.0 / 6.0);
+        assert_eq!(values.weighted_mean(&[1.0, 1.0, 1.0]).unwrap(), values.mean());
+        assert_eq!(values.weighted_mean(&[0.0, 0.0, 2.0]).unwrap(), 3.0);
+        assert_eq!(VecDeque::new().weighted_mean(&[]).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_weighted_mean_invalid_weights() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        let error = values.weighted_mean(&[1.0, 2.0]).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::InvalidInput);
+        let error = values.weighted_mean(&[1.0, -1.0, 0.0]).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::DivideByZero);
+    }
+
+    #[test]
+    fn test_sum_compensated() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1e16, 1.0, -1e16]);
+        assert_eq!(values.iter().sum::<f64>(), 0.0);
+        assert_eq!(values.sum_compensated(), 1.0);
+        assert_eq!(VecDeque::new().sum_compensated(), 0.0);
+    }
+
+    #[test]
+    fn test_mean_large_prices() {
+        let values: VecDeque<f64> = (0..1000).map(|_| 100_000.1).collect();
+        assert_eq!(values.mean(), 100_000.1);
+        assert_eq!(values.variance(), 0.0);
+    }
+
+    #[test]
+    fn test_argmax() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 5.0, 3.0, 2.0]);
+        assert_eq!(values.argmax(), Some(1));
+        assert_eq!(values.argmax_age(), Some(2));
+        let values: VecDeque<f64> = VecDeque::from(vec![5.0, 1.0, 5.0, 2.0]);
+        assert_eq!(values.argmax(), Some(2));
+        assert_eq!(values.argmax_age(), Some(1));
+        assert_eq!(VecDeque::new().argmax(), None);
+        assert_eq!(VecDeque::new().argmax_age(), None);
+    }
+
+    #[test]
+    fn test_argmin() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0, 4.0, 2.0]);
+        assert_eq!(values.argmin(), Some(1));
+        assert_eq!(values.argmin_age(), Some(2));
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 3.0, 4.0, 1.0]);
+        assert_eq!(values.argmin(), Some(3));
+        assert_eq!(values.argmin_age(), Some(0));
+        assert_eq!(VecDeque::new().argmin(), None);
+    }
+
+    #[test]
+    fn test_zscore_last() {
+        let values: VecDeque<f64> = VecDeque::from(vec![2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_abs_diff_eq!(values.zscore_last(), 2.0, epsilon = 10e-7);
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        assert_abs_diff_eq!(
+            values.zscore_last(),
+            1.0 / values.standard_deviation(),
+            epsilon = 10e-7
+        );
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 3.0]);
+        assert_eq!(values.zscore_last(), 0.0);
+        assert_eq!(VecDeque::new().zscore_last(), 0.0);
+    }
+
+    #[test]
+    fn test_root_mean_square() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, -7.0, 1.0, 1.0]);
+        assert_eq!(values.root_mean_square(), 13.0_f64.sqrt());
+        assert_eq!(VecDeque::new().root_mean_square(), 0.0);
+    }
+
+    #[test]
+    fn test_mean_absolute_deviation() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0, 10.0]);
+        assert_eq!(values.mean_absolute_deviation(), 3.0);
+        assert_eq!(values.mean_absolute_deviation_from_median(), 2.5);
+        assert_eq!(VecDeque::new().mean_absolute_deviation(), 0.0);
+        assert_eq!(VecDeque::new().mean_absolute_deviation_from_median(), 0.0);
+    }
+
+    #[test]
+    fn test_ew_mean() {
+        let values: VecDeque<f64> = VecDeque::from(vec![4.0, 2.0, 1.0]);
+        assert_abs_diff_eq!(values.ew_mean(0.5).unwrap(), 3.0 / 1.75, epsilon = 10e-7);
+        assert_eq!(values.ew_mean(1.0).unwrap(), values.mean());
+        assert_eq!(VecDeque::new().ew_mean(0.5).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_ew_variance() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, 1.0]);
+        assert_abs_diff_eq!(values.ew_variance(0.5).unwrap(), 8.0 / 9.0, epsilon = 10e-7);
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        assert_abs_diff_eq!(
+            values.ew_variance(1.0).unwrap(),
+            values.variance(),
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_ew_invalid_lambda() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.0, 2.0]);
+        assert!(values.ew_mean(0.0).is_err());
+        assert!(values.ew_variance(1.5).is_err());
+    }
+
+    #[test]
+    fn test_mode() {
+        let values: VecDeque<f64> = VecDeque::from(vec![1.2, 3.1, 3.4, 1.9, 3.9, 0.1]);
+        assert_eq!(values.mode(1.0).unwrap(), 3.5);
+        assert_eq!(values.mode(2.0).unwrap(), 1.0);
+        let values: VecDeque<f64> = VecDeque::from(vec![-0.5, -0.2, 0.5, 0.7]);
+        assert_eq!(values.mode(1.0).unwrap(), -0.5);
+        assert_eq!(VecDeque::new().mode(1.0).unwrap(), 0.0);
+        assert!(values.mode(0.0).is_err());
+    }
+
+    #[test]
+    fn test_histogram() {
+        let values: VecDeque<f64> = VecDeque::from(vec![0.0, 1.0, 1.5, 2.5, 4.0]);
+        let histogram = values.histogram(2).unwrap();
+        assert_eq!(
+            histogram,
+            vec![
+                HistogramBin { lower: 0.0, upper: 2.0, count: 3 },
+                HistogramBin { lower: 2.0, upper: 4.0, count: 2 },
+            ]
+        );
+        let values: VecDeque<f64> = VecDeque::from(vec![2.0, 2.0]);
+        let histogram = values.histogram(3).unwrap();
+        assert_eq!(histogram[0].count, 2);
+        assert_eq!(histogram[2].count, 0);
+        assert_eq!(VecDeque::new().histogram(3).unwrap(), vec![]);
+        assert!(values.histogram(0).is_err());
+    }
+
+    #[test]
+    fn test_value_range() {
+        let values: VecDeque<f64> = VecDeque::from(vec![3.0, -1.0, 7.0, 2.0]);
+        assert_eq!(values.value_range(), 8.0);
+        assert_eq!(values.midrange(), 3.0);
+        let values: VecDeque<f64> = VecDeque::from(vec![5.0]);
+        assert_eq!(values.value_range(), 0.0);
+        assert_eq!(values.midrange(), 5.0);
+        assert_eq!(VecDeque::new().value_range(), 0.0);
+        assert_eq!(VecDeque::new().midrange(), 0.0);
+    }
+}
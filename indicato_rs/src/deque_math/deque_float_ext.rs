@@ -0,0 +1,107 @@
+use std::{cmp::Ordering, collections::VecDeque};
+
+use num_traits::Float;
+
+use super::compensated_sum;
+
+/// The core window statistics for deques of any floating point type, such as `f32` windows for memory constrained
+/// processing. `VecDeque<f64>` windows have the rest of the statistics through
+/// [`DequeMathExtF64`](super::DequeMathExtF64), which builds on this trait.
+///
+/// # Example
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::DequeMathExt;
+///
+/// let values: VecDeque<f32> = VecDeque::from(vec![1.0, 2.0, 3.0, 10.0]);
+/// assert_eq!(values.mean(), 4.0);
+/// assert_eq!(values.median(), 2.5);
+/// assert_eq!(values.max(), 10.0);
+/// ```
+pub trait DequeMathExt<T> {
+    fn mean(&self) -> T;
+    fn variance(&self) -> T;
+    fn standard_deviation(&self) -> T;
+    fn max(&self) -> T;
+    fn min(&self) -> T;
+    fn median(&self) -> T;
+}
+
+fn length<T: Float>(values: &VecDeque<T>) -> T {
+    T::from(values.len()).unwrap_or_else(T::nan)
+}
+
+impl<T: Float> DequeMathExt<T> for VecDeque<T> {
+    fn mean(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+        compensated_sum(self.iter().copied()) / length(self)
+    }
+
+    fn variance(&self) -> T {
+        let mean = compensated_sum(self.iter().copied()) / length(self);
+        compensated_sum(self.iter().map(|&x| (x - mean).powi(2))) / length(self)
+    }
+
+    fn standard_deviation(&self) -> T {
+        self.variance().sqrt()
+    }
+
+    fn max(&self) -> T {
+        self.iter().fold(T::min_value(), |acc, &x| acc.max(x))
+    }
+
+    fn min(&self) -> T {
+        self.iter().fold(T::max_value(), |acc, &x| acc.min(x))
+    }
+
+    fn median(&self) -> T {
+        if self.is_empty() {
+            return T::zero();
+        }
+        let mut sorted = self.iter().copied().collect::<Vec<T>>();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+        let middle = sorted.len() / 2;
+        match sorted.len() % 2 {
+            1 => sorted[middle],
+            _ => (sorted[middle - 1] + sorted[middle]) / (T::one() + T::one()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32() {
+        let values: VecDeque<f32> = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        assert_eq!(values.mean(), 2.0);
+        assert_eq!(values.variance(), 2.0 / 3.0);
+        assert_eq!(values.standard_deviation(), (2.0_f32 / 3.0).sqrt());
+        assert_eq!(values.max(), 3.0);
+        assert_eq!(values.min(), 1.0);
+        assert_eq!(values.median(), 2.0);
+    }
+
+    #[test]
+    fn test_with_f64_extension() {
+        // the f64 extension builds on the core statistics, so importing both leaves no method ambiguous
+        use crate::deque_math::{DequeMathExtF64, QuantileInterpolation};
+
+        let values: VecDeque<f64> = VecDeque::from(vec![4.0, -2.0, 9.0, 1.5]);
+        assert_eq!(values.mean(), 3.125);
+        assert_eq!(values.max(), 9.0);
+        assert_eq!(values.min(), -2.0);
+        assert_eq!(values.median(), values.quantile(0.5, QuantileInterpolation::Linear));
+        assert_eq!(values.value_range(), values.max() - values.min());
+    }
+
+    #[test]
+    fn test_empty() {
+        let values: VecDeque<f32> = VecDeque::new();
+        assert_eq!(values.mean(), 0.0);
+        assert_eq!(values.median(), 0.0);
+    }
+}
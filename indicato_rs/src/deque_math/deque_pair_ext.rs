@@ -0,0 +1,285 @@
+use std::collections::VecDeque;
+
+use crate::fin_error::{FinError, FinErrorType};
+
+pub trait DequePairMathExtF64 {
+    fn means(&self) -> (f64, f64);
+    fn variances(&self) -> (f64, f64);
+    fn covariance(&self) -> f64;
+    fn correlation(&self) -> f64;
+    fn dot_product(&self) -> f64;
+    fn differences(&self) -> VecDeque<f64>;
+}
+
+impl DequePairMathExtF64 for VecDeque<(f64, f64)> {
+    fn means(&self) -> (f64, f64) {
+        if self.is_empty() {
+            return (0.0, 0.0);
+        }
+        let (sum_x, sum_y) = self
+            .iter()
+            .fold((0.0, 0.0), |(sum_x, sum_y), (x, y)| (sum_x + x, sum_y + y));
+        (sum_x / self.len() as f64, sum_y / self.len() as f64)
+    }
+
+    fn variances(&self) -> (f64, f64) {
+        if self.is_empty() {
+            return (0.0, 0.0);
+        }
+        let (mean_x, mean_y) = self.means();
+        let (sxx, syy) = self.iter().fold((0.0, 0.0), |(sxx, syy), (x, y)| {
+            (sxx + (x - mean_x).powi(2), syy + (y - mean_y).powi(2))
+        });
+        (sxx / self.len() as f64, syy / self.len() as f64)
+    }
+
+    fn covariance(&self) -> f64 {
+        if self.is_empty() {
+            return 0.0;
+        }
+        let (mean_x, mean_y) = self.means();
+        self.iter()
+            .map(|(x, y)| (x - mean_x) * (y - mean_y))
+            .sum::<f64>()
+            / self.len() as f64
+    }
+
+    fn correlation(&self) -> f64 {
+        let (variance_x, variance_y) = self.variances();
+        match variance_x == 0.0 || variance_y == 0.0 {
+            true => 0.0,
+            false => self.covariance() / (variance_x * variance_y).sqrt(),
+        }
+    }
+
+    fn dot_product(&self) -> f64 {
+        self.iter().map(|(x, y)| x * y).sum()
+    }
+
+    fn differences(&self) -> VecDeque<f64> {
+        self.iter().map(|(x, y)| x - y).collect()
+    }
+}
+
+fn paired(first: &VecDeque<f64>, second: &VecDeque<f64>) -> Result<VecDeque<(f64, f64)>, FinError> {
+    match first.len() == second.len() {
+        true => Ok(first.iter().copied().zip(second.iter().copied()).collect()),
+        false => Err(FinError::new(
+            FinErrorType::InvalidInput,
+            "Deques must be of equal length",
+        )),
+    }
+}
+
+/// Calculates the population covariance between two deques of equal length, paired by index.
+///
+/// # Arguments
+/// * `first` - The first deque of values
+/// * `second` - The second deque of values
+///
+/// # Example
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::covariance;
+///
+/// let first = VecDeque::from(vec![1.0, 2.0, 3.0]);
+/// let second = VecDeque::from(vec![3.0, 2.0, 1.0]);
+/// assert_eq!(covariance(&first, &second).unwrap(), -2.0 / 3.0);
+/// ```
+/// # Errors
+/// Will return an error if the deques are not of equal length
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::covariance;
+///
+/// let first = VecDeque::from(vec![1.0, 2.0, 3.0]);
+/// let second = VecDeque::from(vec![3.0, 2.0]);
+/// assert!(covariance(&first, &second).is_err());
+/// ```
+pub fn covariance(first: &VecDeque<f64>, second: &VecDeque<f64>) -> Result<f64, FinError> {
+    paired(first, second).map(|values| values.covariance())
+}
+
+/// Calculates the Pearson correlation between two deques of equal length, paired by index. If either deque has no
+/// variance the correlation is 0.
+///
+/// # Arguments
+/// * `first` - The first deque of values
+/// * `second` - The second deque of values
+///
+/// # Example
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::correlation;
+///
+/// let first = VecDeque::from(vec![1.0, 2.0, 3.0]);
+/// let second = VecDeque::from(vec![2.0, 4.0, 6.0]);
+/// assert_eq!(correlation(&first, &second).unwrap(), 1.0);
+/// ```
+/// # Errors
+/// Will return an error if the deques are not of equal length
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::correlation;
+///
+/// let first = VecDeque::from(vec![1.0]);
+/// let second = VecDeque::new();
+/// assert!(correlation(&first, &second).is_err());
+/// ```
+pub fn correlation(first: &VecDeque<f64>, second: &VecDeque<f64>) -> Result<f64, FinError> {
+    paired(first, second).map(|values| values.correlation())
+}
+
+/// Calculates the sum of the products of two deques of equal length, paired by index.
+///
+/// # Arguments
+/// * `first` - The first deque of values
+/// * `second` - The second deque of values
+///
+/// # Example
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::dot_product;
+///
+/// let first = VecDeque::from(vec![1.0, 2.0, 3.0]);
+/// let second = VecDeque::from(vec![4.0, -5.0, 6.0]);
+/// assert_eq!(dot_product(&first, &second).unwrap(), 12.0);
+/// ```
+/// # Errors
+/// Will return an error if the deques are not of equal length
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::dot_product;
+///
+/// let first = VecDeque::from(vec![1.0, 2.0]);
+/// assert!(dot_product(&first, &VecDeque::new()).is_err());
+/// ```
+pub fn dot_product(first: &VecDeque<f64>, second: &VecDeque<f64>) -> Result<f64, FinError> {
+    paired(first, second).map(|values| values.dot_product())
+}
+
+/// Calculates the element wise differences of the first deque less the second, paired by index.
+///
+/// # Arguments
+/// * `first` - The deque of values subtracted from
+/// * `second` - The deque of values subtracted
+///
+/// # Example
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::differences;
+///
+/// let first = VecDeque::from(vec![5.0, 2.0, 3.0]);
+/// let second = VecDeque::from(vec![1.0, 4.0, 3.0]);
+/// assert_eq!(differences(&first, &second).unwrap(), VecDeque::from(vec![4.0, -2.0, 0.0]));
+/// ```
+/// # Errors
+/// Will return an error if the deques are not of equal length
+/// ```
+/// use std::collections::VecDeque;
+/// use indicato_rs::deque_math::differences;
+///
+/// let first = VecDeque::from(vec![1.0, 2.0]);
+/// assert!(differences(&first, &VecDeque::from(vec![1.0])).is_err());
+/// ```
+pub fn differences(
+    first: &VecDeque<f64>,
+    second: &VecDeque<f64>,
+) -> Result<VecDeque<f64>, FinError> {
+    paired(first, second).map(|values| values.differences())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_means() {
+        let values = VecDeque::from(vec![(1.0, 2.0), (2.0, 4.0), (3.0, 9.0)]);
+        assert_eq!(values.means(), (2.0, 5.0));
+    }
+
+    #[test]
+    fn test_variances() {
+        let values = VecDeque::from(vec![(1.0, 2.0), (2.0, 4.0), (3.0, 9.0)]);
+        assert_eq!(values.variances(), (2.0 / 3.0, 26.0 / 3.0));
+    }
+
+    #[test]
+    fn test_covariance() {
+        let values = VecDeque::from(vec![(1.0, 2.0), (2.0, 4.0), (3.0, 9.0)]);
+        assert_eq!(values.covariance(), 7.0 / 3.0);
+
+        let values = VecDeque::from(vec![(1.0, 3.0), (2.0, 2.0), (3.0, 1.0)]);
+        assert_eq!(values.covariance(), -2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_correlation() {
+        let values = VecDeque::from(vec![(1.0, 2.0), (2.0, 4.0), (3.0, 6.0)]);
+        assert_eq!(values.correlation(), 1.0);
+
+        let values = VecDeque::from(vec![(1.0, 3.0), (2.0, 2.0), (3.0, 1.0)]);
+        assert_eq!(values.correlation(), -1.0);
+
+        let values = VecDeque::from(vec![(1.0, 2.0), (2.0, 2.0), (3.0, 2.0)]);
+        assert_eq!(values.correlation(), 0.0);
+    }
+
+    #[test]
+    fn test_empty() {
+        let values = VecDeque::new();
+        assert_eq!(values.means(), (0.0, 0.0));
+        assert_eq!(values.variances(), (0.0, 0.0));
+        assert_eq!(values.covariance(), 0.0);
+        assert_eq!(values.correlation(), 0.0);
+    }
+
+    #[test]
+    fn test_separate_deques() {
+        let first = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        let second = VecDeque::from(vec![2.0, 4.0, 9.0]);
+        assert_eq!(covariance(&first, &second).unwrap(), 7.0 / 3.0);
+        let second = VecDeque::from(vec![3.0, 2.0, 1.0]);
+        assert_eq!(correlation(&first, &second).unwrap(), -1.0);
+        assert_eq!(
+            correlation(&VecDeque::new(), &VecDeque::new()).unwrap(),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_separate_deques_length_mismatch() {
+        let first = VecDeque::from(vec![1.0, 2.0, 3.0]);
+        let second = VecDeque::from(vec![2.0, 4.0]);
+        let error = covariance(&first, &second).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::InvalidInput);
+        assert!(correlation(&second, &first).is_err());
+    }
+
+    #[test]
+    fn test_dot_product() {
+        let values = VecDeque::from(vec![(1.0, 4.0), (2.0, -5.0), (3.0, 6.0)]);
+        assert_eq!(values.dot_product(), 12.0);
+        assert_eq!(VecDeque::new().dot_product(), 0.0);
+    }
+
+    #[test]
+    fn test_differences() {
+        let values = VecDeque::from(vec![(5.0, 1.0), (2.0, 4.0), (3.0, 3.0)]);
+        assert_eq!(values.differences(), VecDeque::from(vec![4.0, -2.0, 0.0]));
+        assert!(VecDeque::new().differences().is_empty());
+    }
+
+    #[test]
+    fn test_separate_deque_helpers() {
+        let first = VecDeque::from(vec![1.0, 2.0]);
+        let second = VecDeque::from(vec![3.0, 5.0]);
+        assert_eq!(dot_product(&first, &second).unwrap(), 13.0);
+        assert_eq!(
+            differences(&second, &first).unwrap(),
+            VecDeque::from(vec![2.0, 3.0])
+        );
+        assert!(dot_product(&first, &VecDeque::from(vec![1.0])).is_err());
+    }
+}
@@ -1,2 +1,14 @@
 mod deque_ext;
-pub use deque_ext::DequeMathExtF64;
\ No newline at end of file
+pub(crate) use deque_ext::{compensated_mean, compensated_sum};
+pub use deque_ext::{DequeMathExtF64, HistogramBin, LinearRegressionFit, QuantileInterpolation};
+
+mod deque_float_ext;
+pub use deque_float_ext::DequeMathExt;
+
+mod deque_pair_ext;
+pub use deque_pair_ext::{correlation, covariance, differences, dot_product, DequePairMathExtF64};
+
+mod pivot_points;
+pub use pivot_points::{pivot_points, PivotPointMethod, PivotPoints};
+mod rolling_statistics;
+pub use rolling_statistics::RollingStatistics;
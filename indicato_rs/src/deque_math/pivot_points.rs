@@ -0,0 +1,184 @@
+use crate::fin_error::{FinError, FinErrorType};
+
+/// The method used to calculate pivot point levels.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PivotPointMethod {
+    /// Floor trader pivots, levels are projected from the pivot using the session range.
+    Classic,
+    /// Levels are placed at fibonacci ratios of the session range from the pivot.
+    Fibonacci,
+    /// Levels are placed at fractions of the session range from the close.
+    Camarilla,
+    /// Classic levels calculated from a pivot that gives the close double weighting.
+    Woodie,
+}
+
+/// Support and resistance levels produced by [`pivot_points`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PivotPoints {
+    /// The pivot point.
+    pub pivot: f64,
+    /// The first resistance level.
+    pub r1: f64,
+    /// The second resistance level.
+    pub r2: f64,
+    /// The third resistance level.
+    pub r3: f64,
+    /// The first support level.
+    pub s1: f64,
+    /// The second support level.
+    pub s2: f64,
+    /// The third support level.
+    pub s3: f64,
+}
+
+fn floor_levels(pivot: f64, high: f64, low: f64) -> PivotPoints {
+    let range = high - low;
+    PivotPoints {
+        pivot,
+        r1: 2.0 * pivot - low,
+        r2: pivot + range,
+        r3: high + 2.0 * (pivot - low),
+        s1: 2.0 * pivot - high,
+        s2: pivot - range,
+        s3: low - 2.0 * (high - pivot),
+    }
+}
+
+/// Calculates the pivot point support and resistance levels for a session from its high, low and close.
+///
+/// Pivot points are calculated once per session, typically from the previous session's values, as opposed
+/// to signals which are aggregated over a stream of inputs.
+///
+/// # Arguments
+/// * `high` - The high of the session
+/// * `low` - The low of the session
+/// * `close` - The close of the session
+/// * `method` - The method used to calculate the levels
+///
+/// # Example
+/// ```
+/// use indicato_rs::deque_math::{pivot_points, PivotPointMethod};
+///
+/// let levels = pivot_points(12.0, 6.0, 9.0, PivotPointMethod::Classic).unwrap();
+/// assert_eq!(levels.pivot, 9.0);
+/// assert_eq!(levels.r1, 12.0);
+/// assert_eq!(levels.s1, 6.0);
+/// ```
+/// # Errors
+/// Will return an error if the high is less than the low
+/// ```
+/// use indicato_rs::deque_math::{pivot_points, PivotPointMethod};
+///
+/// assert!(pivot_points(6.0, 12.0, 9.0, PivotPointMethod::Classic).is_err());
+/// ```
+pub fn pivot_points(
+    high: f64,
+    low: f64,
+    close: f64,
+    method: PivotPointMethod,
+) -> Result<PivotPoints, FinError> {
+    if high < low {
+        return Err(FinError::new(
+            FinErrorType::InvalidInput,
+            "High must be greater than or equal to low",
+        ));
+    }
+    let range = high - low;
+    let classic_pivot = (high + low + close) / 3.0;
+    Ok(match method {
+        PivotPointMethod::Classic => floor_levels(classic_pivot, high, low),
+        PivotPointMethod::Woodie => floor_levels((high + low + 2.0 * close) / 4.0, high, low),
+        PivotPointMethod::Fibonacci => PivotPoints {
+            pivot: classic_pivot,
+            r1: classic_pivot + 0.382 * range,
+            r2: classic_pivot + 0.618 * range,
+            r3: classic_pivot + range,
+            s1: classic_pivot - 0.382 * range,
+            s2: classic_pivot - 0.618 * range,
+            s3: classic_pivot - range,
+        },
+        PivotPointMethod::Camarilla => PivotPoints {
+            pivot: classic_pivot,
+            r1: close + 1.1 * range / 12.0,
+            r2: close + 1.1 * range / 6.0,
+            r3: close + 1.1 * range / 4.0,
+            s1: close - 1.1 * range / 12.0,
+            s2: close - 1.1 * range / 6.0,
+            s3: close - 1.1 * range / 4.0,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_classic() {
+        let levels = pivot_points(12.0, 6.0, 9.0, PivotPointMethod::Classic).unwrap();
+        assert_eq!(
+            levels,
+            PivotPoints {
+                pivot: 9.0,
+                r1: 12.0,
+                r2: 15.0,
+                r3: 18.0,
+                s1: 6.0,
+                s2: 3.0,
+                s3: 0.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_woodie() {
+        let levels = pivot_points(12.0, 6.0, 11.0, PivotPointMethod::Woodie).unwrap();
+        assert_eq!(
+            levels,
+            PivotPoints {
+                pivot: 10.0,
+                r1: 14.0,
+                r2: 16.0,
+                r3: 20.0,
+                s1: 8.0,
+                s2: 4.0,
+                s3: 2.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fibonacci() {
+        let levels = pivot_points(12.0, 2.0, 10.0, PivotPointMethod::Fibonacci).unwrap();
+        assert_eq!(levels.pivot, 8.0);
+        assert_abs_diff_eq!(levels.r1, 11.82, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.r2, 14.18, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.r3, 18.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.s1, 4.18, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.s2, 1.82, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.s3, -2.0, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_camarilla() {
+        let levels = pivot_points(18.0, 6.0, 12.0, PivotPointMethod::Camarilla).unwrap();
+        assert_eq!(levels.pivot, 12.0);
+        assert_abs_diff_eq!(levels.r1, 13.1, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.r2, 14.2, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.r3, 15.3, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.s1, 10.9, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.s2, 9.8, epsilon = 10e-7);
+        assert_abs_diff_eq!(levels.s3, 8.7, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_invalid_range() {
+        let levels = pivot_points(6.0, 12.0, 9.0, PivotPointMethod::Classic);
+        assert!(levels.is_err());
+    }
+}
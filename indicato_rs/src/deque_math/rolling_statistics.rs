@@ -0,0 +1,184 @@
+use std::collections::VecDeque;
+
+/// Incrementally maintained statistics of a window of values, giving a constant time mean and variance as values
+/// enter and leave the window rather than iterating the whole window each step.
+///
+/// The mean and squared deviations are updated with Welford's algorithm. When created with extrema the minimum and
+/// maximum are also tracked, which requires values to be popped in the same order they were pushed.
+///
+/// # Example
+/// ```
+/// use indicato_rs::deque_math::RollingStatistics;
+///
+/// let mut statistics = RollingStatistics::with_extrema();
+/// statistics.push(2.0);
+/// statistics.push(4.0);
+/// statistics.push(9.0);
+/// assert_eq!(statistics.mean(), 5.0);
+/// assert_eq!(statistics.max(), Some(9.0));
+///
+/// // remove the oldest value as the window moves on
+/// statistics.pop(2.0);
+/// assert_eq!(statistics.count(), 2);
+/// assert_eq!(statistics.mean(), 6.5);
+/// assert_eq!(statistics.variance(), 6.25);
+/// assert_eq!(statistics.min(), Some(4.0));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingStatistics {
+    count: usize,
+    mean: f64,
+    /// The sum of squared deviations from the mean.
+    m2: f64,
+    /// Monotonic candidates for the (minimum, maximum), the front of each is the current extreme.
+    extrema: Option<(VecDeque<f64>, VecDeque<f64>)>,
+}
+
+impl RollingStatistics {
+    /// Create a new Rolling Statistics instance tracking the count, mean and variance
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a new Rolling Statistics instance that also tracks the minimum and maximum
+    pub fn with_extrema() -> Self {
+        Self {
+            extrema: Some((VecDeque::new(), VecDeque::new())),
+            ..Self::default()
+        }
+    }
+
+    /// Add a value to the window
+    pub fn push(&mut self, value: f64) {
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+        if let Some((minimums, maximums)) = &mut self.extrema {
+            while minimums.back().is_some_and(|minimum| *minimum > value) {
+                minimums.pop_back();
+            }
+            minimums.push_back(value);
+            while maximums.back().is_some_and(|maximum| *maximum < value) {
+                maximums.pop_back();
+            }
+            maximums.push_back(value);
+        }
+    }
+
+    /// Remove a value from the window, when tracking extrema this must be the oldest value in the window
+    pub fn pop(&mut self, value: f64) {
+        match self.count {
+            0 => return,
+            1 => {
+                self.count = 0;
+                self.mean = 0.0;
+                self.m2 = 0.0;
+            }
+            _ => {
+                let delta = value - self.mean;
+                self.count -= 1;
+                self.mean -= delta / self.count as f64;
+                self.m2 = (self.m2 - delta * (value - self.mean)).max(0.0);
+            }
+        }
+        if let Some((minimums, maximums)) = &mut self.extrema {
+            if minimums.front() == Some(&value) {
+                minimums.pop_front();
+            }
+            if maximums.front() == Some(&value) {
+                maximums.pop_front();
+            }
+        }
+    }
+
+    /// The number of values in the window
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The mean of the window, 0 when empty
+    pub fn mean(&self) -> f64 {
+        self.mean
+    }
+
+    /// The population variance of the window, 0 when empty
+    pub fn variance(&self) -> f64 {
+        match self.count {
+            0 => 0.0,
+            count => self.m2 / count as f64,
+        }
+    }
+
+    /// The population standard deviation of the window, 0 when empty
+    pub fn standard_deviation(&self) -> f64 {
+        self.variance().sqrt()
+    }
+
+    /// The minimum of the window, `None` when empty or not tracking extrema
+    pub fn min(&self) -> Option<f64> {
+        self.extrema
+            .as_ref()
+            .and_then(|(minimums, _)| minimums.front().copied())
+    }
+
+    /// The maximum of the window, `None` when empty or not tracking extrema
+    pub fn max(&self) -> Option<f64> {
+        self.extrema
+            .as_ref()
+            .and_then(|(_, maximums)| maximums.front().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deque_math::DequeMathExt;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_push() {
+        let mut statistics = RollingStatistics::new();
+        assert_eq!(statistics.mean(), 0.0);
+        assert_eq!(statistics.variance(), 0.0);
+        for value in [1.0, 2.0, 3.0] {
+            statistics.push(value);
+        }
+        assert_eq!(statistics.count(), 3);
+        assert_eq!(statistics.mean(), 2.0);
+        assert_abs_diff_eq!(statistics.variance(), 2.0 / 3.0, epsilon = 10e-7);
+        assert_eq!(statistics.min(), None);
+        assert_eq!(statistics.max(), None);
+    }
+
+    #[test]
+    fn test_matches_window() {
+        let inputs = [5.0, 3.0, 8.0, 1.0, 9.0, 9.0, 2.0, 7.0, 4.0, 6.0];
+        let mut statistics = RollingStatistics::with_extrema();
+        let mut window = VecDeque::new();
+        for input in inputs {
+            statistics.push(input);
+            window.push_back(input);
+            if window.len() > 4 {
+                statistics.pop(window.pop_front().unwrap());
+            }
+            assert_eq!(statistics.count(), window.len());
+            assert_abs_diff_eq!(statistics.mean(), window.mean(), epsilon = 10e-7);
+            assert_abs_diff_eq!(statistics.variance(), window.variance(), epsilon = 10e-7);
+            assert_eq!(statistics.min(), Some(window.min()));
+            assert_eq!(statistics.max(), Some(window.max()));
+        }
+    }
+
+    #[test]
+    fn test_pop_to_empty() {
+        let mut statistics = RollingStatistics::with_extrema();
+        statistics.push(4.0);
+        statistics.pop(4.0);
+        assert_eq!(statistics, RollingStatistics::with_extrema());
+        statistics.pop(4.0);
+        assert_eq!(statistics.count(), 0);
+        assert_eq!(statistics.max(), None);
+    }
+}
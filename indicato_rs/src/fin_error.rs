@@ -1,8 +1,13 @@
+/// The kind of a [`FinError`].
+///
+/// Kinds of error may be added in later releases, so a match on the kind needs a wildcard arm.
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum FinErrorType {
     DivideByZero,
     InvalidInput,
     InvalidOperation,
+    IncompatibleVersion,
 }
 
 #[derive(Debug)]
@@ -0,0 +1,281 @@
+use std::collections::VecDeque;
+
+use crate::traits::Inspect;
+
+/// A structured view of the internal state of a signal, returned by [`Inspect::inspect`].
+///
+/// Signals built from other signals hold the state of each inner signal as a [`StateValue::Nested`] field, so the
+/// whole tree of state can be walked or printed when tracking down why a signal disagrees with another source.
+///
+/// # Example
+/// ```
+/// use indicato_rs::inspect::StateValue;
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::traits::{Apply, Inspect};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// sma.apply(1.0);
+/// sma.apply(3.0);
+///
+/// let state = sma.inspect();
+/// assert_eq!(state.name, "SimpleMovingAverage");
+/// assert_eq!(state.get("period"), Some(&StateValue::Count(2)));
+/// assert_eq!(
+///     state.get("values"),
+///     Some(&StateValue::List(vec![StateValue::Number(1.0), StateValue::Number(3.0)]))
+/// );
+/// ```
+///
+/// With the `serde` feature a state can be serialized, such as to send it to a dashboard. It can't be deserialized, as
+/// the names of the signal and its fields are static.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SignalState {
+    /// The name of the signal type, without its type parameters
+    pub name: &'static str,
+    /// The internal fields of the signal, in the order they are declared
+    pub fields: Vec<(&'static str, StateValue)>,
+}
+
+impl SignalState {
+    /// Create a new Signal State with no fields
+    pub fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds a field to the state
+    pub fn field(mut self, name: &'static str, value: StateValue) -> Self {
+        self.fields.push((name, value));
+        self
+    }
+
+    /// The value of the field called `name`, if the signal has one
+    pub fn get(&self, name: &str) -> Option<&StateValue> {
+        self.fields
+            .iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+/// A value held in the internal state of a signal.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum StateValue {
+    /// A flag
+    Bool(bool),
+    /// A count or length, such as a period or the number of values seen
+    Count(usize),
+    /// A whole number, such as a timestamp
+    Integer(i64),
+    /// A number being aggregated
+    Number(f64),
+    /// A value with no numeric view, such as the mode of a signal or a decimal kept exactly
+    Text(String),
+    /// A value that isn't set yet, such as a seed before the signal is warmed up
+    None,
+    /// A window of values or the members of a tuple
+    List(Vec<StateValue>),
+    /// The state of an inner signal or structure
+    Nested(SignalState),
+}
+
+/// Converts a field of a signal into a [`StateValue`] when it is inspected.
+pub trait ToState {
+    /// The value of the field
+    fn to_state(&self) -> StateValue;
+}
+
+/// Implements [`ToState`] for types with no numeric view, such as the modes of signals, as the text of their `Debug`
+/// output.
+macro_rules! debug_state {
+    ($($ty:ty),+) => {
+        $(impl crate::inspect::ToState for $ty {
+            fn to_state(&self) -> crate::inspect::StateValue {
+                crate::inspect::StateValue::Text(format!("{:?}", self))
+            }
+        })+
+    };
+}
+pub(crate) use debug_state;
+
+impl<S: Inspect> ToState for S {
+    fn to_state(&self) -> StateValue {
+        StateValue::Nested(self.inspect())
+    }
+}
+
+impl ToState for bool {
+    fn to_state(&self) -> StateValue {
+        StateValue::Bool(*self)
+    }
+}
+
+impl ToState for usize {
+    fn to_state(&self) -> StateValue {
+        StateValue::Count(*self)
+    }
+}
+
+impl ToState for i64 {
+    fn to_state(&self) -> StateValue {
+        StateValue::Integer(*self)
+    }
+}
+
+impl ToState for f64 {
+    fn to_state(&self) -> StateValue {
+        StateValue::Number(*self)
+    }
+}
+
+impl ToState for f32 {
+    fn to_state(&self) -> StateValue {
+        StateValue::Number(f64::from(*self))
+    }
+}
+
+impl ToState for String {
+    fn to_state(&self) -> StateValue {
+        StateValue::Text(self.clone())
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl ToState for rust_decimal::Decimal {
+    fn to_state(&self) -> StateValue {
+        StateValue::Text(self.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToState for chrono::DateTime<chrono::Utc> {
+    fn to_state(&self) -> StateValue {
+        StateValue::Text(self.to_rfc3339())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl ToState for chrono::TimeDelta {
+    fn to_state(&self) -> StateValue {
+        StateValue::Text(self.to_string())
+    }
+}
+
+impl<T: ToState> ToState for Option<T> {
+    fn to_state(&self) -> StateValue {
+        match self {
+            Some(value) => value.to_state(),
+            None => StateValue::None,
+        }
+    }
+}
+
+impl<T: ToState> ToState for Vec<T> {
+    fn to_state(&self) -> StateValue {
+        StateValue::List(self.iter().map(ToState::to_state).collect())
+    }
+}
+
+impl<T: ToState> ToState for VecDeque<T> {
+    fn to_state(&self) -> StateValue {
+        StateValue::List(self.iter().map(ToState::to_state).collect())
+    }
+}
+
+impl<T: ToState, const N: usize> ToState for [T; N] {
+    fn to_state(&self) -> StateValue {
+        StateValue::List(self.iter().map(ToState::to_state).collect())
+    }
+}
+
+impl<A: ToState, B: ToState> ToState for (A, B) {
+    fn to_state(&self) -> StateValue {
+        StateValue::List(vec![self.0.to_state(), self.1.to_state()])
+    }
+}
+
+impl<A: ToState, B: ToState, C: ToState> ToState for (A, B, C) {
+    fn to_state(&self) -> StateValue {
+        StateValue::List(vec![self.0.to_state(), self.1.to_state(), self.2.to_state()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{ExponentialMovingAverage, FixedMaximumPeriod, Momentum, MovingAverageConvergenceDivergence};
+    use crate::traits::{Apply, Then};
+
+    #[test]
+    fn test_values() {
+        assert_eq!(Some(2.0).to_state(), StateValue::Number(2.0));
+        assert_eq!(None::<usize>.to_state(), StateValue::None);
+        assert_eq!(
+            VecDeque::from(vec![(1.0, true)]).to_state(),
+            StateValue::List(vec![StateValue::List(vec![
+                StateValue::Number(1.0),
+                StateValue::Bool(true)
+            ])])
+        );
+        assert_eq!(
+            [3_i64; 2].to_state(),
+            StateValue::List(vec![StateValue::Integer(3), StateValue::Integer(3)])
+        );
+    }
+
+    #[test]
+    fn test_get() {
+        let state = SignalState::new("Signal")
+            .field("period", StateValue::Count(3))
+            .field("seed", StateValue::None);
+        assert_eq!(state.get("period"), Some(&StateValue::Count(3)));
+        assert_eq!(state.get("seed"), Some(&StateValue::None));
+        assert_eq!(state.get("missing"), None);
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut smoothed = Momentum::new(1)
+            .unwrap()
+            .then(ExponentialMovingAverage::new(3).unwrap());
+        smoothed.apply(1.0);
+        let state = smoothed.inspect();
+        assert_eq!(state.name, "Chain");
+        match state.get("first") {
+            Some(StateValue::Nested(momentum)) => assert_eq!(momentum.name, "Momentum"),
+            other => panic!("expected the momentum state, got {:?}", other),
+        }
+        match state.get("second") {
+            Some(StateValue::Nested(ema)) => assert_eq!(ema.name, "ExponentialMovingAverage"),
+            other => panic!("expected the EMA state, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_name() {
+        let smoothed = Momentum::new(1)
+            .unwrap()
+            .then(ExponentialMovingAverage::new(3).unwrap());
+        assert_eq!(smoothed.name(), smoothed.inspect().name);
+        let macd = MovingAverageConvergenceDivergence::new(3, 6).unwrap();
+        assert_eq!(macd.name(), macd.inspect().name);
+        let max = FixedMaximumPeriod::<3>::new().unwrap();
+        assert_eq!(max.name(), max.inspect().name);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serialize() {
+        let state = SignalState::new("Signal")
+            .field("period", StateValue::Count(2))
+            .field("values", StateValue::List(vec![StateValue::Number(1.5)]));
+        assert_eq!(
+            serde_json::to_string(&state).unwrap(),
+            r#"{"name":"Signal","fields":[["period",{"Count":2}],["values",{"List":[{"Number":1.5}]}]]}"#
+        );
+    }
+}
@@ -0,0 +1,90 @@
+use arrow_array::Float64Array;
+
+use super::apply_nullable;
+use crate::traits::{Executable, ReadyValue, WarmUp};
+
+/// Computes signals column-wise over Arrow arrays, so they can be added to Arrow based data pipelines.
+///
+/// Each value of the input array is applied to the signal with [`apply_nullable`], which covers null inputs, warm up
+/// and signals with more than one output, and its output is written to the same position of the output array. Sliced
+/// arrays are read from their offset.
+///
+/// # Example
+/// ```
+/// use arrow_array::{Array, Float64Array};
+/// use indicato_rs::io::arrow::ArrowSignalExt;
+/// use indicato_rs::signals::{LinearRegression, SimpleMovingAverage};
+/// use indicato_rs::traits::Combine;
+///
+/// let prices = Float64Array::from(vec![Some(1.0), Some(3.0), None, Some(5.0)]);
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// let averages = sma.apply_array(&prices);
+/// assert_eq!(averages, Float64Array::from(vec![None, Some(2.0), None, Some(4.0)]));
+/// assert_eq!(averages.null_count(), 2);
+///
+/// // the slope of the line fitted to the prices
+/// let mut slope = LinearRegression::new(2).unwrap().map(|fit| fit.map(|fit| fit.slope));
+/// let slopes = slope.apply_array(&Float64Array::from(vec![1.0, 3.0, 4.0]));
+/// assert_eq!(slopes, Float64Array::from(vec![None, Some(2.0), Some(1.0)]));
+/// ```
+pub trait ArrowSignalExt: Executable<Input = f64> + WarmUp {
+    /// Applies each value of `array` to the signal, returning an array of its outputs with nulls for null inputs and
+    /// while the signal is warming up.
+    fn apply_array(&mut self, array: &Float64Array) -> Float64Array
+    where
+        Self::Output: ReadyValue<Value = f64>,
+    {
+        array.iter().map(|input| apply_nullable(self, input)).collect()
+    }
+}
+
+impl<S: Executable<Input = f64> + WarmUp> ArrowSignalExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::Array;
+
+    use super::*;
+    use crate::signals::{RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::traits::Current;
+
+    #[test]
+    fn test_validity() {
+        let prices = Float64Array::from(vec![Some(10.0), None, Some(9.0), Some(8.0), None, Some(9.0)]);
+        let mut rsi = RelativeStrengthIndex::new(2, 0).unwrap();
+        let outputs = rsi.apply_array(&prices);
+        assert_eq!(outputs.len(), 6);
+        assert_eq!(outputs.null_count(), 4);
+        assert!(outputs.is_valid(3));
+        assert!(outputs.is_valid(5));
+        assert_eq!(outputs.value(3), 0.0);
+        assert_eq!(outputs.value(5), 50.0);
+    }
+
+    #[test]
+    fn test_sliced() {
+        // a slice shares the buffer of its array, only the values from its offset are applied
+        let prices = Float64Array::from(vec![100.0, 1.0, 3.0, 5.0, 100.0]);
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let outputs = sma.apply_array(&prices.slice(1, 3));
+        assert_eq!(outputs.offset(), 0);
+        assert_eq!(outputs, Float64Array::from(vec![None, Some(2.0), Some(4.0)]));
+        assert_eq!(sma.current(), 4.0);
+    }
+
+    #[test]
+    fn test_all_null() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let outputs = sma.apply_array(&Float64Array::new_null(3));
+        assert_eq!(outputs.len(), 3);
+        assert_eq!(outputs.null_count(), 3);
+        assert_eq!(sma.warmup_remaining(), 2);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        assert!(sma.apply_array(&Float64Array::from(Vec::<f64>::new())).is_empty());
+    }
+}
@@ -0,0 +1,447 @@
+use std::{fs::File, io::Read, path::Path};
+
+use ::csv::{Reader, StringRecord};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    types::Candle,
+};
+
+/// A column of a CSV file, found by its header or by its position.
+///
+/// Strings convert into a [`Name`](CsvColumn::Name) and numbers into an [`Index`](CsvColumn::Index).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CsvColumn {
+    /// The column with this header, compared without case or surrounding whitespace
+    Name(String),
+    /// The column at this position, counting from 0
+    Index(usize),
+}
+
+impl From<&str> for CsvColumn {
+    fn from(name: &str) -> Self {
+        Self::Name(name.to_string())
+    }
+}
+
+impl From<String> for CsvColumn {
+    fn from(name: String) -> Self {
+        Self::Name(name)
+    }
+}
+
+impl From<usize> for CsvColumn {
+    fn from(index: usize) -> Self {
+        Self::Index(index)
+    }
+}
+
+impl CsvColumn {
+    /// The position of the column in a file with `headers`
+    fn resolve(&self, headers: Option<&StringRecord>) -> Result<usize, FinError> {
+        match (self, headers) {
+            (Self::Index(index), _) => Ok(*index),
+            (Self::Name(name), Some(headers)) => headers
+                .iter()
+                .position(|header| header.trim().eq_ignore_ascii_case(name.trim()))
+                .ok_or_else(|| {
+                    FinError::new(
+                        FinErrorType::InvalidInput,
+                        &format!("Column \"{}\" is not in the headers", name),
+                    )
+                }),
+            (Self::Name(name), None) => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                &format!("Column \"{}\" can't be found by name in a file without headers", name),
+            )),
+        }
+    }
+}
+
+/// Maps the columns of a CSV file onto the fields of a [`Candle`].
+///
+/// By default the columns are found by the headers `open`, `high`, `low`, `close`, `volume` and `timestamp`, any of
+/// them can be found by another header or by position instead. The volume and timestamp can be left out of the file,
+/// the volume of each candle is then 0 and the timestamp is the number of the row, counting from 0.
+///
+/// # Example
+/// ```
+/// use indicato_rs::io::csv::CandleColumns;
+///
+/// // a file with the headers Date, Open, High, Low, Close, Adj Close and Volume
+/// let columns = CandleColumns::default().timestamp("Date").close("Adj Close");
+///
+/// // a file without headers, holding the open, high, low and close
+/// let columns = CandleColumns::default().open(0).high(1).low(2).close(3).without_volume().without_timestamp();
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CandleColumns {
+    open: CsvColumn,
+    high: CsvColumn,
+    low: CsvColumn,
+    close: CsvColumn,
+    volume: Option<CsvColumn>,
+    timestamp: Option<CsvColumn>,
+}
+
+impl CandleColumns {
+    /// Set the column holding the opening prices
+    pub fn open(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.open = column.into();
+        self
+    }
+
+    /// Set the column holding the highest prices
+    pub fn high(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.high = column.into();
+        self
+    }
+
+    /// Set the column holding the lowest prices
+    pub fn low(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.low = column.into();
+        self
+    }
+
+    /// Set the column holding the closing prices
+    pub fn close(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.close = column.into();
+        self
+    }
+
+    /// Set the column holding the volumes
+    pub fn volume(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.volume = Some(column.into());
+        self
+    }
+
+    /// Read the candles without volumes, the volume of each candle is 0
+    pub fn without_volume(mut self) -> Self {
+        self.volume = None;
+        self
+    }
+
+    /// Set the column holding the timestamps, which must be whole numbers
+    pub fn timestamp(mut self, column: impl Into<CsvColumn>) -> Self {
+        self.timestamp = Some(column.into());
+        self
+    }
+
+    /// Read the candles without timestamps, the timestamp of each candle is the number of its row
+    pub fn without_timestamp(mut self) -> Self {
+        self.timestamp = None;
+        self
+    }
+}
+
+impl Default for CandleColumns {
+    /// Create a new mapping of the columns with the headers `open`, `high`, `low`, `close`, `volume` and `timestamp`
+    fn default() -> Self {
+        Self {
+            open: "open".into(),
+            high: "high".into(),
+            low: "low".into(),
+            close: "close".into(),
+            volume: Some("volume".into()),
+            timestamp: Some("timestamp".into()),
+        }
+    }
+}
+
+/// The positions of the mapped columns in a file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct ColumnIndices {
+    open: usize,
+    high: usize,
+    low: usize,
+    close: usize,
+    volume: Option<usize>,
+    timestamp: Option<usize>,
+}
+
+/// # CSV Candles
+/// An iterator over the candles in a CSV file
+///
+/// Each row of the file is read into a [`Candle`] as the iterator advances, so files of any size can be fed to
+/// signals without holding them in memory. A row that can't be read, or has a value that isn't a number, is reported
+/// as an error with its line number, and the iterator carries on with the next row.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::io::csv::{CandleColumns, CsvCandles};
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::traits::{Apply, Current};
+///
+/// let file = "\
+/// timestamp,open,high,low,close,volume
+/// 60,2.0,4.0,1.0,3.0,100
+/// 120,3.0,6.0,3.0,6.0,150
+/// ";
+///
+/// // read the candles from the file
+/// let candles = CsvCandles::from_reader(file.as_bytes(), &CandleColumns::default()).unwrap();
+///
+/// // feed their closing prices to a signal
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// for candle in candles {
+///     sma.apply(candle.unwrap().close);
+/// }
+/// assert_eq!(sma.current(), 4.5);
+/// ```
+#[derive(Debug)]
+pub struct CsvCandles<R> {
+    reader: Reader<R>,
+    columns: ColumnIndices,
+    record: StringRecord,
+    /// The number of rows that have been read.
+    rows: i64,
+}
+
+impl CsvCandles<File> {
+    /// Open the CSV file at `path`, which has headers and is separated by commas
+    /// # Arguments
+    /// * `path` - The path of the file
+    /// * `columns` - The columns of the file holding the fields of the candles
+    ///
+    /// # Errors
+    /// Will return an error if the file can't be opened or a column isn't in its headers
+    /// ```
+    /// use indicato_rs::io::csv::{CandleColumns, CsvCandles};
+    ///
+    /// let candles = CsvCandles::from_path("missing.csv", &CandleColumns::default());
+    ///
+    /// assert!(candles.is_err());
+    /// ```
+    pub fn from_path(path: impl AsRef<Path>, columns: &CandleColumns) -> Result<Self, FinError> {
+        let reader = Reader::from_path(path).map_err(|error| {
+            FinError::new(
+                FinErrorType::InvalidOperation,
+                &format!("CSV file could not be opened: {}", error),
+            )
+        })?;
+        Self::from_csv_reader(reader, columns)
+    }
+}
+
+impl<R: Read> CsvCandles<R> {
+    /// Create a new CSV Candles instance reading from `reader`, which has headers and is separated by commas
+    /// # Arguments
+    /// * `reader` - The source of the file
+    /// * `columns` - The columns of the file holding the fields of the candles
+    ///
+    /// # Errors
+    /// Will return an error if the headers can't be read or a column isn't in them
+    /// ```
+    /// use indicato_rs::io::csv::{CandleColumns, CsvCandles};
+    ///
+    /// let candles = CsvCandles::from_reader("time,open,high,low,close".as_bytes(), &CandleColumns::default());
+    ///
+    /// assert!(candles.is_err());
+    /// ```
+    pub fn from_reader(reader: R, columns: &CandleColumns) -> Result<Self, FinError> {
+        Self::from_csv_reader(Reader::from_reader(reader), columns)
+    }
+
+    /// Create a new CSV Candles instance from a configured [`csv::Reader`](::csv::Reader), such as one for a file
+    /// without headers or separated by another delimiter
+    /// # Arguments
+    /// * `reader` - The reader of the file
+    /// * `columns` - The columns of the file holding the fields of the candles
+    ///
+    /// # Example
+    /// ```
+    /// use csv::ReaderBuilder;
+    /// use indicato_rs::io::csv::{CandleColumns, CsvCandles};
+    ///
+    /// let reader = ReaderBuilder::new().has_headers(false).delimiter(b';').from_reader("2;4;1;3".as_bytes());
+    /// let columns = CandleColumns::default().open(0).high(1).low(2).close(3).without_volume().without_timestamp();
+    ///
+    /// let mut candles = CsvCandles::from_csv_reader(reader, &columns).unwrap();
+    /// assert_eq!(candles.next().unwrap().unwrap().close, 3.0);
+    /// ```
+    /// # Errors
+    /// Will return an error if a column is found by name and the file has no headers, or the headers can't be read or
+    /// don't include it
+    /// ```
+    /// use csv::ReaderBuilder;
+    /// use indicato_rs::io::csv::{CandleColumns, CsvCandles};
+    ///
+    /// let reader = ReaderBuilder::new().has_headers(false).from_reader("2,4,1,3".as_bytes());
+    /// let candles = CsvCandles::from_csv_reader(reader, &CandleColumns::default());
+    ///
+    /// assert!(candles.is_err());
+    /// ```
+    pub fn from_csv_reader(mut reader: Reader<R>, columns: &CandleColumns) -> Result<Self, FinError> {
+        let headers = match reader.has_headers() {
+            true => Some(reader.headers().map_err(|error| {
+                FinError::new(
+                    FinErrorType::InvalidInput,
+                    &format!("CSV headers could not be read: {}", error),
+                )
+            })?),
+            false => None,
+        };
+        let columns = ColumnIndices {
+            open: columns.open.resolve(headers)?,
+            high: columns.high.resolve(headers)?,
+            low: columns.low.resolve(headers)?,
+            close: columns.close.resolve(headers)?,
+            volume: columns.volume.as_ref().map(|column| column.resolve(headers)).transpose()?,
+            timestamp: columns.timestamp.as_ref().map(|column| column.resolve(headers)).transpose()?,
+        };
+        Ok(Self {
+            reader,
+            columns,
+            record: StringRecord::new(),
+            rows: 0,
+        })
+    }
+
+    /// The field of the current record at `index`, parsed as a `T`
+    fn field<T: std::str::FromStr>(&self, index: usize, name: &str) -> Result<T, FinError> {
+        let line = self.record.position().map_or(0, |position| position.line());
+        let value = self.record.get(index).ok_or_else(|| {
+            FinError::new(
+                FinErrorType::InvalidInput,
+                &format!("Row on line {} has no {} column at position {}", line, name, index),
+            )
+        })?;
+        value.trim().parse().map_err(|_| {
+            FinError::new(
+                FinErrorType::InvalidInput,
+                &format!("The {} \"{}\" on line {} is not a number", name, value, line),
+            )
+        })
+    }
+
+    /// The candle in the current record
+    fn candle(&self) -> Result<Candle, FinError> {
+        Ok(Candle {
+            open: self.field(self.columns.open, "open")?,
+            high: self.field(self.columns.high, "high")?,
+            low: self.field(self.columns.low, "low")?,
+            close: self.field(self.columns.close, "close")?,
+            volume: match self.columns.volume {
+                Some(index) => self.field(index, "volume")?,
+                None => 0.0,
+            },
+            timestamp: match self.columns.timestamp {
+                Some(index) => self.field(index, "timestamp")?,
+                None => self.rows - 1,
+            },
+        })
+    }
+}
+
+impl<R: Read> Iterator for CsvCandles<R> {
+    type Item = Result<Candle, FinError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.reader.read_record(&mut self.record) {
+            Ok(true) => {
+                self.rows += 1;
+                Some(self.candle())
+            }
+            Ok(false) => None,
+            Err(error) => {
+                self.rows += 1;
+                Some(Err(FinError::new(
+                    FinErrorType::InvalidInput,
+                    &format!("CSV row could not be read: {}", error),
+                )))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::SimpleMovingAverage;
+    use crate::traits::Apply;
+
+    const FILE: &str = "\
+Date, Open, High, Low, Close, Volume
+1,2.0,4.0,1.0,3.0,100
+2,3.0,6.0,3.0,6.0,150
+3,6.0,7.0,4.0,5.0,120
+";
+
+    #[test]
+    fn test_read() {
+        let columns = CandleColumns::default().timestamp("date");
+        let candles = CsvCandles::from_reader(FILE.as_bytes(), &columns)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(candles.len(), 3);
+        assert_eq!(
+            candles[1],
+            Candle {
+                open: 3.0,
+                high: 6.0,
+                low: 3.0,
+                close: 6.0,
+                volume: 150.0,
+                timestamp: 2,
+            }
+        );
+
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        let closes: Vec<f64> = candles.iter().map(|candle| sma.apply(candle.close)).collect();
+        assert_eq!(closes, vec![3.0, 4.5, 14.0 / 3.0]);
+    }
+
+    #[test]
+    fn test_optional_columns() {
+        let columns = CandleColumns::default()
+            .open(4)
+            .close(1)
+            .without_volume()
+            .without_timestamp();
+        let candles = CsvCandles::from_reader(FILE.as_bytes(), &columns)
+            .unwrap()
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+        assert_eq!(candles[2].open, 5.0);
+        assert_eq!(candles[2].close, 6.0);
+        assert_eq!(candles[2].volume, 0.0);
+        assert_eq!(
+            candles.iter().map(|candle| candle.timestamp).collect::<Vec<_>>(),
+            vec![0, 1, 2]
+        );
+    }
+
+    #[test]
+    fn test_missing_column() {
+        let error = CsvCandles::from_reader(FILE.as_bytes(), &CandleColumns::default()).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::InvalidInput);
+        assert_eq!(error.message, "Column \"timestamp\" is not in the headers");
+    }
+
+    #[test]
+    fn test_invalid_rows() {
+        let file = "open,high,low,close\n1,2,0.5,1.5\n1,n/a,0.5,1.5\n1,2,0.5\n2,3,1,2.5\n";
+        let columns = CandleColumns::default().without_volume().without_timestamp();
+        let candles = CsvCandles::from_reader(file.as_bytes(), &columns).unwrap().collect::<Vec<_>>();
+        assert_eq!(candles.len(), 4);
+        assert_eq!(
+            candles[1].as_ref().unwrap_err().message,
+            "The high \"n/a\" on line 3 is not a number"
+        );
+        assert!(candles[2].is_err());
+        // rows after an invalid row are still read, keeping their row numbers
+        assert_eq!(candles[3].as_ref().unwrap().timestamp, 3);
+    }
+
+    #[test]
+    fn test_from_path() {
+        let path = std::env::temp_dir().join("indicato_rs_test_from_path.csv");
+        std::fs::write(&path, FILE).unwrap();
+        let columns = CandleColumns::default().timestamp(0);
+        let candles: Vec<_> = CsvCandles::from_path(&path, &columns).unwrap().collect();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(candles.len(), 3);
+        assert_eq!(candles[0].as_ref().unwrap().timestamp, 1);
+    }
+}
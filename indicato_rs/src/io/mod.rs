@@ -0,0 +1,93 @@
+/// Reads candles from CSV files, enabled with the `csv` feature.
+#[cfg(feature = "csv")]
+pub mod csv;
+
+/// Computes signals over Arrow arrays, enabled with the `arrow` feature.
+#[cfg(feature = "arrow")]
+pub mod arrow;
+
+/// Runs signals over Polars series, enabled with the `polars` feature.
+#[cfg(feature = "polars")]
+pub mod polars;
+
+#[cfg(any(feature = "arrow", feature = "polars"))]
+use crate::traits::{Executable, ExecutionContext, ReadyValue, WarmUp};
+
+/// Applies a nullable value of a column to a signal, returning the value to write to the same row of the output
+/// column, this is how the `arrow` and `polars` adapters compute their columns.
+///
+/// A null input is not applied, the signal is left untouched and the output is null. The output is also null while
+/// the signal is warming up, whether the signal outputs `None`, like the
+/// [`RelativeStrengthIndex`](crate::signals::RelativeStrengthIndex), or a value from the first input, like the
+/// [`SimpleMovingAverage`](crate::signals::SimpleMovingAverage), so the leading nulls of every signal line up with its
+/// warm up.
+///
+/// Signals with more than one output, such as the [`LinearRegression`](crate::signals::LinearRegression), can be mapped
+/// to the output to write with [`Combine::map`](crate::traits::Combine::map).
+///
+/// # Example
+/// ```
+/// use indicato_rs::io::apply_nullable;
+/// use indicato_rs::signals::{LinearRegression, SimpleMovingAverage};
+/// use indicato_rs::traits::Combine;
+///
+/// let prices = [Some(1.0), Some(3.0), None, Some(5.0)];
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// let averages = prices.map(|price| apply_nullable(&mut sma, price));
+/// assert_eq!(averages, [None, Some(2.0), None, Some(4.0)]);
+///
+/// // the slope of the line fitted to the prices
+/// let mut slope = LinearRegression::new(2).unwrap().map(|fit| fit.map(|fit| fit.slope));
+/// let slopes = prices.map(|price| apply_nullable(&mut slope, price));
+/// assert_eq!(slopes, [None, Some(2.0), None, Some(2.0)]);
+/// ```
+#[cfg(any(feature = "arrow", feature = "polars"))]
+pub fn apply_nullable<S>(signal: &mut S, input: Option<f64>) -> Option<f64>
+where
+    S: Executable<Input = f64> + WarmUp + ?Sized,
+    S::Output: ReadyValue<Value = f64>,
+{
+    let output = signal.execute(input?, &ExecutionContext::Apply);
+    match signal.warmup_remaining() {
+        0 => output.into_ready(),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, any(feature = "arrow", feature = "polars")))]
+mod tests {
+    use super::*;
+    use crate::signals::{ExponentialMovingAverage, RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::traits::{Apply, Current, Then};
+
+    #[test]
+    fn test_warmup_nulls() {
+        let prices = [10.0, 9.0, 8.0, 9.0];
+        let mut rsi = RelativeStrengthIndex::new(2, 0).unwrap();
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        assert_eq!(prices.map(|price| apply_nullable(&mut rsi, Some(price))), [None, None, Some(0.0), Some(50.0)]);
+        assert_eq!(
+            prices.map(|price| apply_nullable(&mut sma, Some(price))),
+            [None, None, Some(9.0), Some(26.0 / 3.0)]
+        );
+        assert_eq!(rsi.current(), Some(50.0));
+    }
+
+    #[test]
+    fn test_null_inputs() {
+        // null inputs are skipped, so the signal matches one applied to the valid inputs
+        let prices = [Some(2.0), None, Some(4.0), None, Some(8.0)];
+        let mut chain = SimpleMovingAverage::new(2)
+            .unwrap()
+            .then(ExponentialMovingAverage::new(2).unwrap());
+        let outputs = prices.map(|price| apply_nullable(&mut chain, price));
+
+        let mut expected = SimpleMovingAverage::new(2)
+            .unwrap()
+            .then(ExponentialMovingAverage::new(2).unwrap());
+        expected.apply(2.0);
+        assert_eq!(outputs[..4], [None, None, expected.apply(4.0), None]);
+        assert_eq!(outputs[4], expected.apply(8.0));
+    }
+}
@@ -0,0 +1,135 @@
+use polars_core::prelude::{DataType, Float64Chunked, IntoSeries, Series};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Executable, ReadyValue, WarmUp},
+};
+
+use super::apply_nullable;
+
+/// Runs signals over Polars series, so indicator columns can be added to a `DataFrame` in one call.
+///
+/// Each value of the input is applied to the signal with [`apply_nullable`], which covers null inputs, warm up and
+/// signals with more than one output, and its output is written to the same row of the output. The output keeps the
+/// name of the input and is read across all of its chunks.
+///
+/// # Example
+/// ```
+/// use indicato_rs::io::polars::PolarsSignalExt;
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use polars_core::prelude::*;
+///
+/// let mut df = df!("close" => [1.0, 3.0, 5.0]).unwrap();
+///
+/// // add a column of the 2 period simple moving average of the closing prices
+/// let close = df.column("close").unwrap().as_materialized_series();
+/// let sma = SimpleMovingAverage::new(2).unwrap().apply_series(close).unwrap();
+/// df.with_column(sma.with_name("sma".into())).unwrap();
+///
+/// let expected = Series::new("sma".into(), [None, Some(2.0), Some(4.0)]);
+/// assert!(df.column("sma").unwrap().as_materialized_series().equals_missing(&expected));
+/// ```
+pub trait PolarsSignalExt: Executable<Input = f64> + WarmUp {
+    /// Applies each value of `values` to the signal, returning its outputs with nulls for null inputs and while the
+    /// signal is warming up, under the same name as `values`.
+    fn apply_chunked(&mut self, values: &Float64Chunked) -> Float64Chunked
+    where
+        Self::Output: ReadyValue<Value = f64>,
+    {
+        let outputs: Float64Chunked = values.iter().map(|input| apply_nullable(self, input)).collect();
+        outputs.with_name(values.name().clone())
+    }
+
+    /// Applies each value of `series` to the signal, returning a `Float64` series of its outputs with nulls for null
+    /// inputs and while the signal is warming up, under the same name as `series`.
+    ///
+    /// Series of any numeric type are cast to `Float64` before they are applied.
+    ///
+    /// # Errors
+    /// Will return an error if the series can't be cast to `Float64`
+    /// ```
+    /// use indicato_rs::io::polars::PolarsSignalExt;
+    /// use indicato_rs::signals::SimpleMovingAverage;
+    /// use polars_core::prelude::*;
+    ///
+    /// let dates = Series::new("date".into(), ["2024-01-02", "2024-01-03"]);
+    /// let sma = SimpleMovingAverage::new(2).unwrap().apply_series(&dates);
+    ///
+    /// assert!(sma.is_err());
+    /// ```
+    fn apply_series(&mut self, series: &Series) -> Result<Series, FinError>
+    where
+        Self::Output: ReadyValue<Value = f64>,
+    {
+        let invalid = |message: String| FinError::new(FinErrorType::InvalidInput, &message);
+        if !series.dtype().is_primitive_numeric() {
+            return Err(invalid(format!(
+                "Series \"{}\" of type {} is not numeric",
+                series.name(),
+                series.dtype()
+            )));
+        }
+        let values = series
+            .cast(&DataType::Float64)
+            .map_err(|error| invalid(format!("Series \"{}\" could not be cast: {}", series.name(), error)))?;
+        let values = values
+            .f64()
+            .map_err(|error| invalid(format!("Series \"{}\" could not be read: {}", series.name(), error)))?;
+        Ok(self.apply_chunked(values).into_series())
+    }
+}
+
+impl<S: Executable<Input = f64> + WarmUp> PolarsSignalExt for S {}
+
+#[cfg(test)]
+mod tests {
+    use polars_core::prelude::NamedFrom;
+
+    use super::*;
+    use crate::signals::{RelativeStrengthIndex, SimpleMovingAverage};
+    use crate::traits::Current;
+
+    #[test]
+    fn test_name() {
+        let prices = Series::new("close".into(), [Some(10.0), None, Some(9.0), Some(8.0)]);
+        let mut rsi = RelativeStrengthIndex::new(2, 0).unwrap();
+        let outputs = rsi.apply_series(&prices).unwrap();
+        assert_eq!(outputs.name().as_str(), "close");
+        assert_eq!(outputs.len(), 4);
+        assert_eq!(outputs.null_count(), 3);
+    }
+
+    #[test]
+    fn test_chunks() {
+        // the values are applied in order across chunks, and the output is a single chunk
+        let mut prices = Float64Chunked::new("close".into(), [Some(2.0), None]);
+        prices.append(&Float64Chunked::new("close".into(), [Some(4.0), Some(9.0)])).unwrap();
+        assert_eq!(prices.chunks().len(), 2);
+
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let outputs = sma.apply_chunked(&prices);
+        assert_eq!(outputs.chunks().len(), 1);
+        assert_eq!(outputs.iter().collect::<Vec<_>>(), vec![None, None, Some(3.0), Some(6.5)]);
+        assert_eq!(sma.current(), 6.5);
+    }
+
+    #[test]
+    fn test_cast() {
+        let volumes = Series::new("volume".into(), [100_i64, 300, 200]);
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        let outputs = sma.apply_series(&volumes).unwrap();
+        assert_eq!(outputs.dtype(), &DataType::Float64);
+        assert_eq!(
+            outputs.f64().unwrap().iter().collect::<Vec<_>>(),
+            vec![None, Some(200.0), Some(250.0)]
+        );
+    }
+
+    #[test]
+    fn test_not_numeric() {
+        let flags = Series::new("flag".into(), [true, false]);
+        let error = SimpleMovingAverage::new(2).unwrap().apply_series(&flags).unwrap_err();
+        assert_eq!(error.error_type, FinErrorType::InvalidInput);
+        assert_eq!(error.message, "Series \"flag\" of type bool is not numeric");
+    }
+}
@@ -8,8 +8,38 @@ pub mod fin_error;
 /// The signals module contains the signal types that can be created.
 pub mod signals;
 
+/// The types module contains the market data types that signals are fed with.
+pub mod types;
+
+/// The resample module builds candles from a raw trade feed, ahead of the signals.
+pub mod resample;
+
 /// The traits module contains the traits that are used to define the functionality signals.
 pub mod traits;
 
+/// The stream module adapts `futures` streams to drive signals, enabled with the `futures` feature.
+#[cfg(feature = "futures")]
+pub mod stream;
+
+/// The io module reads market data into the types that signals are fed with, each format is enabled with its own
+/// feature.
+#[cfg(any(feature = "csv", feature = "arrow", feature = "polars"))]
+pub mod io;
+
+/// The inspect module holds the structured views of the internal state of signals, returned by
+/// [`Inspect::inspect`](traits::Inspect::inspect).
+pub mod inspect;
+
+/// The checkpoint module writes signals to versioned snapshots and restores them, enabled with the `serde` feature.
+#[cfg(feature = "serde")]
+pub mod checkpoint;
+
+/// Checks shared by the signals validating their inputs.
+pub(crate) mod validation;
+
+/// Vectorised kernels behind the batch paths of the signals, enabled with the `simd` feature.
+#[cfg(feature = "simd")]
+pub(crate) mod simd;
+
 /// The math module contains calculations that are once-off, as opposed to signals which are aggregations
 pub mod deque_math;
\ No newline at end of file
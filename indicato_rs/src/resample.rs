@@ -0,0 +1,286 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate},
+    types::Candle,
+    validation,
+};
+
+/// Decides when a [`CandleAggregator`] completes a candle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CandleBoundary {
+    /// Candles span a fixed interval of timestamps, aligned to multiples of the interval. A candle is completed by the
+    /// first tick of a later interval, and intervals without any ticks produce no candle.
+    Interval(i64),
+    /// Candles span a fixed number of ticks and are completed by their last tick.
+    Ticks(usize),
+}
+
+crate::inspect::debug_state!(CandleBoundary);
+
+/// # Candle Aggregator
+/// Container for building candles from a raw trade feed
+///
+/// Each tick updates the candle in progress, which opens at the price of its first tick and closes at the price of its
+/// last, with the volume being the sum of the tick sizes. Applying a tick outputs the candle it completed, if any, so
+/// the completed candles can be passed straight on to signals.
+///
+/// Candles on an [`Interval`](CandleBoundary::Interval) boundary are stamped with the start of their interval, candles
+/// on a [`Ticks`](CandleBoundary::Ticks) boundary are stamped with the timestamp of their first tick.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::resample::{CandleAggregator, CandleBoundary};
+/// use indicato_rs::signals::MoneyFlowIndex;
+/// use indicato_rs::traits::{Apply, Evaluate};
+/// use indicato_rs::types::Candle;
+///
+/// // create a new Candle Aggregator building one minute candles from timestamps in seconds
+/// let mut aggregator = CandleAggregator::new(CandleBoundary::Interval(60)).unwrap();
+/// let mut mfi = MoneyFlowIndex::<Candle>::for_input(14).unwrap();
+///
+/// // apply some ticks of (price, size, timestamp), the first tick of the next minute completes the candle
+/// assert_eq!(aggregator.apply((10.0, 2.0, 65)), None);
+/// assert_eq!(aggregator.apply((12.0, 1.0, 90)), None);
+/// assert_eq!(aggregator.apply((9.0, 3.0, 110)), None);
+/// let candle = aggregator.apply((11.0, 1.0, 121)).unwrap();
+/// assert_eq!(candle, Candle { open: 10.0, high: 12.0, low: 9.0, close: 9.0, volume: 6.0, timestamp: 60 });
+///
+/// // feed the completed candle to a signal
+/// assert_eq!(mfi.apply(candle), None);
+///
+/// // evaluate some ticks, these won't affect the candle in progress
+/// assert!(aggregator.evaluate((13.0, 1.0, 185)).is_some());
+///
+/// // fetch the candle in progress
+/// assert_eq!(aggregator.partial().unwrap().timestamp, 120);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CandleAggregator {
+    boundary: CandleBoundary,
+    /// The candle in progress.
+    candle: Option<Candle>,
+    /// The number of ticks in the candle in progress.
+    ticks: usize,
+}
+
+impl IoState for CandleAggregator {
+    /// Input is a tuple of (price, size, timestamp)
+    type Input = (f64, f64, i64);
+    /// Output is the candle completed by the tick, if any
+    type Output = Option<Candle>;
+}
+
+impl CandleAggregator {
+    /// Create a new Candle Aggregator instance
+    /// # Arguments
+    /// * `boundary` - When a candle is completed, the interval or number of ticks must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::resample::{CandleAggregator, CandleBoundary};
+    ///
+    /// let aggregator = CandleAggregator::new(CandleBoundary::Ticks(100));
+    /// assert!(aggregator.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the interval or number of ticks is not greater than 0
+    /// ```
+    /// use indicato_rs::resample::{CandleAggregator, CandleBoundary};
+    ///
+    /// let aggregator = CandleAggregator::new(CandleBoundary::Interval(0));
+    ///
+    /// assert!(aggregator.is_err());
+    /// ```
+    pub fn new(boundary: CandleBoundary) -> Result<Self, FinError> {
+        match boundary {
+            CandleBoundary::Interval(interval) if interval <= 0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Interval must be greater than 0",
+            )),
+            CandleBoundary::Ticks(0) => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Ticks must be greater than 0",
+            )),
+            _ => Ok(Self {
+                boundary,
+                candle: None,
+                ticks: 0,
+            }),
+        }
+    }
+
+    /// The boundary completing the candles
+    pub fn boundary(&self) -> CandleBoundary {
+        self.boundary
+    }
+
+    /// The candle in progress, which hasn't been completed by its boundary yet
+    pub fn partial(&self) -> Option<Candle> {
+        self.candle
+    }
+
+    /// Completes the candle in progress early and returns it, such as when the feed ends
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::resample::{CandleAggregator, CandleBoundary};
+    /// use indicato_rs::traits::Apply;
+    ///
+    /// let mut aggregator = CandleAggregator::new(CandleBoundary::Ticks(3)).unwrap();
+    /// aggregator.apply((10.0, 1.0, 0));
+    /// assert_eq!(aggregator.flush().unwrap().volume, 1.0);
+    /// assert_eq!(aggregator.flush(), None);
+    /// ```
+    pub fn flush(&mut self) -> Option<Candle> {
+        self.ticks = 0;
+        self.candle.take()
+    }
+
+    /// The start of the interval containing `timestamp`.
+    fn interval_start(timestamp: i64, interval: i64) -> i64 {
+        timestamp - timestamp.rem_euclid(interval)
+    }
+}
+
+impl Validate for CandleAggregator {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (price, size, timestamp) = *input;
+        validation::value(price, "Price")?;
+        validation::non_negative(size, "Size")?;
+        match self.candle {
+            Some(candle) if timestamp < candle.timestamp => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Timestamp must not be before the start of the candle in progress",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Executable for CandleAggregator {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        if let ExecutionContext::Evaluate = execution_context {
+            return self.clone().execute(input, &ExecutionContext::Apply);
+        }
+        let (price, size, timestamp) = input;
+        let start = match self.boundary {
+            CandleBoundary::Interval(interval) => Self::interval_start(timestamp, interval),
+            CandleBoundary::Ticks(_) => timestamp,
+        };
+        let completed = match (self.boundary, self.candle) {
+            (CandleBoundary::Interval(_), Some(candle)) if candle.timestamp != start => self.flush(),
+            _ => None,
+        };
+        self.candle = Some(match self.candle {
+            Some(candle) => Candle {
+                high: candle.high.max(price),
+                low: candle.low.min(price),
+                close: price,
+                volume: candle.volume + size,
+                ..candle
+            },
+            None => Candle {
+                open: price,
+                high: price,
+                low: price,
+                close: price,
+                volume: size,
+                timestamp: start,
+            },
+        });
+        self.ticks += 1;
+        match self.boundary {
+            CandleBoundary::Ticks(ticks) if self.ticks == ticks => self.flush(),
+            _ => completed,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::TryApply;
+
+    #[test]
+    fn test_interval() {
+        let mut aggregator = CandleAggregator::new(CandleBoundary::Interval(10)).unwrap();
+        assert_eq!(aggregator.apply((5.0, 1.0, 3)), None);
+        assert_eq!(aggregator.apply((7.0, 2.0, 9)), None);
+        assert_eq!(
+            aggregator.apply((6.0, 1.0, 10)),
+            Some(Candle { open: 5.0, high: 7.0, low: 5.0, close: 7.0, volume: 3.0, timestamp: 0 })
+        );
+        // the empty interval from 20 to 30 produces no candle
+        assert_eq!(
+            aggregator.apply((4.0, 1.0, 35)),
+            Some(Candle { open: 6.0, high: 6.0, low: 6.0, close: 6.0, volume: 1.0, timestamp: 10 })
+        );
+        assert_eq!(aggregator.partial().unwrap().timestamp, 30);
+    }
+
+    #[test]
+    fn test_negative_timestamps() {
+        let mut aggregator = CandleAggregator::new(CandleBoundary::Interval(10)).unwrap();
+        assert_eq!(aggregator.apply((5.0, 1.0, -3)), None);
+        assert_eq!(aggregator.partial().unwrap().timestamp, -10);
+        assert_eq!(aggregator.apply((5.0, 1.0, 0)).unwrap().timestamp, -10);
+    }
+
+    #[test]
+    fn test_ticks() {
+        let mut aggregator = CandleAggregator::new(CandleBoundary::Ticks(2)).unwrap();
+        assert_eq!(aggregator.apply((5.0, 1.0, 3)), None);
+        assert_eq!(
+            aggregator.apply((3.0, 2.0, 100)),
+            Some(Candle { open: 5.0, high: 5.0, low: 3.0, close: 3.0, volume: 3.0, timestamp: 3 })
+        );
+        assert_eq!(aggregator.partial(), None);
+        assert_eq!(aggregator.apply((4.0, 1.0, 101)), None);
+        assert_eq!(aggregator.apply((8.0, 1.0, 102)).unwrap().high, 8.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut aggregator = CandleAggregator::new(CandleBoundary::Ticks(2)).unwrap();
+        aggregator.apply((5.0, 1.0, 3));
+        assert_eq!(aggregator.evaluate((9.0, 1.0, 4)).unwrap().close, 9.0);
+        assert_eq!(aggregator.evaluate((7.0, 1.0, 4)).unwrap().close, 7.0);
+        assert_eq!(aggregator.partial().unwrap().close, 5.0);
+        assert_eq!(aggregator.apply((7.0, 1.0, 4)).unwrap().close, 7.0);
+    }
+
+    #[test]
+    fn test_flush() {
+        let mut aggregator = CandleAggregator::new(CandleBoundary::Ticks(3)).unwrap();
+        aggregator.apply((5.0, 1.0, 3));
+        aggregator.apply((6.0, 1.0, 4));
+        assert_eq!(aggregator.flush().unwrap().close, 6.0);
+        aggregator.apply((7.0, 1.0, 5));
+        aggregator.apply((8.0, 1.0, 6));
+        assert_eq!(aggregator.apply((9.0, 1.0, 7)).unwrap().open, 7.0);
+    }
+
+    #[test]
+    fn test_invalid_boundary() {
+        assert!(CandleAggregator::new(CandleBoundary::Interval(-60)).is_err());
+        assert!(CandleAggregator::new(CandleBoundary::Ticks(0)).is_err());
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut aggregator = CandleAggregator::new(CandleBoundary::Interval(10)).unwrap();
+        assert!(aggregator.try_apply((f64::NAN, 1.0, 3)).is_err());
+        assert!(aggregator.try_apply((5.0, -1.0, 3)).is_err());
+        assert_eq!(aggregator.try_apply((5.0, 1.0, 13)).unwrap(), None);
+        assert!(aggregator.try_apply((5.0, 1.0, 9)).is_err());
+        assert_eq!(aggregator.try_apply((5.0, 1.0, 10)).unwrap(), None);
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        chained_warmup, Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic,
+        Validate, WarmUp,
+    },
+    types::{HasClose, HasHighLow},
+    validation,
+};
+
+use super::AverageDirectionalIndex;
+
+/// # Average Directional Index Rating
+/// Container for Average Directional Index Rating (ADXR) aggregation
+///
+/// The ADXR is the mean of the current [`AverageDirectionalIndex`] and the ADX `l` steps ago. Entries will produce
+/// `None` until there is an ADX `l` steps ago, after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub>
+///                     <mi>ADX</mi>
+///                     <mn>n</mn>
+///                 </msub>
+///                 <mo>+</mo>
+///                 <msub>
+///                     <mi>ADX</mi>
+///                     <mn>n-l</mn>
+///                 </msub>
+///             </mrow>
+///             <mn>2</mn>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `l` is the lag and `ADX` is the Average Directional Index.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::Adxr;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new ADXR with an ADX period of 2 and a lag of 1
+/// let mut adxr = Adxr::new(2, 1).unwrap();
+///
+/// // apply some (high, low, close) values and check their output
+/// assert_eq!(adxr.apply((10.0, 8.0, 9.0)), None);
+/// assert_eq!(adxr.apply((11.0, 9.0, 10.0)), None);
+/// assert_eq!(adxr.apply((12.0, 10.0, 11.0)), None);
+/// assert_eq!(adxr.apply((11.0, 9.0, 9.5)), None);
+/// assert!(adxr.apply((10.0, 7.0, 8.0)).is_some());
+///
+/// // evaluate some values, these won't affect the internal state of the ADXR
+/// assert!(adxr.evaluate((12.0, 8.0, 11.0)).is_some());
+///
+/// // fetch the current value of the ADXR
+/// assert!(adxr.current().is_some());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Adxr<I: HasHighLow + HasClose = (f64, f64, f64)> {
+    adx: AverageDirectionalIndex<I>,
+    lag: usize,
+    /// The last `lag + 1` ADX outputs, the front is the output `lag` steps before the back.
+    values: VecDeque<f64>,
+}
+
+impl<I: HasHighLow + HasClose> Adxr<I> {
+    /// Create a new Average Directional Index Rating instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the Average Directional Index, must be greater than 0
+    /// * `lag` - The number of steps back the earlier ADX is taken from, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::Adxr;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let adxr = Adxr::<Candle>::for_input(14, 14);
+    /// assert!(adxr.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period or lag is 0
+    pub fn for_input(period: usize, lag: usize) -> Result<Self, FinError> {
+        if lag == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Lag must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            adx: AverageDirectionalIndex::for_input(period)?,
+            lag,
+            values: VecDeque::with_capacity(lag + 2),
+        })
+    }
+
+    fn rating(&self, values: &VecDeque<f64>) -> Option<f64> {
+        match values.len() > self.lag {
+            true => Some((values[values.len() - 1] + values[values.len() - 1 - self.lag]) / 2.0),
+            false => None,
+        }
+    }
+}
+
+impl Adxr {
+    /// Create a new Average Directional Index Rating instance
+    /// # Arguments
+    /// * `period` - The period of the Average Directional Index, must be greater than 0
+    /// * `lag` - The number of steps back the earlier ADX is taken from, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::Adxr;
+    ///
+    /// let adxr = Adxr::new(14, 14);
+    /// assert!(adxr.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period or lag is 0
+    /// ```
+    /// use indicato_rs::signals::Adxr;
+    ///
+    /// let adxr = Adxr::new(14, 0);
+    ///
+    /// assert!(adxr.is_err());
+    /// ```
+    pub fn new(period: usize, lag: usize) -> Result<Self, FinError> {
+        Self::for_input(period, lag)
+    }
+
+    /// Create a new Average Directional Index Rating instance with the conventional period of 14 and lag of 14
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::Adxr;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let adxr = Adxr::standard();
+    /// assert_eq!(adxr.period(), 14);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(14, 14).unwrap()
+    }
+}
+
+impl Default for Adxr {
+    /// Create a new Average Directional Index Rating with the conventional period of 14 and lag of 14
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for Adxr<I> {
+    /// Input is anything with a high, low and close, by default a tuple of (high, low, close)
+    type Input = I;
+    type Output = Option<f64>;
+}
+
+impl<I: HasHighLow + HasClose> Validate for Adxr<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for Adxr<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let adx = self.adx.execute(input, execution_context)?;
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(adx);
+                if self.values.len() > self.lag + 1 {
+                    self.values.pop_front();
+                }
+                self.rating(&self.values)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(adx);
+                if values.len() > self.lag + 1 {
+                    values.pop_front();
+                }
+                self.rating(&values)
+            }
+        }
+    }
+}
+
+impl<I: HasHighLow + HasClose> Current for Adxr<I> {
+    fn current(&self) -> Self::Output {
+        self.rating(&self.values)
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for Adxr<I> {
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(
+            self.adx.warmup_remaining(),
+            (self.lag + 1).saturating_sub(self.values.len()),
+        )
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for Adxr<I> {
+    fn period(&self) -> usize {
+        self.adx.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.adx.effective_lookback() + self.lag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Candle;
+    use approx::assert_abs_diff_eq;
+
+    const BARS: [(f64, f64, f64); 8] = [
+        (10.0, 8.0, 9.0),
+        (11.0, 9.0, 10.0),
+        (12.0, 10.0, 11.0),
+        (11.0, 9.0, 9.5),
+        (10.0, 7.0, 8.0),
+        (12.0, 8.0, 11.0),
+        (13.0, 11.0, 12.0),
+        (14.0, 12.0, 13.0),
+    ];
+
+    #[test]
+    fn test_apply() {
+        let mut adxr = Adxr::new(2, 2).unwrap();
+        let expected = [
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(46.72619047619048),
+            Some(53.66612554112554),
+            Some(52.39697254499886),
+        ];
+        for (bar, expected) in BARS.into_iter().zip(expected) {
+            match expected {
+                Some(expected) => {
+                    assert_abs_diff_eq!(adxr.apply(bar).unwrap(), expected, epsilon = 10e-7)
+                }
+                None => assert_eq!(adxr.apply(bar), None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut adxr = Adxr::new(2, 2).unwrap();
+        for bar in &BARS[..5] {
+            assert_eq!(adxr.evaluate(*bar), None);
+            assert_eq!(adxr.apply(*bar), None);
+        }
+        let evaluated = adxr.evaluate(BARS[5]).unwrap();
+        assert_abs_diff_eq!(evaluated, 46.72619047619048, epsilon = 10e-7);
+        assert_eq!(adxr.evaluate(BARS[5]), Some(evaluated));
+        assert_eq!(adxr.apply(BARS[5]), Some(evaluated));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut adxr = Adxr::new(2, 2).unwrap();
+        assert_eq!(adxr.current(), None);
+        for bar in &BARS[..6] {
+            adxr.apply(*bar);
+        }
+        adxr.evaluate(BARS[6]);
+        assert_abs_diff_eq!(adxr.current().unwrap(), 46.72619047619048, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(Adxr::new(0, 14).is_err());
+        assert!(Adxr::new(14, 0).is_err());
+    }
+
+    #[test]
+    fn test_for_input() {
+        let mut adxr = Adxr::new(2, 2).unwrap();
+        let mut candles = Adxr::<Candle>::for_input(2, 2).unwrap();
+        for (high, low, close) in BARS {
+            let candle = Candle { open: close, high, low, close, volume: 0.0, timestamp: 0 };
+            assert_eq!(candles.evaluate(candle), adxr.evaluate((high, low, close)));
+            assert_eq!(candles.apply(candle), adxr.apply((high, low, close)));
+        }
+        assert!(candles.current().is_some());
+    }
+}
@@ -0,0 +1,385 @@
+use std::{fmt, sync::Arc};
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp,
+    },
+};
+
+/// A condition on the output of a signal watched by [`Alerts`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Threshold {
+    /// The output crosses from below the level to above it.
+    CrossAbove(f64),
+    /// The output crosses from above the level to below it.
+    CrossBelow(f64),
+    /// The output moves into the band of (lower, upper), inclusive of its bounds.
+    EnterBand(f64, f64),
+    /// The output moves out of the band of (lower, upper), inclusive of its bounds.
+    ExitBand(f64, f64),
+}
+
+crate::inspect::debug_state!(Threshold);
+
+impl Threshold {
+    /// Whether the value is on the side of the threshold that fires, `None` when the value touches a level so the side
+    /// it was last on is kept.
+    fn side(&self, value: f64) -> Option<bool> {
+        match *self {
+            Self::CrossAbove(level) => (value != level).then_some(value > level),
+            Self::CrossBelow(level) => (value != level).then_some(value < level),
+            Self::EnterBand(lower, upper) => Some(lower <= value && value <= upper),
+            Self::ExitBand(lower, upper) => Some(value < lower || upper < value),
+        }
+    }
+}
+
+/// A threshold met by the output of a signal, output by [`Alerts`].
+#[derive(Debug, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alert {
+    /// The threshold that was met
+    pub threshold: Threshold,
+    /// The output of the signal that met it
+    pub value: f64,
+}
+
+/// A callback receiving each alert as its input is applied.
+#[derive(Clone)]
+struct AlertCallback(Arc<dyn Fn(&Alert) + Send + Sync>);
+
+impl fmt::Debug for AlertCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("AlertCallback").finish_non_exhaustive()
+    }
+}
+
+crate::inspect::debug_state!(AlertCallback);
+
+/// # Alerts
+/// Container for watching the output of a signal for thresholds
+///
+/// Thresholds are registered against the output of the signal, such as an RSI crossing above 70 or below 30, and each
+/// input outputs the signal output along with an [`Alert`] for every threshold met. Alerts can also be received by a
+/// callback, which is only called when inputs are applied, not evaluated.
+///
+/// Thresholds fire on the transition, not while they hold. A threshold registered part way through only fires once
+/// the output has been on one side of it. Levels are crossed the same way as a [`Crossover`](super::Crossover), an
+/// output equal to the level touches it without crossing. Outputs of `None` while the signal is warming up are
+/// skipped.
+///
+/// The callback can't be serialized, so it is dropped when the alerts are serialized with the `serde` feature and has
+/// to be set again after they are restored.
+///
+/// # Example Usage
+/// ```
+/// use std::sync::{Arc, Mutex};
+/// use indicato_rs::signals::{Alert, Alerts, RelativeStrengthIndex, Threshold};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create alerts on an RSI for overbought and oversold levels
+/// let mut alerts = Alerts::new(RelativeStrengthIndex::new(2, 0).unwrap());
+/// alerts.register(Threshold::CrossAbove(70.0)).unwrap();
+/// alerts.register(Threshold::CrossBelow(30.0)).unwrap();
+///
+/// // collect the alerts with a callback
+/// let received = Arc::new(Mutex::new(Vec::new()));
+/// let sink = received.clone();
+/// alerts.on_alert(move |alert| sink.lock().unwrap().push(*alert));
+///
+/// // apply some values and check their output
+/// assert_eq!(alerts.apply(10.0), (None, vec![]));
+/// assert_eq!(alerts.apply(9.0), (None, vec![]));
+/// assert_eq!(alerts.apply(8.0), (Some(0.0), vec![]));
+/// let (rsi, triggered) = alerts.apply(12.0);
+/// assert_eq!(triggered, vec![Alert { threshold: Threshold::CrossAbove(70.0), value: rsi.unwrap() }]);
+///
+/// // evaluate some values, these won't affect the internal state of the Alerts or call the callback
+/// assert_eq!(alerts.evaluate(4.0).1[0].threshold, Threshold::CrossBelow(30.0));
+///
+/// // fetch the current value of the Alerts
+/// assert_eq!(alerts.current().1, triggered);
+/// assert_eq!(*received.lock().unwrap(), triggered);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Alerts<S>
+where
+    S: Executable,
+    S::Output: ChainValue<f64> + Clone,
+{
+    signal: S,
+    /// The thresholds and the side of each the output was last on.
+    thresholds: Vec<(Threshold, Option<bool>)>,
+    /// The alerts output by the last input applied.
+    alerts: Vec<Alert>,
+    #[cfg_attr(feature = "serde", serde(skip))]
+    callback: Option<AlertCallback>,
+}
+
+impl<S> IoState for Alerts<S>
+where
+    S: Executable,
+    S::Output: ChainValue<f64> + Clone,
+{
+    type Input = S::Input;
+    /// Output is a tuple of (signal output, alerts)
+    type Output = (S::Output, Vec<Alert>);
+}
+
+impl<S> Alerts<S>
+where
+    S: Executable,
+    S::Output: ChainValue<f64> + Clone,
+{
+    /// Create a new Alerts instance, with no thresholds
+    /// # Arguments
+    /// * `signal` - The signal to watch
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{Alerts, SimpleMovingAverage};
+    ///
+    /// let alerts = Alerts::new(SimpleMovingAverage::new(10).unwrap());
+    /// assert!(alerts.thresholds().is_empty());
+    /// ```
+    pub fn new(signal: S) -> Self {
+        Self {
+            signal,
+            thresholds: Vec::new(),
+            alerts: Vec::new(),
+            callback: None,
+        }
+    }
+
+    /// Registers a threshold to watch for
+    /// # Arguments
+    /// * `threshold` - The condition on the output of the signal, levels must be numbers and the lower bound of a band
+    ///   must not be above the upper bound
+    ///
+    /// # Errors
+    /// Will return an error if the threshold is invalid
+    /// ```
+    /// use indicato_rs::signals::{Alerts, SimpleMovingAverage, Threshold};
+    ///
+    /// let mut alerts = Alerts::new(SimpleMovingAverage::new(10).unwrap());
+    ///
+    /// assert!(alerts.register(Threshold::EnterBand(2.0, 1.0)).is_err());
+    /// ```
+    pub fn register(&mut self, threshold: Threshold) -> Result<(), FinError> {
+        let valid = match threshold {
+            Threshold::CrossAbove(level) | Threshold::CrossBelow(level) => !level.is_nan(),
+            Threshold::EnterBand(lower, upper) | Threshold::ExitBand(lower, upper) => lower <= upper,
+        };
+        match valid {
+            true => {
+                self.thresholds.push((threshold, None));
+                Ok(())
+            }
+            false => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Threshold levels must be numbers and the lower bound must not be above the upper bound",
+            )),
+        }
+    }
+
+    /// Sets the callback receiving each alert as its input is applied, replacing any previous callback
+    pub fn on_alert(&mut self, callback: impl Fn(&Alert) + Send + Sync + 'static) {
+        self.callback = Some(AlertCallback(Arc::new(callback)));
+    }
+
+    /// The thresholds being watched, in the order they were registered
+    pub fn thresholds(&self) -> Vec<Threshold> {
+        self.thresholds.iter().map(|(threshold, _)| *threshold).collect()
+    }
+
+    /// The signal being watched
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+}
+
+impl<S> Validate for Alerts<S>
+where
+    S: Executable + Validate,
+    S::Output: ChainValue<f64> + Clone,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        self.signal.validate(input)
+    }
+}
+
+impl<S> Executable for Alerts<S>
+where
+    S: Executable,
+    S::Output: ChainValue<f64> + Clone,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let output = self.signal.execute(input, execution_context);
+        let value = match output.clone().into_input() {
+            Some(value) if !value.is_nan() => value,
+            _ => return (output, Vec::new()),
+        };
+        let mut alerts = Vec::new();
+        let mut sides = Vec::with_capacity(self.thresholds.len());
+        for (threshold, previous) in self.thresholds.iter() {
+            let side = threshold.side(value).or(*previous);
+            if let (Some(false), Some(true)) = (previous, side) {
+                alerts.push(Alert {
+                    threshold: *threshold,
+                    value,
+                });
+            }
+            sides.push(side);
+        }
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.thresholds
+                    .iter_mut()
+                    .zip(sides)
+                    .for_each(|((_, previous), side)| *previous = side);
+                if let Some(AlertCallback(callback)) = &self.callback {
+                    for alert in alerts.iter() {
+                        callback(alert);
+                    }
+                }
+                self.alerts = alerts.clone();
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        (output, alerts)
+    }
+}
+
+impl<S> Current for Alerts<S>
+where
+    S: Executable + Current,
+    S::Output: ChainValue<f64> + Clone,
+{
+    fn current(&self) -> Self::Output {
+        (self.signal.current(), self.alerts.clone())
+    }
+}
+
+impl<S> WarmUp for Alerts<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64> + Clone,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.signal.warmup_remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{CumulativeSum, SimpleMovingAverage};
+    use crate::traits::TryApply;
+
+    fn alerts(threshold: Threshold) -> Alerts<CumulativeSum<f64>> {
+        let mut alerts = Alerts::new(CumulativeSum::new());
+        alerts.register(threshold).unwrap();
+        alerts
+    }
+
+    fn fired(alerts: &mut Alerts<CumulativeSum<f64>>, input: f64) -> bool {
+        !alerts.apply(input).1.is_empty()
+    }
+
+    #[test]
+    fn test_cross_above() {
+        let mut alerts = alerts(Threshold::CrossAbove(5.0));
+        assert!(!fired(&mut alerts, 6.0));
+        assert!(!fired(&mut alerts, -3.0));
+        // touching the level isn't a cross
+        assert!(!fired(&mut alerts, 2.0));
+        assert!(!fired(&mut alerts, -1.0));
+        assert!(fired(&mut alerts, 3.0));
+        assert!(!fired(&mut alerts, 1.0));
+    }
+
+    #[test]
+    fn test_cross_below() {
+        let mut alerts = alerts(Threshold::CrossBelow(5.0));
+        assert!(!fired(&mut alerts, 6.0));
+        assert!(!fired(&mut alerts, -1.0));
+        assert!(fired(&mut alerts, -1.0));
+        assert!(!fired(&mut alerts, -1.0));
+    }
+
+    #[test]
+    fn test_bands() {
+        let mut alerts = Alerts::new(CumulativeSum::new());
+        alerts.register(Threshold::EnterBand(0.0, 2.0)).unwrap();
+        alerts.register(Threshold::ExitBand(0.0, 2.0)).unwrap();
+        assert_eq!(alerts.apply(3.0).1, vec![]);
+        assert_eq!(
+            alerts.apply(-1.0).1,
+            vec![Alert { threshold: Threshold::EnterBand(0.0, 2.0), value: 2.0 }]
+        );
+        assert_eq!(alerts.apply(-2.0).1, vec![]);
+        assert_eq!(
+            alerts.apply(-1.0).1,
+            vec![Alert { threshold: Threshold::ExitBand(0.0, 2.0), value: -1.0 }]
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let count = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+        let mut alerts = alerts(Threshold::CrossAbove(1.0));
+        let counter = count.clone();
+        alerts.on_alert(move |_| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+        });
+        alerts.apply(0.0);
+        assert_eq!(alerts.evaluate(2.0).1.len(), 1);
+        assert_eq!(alerts.evaluate(2.0).1.len(), 1);
+        assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 0);
+        assert_eq!(alerts.current(), (0.0, vec![]));
+        assert_eq!(alerts.apply(2.0).1.len(), 1);
+        assert_eq!(count.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert_eq!(alerts.current().1.len(), 1);
+    }
+
+    #[test]
+    fn test_warming_up() {
+        let mut alerts = Alerts::new(crate::signals::Momentum::new(1).unwrap());
+        alerts.register(Threshold::CrossAbove(0.0)).unwrap();
+        assert_eq!(alerts.warmup_remaining(), 2);
+        assert_eq!(alerts.apply(1.0), (None, vec![]));
+        assert_eq!(alerts.apply(0.0), (Some(-1.0), vec![]));
+        assert_eq!(alerts.apply(2.0).1.len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_threshold() {
+        let mut alerts = Alerts::new(SimpleMovingAverage::new(2).unwrap());
+        assert!(alerts.register(Threshold::CrossAbove(f64::NAN)).is_err());
+        assert!(alerts.register(Threshold::ExitBand(1.0, 0.0)).is_err());
+        assert!(alerts.register(Threshold::ExitBand(1.0, 1.0)).is_ok());
+        assert!(alerts.try_apply(f64::NAN).is_err());
+        assert_eq!(alerts.thresholds(), vec![Threshold::ExitBand(1.0, 1.0)]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut alerts = alerts(Threshold::CrossAbove(1.0));
+        alerts.on_alert(|_| {});
+        alerts.apply(0.0);
+        let json = serde_json::to_string(&alerts).unwrap();
+        let mut restored: Alerts<CumulativeSum<f64>> = serde_json::from_str(&json).unwrap();
+        assert!(restored.callback.is_none());
+        assert_eq!(restored.apply(2.0), alerts.apply(2.0));
+    }
+}
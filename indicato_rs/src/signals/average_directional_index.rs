@@ -0,0 +1,391 @@
+use std::marker::PhantomData;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::FinError,
+    traits::{
+        chained_warmup, Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic,
+        Validate, WarmUp,
+    },
+    types::{HasClose, HasHighLow},
+    validation,
+};
+
+use super::WildersSmoothing;
+
+/// # Average Directional Index
+/// Container for Average Directional Index (ADX) aggregation
+///
+/// The directional movement is the portion of the current range extending beyond the previous range, the positive
+/// and negative directional movement and the true range are smoothed with [`WildersSmoothing`] to form the
+/// directional indicators, and the directional index derived from them is smoothed again to form the ADX:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>DX</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mn>100</mn>
+///         <mo>×</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mo>|</mo>
+///                 <msup><mi>DI</mi><mo>+</mo></msup>
+///                 <mo>−</mo>
+///                 <msup><mi>DI</mi><mo>−</mo></msup>
+///                 <mo>|</mo>
+///             </mrow>
+///             <mrow>
+///                 <msup><mi>DI</mi><mo>+</mo></msup>
+///                 <mo>+</mo>
+///                 <msup><mi>DI</mi><mo>−</mo></msup>
+///             </mrow>
+///         </mfrac>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msup><mi>DI</mi><mo>±</mo></msup>
+///         <mo>=</mo>
+///         <mn>100</mn>
+///         <mo>×</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mi>W</mi>
+///                 <mo>(</mo>
+///                 <msup><mi>DM</mi><mo>±</mo></msup>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <mi>W</mi>
+///                 <mo>(</mo>
+///                 <mi>TR</mi>
+///                 <mo>)</mo>
+///             </mrow>
+///         </mfrac>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>W</mi>
+///         <mo>(</mo>
+///         <mi>DX</mi>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `W` is Wilders Smoothing, `DM` is the directional movement,
+/// `TR` is the true range and `DI` is the directional indicator. If the smoothed true range or the sum of the
+/// directional indicators is 0 the respective value will be 0. The first `2p - 1` entries will produce `None` as
+/// the smoothing is seeded.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::AverageDirectionalIndex;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new ADX with a period of 2
+/// let mut adx = AverageDirectionalIndex::new(2).unwrap();
+///
+/// // apply some (high, low, close) values and check their output
+/// assert_eq!(adx.apply((10.0, 8.0, 9.0)), None);
+/// assert_eq!(adx.apply((11.0, 9.0, 10.0)), None);
+/// assert_eq!(adx.apply((12.0, 10.0, 11.0)), None);
+/// assert_eq!(adx.apply((11.0, 9.0, 9.5)), Some(50.0));
+///
+/// // evaluate some values, these won't affect the internal state of the ADX
+/// assert!(adx.evaluate((10.0, 7.0, 8.0)).is_some());
+///
+/// // fetch the current value of the ADX
+/// assert_eq!(adx.current(), Some(50.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AverageDirectionalIndex<I: HasHighLow + HasClose = (f64, f64, f64)> {
+    true_range: WildersSmoothing,
+    positive_movement: WildersSmoothing,
+    negative_movement: WildersSmoothing,
+    directional_index: WildersSmoothing,
+    /// The previous (high, low, close) input.
+    previous: Option<(f64, f64, f64)>,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: HasHighLow + HasClose> AverageDirectionalIndex<I> {
+    /// Create a new Average Directional Index instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the Wilders Smoothing used throughout, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::AverageDirectionalIndex;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let adx = AverageDirectionalIndex::<Candle>::for_input(14);
+    /// assert!(adx.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    pub fn for_input(period: usize) -> Result<Self, FinError> {
+        Ok(Self {
+            true_range: WildersSmoothing::new(period)?,
+            positive_movement: WildersSmoothing::new(period)?,
+            negative_movement: WildersSmoothing::new(period)?,
+            directional_index: WildersSmoothing::new(period)?,
+            previous: None,
+            input: PhantomData,
+        })
+    }
+}
+
+impl AverageDirectionalIndex {
+    /// Create a new Average Directional Index instance
+    /// # Arguments
+    /// * `period` - The period of the Wilders Smoothing used throughout, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::AverageDirectionalIndex;
+    ///
+    /// let adx = AverageDirectionalIndex::new(14);
+    /// assert!(adx.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::AverageDirectionalIndex;
+    ///
+    /// let adx = AverageDirectionalIndex::new(0);
+    ///
+    /// assert!(adx.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_input(period)
+    }
+
+    /// Create a new Average Directional Index instance with the conventional period of 14
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::AverageDirectionalIndex;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let adx = AverageDirectionalIndex::standard();
+    /// assert_eq!(adx.period(), 14);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl Default for AverageDirectionalIndex {
+    /// Create a new Average Directional Index with the conventional period of 14
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for AverageDirectionalIndex<I> {
+    /// Input is anything with a high, low and close, by default a tuple of (high, low, close)
+    type Input = I;
+    type Output = Option<f64>;
+}
+
+impl<I: HasHighLow + HasClose> Validate for AverageDirectionalIndex<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for AverageDirectionalIndex<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+        let result = self
+            .previous
+            .and_then(|(previous_high, previous_low, previous_close)| {
+                let up = high - previous_high;
+                let down = previous_low - low;
+                let positive = match up > down && up > 0.0 {
+                    true => up,
+                    false => 0.0,
+                };
+                let negative = match down > up && down > 0.0 {
+                    true => down,
+                    false => 0.0,
+                };
+                let true_range = (high - low)
+                    .max((high - previous_close).abs())
+                    .max((low - previous_close).abs());
+                let true_range = self.true_range.execute(true_range, execution_context);
+                let positive = self.positive_movement.execute(positive, execution_context);
+                let negative = self.negative_movement.execute(negative, execution_context);
+                let (true_range, positive, negative) = (true_range?, positive?, negative?);
+                let (positive, negative) = match true_range == 0.0 {
+                    true => (0.0, 0.0),
+                    false => (100.0 * positive / true_range, 100.0 * negative / true_range),
+                };
+                let directional_index = match positive + negative == 0.0 {
+                    true => 0.0,
+                    false => 100.0 * (positive - negative).abs() / (positive + negative),
+                };
+                self.directional_index
+                    .execute(directional_index, execution_context)
+            });
+        if let ExecutionContext::Apply = execution_context {
+            self.previous = Some((high, low, close));
+        }
+        result
+    }
+}
+
+impl<I: HasHighLow + HasClose> Current for AverageDirectionalIndex<I> {
+    fn current(&self) -> Self::Output {
+        self.directional_index.current()
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for AverageDirectionalIndex<I> {
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(
+            usize::from(self.previous.is_none()) + self.true_range.warmup_remaining(),
+            self.directional_index.warmup_remaining(),
+        )
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for AverageDirectionalIndex<I> {
+    fn period(&self) -> usize {
+        self.true_range.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        2 * self.period()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Candle;
+    use approx::assert_abs_diff_eq;
+
+    const BARS: [(f64, f64, f64); 8] = [
+        (10.0, 8.0, 9.0),
+        (11.0, 9.0, 10.0),
+        (12.0, 10.0, 11.0),
+        (11.0, 9.0, 9.5),
+        (10.0, 7.0, 8.0),
+        (12.0, 8.0, 11.0),
+        (13.0, 11.0, 12.0),
+        (14.0, 12.0, 13.0),
+    ];
+
+    #[test]
+    fn test_apply() {
+        let mut adx = AverageDirectionalIndex::new(2).unwrap();
+        let expected = [
+            None,
+            None,
+            None,
+            Some(50.0),
+            Some(58.333333333333336),
+            Some(43.452380952380956),
+            Some(48.99891774891775),
+            Some(61.341564137616764),
+        ];
+        for (bar, expected) in BARS.into_iter().zip(expected) {
+            match expected {
+                Some(expected) => {
+                    assert_abs_diff_eq!(adx.apply(bar).unwrap(), expected, epsilon = 10e-7)
+                }
+                None => assert_eq!(adx.apply(bar), None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut adx = AverageDirectionalIndex::new(2).unwrap();
+        for bar in &BARS[..3] {
+            assert_eq!(adx.evaluate(*bar), None);
+            assert_eq!(adx.apply(*bar), None);
+        }
+        assert_eq!(adx.evaluate(BARS[3]), Some(50.0));
+        assert_eq!(adx.evaluate(BARS[3]), Some(50.0));
+        assert_eq!(adx.apply(BARS[3]), Some(50.0));
+        let evaluated = adx.evaluate(BARS[4]).unwrap();
+        assert_abs_diff_eq!(evaluated, 58.333333333333336, epsilon = 10e-7);
+        assert_eq!(adx.apply(BARS[4]), Some(evaluated));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut adx = AverageDirectionalIndex::new(2).unwrap();
+        assert_eq!(adx.current(), None);
+        for bar in &BARS[..4] {
+            adx.apply(*bar);
+        }
+        adx.evaluate(BARS[4]);
+        assert_eq!(adx.current(), Some(50.0));
+    }
+
+    #[test]
+    fn test_flat_input() {
+        let mut adx = AverageDirectionalIndex::new(2).unwrap();
+        for _ in 0..3 {
+            assert_eq!(adx.apply((1.0, 1.0, 1.0)), None);
+        }
+        assert_eq!(adx.apply((1.0, 1.0, 1.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let adx = AverageDirectionalIndex::new(0);
+        assert!(adx.is_err());
+    }
+
+    #[test]
+    fn test_periodic() {
+        let adx = AverageDirectionalIndex::new(14).unwrap();
+        assert_eq!(adx.period(), 14);
+        assert_eq!(adx.effective_lookback(), 28);
+        assert_eq!(adx.effective_lookback(), adx.warmup_remaining());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut adx = AverageDirectionalIndex::new(2).unwrap();
+        assert_eq!(adx.warmup_remaining(), 4);
+        for bar in BARS {
+            let remaining = adx.warmup_remaining();
+            let output = adx.apply(bar);
+            assert_eq!(adx.warmup_remaining(), remaining.saturating_sub(1));
+            assert_eq!(adx.is_ready(), output.is_some());
+        }
+    }
+
+    #[test]
+    fn test_for_input() {
+        let mut adx = AverageDirectionalIndex::new(2).unwrap();
+        let mut candles = AverageDirectionalIndex::<Candle>::for_input(2).unwrap();
+        for (high, low, close) in BARS {
+            let candle = Candle { open: close, high, low, close, volume: 0.0, timestamp: 0 };
+            assert_eq!(candles.evaluate(candle), adx.evaluate((high, low, close)));
+            assert_eq!(candles.apply(candle), adx.apply((high, low, close)));
+        }
+        assert!(candles.current().is_some());
+    }
+}
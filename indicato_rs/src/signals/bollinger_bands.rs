@@ -1,17 +1,150 @@
-use std::collections::VecDeque;
+use std::{collections::VecDeque, marker::PhantomData};
 
-use crate::traits::{Current, Executable, ExecutionContext, IoState};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::traits::{
+    Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+};
 use crate::fin_error::{FinError, FinErrorType};
-use crate::deque_math::DequeMathExtF64;
+use crate::inspect::{SignalState, StateValue, ToState};
+use crate::deque_math::DequeMathExt;
+use crate::types::{HasClose, HasHighLow};
+use crate::validation;
+#[cfg(feature = "simd")]
+use crate::simd;
+
+use super::HighLowClose;
 
-pub struct BollingerBands {
+/// Output of the [`BollingerBands`] signal.
+///
+/// Tuples of (upper, middle, lower) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BollingerOutput {
+    /// The upper band.
+    pub upper: f64,
+    /// The middle band, the Simple Moving Average of the typical price.
+    pub middle: f64,
+    /// The lower band.
+    pub lower: f64,
+}
+
+impl From<(f64, f64, f64)> for BollingerOutput {
+    fn from((upper, middle, lower): (f64, f64, f64)) -> Self {
+        Self {
+            upper,
+            middle,
+            lower,
+        }
+    }
+}
+
+impl From<BollingerOutput> for (f64, f64, f64) {
+    fn from(output: BollingerOutput) -> Self {
+        (output.upper, output.middle, output.lower)
+    }
+}
+
+/// # Bollinger Bands
+/// Container for Bollinger Bands aggregation
+///
+/// The middle band is the Simple Moving Average of the typical price, with the upper and lower bands placed a number
+/// of standard deviations of the typical price above and below it. The aggregation will begin producing values
+/// immediately, with the period being filled as values are applied, the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>t</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub><mi>h</mi><mn>n</mn></msub>
+///                 <mo>+</mo>
+///                 <msub><mi>l</mi><mn>n</mn></msub>
+///                 <mo>+</mo>
+///                 <msub><mi>c</mi><mn>n</mn></msub>
+///             </mrow>
+///             <mn>3</mn>
+///         </mfrac>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>SMA</mi>
+///         <mo>(</mo>
+///         <mi>t</mi>
+///         <mo>)</mo>
+///         <mo>±</mo>
+///         <mi>k</mi>
+///         <mi>σ</mi>
+///         <mo>(</mo>
+///         <mi>t</mi>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `t` is the typical price, `h` is the high, `l` is the low,
+/// `c` is the close, `k` is the number of standard deviations and `σ` is the population standard deviation of the
+/// typical price over the period.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{BollingerBands, BollingerOutput, HighLowClose};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Bollinger Bands with a period of 2 and 2 standard deviations
+/// let mut bollinger = BollingerBands::new(2, 2.0).unwrap();
+///
+/// // apply some values and check their output
+/// let output = bollinger.apply(HighLowClose { high: 3.0, low: 3.0, close: 3.0 });
+/// assert_eq!(output, BollingerOutput { upper: 3.0, middle: 3.0, lower: 3.0 });
+/// let output = bollinger.apply((5.0, 5.0, 5.0).into());
+/// assert_eq!(output.upper, 6.0);
+/// assert_eq!(output.middle, 4.0);
+/// assert_eq!(output.lower, 2.0);
+///
+/// // evaluate some values, these won't affect the internal state of the Bollinger Bands
+/// assert_eq!(bollinger.evaluate((1.0, 1.0, 1.0).into()), (7.0, 3.0, -1.0).into());
+///
+/// // fetch the current value of the Bollinger Bands
+/// assert_eq!(bollinger.current(), output);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BollingerBands<I: HasHighLow + HasClose = HighLowClose> {
     typical_price: VecDeque<f64>,
     std_dev_count: f64,
-    period: usize,   
+    period: usize,
+    input: PhantomData<fn(I)>,
 }
 
-impl BollingerBands {
-    pub fn new(period: usize, std_dev_count: f64) -> Result<Self, FinError> {
+impl<I: HasHighLow + HasClose> BollingerBands<I> {
+    /// Create a new Bollinger Bands instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the Bollinger Bands aggregation, must be greater than 0
+    /// * `std_dev_count` - The number of standard deviations the upper and lower bands are placed from the middle band
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::BollingerBands;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let bollinger = BollingerBands::<Candle>::for_input(20, 2.0);
+    /// assert!(bollinger.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    pub fn for_input(period: usize, std_dev_count: f64) -> Result<Self, FinError> {
         match period {
             0 => Err(FinError::new(
                 FinErrorType::InvalidInput,
@@ -21,21 +154,156 @@ impl BollingerBands {
                 typical_price: VecDeque::with_capacity(period),
                 std_dev_count,
                 period,
+                input: PhantomData,
             }),
         }
     }
 }
 
-impl IoState for BollingerBands {
-    /// Input is a tuple of (high, low, close)
-    type Input = (f64, f64, f64);
-    /// Output is a tuple of (upper_band, typical_price_sma, lower_band)
-    type Output = (f64, f64, f64);
+impl BollingerBands {
+    /// Create a new Bollinger Bands instance
+    /// # Arguments
+    /// * `period` - The period of the Bollinger Bands aggregation, must be greater than 0
+    /// * `std_dev_count` - The number of standard deviations the upper and lower bands are placed from the middle band
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::BollingerBands;
+    ///
+    /// let bollinger = BollingerBands::new(20, 2.0);
+    /// assert!(bollinger.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::BollingerBands;
+    ///
+    /// let bollinger = BollingerBands::new(0, 2.0);
+    ///
+    /// assert!(bollinger.is_err());
+    /// ```
+    pub fn new(period: usize, std_dev_count: f64) -> Result<Self, FinError> {
+        Self::for_input(period, std_dev_count)
+    }
+
+    /// Create a new Bollinger Bands instance with the conventional period of 20 and bands 2 standard deviations
+    /// from the middle band
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::BollingerBands;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let bollinger = BollingerBands::standard();
+    /// assert_eq!(bollinger.period(), 20);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(20, 2.0).unwrap()
+    }
+
+    /// Create a new [`BollingerBandsBuilder`] starting from the conventional period of 20 and bands 2 standard
+    /// deviations from the middle band
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::BollingerBands;
+    ///
+    /// let bollinger = BollingerBands::builder().period(10).std_dev_count(1.5).build();
+    /// assert!(bollinger.is_ok());
+    /// ```
+    pub fn builder() -> BollingerBandsBuilder {
+        BollingerBandsBuilder::default()
+    }
+}
+
+impl Default for BollingerBands {
+    /// Create a new Bollinger Bands with the conventional period of 20 and bands 2 standard deviations
+    /// from the middle band
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Builder for [`BollingerBands`] and [`BollingerBandsExtended`], created with [`BollingerBands::builder`].
+///
+/// Any parameter that isn't set keeps its conventional value, the period is validated when the bands are built.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BollingerBandsBuilder {
+    period: usize,
+    std_dev_count: f64,
+}
+
+impl BollingerBandsBuilder {
+    /// Set the period of the Bollinger Bands aggregation
+    pub fn period(mut self, period: usize) -> Self {
+        self.period = period;
+        self
+    }
+
+    /// Set the number of standard deviations the upper and lower bands are placed from the middle band
+    pub fn std_dev_count(mut self, std_dev_count: f64) -> Self {
+        self.std_dev_count = std_dev_count;
+        self
+    }
+
+    /// Build the Bollinger Bands
+    ///
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::BollingerBands;
+    ///
+    /// let bollinger = BollingerBands::builder().period(0).build();
+    ///
+    /// assert!(bollinger.is_err());
+    /// ```
+    pub fn build(self) -> Result<BollingerBands, FinError> {
+        BollingerBands::new(self.period, self.std_dev_count)
+    }
+
+    /// Build the Bollinger Bands Extended, which also outputs the %B and bandwidth
+    ///
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::BollingerBands;
+    ///
+    /// let bollinger = BollingerBands::builder().period(0).build_extended();
+    ///
+    /// assert!(bollinger.is_err());
+    /// ```
+    pub fn build_extended(self) -> Result<BollingerBandsExtended, FinError> {
+        BollingerBandsExtended::new(self.period, self.std_dev_count)
+    }
+}
+
+impl Default for BollingerBandsBuilder {
+    /// Create a new Bollinger Bands builder with the conventional period of 20 and bands 2 standard deviations from
+    /// the middle band
+    fn default() -> Self {
+        Self {
+            period: 20,
+            std_dev_count: 2.0,
+        }
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for BollingerBands<I> {
+    /// Input is anything with a high, low and close, by default a [`HighLowClose`]
+    type Input = I;
+    type Output = BollingerOutput;
 }
 
-impl Executable for BollingerBands {
+impl<I: HasHighLow + HasClose> Validate for BollingerBands<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for BollingerBands<I> {
     fn execute(&mut self, input: Self::Input, execution_context: &ExecutionContext) -> Self::Output {
-        let typical_price = (input.0 + input.1 + input.2) / 3.0;
+        let typical_price = (input.high() + input.low() + input.close()) / 3.0;
         let mean: f64;
         let std_dev: f64;
         match execution_context {
@@ -57,18 +325,505 @@ impl Executable for BollingerBands {
                 std_dev = typical_price_clone.standard_deviation();
             }
         }
-        let upper_band = mean + (std_dev * self.std_dev_count);
-        let lower_band = mean - (std_dev * self.std_dev_count);
-        (upper_band, mean, lower_band)
+        BollingerOutput {
+            upper: mean + (std_dev * self.std_dev_count),
+            middle: mean,
+            lower: mean - (std_dev * self.std_dev_count),
+        }
+    }
+
+    /// With the `simd` feature the statistics of four windows of typical prices are calculated at a time.
+    #[cfg(feature = "simd")]
+    fn execute_slice(&mut self, inputs: &[I]) -> Vec<BollingerOutput> {
+        let typical_prices = inputs
+            .iter()
+            .map(|input| (input.high() + input.low() + input.close()) / 3.0)
+            .collect::<Vec<f64>>();
+        let std_dev_count = self.std_dev_count;
+        simd::apply_windows(
+            &mut self.typical_price,
+            self.period,
+            &typical_prices,
+            simd::window_mean_std_devs,
+            |window| (window.mean(), window.standard_deviation()),
+        )
+        .into_iter()
+        .map(|(mean, std_dev)| BollingerOutput {
+            upper: mean + (std_dev * std_dev_count),
+            middle: mean,
+            lower: mean - (std_dev * std_dev_count),
+        })
+        .collect()
     }
 }
 
-impl Current for BollingerBands{
-    fn current(&self) -> (f64, f64, f64) {
+impl<I: HasHighLow + HasClose> Current for BollingerBands<I> {
+    fn current(&self) -> Self::Output {
         let mean = self.typical_price.mean();
         let std_dev = self.typical_price.standard_deviation();
-        let upper_band = mean + (std_dev * self.std_dev_count);
-        let lower_band = mean - (std_dev * self.std_dev_count);
-        (upper_band, mean, lower_band)
+        BollingerOutput {
+            upper: mean + (std_dev * self.std_dev_count),
+            middle: mean,
+            lower: mean - (std_dev * self.std_dev_count),
+        }
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for BollingerBands<I> {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.typical_price.len())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for BollingerBands<I> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+/// Output of the [`BollingerBandsExtended`] signal, the bands of a [`BollingerOutput`] along with the %B and Bandwidth
+/// derived from them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BollingerBandsExtendedOutput {
+    /// The upper band.
+    pub upper: f64,
+    /// The middle band, the Simple Moving Average of the typical price.
+    pub middle: f64,
+    /// The lower band.
+    pub lower: f64,
+    /// The position of the close within the bands, 0 at the lower band and 1 at the upper band.
+    pub percent_b: f64,
+    /// The width of the bands relative to the middle band.
+    pub bandwidth: f64,
+}
+
+impl ToState for BollingerBandsExtendedOutput {
+    fn to_state(&self) -> StateValue {
+        StateValue::Nested(
+            SignalState::new("BollingerBandsExtendedOutput")
+                .field("upper", self.upper.to_state())
+                .field("middle", self.middle.to_state())
+                .field("lower", self.lower.to_state())
+                .field("percent_b", self.percent_b.to_state())
+                .field("bandwidth", self.bandwidth.to_state()),
+        )
+    }
+}
+
+/// # Bollinger Bands Extended
+/// Container for Bollinger Bands aggregation with %B and Bandwidth
+///
+/// Produces the same bands as [`BollingerBands`], along with the %B and Bandwidth derived from them:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <mi>%B</mi>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub><mi>c</mi><mn>n</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>l</mi><mn>n</mn></msub>
+///             </mrow>
+///             <mrow>
+///                 <msub><mi>u</mi><mn>n</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>l</mi><mn>n</mn></msub>
+///             </mrow>
+///         </mfrac>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <mi>Bandwidth</mi>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub><mi>u</mi><mn>n</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>l</mi><mn>n</mn></msub>
+///             </mrow>
+///             <msub><mi>m</mi><mn>n</mn></msub>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `n` is the current step, `c` is the close, `u` is the upper band, `m` is the middle band and `l` is the
+/// lower band. When the bands have no width the %B will be 0.5, and when the middle band is 0 the Bandwidth will
+/// be 0.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{BollingerBandsExtended, BollingerBandsExtendedOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Bollinger Bands Extended with a period of 2 and 2 standard deviations
+/// let mut bollinger = BollingerBandsExtended::new(2, 2.0).unwrap();
+///
+/// // apply some values and check their output
+/// bollinger.apply((3.0, 3.0, 3.0).into());
+/// let output = bollinger.apply((5.0, 5.0, 5.0).into());
+/// assert_eq!(output.upper, 6.0);
+/// assert_eq!(output.percent_b, 0.75);
+/// assert_eq!(output.bandwidth, 1.0);
+///
+/// // evaluate some values, these won't affect the internal state of the Bollinger Bands Extended
+/// assert_eq!(bollinger.evaluate((1.0, 1.0, 1.0).into()).percent_b, 0.25);
+///
+/// // fetch the current value of the Bollinger Bands Extended
+/// assert_eq!(bollinger.current(), output);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct BollingerBandsExtended<I: HasHighLow + HasClose = HighLowClose> {
+    bands: BollingerBands<I>,
+    current: BollingerBandsExtendedOutput,
+}
+
+impl<I: HasHighLow + HasClose> BollingerBandsExtended<I> {
+    /// Create a new Bollinger Bands Extended instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the Bollinger Bands aggregation, must be greater than 0
+    /// * `std_dev_count` - The number of standard deviations the upper and lower bands are placed from the middle band
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::BollingerBandsExtended;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let bollinger = BollingerBandsExtended::<Candle>::for_input(20, 2.0);
+    /// assert!(bollinger.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    pub fn for_input(period: usize, std_dev_count: f64) -> Result<Self, FinError> {
+        Ok(Self {
+            bands: BollingerBands::for_input(period, std_dev_count)?,
+            current: BollingerBandsExtendedOutput {
+                upper: 0.0,
+                middle: 0.0,
+                lower: 0.0,
+                percent_b: 0.5,
+                bandwidth: 0.0,
+            },
+        })
+    }
+}
+
+impl BollingerBandsExtended {
+    /// Create a new Bollinger Bands Extended instance
+    /// # Arguments
+    /// * `period` - The period of the Bollinger Bands aggregation, must be greater than 0
+    /// * `std_dev_count` - The number of standard deviations the upper and lower bands are placed from the middle band
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::BollingerBandsExtended;
+    ///
+    /// let bollinger = BollingerBandsExtended::new(20, 2.0);
+    /// assert!(bollinger.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::BollingerBandsExtended;
+    ///
+    /// let bollinger = BollingerBandsExtended::new(0, 2.0);
+    ///
+    /// assert!(bollinger.is_err());
+    /// ```
+    pub fn new(period: usize, std_dev_count: f64) -> Result<Self, FinError> {
+        Self::for_input(period, std_dev_count)
+    }
+
+    /// Create a new Bollinger Bands Extended instance with the conventional period of 20 and bands 2 standard deviations
+    /// from the middle band
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::BollingerBandsExtended;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let bollinger = BollingerBandsExtended::standard();
+    /// assert_eq!(bollinger.period(), 20);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(20, 2.0).unwrap()
+    }
+}
+
+impl Default for BollingerBandsExtended {
+    /// Create a new Bollinger Bands Extended with the conventional period of 20 and bands 2 standard deviations
+    /// from the middle band
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for BollingerBandsExtended<I> {
+    /// Input is anything with a high, low and close, by default a [`HighLowClose`]
+    type Input = I;
+    type Output = BollingerBandsExtendedOutput;
+}
+
+impl<I: HasHighLow + HasClose> Validate for BollingerBandsExtended<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for BollingerBandsExtended<I> {
+    fn execute(&mut self, input: Self::Input, execution_context: &ExecutionContext) -> Self::Output {
+        let close = input.close();
+        let BollingerOutput {
+            upper,
+            middle,
+            lower,
+        } = self.bands.execute(input, execution_context);
+        let width = upper - lower;
+        let result = BollingerBandsExtendedOutput {
+            upper,
+            middle,
+            lower,
+            percent_b: match width == 0.0 {
+                true => 0.5,
+                false => (close - lower) / width,
+            },
+            bandwidth: match middle == 0.0 {
+                true => 0.0,
+                false => width / middle,
+            },
+        };
+        if let ExecutionContext::Apply = execution_context {
+            self.current = result;
+        }
+        result
+    }
+}
+
+impl<I: HasHighLow + HasClose> Current for BollingerBandsExtended<I> {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for BollingerBandsExtended<I> {
+    fn warmup_remaining(&self) -> usize {
+        self.bands.warmup_remaining()
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for BollingerBandsExtended<I> {
+    fn period(&self) -> usize {
+        self.bands.period()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{ApplyBatch, TryApply};
+    use crate::types::Candle;
+
+    #[test]
+    fn test_apply() {
+        let mut bollinger = BollingerBands::new(2, 2.0).unwrap();
+        assert_eq!(bollinger.apply((3.0, 3.0, 3.0).into()), (3.0, 3.0, 3.0).into());
+        assert_eq!(bollinger.apply((5.0, 5.0, 5.0).into()), (6.0, 4.0, 2.0).into());
+        assert_eq!(bollinger.apply((1.0, 1.0, 1.0).into()), (7.0, 3.0, -1.0).into());
+        assert_eq!(bollinger.apply((4.0, 1.0, 1.0).into()), (2.5, 1.5, 0.5).into());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut bollinger = BollingerBands::new(2, 2.0).unwrap();
+        assert_eq!(bollinger.evaluate((3.0, 3.0, 3.0).into()), (3.0, 3.0, 3.0).into());
+        assert_eq!(bollinger.apply((3.0, 3.0, 3.0).into()), (3.0, 3.0, 3.0).into());
+        assert_eq!(bollinger.evaluate((5.0, 5.0, 5.0).into()), (6.0, 4.0, 2.0).into());
+        assert_eq!(bollinger.evaluate((1.0, 1.0, 1.0).into()), (4.0, 2.0, 0.0).into());
+        assert_eq!(bollinger.apply((5.0, 5.0, 5.0).into()), (6.0, 4.0, 2.0).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut bollinger = BollingerBands::new(2, 2.0).unwrap();
+        bollinger.apply((3.0, 3.0, 3.0).into());
+        bollinger.apply((5.0, 5.0, 5.0).into());
+        bollinger.evaluate((1.0, 1.0, 1.0).into());
+        assert_eq!(bollinger.current(), (6.0, 4.0, 2.0).into());
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let bollinger = BollingerBands::new(0, 2.0);
+        assert!(bollinger.is_err());
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut bollinger = BollingerBands::new(2, 2.0).unwrap();
+        assert!(bollinger.try_apply((3.0, 3.0, 3.0).into()).is_ok());
+        assert_eq!(
+            bollinger.try_apply((1.0, 5.0, 5.0).into()).err().unwrap().message,
+            "High must not be less than the low"
+        );
+        assert_eq!(bollinger.current(), (3.0, 3.0, 3.0).into());
+    }
+
+    #[test]
+    fn test_extended_apply() {
+        let mut bollinger = BollingerBandsExtended::new(2, 2.0).unwrap();
+        assert_eq!(
+            bollinger.apply((3.0, 3.0, 3.0).into()),
+            BollingerBandsExtendedOutput {
+                upper: 3.0,
+                middle: 3.0,
+                lower: 3.0,
+                percent_b: 0.5,
+                bandwidth: 0.0,
+            }
+        );
+        assert_eq!(
+            bollinger.apply((5.0, 5.0, 5.0).into()),
+            BollingerBandsExtendedOutput {
+                upper: 6.0,
+                middle: 4.0,
+                lower: 2.0,
+                percent_b: 0.75,
+                bandwidth: 1.0,
+            }
+        );
+        assert_eq!(
+            bollinger.apply((1.0, 1.0, 1.0).into()),
+            BollingerBandsExtendedOutput {
+                upper: 7.0,
+                middle: 3.0,
+                lower: -1.0,
+                percent_b: 0.25,
+                bandwidth: 8.0 / 3.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_extended_zero_middle() {
+        let mut bollinger = BollingerBandsExtended::new(2, 2.0).unwrap();
+        bollinger.apply((1.0, 1.0, 1.0).into());
+        let output = bollinger.apply((-1.0, -1.0, -1.0).into());
+        assert_eq!(output.middle, 0.0);
+        assert_eq!(output.bandwidth, 0.0);
+        assert_eq!(output.percent_b, 0.25);
+    }
+
+    #[test]
+    fn test_extended_evaluate_and_current() {
+        let mut bollinger = BollingerBandsExtended::new(2, 2.0).unwrap();
+        assert_eq!(bollinger.current().percent_b, 0.5);
+        bollinger.apply((3.0, 3.0, 3.0).into());
+        let output = bollinger.apply((5.0, 5.0, 5.0).into());
+        assert_eq!(bollinger.evaluate((1.0, 1.0, 1.0).into()).percent_b, 0.25);
+        assert_eq!(bollinger.current(), output);
+        assert_eq!(bollinger.apply((1.0, 1.0, 1.0).into()).percent_b, 0.25);
+    }
+
+    #[test]
+    fn test_extended_invalid_period() {
+        let bollinger = BollingerBandsExtended::new(0, 2.0);
+        assert!(bollinger.is_err());
+    }
+
+    #[test]
+    fn test_standard() {
+        let mut standard = BollingerBands::standard();
+        let mut bollinger = BollingerBands::new(20, 2.0).unwrap();
+        for input in [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 8.0, 7.0] {
+            let input = HighLowClose::from((input + 1.0, input - 1.0, input));
+            assert_eq!(standard.apply(input), bollinger.apply(input));
+        }
+        assert_eq!(BollingerBandsExtended::standard().period(), 20);
+    }
+
+    #[test]
+    fn test_builder() {
+        let builder = BollingerBands::builder().period(3).std_dev_count(1.5);
+        let mut built = builder.build().unwrap();
+        let mut built_extended = builder.build_extended().unwrap();
+        let mut bollinger = BollingerBands::new(3, 1.5).unwrap();
+        let mut extended = BollingerBandsExtended::new(3, 1.5).unwrap();
+        for input in [1.0, 3.0, 2.0, 5.0, 4.0] {
+            let input = HighLowClose::from((input + 1.0, input - 1.0, input));
+            assert_eq!(built.apply(input), bollinger.apply(input));
+            assert_eq!(built_extended.apply(input), extended.apply(input));
+        }
+        assert!(BollingerBands::builder().period(0).build().is_err());
+    }
+
+    #[test]
+    fn test_apply_slice() {
+        let inputs = (0..37)
+            .map(|i| f64::from(i * 7 % 13) - 6.5)
+            .map(|price| HighLowClose::from((price + 1.0, price - 2.0, price)))
+            .collect::<Vec<HighLowClose>>();
+        let mut bollinger = BollingerBands::new(6, 2.0).unwrap();
+        let mut expected = bollinger.clone();
+        bollinger.apply(inputs[0]);
+        expected.apply(inputs[0]);
+        let outputs = inputs.iter().map(|&input| expected.apply(input)).collect::<Vec<_>>();
+        assert_eq!(bollinger.apply_slice(&inputs), outputs);
+        assert_eq!(bollinger.current(), expected.current());
+    }
+
+    #[test]
+    fn test_for_input() {
+        let mut bollinger = BollingerBandsExtended::new(3, 2.0).unwrap();
+        let mut candles = BollingerBandsExtended::<Candle>::for_input(3, 2.0).unwrap();
+        for price in [3.0, 5.0, 1.0, 4.0] {
+            let candle = Candle {
+                open: price,
+                high: price + 1.0,
+                low: price - 2.0,
+                close: price,
+                volume: 0.0,
+                timestamp: 0,
+            };
+            assert_eq!(candles.apply(candle), bollinger.apply(candle.into()));
+        }
+        assert_eq!(candles.current(), bollinger.current());
+    }
+
+    #[test]
+    fn test_inspect_extended() {
+        let mut bollinger = BollingerBandsExtended::new(2, 2.0).unwrap();
+        bollinger.apply((1.0, 1.0, 1.0).into());
+        bollinger.apply((3.0, 3.0, 3.0).into());
+        let state = bollinger.inspect();
+        let Some(StateValue::Nested(current)) = state.get("current") else {
+            panic!("the current output is nested");
+        };
+        assert_eq!(current.name, "BollingerBandsExtendedOutput");
+        assert_eq!(current.get("middle"), Some(&StateValue::Number(2.0)));
+        assert_eq!(current.get("bandwidth"), Some(&StateValue::Number(2.0)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_outputs() {
+        let mut bollinger = BollingerBands::new(2, 2.0).unwrap();
+        bollinger.apply((1.0, 1.0, 1.0).into());
+        let output = bollinger.apply((3.0, 3.0, 3.0).into());
+        let json = serde_json::to_string(&output).unwrap();
+        assert_eq!(json, r#"{"upper":4.0,"middle":2.0,"lower":0.0}"#);
+        assert_eq!(serde_json::from_str::<BollingerOutput>(&json).unwrap(), output);
+
+        let mut extended = BollingerBandsExtended::new(2, 2.0).unwrap();
+        extended.apply((1.0, 1.0, 1.0).into());
+        let output = extended.apply((3.0, 3.0, 3.0).into());
+        let json = serde_json::to_string(&output).unwrap();
+        assert_eq!(serde_json::from_str::<BollingerBandsExtendedOutput>(&json).unwrap(), output);
     }
 }
@@ -0,0 +1,163 @@
+use std::fmt;
+
+use indicato_rs_proc::{Apply, Evaluate};
+
+use crate::traits::{
+    Apply, Current, DynSignal, Evaluate, Executable, ExecutionContext, IoState, WarmUp,
+};
+
+/// # Boxed Signal
+/// Container for a signal of any type with the input `I` and output `O`
+///
+/// The signal is stored as a [`DynSignal`] trait object, so heterogeneous collections of signals with the same input
+/// and output types can be stored together, such as in a `Vec<BoxedSignal<f64, f64>>`, and driven uniformly.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{BoxedSignal, ExponentialMovingAverage, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // store different signals with the same input and output types together
+/// let mut signals: Vec<BoxedSignal<f64, f64>> = vec![
+///     BoxedSignal::new(SimpleMovingAverage::new(3).unwrap()),
+///     BoxedSignal::new(ExponentialMovingAverage::new(3).unwrap()),
+/// ];
+///
+/// // apply some values to every signal and check their output
+/// let outputs: Vec<f64> = signals.iter_mut().map(|signal| signal.apply(2.0)).collect();
+/// assert_eq!(outputs, vec![2.0, 2.0]);
+/// let outputs: Vec<f64> = signals.iter_mut().map(|signal| signal.apply(4.0)).collect();
+/// assert_eq!(outputs, vec![3.0, 3.0]);
+///
+/// // evaluate a value, this won't affect the internal state of the signals
+/// assert_eq!(signals[1].evaluate(5.0), 4.0);
+///
+/// // fetch the current value of the signals
+/// assert_eq!(signals[1].current(), 3.0);
+/// ```
+#[derive(Apply, Evaluate)]
+pub struct BoxedSignal<I, O> {
+    signal: Box<dyn DynSignal<I, O>>,
+}
+
+impl<I, O> Clone for BoxedSignal<I, O> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: self.signal.clone_box(),
+        }
+    }
+}
+
+impl<I, O> fmt::Debug for BoxedSignal<I, O> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedSignal").finish_non_exhaustive()
+    }
+}
+
+impl<I, O> IoState for BoxedSignal<I, O> {
+    type Input = I;
+    type Output = O;
+}
+
+impl<I, O> BoxedSignal<I, O> {
+    /// Create a new Boxed Signal instance
+    /// # Arguments
+    /// * `signal` - The signal to box, its input and output types become the input and output types of the box
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{BoxedSignal, RelativeStrengthIndex};
+    ///
+    /// let rsi: BoxedSignal<f64, Option<f64>> = BoxedSignal::new(RelativeStrengthIndex::new(14, 14).unwrap());
+    /// ```
+    pub fn new<S>(signal: S) -> Self
+    where
+        S: Executable<Input = I, Output = O> + Current + WarmUp + Clone + Send + Sync + 'static,
+    {
+        Self {
+            signal: Box::new(signal),
+        }
+    }
+}
+
+impl<I, O> Executable for BoxedSignal<I, O> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        self.signal.execute(input, execution_context)
+    }
+
+    fn execute_slice(&mut self, inputs: &[I]) -> Vec<O>
+    where
+        I: Clone,
+    {
+        self.signal.execute_slice(inputs)
+    }
+}
+
+impl<I, O> Current for BoxedSignal<I, O> {
+    fn current(&self) -> Self::Output {
+        self.signal.current()
+    }
+}
+
+impl<I, O> WarmUp for BoxedSignal<I, O> {
+    fn warmup_remaining(&self) -> usize {
+        self.signal.warmup_remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{Momentum, RateOfChange, SimpleMovingAverage};
+    use crate::traits::ApplyBatch;
+
+    #[test]
+    fn test_apply() {
+        let mut signals: Vec<BoxedSignal<f64, Option<f64>>> = vec![
+            BoxedSignal::new(Momentum::new(1).unwrap()),
+            BoxedSignal::new(RateOfChange::new(1).unwrap()),
+        ];
+        for signal in signals.iter_mut() {
+            assert_eq!(signal.apply(2.0), None);
+        }
+        assert_eq!(signals[0].apply(3.0), Some(1.0));
+        assert_eq!(signals[1].apply(3.0), Some(50.0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut signal: BoxedSignal<f64, Option<f64>> = BoxedSignal::new(Momentum::new(1).unwrap());
+        assert_eq!(signal.apply(2.0), None);
+        assert_eq!(signal.evaluate(5.0), Some(3.0));
+        assert_eq!(signal.apply(3.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut signal: BoxedSignal<f64, Option<f64>> = BoxedSignal::new(Momentum::new(1).unwrap());
+        assert_eq!(signal.current(), None);
+        signal.apply(2.0);
+        signal.apply(3.0);
+        assert_eq!(signal.current(), Some(1.0));
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut signal: BoxedSignal<f64, Option<f64>> = BoxedSignal::new(Momentum::new(1).unwrap());
+        signal.apply(2.0);
+        let mut fork = signal.clone();
+        assert_eq!(fork.apply(5.0), Some(3.0));
+        assert_eq!(signal.apply(3.0), Some(1.0));
+    }
+
+    #[test]
+    fn test_apply_slice() {
+        let mut signal: BoxedSignal<f64, f64> = BoxedSignal::new(SimpleMovingAverage::new(2).unwrap());
+        assert_eq!(signal.apply_slice(&[1.0, 3.0, 5.0]), vec![1.0, 2.0, 4.0]);
+        assert_eq!(signal.current(), 4.0);
+    }
+}
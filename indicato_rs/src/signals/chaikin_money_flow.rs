@@ -0,0 +1,329 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    types::{HasClose, HasHighLow, HasVolume},
+    validation,
+};
+
+fn money_flow_volume(high: f64, low: f64, close: f64, volume: f64) -> f64 {
+    match high == low {
+        true => 0.0,
+        false => ((close - low) - (high - close)) / (high - low) * volume,
+    }
+}
+
+fn chaikin_money_flow(values: &VecDeque<(f64, f64)>, period: usize) -> Option<f64> {
+    if values.len() < period {
+        return None;
+    }
+    let (money_flow_volume, volume) = values
+        .iter()
+        .fold((0.0, 0.0), |(mfv_acc, volume_acc), &(mfv, volume)| {
+            (mfv_acc + mfv, volume_acc + volume)
+        });
+    match volume == 0.0 {
+        true => Some(0.0),
+        false => Some(money_flow_volume / volume),
+    }
+}
+
+/// # Chaikin Money Flow
+/// Container for Chaikin Money Flow (CMF) aggregation
+///
+/// The Chaikin Money Flow measures the amount of money flow volume over a period. Each input is weighted by
+/// its money flow multiplier, which describes where the close sits within the high low range:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>M</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mo>(</mo>
+///                 <msub><mi>c</mi><mn>n</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>l</mi><mn>n</mn></msub>
+///                 <mo>)</mo>
+///                 <mo>−</mo>
+///                 <mo>(</mo>
+///                 <msub><mi>h</mi><mn>n</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>c</mi><mn>n</mn></msub>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <msub><mi>h</mi><mn>n</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>l</mi><mn>n</mn></msub>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// The output is then the money flow volume divided by the volume over the period:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mi>k=n-p</mi>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub><mi>M</mi><mn>k</mn></msub>
+///                 <mo>⋅</mo>
+///                 <msub><mi>v</mi><mn>k</mn></msub>
+///             </mrow>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mi>k=n-p</mi>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub><mi>v</mi><mn>k</mn></msub>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period, `M` is the money flow multiplier, `h` is the high value,
+/// `l` is the low value, `c` is the close value and `v` is the volume. A bar with no range has a money flow multiplier of 0,
+/// and a period with no volume produces 0.
+///
+/// The first `p - 1` entries will produce `None` as the period is being filled.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::ChaikinMoneyFlow;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Chaikin Money Flow with a period of 2
+/// let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(cmf.apply((4.0, 2.0, 4.0, 100.0)), None);
+/// assert_eq!(cmf.apply((4.0, 2.0, 2.0, 300.0)), Some(-0.5));
+/// assert_eq!(cmf.apply((4.0, 2.0, 3.0, 100.0)), Some(-0.75));
+///
+/// // evaluate some values, these won't affect the internal state of the CMF
+/// assert_eq!(cmf.evaluate((4.0, 2.0, 4.0, 100.0)), Some(0.5));
+///
+/// // fetch the current value of the CMF
+/// assert_eq!(cmf.current(), Some(-0.75));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChaikinMoneyFlow<I: HasHighLow + HasClose + HasVolume = (f64, f64, f64, f64)> {
+    period: usize,
+    /// The money flow volume and volume of each input over the period.
+    values: VecDeque<(f64, f64)>,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> IoState for ChaikinMoneyFlow<I> {
+    /// Input is anything with a high, low, close and volume, by default a tuple of (high, low, close, volume)
+    type Input = I;
+    type Output = Option<f64>;
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> ChaikinMoneyFlow<I> {
+    /// Create a new Chaikin Money Flow instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the Chaikin Money Flow aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ChaikinMoneyFlow;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let cmf = ChaikinMoneyFlow::<Candle>::for_input(20);
+    /// assert!(cmf.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    pub fn for_input(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+                input: PhantomData,
+            }),
+        }
+    }
+}
+
+impl ChaikinMoneyFlow {
+    /// Create a new Chaikin Money Flow instance
+    /// # Arguments
+    /// * `period` - The period of the Chaikin Money Flow aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ChaikinMoneyFlow;
+    ///
+    /// let cmf = ChaikinMoneyFlow::new(20);
+    /// assert!(cmf.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::ChaikinMoneyFlow;
+    ///
+    /// let cmf = ChaikinMoneyFlow::new(0);
+    ///
+    /// assert!(cmf.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_input(period)
+    }
+
+    /// Create a new Chaikin Money Flow instance with the conventional period of 20
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ChaikinMoneyFlow;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let cmf = ChaikinMoneyFlow::standard();
+    /// assert_eq!(cmf.period(), 20);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(20).unwrap()
+    }
+}
+
+impl Default for ChaikinMoneyFlow {
+    /// Create a new Chaikin Money Flow with the conventional period of 20
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Validate for ChaikinMoneyFlow<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (high, low, close, volume) = (input.high(), input.low(), input.close(), input.volume());
+        validation::value(close, "Close")?;
+        validation::volume(volume)?;
+        validation::high_low(high, low)
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Executable for ChaikinMoneyFlow<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (high, low, close, volume) = (input.high(), input.low(), input.close(), input.volume());
+        let value = (money_flow_volume(high, low, close, volume), volume);
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(value);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                chaikin_money_flow(&self.values, self.period)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(value);
+                if values.len() > self.period {
+                    values.pop_front();
+                }
+                chaikin_money_flow(&values, self.period)
+            }
+        }
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Current for ChaikinMoneyFlow<I> {
+    fn current(&self) -> Self::Output {
+        chaikin_money_flow(&self.values, self.period)
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> WarmUp for ChaikinMoneyFlow<I> {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Periodic for ChaikinMoneyFlow<I> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        assert_eq!(cmf.apply((4.0, 2.0, 4.0, 100.0)), None);
+        assert_eq!(cmf.apply((4.0, 2.0, 2.0, 300.0)), Some(-0.5));
+        assert_eq!(cmf.apply((4.0, 2.0, 3.0, 100.0)), Some(-0.75));
+        assert_eq!(cmf.apply((4.0, 2.0, 4.0, 100.0)), Some(0.5));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        assert_eq!(cmf.apply((4.0, 2.0, 4.0, 100.0)), None);
+        assert_eq!(cmf.evaluate((4.0, 2.0, 2.0, 300.0)), Some(-0.5));
+        assert_eq!(cmf.apply((4.0, 2.0, 2.0, 300.0)), Some(-0.5));
+        assert_eq!(cmf.evaluate((4.0, 2.0, 4.0, 100.0)), Some(-0.5));
+        assert_eq!(cmf.apply((4.0, 2.0, 3.0, 100.0)), Some(-0.75));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        assert_eq!(cmf.apply((4.0, 2.0, 4.0, 100.0)), None);
+        assert_eq!(cmf.current(), None);
+        assert_eq!(cmf.apply((4.0, 2.0, 2.0, 300.0)), Some(-0.5));
+        assert_eq!(cmf.current(), Some(-0.5));
+    }
+
+    #[test]
+    fn test_zero_range_and_volume() {
+        let mut cmf = ChaikinMoneyFlow::new(2).unwrap();
+        assert_eq!(cmf.apply((3.0, 3.0, 3.0, 100.0)), None);
+        assert_eq!(cmf.apply((3.0, 3.0, 3.0, 100.0)), Some(0.0));
+        assert_eq!(cmf.apply((4.0, 2.0, 4.0, 0.0)), Some(0.0));
+        assert_eq!(cmf.apply((4.0, 2.0, 4.0, 0.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let cmf = ChaikinMoneyFlow::new(0);
+        assert!(cmf.is_err());
+    }
+}
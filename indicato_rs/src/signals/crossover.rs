@@ -0,0 +1,234 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::fin_error::FinError;
+use crate::traits::{
+    Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp,
+};
+use crate::validation;
+
+/// An event output by a [`Crossover`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CrossoverEvent {
+    /// The first series crossed from below the second series to above it.
+    CrossAbove,
+    /// The first series crossed from above the second series to below it.
+    CrossBelow,
+    /// The series didn't cross.
+    #[default]
+    None,
+}
+
+crate::inspect::debug_state!(CrossoverEvent);
+
+/// # Crossover
+/// Container for detecting when one series crosses another
+///
+/// Each input is a pair of values from two series, such as a price and its moving average or a MACD and its signal
+/// line. A [`CrossAbove`](CrossoverEvent::CrossAbove) is output when the first series moves from below the second to
+/// above it, and a [`CrossBelow`](CrossoverEvent::CrossBelow) when it moves from above to below.
+///
+/// Equal values touch without crossing, the series are compared against the side they were last on before the touch.
+/// A series that touches and then carries on through crosses when it first moves past, one that touches and then turns
+/// back doesn't cross at all. No event is output until the series have been on one side, so the first inputs can't
+/// cross.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Crossover, CrossoverEvent};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Crossover
+/// let mut crossover = Crossover::new();
+///
+/// // apply some values and check their output
+/// assert_eq!(crossover.apply((1.0, 2.0)), CrossoverEvent::None);
+/// assert_eq!(crossover.apply((2.0, 2.0)), CrossoverEvent::None);
+/// assert_eq!(crossover.apply((3.0, 2.0)), CrossoverEvent::CrossAbove);
+///
+/// // evaluate some values, these won't affect the internal state of the Crossover
+/// assert_eq!(crossover.evaluate((1.0, 2.0)), CrossoverEvent::CrossBelow);
+///
+/// // fetch the current value of the Crossover
+/// assert_eq!(crossover.current(), CrossoverEvent::CrossAbove);
+/// ```
+///
+/// The series can be the outputs of two signals fed the same inputs, such as a fast and a slow moving average:
+/// ```
+/// use indicato_rs::signals::{Crossover, CrossoverEvent, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Combine, Then};
+///
+/// let mut cross = SimpleMovingAverage::new(1)
+///     .unwrap()
+///     .fanout(SimpleMovingAverage::new(3).unwrap())
+///     .then(Crossover::new());
+///
+/// assert_eq!(cross.apply(3.0), Some(CrossoverEvent::None));
+/// assert_eq!(cross.apply(1.0), Some(CrossoverEvent::None));
+/// assert_eq!(cross.apply(5.0), Some(CrossoverEvent::CrossAbove));
+/// ```
+///
+/// Chained after a signal that outputs a line and its signal line, such as the [`KnowSureThing`](super::KnowSureThing),
+/// each pair is passed on once both values are available.
+#[derive(Debug, Clone, Apply, Evaluate, Inspect, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Crossover {
+    current: CrossoverEvent,
+    /// Whether the first series was above the second the last time they differed.
+    above: Option<bool>,
+}
+
+impl Crossover {
+    /// Create a new Crossover instance
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{Crossover, CrossoverEvent};
+    /// use indicato_rs::traits::Current;
+    ///
+    /// let crossover = Crossover::new();
+    /// assert_eq!(crossover.current(), CrossoverEvent::None);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            current: CrossoverEvent::None,
+            above: None,
+        }
+    }
+}
+
+impl IoState for Crossover {
+    /// Input is a tuple of (first series, second series)
+    type Input = (f64, f64);
+    type Output = CrossoverEvent;
+}
+
+impl Validate for Crossover {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (first, second) = *input;
+        validation::value(first, "First series")?;
+        validation::value(second, "Second series")
+    }
+}
+
+impl Executable for Crossover {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (first, second) = input;
+        let above = match first == second {
+            true => self.above,
+            false => Some(first > second),
+        };
+        let result = match (self.above, above) {
+            (Some(false), Some(true)) => CrossoverEvent::CrossAbove,
+            (Some(true), Some(false)) => CrossoverEvent::CrossBelow,
+            _ => CrossoverEvent::None,
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.current = result;
+                self.above = above;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl Current for Crossover {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for Crossover {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.above.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::KnowSureThing;
+    use crate::traits::{Then, TryApply};
+
+    #[test]
+    fn test_apply() {
+        let mut crossover = Crossover::new();
+        assert_eq!(crossover.apply((1.0, 2.0)), CrossoverEvent::None);
+        assert_eq!(crossover.apply((3.0, 2.0)), CrossoverEvent::CrossAbove);
+        assert_eq!(crossover.apply((4.0, 2.0)), CrossoverEvent::None);
+        assert_eq!(crossover.apply((1.0, 2.0)), CrossoverEvent::CrossBelow);
+        assert_eq!(crossover.apply((1.0, 0.0)), CrossoverEvent::CrossAbove);
+    }
+
+    #[test]
+    fn test_touch() {
+        let mut crossover = Crossover::new();
+        crossover.apply((1.0, 2.0));
+        // touching and turning back isn't a cross
+        assert_eq!(crossover.apply((2.0, 2.0)), CrossoverEvent::None);
+        assert_eq!(crossover.apply((1.0, 2.0)), CrossoverEvent::None);
+        // touching and carrying on crosses once the series moves past
+        assert_eq!(crossover.apply((2.0, 2.0)), CrossoverEvent::None);
+        assert_eq!(crossover.apply((2.0, 2.0)), CrossoverEvent::None);
+        assert_eq!(crossover.apply((3.0, 2.0)), CrossoverEvent::CrossAbove);
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut crossover = Crossover::new();
+        assert_eq!(crossover.warmup_remaining(), 1);
+        // equal series haven't been on either side yet
+        assert_eq!(crossover.apply((2.0, 2.0)), CrossoverEvent::None);
+        assert_eq!(crossover.warmup_remaining(), 1);
+        assert_eq!(crossover.apply((3.0, 2.0)), CrossoverEvent::None);
+        assert_eq!(crossover.warmup_remaining(), 0);
+        assert_eq!(crossover.apply((1.0, 2.0)), CrossoverEvent::CrossBelow);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut crossover = Crossover::new();
+        crossover.apply((1.0, 2.0));
+        assert_eq!(crossover.evaluate((3.0, 2.0)), CrossoverEvent::CrossAbove);
+        assert_eq!(crossover.evaluate((3.0, 2.0)), CrossoverEvent::CrossAbove);
+        assert_eq!(crossover.current(), CrossoverEvent::None);
+        assert_eq!(crossover.apply((2.0, 2.0)), CrossoverEvent::None);
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut crossover = Crossover::new();
+        assert!(crossover.try_apply((f64::NAN, 2.0)).is_err());
+        assert!(crossover.try_apply((1.0, f64::NAN)).is_err());
+        assert_eq!(crossover.try_apply((1.0, 2.0)).unwrap(), CrossoverEvent::None);
+    }
+
+    #[test]
+    fn test_warming_up_series() {
+        // the signal line of the KST warms up after the KST, pairs with a missing value are not passed on
+        let mut cross = KnowSureThing::new([1, 2, 3, 4], [2, 2, 2, 2], 3)
+            .unwrap()
+            .then(Crossover::new());
+        let outputs = (0..40)
+            .map(|step| cross.apply((step as f64 * 0.3).sin() * 10.0 + 100.0))
+            .collect::<Vec<_>>();
+        assert!(outputs.iter().take(5).all(Option::is_none));
+        assert!(outputs.contains(&Some(CrossoverEvent::CrossAbove)));
+        assert!(outputs.contains(&Some(CrossoverEvent::CrossBelow)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_events() {
+        let events = vec![CrossoverEvent::None, CrossoverEvent::CrossAbove, CrossoverEvent::CrossBelow];
+        let json = serde_json::to_string(&events).unwrap();
+        assert_eq!(json, r#"["None","CrossAbove","CrossBelow"]"#);
+        assert_eq!(serde_json::from_str::<Vec<CrossoverEvent>>(&json).unwrap(), events);
+    }
+}
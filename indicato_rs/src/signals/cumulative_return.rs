@@ -0,0 +1,227 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+    validation,
+};
+
+/// # Cumulative Return
+/// Container for Cumulative Return aggregation
+///
+/// Compounds a stream of simple per period returns, such as those produced by [`Returns`](super::Returns), into the
+/// total return since the first input. When created with a start price the output is instead the equity curve, the
+/// start price grown by the compounded returns. The aggregation will begin producing values immediately, the
+/// following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <munderover>
+///             <mo>∏</mo>
+///             <mrow><mi>k</mi><mo>=</mo><mn>1</mn></mrow>
+///             <mi>n</mi>
+///         </munderover>
+///         <mo>(</mo>
+///         <mn>1</mn>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mi>k</mi>
+///         </msub>
+///         <mo>)</mo>
+///         <mo>−</mo>
+///         <mn>1</mn>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step and `i` is the input return. With a start price `s` the output
+/// is <code>s⋅∏(1 + i<sub>k</sub>)</code>.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::CumulativeReturn;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Cumulative Return
+/// let mut cumulative = CumulativeReturn::new();
+///
+/// // apply some returns and check their output
+/// assert_eq!(cumulative.apply(0.5), 0.5);
+/// assert_eq!(cumulative.apply(-0.5), -0.25);
+///
+/// // evaluate some values, these won't affect the internal state of the Cumulative Return
+/// assert_eq!(cumulative.evaluate(1.0), 0.5);
+///
+/// // fetch the current value of the Cumulative Return
+/// assert_eq!(cumulative.current(), -0.25);
+///
+/// // compounding from a start price produces the equity curve
+/// let mut equity = CumulativeReturn::with_start_price(100.0).unwrap();
+/// assert_eq!(equity.apply(0.5), 150.0);
+/// assert_eq!(equity.apply(-0.5), 75.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CumulativeReturn {
+    start_price: Option<f64>,
+    /// The product of one plus each return applied.
+    growth: f64,
+}
+
+impl CumulativeReturn {
+    /// Create a new Cumulative Return instance producing the total return
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::CumulativeReturn;
+    ///
+    /// let cumulative = CumulativeReturn::new();
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            start_price: None,
+            growth: 1.0,
+        }
+    }
+
+    /// Create a new Cumulative Return instance producing the equity curve from a start price
+    /// # Arguments
+    /// * `start_price` - The price the returns are compounded from, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::CumulativeReturn;
+    ///
+    /// let equity = CumulativeReturn::with_start_price(10_000.0);
+    /// assert!(equity.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the start price is not greater than 0
+    /// ```
+    /// use indicato_rs::signals::CumulativeReturn;
+    ///
+    /// let equity = CumulativeReturn::with_start_price(0.0);
+    ///
+    /// assert!(equity.is_err());
+    /// ```
+    pub fn with_start_price(start_price: f64) -> Result<Self, FinError> {
+        match start_price > 0.0 {
+            true => Ok(Self {
+                start_price: Some(start_price),
+                growth: 1.0,
+            }),
+            false => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Start price must be greater than 0",
+            )),
+        }
+    }
+
+    fn output(&self, growth: f64) -> f64 {
+        match self.start_price {
+            Some(start_price) => start_price * growth,
+            None => growth - 1.0,
+        }
+    }
+}
+
+impl Default for CumulativeReturn {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IoState for CumulativeReturn {
+    type Input = f64;
+    type Output = f64;
+}
+
+impl Validate for CumulativeReturn {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for CumulativeReturn {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let growth = self.growth * (1.0 + input);
+        match execution_context {
+            ExecutionContext::Apply => self.growth = growth,
+            ExecutionContext::Evaluate => {}
+        }
+        self.output(growth)
+    }
+}
+
+impl Current for CumulativeReturn {
+    fn current(&self) -> Self::Output {
+        self.output(self.growth)
+    }
+}
+
+impl WarmUp for CumulativeReturn {
+    fn warmup_remaining(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut cumulative = CumulativeReturn::new();
+        assert_eq!(cumulative.apply(0.5), 0.5);
+        assert_eq!(cumulative.apply(-0.5), -0.25);
+        assert_eq!(cumulative.apply(0.0), -0.25);
+        assert_eq!(cumulative.apply(1.0), 0.5);
+    }
+
+    #[test]
+    fn test_apply_start_price() {
+        let mut equity = CumulativeReturn::with_start_price(100.0).unwrap();
+        assert_eq!(equity.current(), 100.0);
+        assert_eq!(equity.apply(0.5), 150.0);
+        assert_eq!(equity.apply(-0.5), 75.0);
+        assert_eq!(equity.apply(-1.0), 0.0);
+        assert_eq!(equity.apply(0.5), 0.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut cumulative = CumulativeReturn::new();
+        assert_eq!(cumulative.evaluate(0.5), 0.5);
+        assert_eq!(cumulative.apply(0.5), 0.5);
+        assert_eq!(cumulative.evaluate(-0.5), -0.25);
+        assert_eq!(cumulative.evaluate(1.0), 2.0);
+        assert_eq!(cumulative.apply(-0.5), -0.25);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut cumulative = CumulativeReturn::new();
+        assert_eq!(cumulative.current(), 0.0);
+        cumulative.apply(0.5);
+        cumulative.evaluate(1.0);
+        assert_eq!(cumulative.current(), 0.5);
+    }
+
+    #[test]
+    fn test_invalid_start_price() {
+        assert!(CumulativeReturn::with_start_price(0.0).is_err());
+        assert!(CumulativeReturn::with_start_price(-1.0).is_err());
+    }
+}
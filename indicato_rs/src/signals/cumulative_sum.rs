@@ -0,0 +1,228 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+use num_traits::Float;
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+    validation,
+};
+
+/// # Cumulative Sum
+/// Container for Cumulative Sum aggregation
+///
+/// A running total of the inputs, optionally decaying the previous total by a factor each step so older inputs
+/// contribute less. The aggregation will begin producing values immediately, the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>d</mi>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `d` is the decay factor, which is 1 when there is no decay,
+/// and `i` is the input.
+///
+/// The aggregation is generic over the float type of its inputs and defaults to `f64`.
+/// `new` creates an `f64` aggregation, other types are created with [`for_type`](CumulativeSum::for_type).
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::CumulativeSum;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Cumulative Sum with no decay
+/// let mut sum = CumulativeSum::new();
+///
+/// // apply some values and check their output
+/// assert_eq!(sum.apply(1.0), 1.0);
+/// assert_eq!(sum.apply(2.0), 3.0);
+/// assert_eq!(sum.apply(-4.0), -1.0);
+///
+/// // evaluate some values, these won't affect the internal state of the Cumulative Sum
+/// assert_eq!(sum.evaluate(5.0), 4.0);
+///
+/// // fetch the current value of the Cumulative Sum
+/// assert_eq!(sum.current(), -1.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CumulativeSum<T: Float = f64> {
+    decay: T,
+    current: T,
+}
+
+impl<T: Float> CumulativeSum<T> {
+    /// Create a new Cumulative Sum instance with no decay taking inputs of type `T`, such as `f32`
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::CumulativeSum;
+    ///
+    /// let sum = CumulativeSum::<f32>::for_type();
+    /// ```
+    pub fn for_type() -> Self {
+        Self {
+            decay: T::one(),
+            current: T::zero(),
+        }
+    }
+
+    /// Create a new Cumulative Sum instance with a decay factor
+    /// # Arguments
+    /// * `decay` - The factor the previous total is multiplied by each step, must be greater than 0 and at most 1
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::CumulativeSum;
+    ///
+    /// let sum = CumulativeSum::with_decay(0.9);
+    /// assert!(sum.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the decay factor is not greater than 0 and at most 1
+    /// ```
+    /// use indicato_rs::signals::CumulativeSum;
+    ///
+    /// let sum = CumulativeSum::with_decay(1.5);
+    ///
+    /// assert!(sum.is_err());
+    /// ```
+    pub fn with_decay(decay: T) -> Result<Self, FinError> {
+        match decay > T::zero() && decay <= T::one() {
+            true => Ok(Self {
+                decay,
+                current: T::zero(),
+            }),
+            false => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Decay must be greater than 0 and at most 1",
+            )),
+        }
+    }
+}
+
+impl CumulativeSum {
+    /// Create a new Cumulative Sum instance with no decay
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::CumulativeSum;
+    ///
+    /// let sum = CumulativeSum::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::for_type()
+    }
+}
+
+impl<T: Float> Default for CumulativeSum<T> {
+    fn default() -> Self {
+        Self::for_type()
+    }
+}
+
+impl<T: Float> IoState for CumulativeSum<T> {
+    type Input = T;
+    type Output = T;
+}
+
+impl<T: Float> Validate for CumulativeSum<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<T: Float> Executable for CumulativeSum<T> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let result = self.decay * self.current + input;
+        match execution_context {
+            ExecutionContext::Apply => self.current = result,
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl<T: Float> Current for CumulativeSum<T> {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<T: Float> WarmUp for CumulativeSum<T> {
+    fn warmup_remaining(&self) -> usize {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut sum = CumulativeSum::new();
+        assert_eq!(sum.apply(1.0), 1.0);
+        assert_eq!(sum.apply(2.0), 3.0);
+        assert_eq!(sum.apply(-4.0), -1.0);
+        assert_eq!(sum.apply(0.5), -0.5);
+    }
+
+    #[test]
+    fn test_apply_decay() {
+        let mut sum = CumulativeSum::with_decay(0.5).unwrap();
+        assert_eq!(sum.apply(4.0), 4.0);
+        assert_eq!(sum.apply(2.0), 4.0);
+        assert_eq!(sum.apply(0.0), 2.0);
+        assert_eq!(sum.apply(-1.0), 0.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut sum = CumulativeSum::with_decay(0.5).unwrap();
+        assert_eq!(sum.evaluate(4.0), 4.0);
+        assert_eq!(sum.apply(4.0), 4.0);
+        assert_eq!(sum.evaluate(2.0), 4.0);
+        assert_eq!(sum.evaluate(1.0), 3.0);
+        assert_eq!(sum.apply(2.0), 4.0);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut sum = CumulativeSum::default();
+        assert_eq!(sum.current(), 0.0);
+        sum.apply(1.0);
+        sum.apply(2.0);
+        sum.evaluate(5.0);
+        assert_eq!(sum.current(), 3.0);
+    }
+
+    #[test]
+    fn test_invalid_decay() {
+        assert!(CumulativeSum::with_decay(0.0).is_err());
+        assert!(CumulativeSum::with_decay(-0.5).is_err());
+        assert!(CumulativeSum::with_decay(1.5).is_err());
+        assert!(CumulativeSum::with_decay(1.0).is_ok());
+    }
+}
@@ -0,0 +1,268 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        chained_warmup, warmed_up, Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect,
+        IoState, Periodic, Validate, WarmUp,
+    },
+};
+
+/// # Difference
+/// Container for the change in the output of a signal over a number of steps
+///
+/// Each input is applied to the signal and the output is the signal output less its output `steps` outputs earlier,
+/// such as the slope of an EMA or, taking the difference of a difference, its acceleration. Outputs while the signal is
+/// warming up are skipped, so the difference is `None` until there have been more outputs than steps since the signal
+/// warmed up.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Difference, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create the first difference of a Simple Moving Average with a period of 2
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut difference = Difference::new(sma, 1).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(difference.apply(2.0), None);
+/// assert_eq!(difference.apply(4.0), None);
+/// assert_eq!(difference.apply(6.0), Some(2.0));
+/// assert_eq!(difference.apply(10.0), Some(3.0));
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(difference.evaluate(12.0), Some(3.0));
+///
+/// // fetch the current value of the signal
+/// assert_eq!(difference.current(), Some(3.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Difference<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    signal: S,
+    /// The number of outputs the difference is taken over.
+    steps: usize,
+    /// The last `steps + 1` outputs of the signal since it warmed up, oldest first.
+    values: VecDeque<f64>,
+    current: Option<f64>,
+}
+
+impl<S> IoState for Difference<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    type Input = S::Input;
+    /// Output is the change in the output of the signal over the steps, if there have been enough outputs
+    type Output = Option<f64>;
+}
+
+impl<S> Difference<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    /// Create a new Difference instance
+    /// # Arguments
+    /// * `signal` - The signal to take the difference of
+    /// * `steps` - The number of outputs to take the difference over, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{Difference, ExponentialMovingAverage};
+    ///
+    /// let difference = Difference::new(ExponentialMovingAverage::new(10).unwrap(), 1);
+    /// assert!(difference.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the steps are 0
+    /// ```
+    /// use indicato_rs::signals::{Difference, ExponentialMovingAverage};
+    ///
+    /// let difference = Difference::new(ExponentialMovingAverage::new(10).unwrap(), 0);
+    ///
+    /// assert!(difference.is_err());
+    /// ```
+    pub fn new(signal: S, steps: usize) -> Result<Self, FinError> {
+        match steps {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Steps must be greater than 0",
+            )),
+            _ => Ok(Self {
+                signal,
+                steps,
+                values: VecDeque::with_capacity(steps + 1),
+                current: None,
+            }),
+        }
+    }
+
+    /// The signal the difference is taken of
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// The number of outputs the difference is taken over
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+}
+
+impl<S> Validate for Difference<S>
+where
+    S: Executable + WarmUp + Validate,
+    S::Output: ChainValue<f64>,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        self.signal.validate(input)
+    }
+}
+
+impl<S> Executable for Difference<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let value = self
+            .signal
+            .execute(input, execution_context)
+            .into_input()
+            .filter(|_| warmed_up(&self.signal, execution_context));
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.current = value.and_then(|value| {
+                    self.values.push_back(value);
+                    if self.values.len() > self.steps + 1 {
+                        self.values.pop_front();
+                    }
+                    match self.values.len() > self.steps {
+                        true => self.values.front().map(|previous| value - previous),
+                        false => None,
+                    }
+                });
+                self.current
+            }
+            ExecutionContext::Evaluate => value.and_then(|value| match self.values.len() >= self.steps {
+                true => self
+                    .values
+                    .get(self.values.len() - self.steps)
+                    .map(|previous| value - previous),
+                false => None,
+            }),
+        }
+    }
+}
+
+impl<S> Current for Difference<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<S> WarmUp for Difference<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(
+            self.signal.warmup_remaining(),
+            (self.steps + 1).saturating_sub(self.values.len()),
+        )
+    }
+}
+
+impl<S> Periodic for Difference<S>
+where
+    S: Executable + WarmUp + Periodic,
+    S::Output: ChainValue<f64>,
+{
+    fn period(&self) -> usize {
+        self.signal.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.signal.effective_lookback() + self.steps
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{CumulativeSum, Momentum, SimpleMovingAverage};
+
+    #[test]
+    fn test_steps() {
+        let mut difference = Difference::new(CumulativeSum::new(), 2).unwrap();
+        assert_eq!(difference.apply(1.0), None);
+        assert_eq!(difference.apply(2.0), None);
+        assert_eq!(difference.apply(3.0), Some(5.0));
+        assert_eq!(difference.apply(4.0), Some(7.0));
+        assert_eq!(difference.current(), Some(7.0));
+    }
+
+    #[test]
+    fn test_warming_up_outputs() {
+        // the `None` outputs of the momentum are skipped
+        let mut difference = Difference::new(Momentum::new(1).unwrap(), 1).unwrap();
+        assert_eq!(difference.warmup_remaining(), 3);
+        assert_eq!(difference.apply(1.0), None);
+        assert_eq!(difference.apply(2.0), None);
+        assert_eq!(difference.apply(4.0), Some(1.0));
+        assert_eq!(difference.warmup_remaining(), 0);
+        assert_eq!(difference.apply(5.0), Some(-1.0));
+    }
+
+    #[test]
+    fn test_acceleration() {
+        let mut acceleration = Difference::new(Difference::new(CumulativeSum::new(), 1).unwrap(), 1).unwrap();
+        let outputs: Vec<_> = [1.0, 2.0, 4.0, 7.0].into_iter().map(|value| acceleration.apply(value)).collect();
+        assert_eq!(outputs, vec![None, None, Some(2.0), Some(3.0)]);
+    }
+
+    #[test]
+    fn test_lookback() {
+        let difference = Difference::new(SimpleMovingAverage::new(3).unwrap(), 2).unwrap();
+        assert_eq!(difference.effective_lookback(), 5);
+    }
+
+    #[test]
+    fn test_ready_at_lookback() {
+        // the partial window averages of the moving average are skipped
+        let mut difference = Difference::new(SimpleMovingAverage::new(3).unwrap(), 2).unwrap();
+        let lookback = difference.effective_lookback();
+        for step in 1..=8 {
+            assert_eq!(difference.warmup_remaining(), lookback.saturating_sub(step - 1));
+            let output = difference.apply(step as f64);
+            assert_eq!(output.is_some(), step >= lookback);
+        }
+        assert_eq!(difference.current(), Some(2.0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut difference = Difference::new(CumulativeSum::new(), 1).unwrap();
+        assert_eq!(difference.evaluate(1.0), None);
+        difference.apply(1.0);
+        assert_eq!(difference.evaluate(2.0), Some(2.0));
+        assert_eq!(difference.current(), None);
+        assert_eq!(difference.apply(3.0), Some(3.0));
+    }
+}
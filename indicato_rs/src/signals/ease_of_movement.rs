@@ -0,0 +1,330 @@
+use std::marker::PhantomData;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        warmed_up, Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate,
+        WarmUp,
+    },
+    types::{HasHighLow, HasVolume},
+    validation,
+};
+
+use super::SimpleMovingAverage;
+
+/// Inputs the [`EaseOfMovement`] reads a high, low and volume from.
+///
+/// This is implemented for tuples of (high, low, volume), the default input, and for anything implementing
+/// [`HasHighLow`] and [`HasVolume`], such as a [`Candle`](crate::types::Candle) or a tuple of
+/// (high, low, close, volume).
+pub trait EaseOfMovementInput {
+    /// The high, low and volume of the input
+    fn high_low_volume(&self) -> (f64, f64, f64);
+}
+
+/// A tuple of (high, low, volume)
+impl EaseOfMovementInput for (f64, f64, f64) {
+    fn high_low_volume(&self) -> (f64, f64, f64) {
+        *self
+    }
+}
+
+impl<I: HasHighLow + HasVolume> EaseOfMovementInput for I {
+    fn high_low_volume(&self) -> (f64, f64, f64) {
+        (self.high(), self.low(), self.volume())
+    }
+}
+
+/// # Ease of Movement
+/// Container for Ease of Movement (EMV) aggregation
+///
+/// The Ease of Movement relates the distance the midpoint of each input has moved to its volume per unit of range,
+/// known as the box ratio. This is then smoothed by a Simple Moving Average. The first entry has no previous midpoint, so
+/// the output is `None` until the Simple Moving Average has been filled with `period` box ratios, after which the
+/// following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>SMA</mi>
+///         <mo>(</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mfrac>
+///                     <mrow>
+///                         <msub><mi>h</mi><mn>n</mn></msub>
+///                         <mo>+</mo>
+///                         <msub><mi>l</mi><mn>n</mn></msub>
+///                     </mrow>
+///                     <mn>2</mn>
+///                 </mfrac>
+///                 <mo>−</mo>
+///                 <mfrac>
+///                     <mrow>
+///                         <msub><mi>h</mi><mn>n-1</mn></msub>
+///                         <mo>+</mo>
+///                         <msub><mi>l</mi><mn>n-1</mn></msub>
+///                     </mrow>
+///                     <mn>2</mn>
+///                 </mfrac>
+///             </mrow>
+///             <mfrac>
+///                 <mrow>
+///                     <msub><mi>v</mi><mn>n</mn></msub>
+///                     <mo>/</mo>
+///                     <mi>d</mi>
+///                 </mrow>
+///                 <mrow>
+///                     <msub><mi>h</mi><mn>n</mn></msub>
+///                     <mo>−</mo>
+///                     <msub><mi>l</mi><mn>n</mn></msub>
+///                 </mrow>
+///             </mfrac>
+///         </mfrac>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `SMA` is the Simple Moving Average,
+/// `h` is the high value, `l` is the low value, `v` is the volume and `d` is the volume divisor used to bring the volume
+/// into a similar scale to the price. An input with no volume is taken to have no ease of movement.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::EaseOfMovement;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Ease of Movement with a period of 2 and a volume divisor of 100
+/// let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(emv.apply((10.0, 8.0, 100.0)), None);
+/// assert_eq!(emv.apply((12.0, 10.0, 200.0)), None);
+/// assert_eq!(emv.apply((11.0, 9.0, 100.0)), Some(0.0));
+///
+/// // evaluate some values, these won't affect the internal state of the EMV
+/// assert_eq!(emv.evaluate((13.0, 11.0, 400.0)), Some(-0.5));
+///
+/// // fetch the current value of the EMV
+/// assert_eq!(emv.current(), Some(0.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EaseOfMovement<I: EaseOfMovementInput = (f64, f64, f64)> {
+    sma: SimpleMovingAverage,
+    volume_divisor: f64,
+    /// The previous midpoint of the high and low.
+    previous: Option<f64>,
+    current: Option<f64>,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: EaseOfMovementInput> EaseOfMovement<I> {
+    /// Create a new Ease of Movement instance taking inputs of type `I`, such as a [`Candle`](crate::types::Candle)
+    /// or a bar type implementing the [accessor traits](crate::types::HasVolume), see [`EaseOfMovementInput`]
+    /// # Arguments
+    /// * `period` - The period of the Simple Moving Average used to smooth the ease of movement, must be greater than 0
+    /// * `volume_divisor` - The amount the volume is divided by when calculating the box ratio, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::EaseOfMovement;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let emv = EaseOfMovement::<Candle>::for_input(14, 100_000_000.0);
+    /// assert!(emv.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period or volume divisor is 0
+    pub fn for_input(period: usize, volume_divisor: f64) -> Result<Self, FinError> {
+        if volume_divisor <= 0.0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Volume divisor must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            sma: SimpleMovingAverage::new(period)?,
+            volume_divisor,
+            previous: None,
+            current: None,
+            input: PhantomData,
+        })
+    }
+}
+
+impl EaseOfMovement {
+    /// Create a new Ease of Movement instance
+    /// # Arguments
+    /// * `period` - The period of the Simple Moving Average used to smooth the ease of movement, must be greater than 0
+    /// * `volume_divisor` - The amount the volume is divided by when calculating the box ratio, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::EaseOfMovement;
+    ///
+    /// let emv = EaseOfMovement::new(14, 100_000_000.0);
+    /// assert!(emv.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period or volume divisor is 0
+    /// ```
+    /// use indicato_rs::signals::EaseOfMovement;
+    ///
+    /// assert!(EaseOfMovement::new(0, 100_000_000.0).is_err());
+    /// assert!(EaseOfMovement::new(14, 0.0).is_err());
+    /// ```
+    pub fn new(period: usize, volume_divisor: f64) -> Result<Self, FinError> {
+        Self::for_input(period, volume_divisor)
+    }
+}
+
+impl<I: EaseOfMovementInput> IoState for EaseOfMovement<I> {
+    /// Input is anything with a high, low and volume, by default a tuple of (high, low, volume)
+    type Input = I;
+    type Output = Option<f64>;
+}
+
+impl<I: EaseOfMovementInput> Validate for EaseOfMovement<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (high, low, volume) = input.high_low_volume();
+        validation::volume(volume)?;
+        validation::high_low(high, low)
+    }
+}
+
+impl<I: EaseOfMovementInput> Executable for EaseOfMovement<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (high, low, volume) = input.high_low_volume();
+        let midpoint = (high + low) / 2.0;
+        let result = self.previous.map(|previous| {
+            let ease_of_movement = match volume == 0.0 {
+                true => 0.0,
+                false => (midpoint - previous) * (high - low) / (volume / self.volume_divisor),
+            };
+            self.sma.execute(ease_of_movement, execution_context)
+        }).filter(|_| warmed_up(&self.sma, execution_context));
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.previous = Some(midpoint);
+                self.current = result;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl<I: EaseOfMovementInput> Current for EaseOfMovement<I> {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<I: EaseOfMovementInput> WarmUp for EaseOfMovement<I> {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none()) + self.sma.warmup_remaining()
+    }
+}
+
+impl<I: EaseOfMovementInput> Periodic for EaseOfMovement<I> {
+    fn period(&self) -> usize {
+        self.sma.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Candle;
+
+    #[test]
+    fn test_apply() {
+        let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+        assert_eq!(emv.apply((10.0, 8.0, 100.0)), None);
+        assert_eq!(emv.apply((12.0, 10.0, 200.0)), None);
+        assert_eq!(emv.apply((11.0, 9.0, 100.0)), Some(0.0));
+        assert_eq!(emv.apply((13.0, 11.0, 400.0)), Some(-0.5));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+        assert_eq!(emv.evaluate((10.0, 8.0, 100.0)), None);
+        assert_eq!(emv.apply((10.0, 8.0, 100.0)), None);
+        assert_eq!(emv.evaluate((12.0, 10.0, 200.0)), None);
+        assert_eq!(emv.apply((12.0, 10.0, 200.0)), None);
+        assert_eq!(emv.evaluate((11.0, 9.0, 100.0)), Some(0.0));
+        assert_eq!(emv.evaluate((8.0, 6.0, 200.0)), Some(-1.0));
+        assert_eq!(emv.apply((11.0, 9.0, 100.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+        assert_eq!(emv.current(), None);
+        assert_eq!(emv.apply((10.0, 8.0, 100.0)), None);
+        assert_eq!(emv.current(), None);
+        assert_eq!(emv.apply((12.0, 10.0, 200.0)), None);
+        assert_eq!(emv.current(), None);
+        assert_eq!(emv.apply((11.0, 9.0, 100.0)), Some(0.0));
+        assert_eq!(emv.current(), Some(0.0));
+    }
+
+    #[test]
+    fn test_zero_volume_and_range() {
+        let mut emv = EaseOfMovement::new(1, 100.0).unwrap();
+        assert_eq!(emv.apply((10.0, 8.0, 100.0)), None);
+        assert_eq!(emv.apply((12.0, 10.0, 0.0)), Some(0.0));
+        assert_eq!(emv.apply((12.0, 12.0, 100.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(EaseOfMovement::new(0, 100.0).is_err());
+        assert!(EaseOfMovement::new(14, 0.0).is_err());
+        assert!(EaseOfMovement::new(14, -1.0).is_err());
+    }
+
+    #[test]
+    fn test_ready_at_lookback() {
+        let mut emv = EaseOfMovement::new(3, 100.0).unwrap();
+        let lookback = emv.effective_lookback();
+        for step in 1..=6 {
+            assert_eq!(emv.warmup_remaining(), lookback.saturating_sub(step - 1));
+            let price = step as f64;
+            let output = emv.apply((price + 1.0, price - 1.0, 100.0));
+            assert_eq!(output.is_some(), step >= lookback);
+        }
+    }
+
+    #[test]
+    fn test_for_input() {
+        let mut emv = EaseOfMovement::new(2, 100.0).unwrap();
+        let mut candles = EaseOfMovement::<Candle>::for_input(2, 100.0).unwrap();
+        let bars = [(10.0, 8.0, 9.0, 100.0), (12.0, 10.0, 11.0, 200.0), (11.0, 9.0, 10.0, 100.0)];
+        for (high, low, close, volume) in bars {
+            let candle = Candle { open: close, high, low, close, volume, timestamp: 0 };
+            assert_eq!(candles.evaluate(candle), emv.evaluate((high, low, volume)));
+            assert_eq!(candles.apply(candle), emv.apply((high, low, volume)));
+        }
+        assert_eq!(candles.current(), Some(0.0));
+    }
+}
@@ -0,0 +1,308 @@
+use std::marker::PhantomData;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::FinError,
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    types::{HasClose, HasHighLow},
+    validation,
+};
+
+use super::ExponentialMovingAverage;
+
+/// Output of the [`ElderRay`] signal.
+///
+/// Tuples of (bull_power, bear_power) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElderRayOutput {
+    /// The bull power, the high less the Exponential Moving Average of the close.
+    pub bull_power: f64,
+    /// The bear power, the low less the Exponential Moving Average of the close.
+    pub bear_power: f64,
+}
+
+impl From<(f64, f64)> for ElderRayOutput {
+    fn from((bull_power, bear_power): (f64, f64)) -> Self {
+        Self {
+            bull_power,
+            bear_power,
+        }
+    }
+}
+
+impl From<ElderRayOutput> for (f64, f64) {
+    fn from(output: ElderRayOutput) -> Self {
+        (output.bull_power, output.bear_power)
+    }
+}
+
+/// # Elder Ray Index
+/// Container for Elder Ray Index aggregation
+///
+/// The Elder Ray Index measures the buying and selling pressure by comparing the high and low of each input against
+/// an Exponential Moving Average of the close. The aggregation will begin producing values immediately, as the
+/// Exponential Moving Average uses the first close as its first value. The following formulas are applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>bull</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>h</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>−</mo>
+///         <mi>EMA</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>c</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>bear</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>−</mo>
+///         <mi>EMA</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>c</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `bull` is the bull power, `bear` is the bear power, `n` is the current step, `EMA` is the Exponential Moving Average,
+/// `h` is the high value, `l` is the low value and `c` is the close value.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{ElderRay, ElderRayOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Elder Ray Index with a period of 3
+/// let mut elder_ray = ElderRay::new(3).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(elder_ray.apply((3.0, 1.0, 2.0)), ElderRayOutput { bull_power: 1.0, bear_power: -1.0 });
+/// assert_eq!(elder_ray.apply((6.0, 3.0, 4.0)), (3.0, 0.0).into());
+///
+/// // evaluate some values, these won't affect the internal state of the Elder Ray Index
+/// assert_eq!(elder_ray.evaluate((4.0, 1.0, 1.0)), (2.0, -1.0).into());
+///
+/// // fetch the current value of the Elder Ray Index
+/// assert_eq!(elder_ray.current(), (3.0, 0.0).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ElderRay<I: HasHighLow + HasClose = (f64, f64, f64)> {
+    ema: ExponentialMovingAverage,
+    current: ElderRayOutput,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: HasHighLow + HasClose> ElderRay<I> {
+    /// Create a new Elder Ray Index instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the Exponential Moving Average of the close, typically 13, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ElderRay;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let elder_ray = ElderRay::<Candle>::for_input(13);
+    /// assert!(elder_ray.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    pub fn for_input(period: usize) -> Result<Self, FinError> {
+        Ok(Self {
+            ema: ExponentialMovingAverage::new(period)?,
+            current: ElderRayOutput::default(),
+            input: PhantomData,
+        })
+    }
+}
+
+impl ElderRay {
+    /// Create a new Elder Ray Index instance
+    /// # Arguments
+    /// * `period` - The period of the Exponential Moving Average of the close, typically 13, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ElderRay;
+    ///
+    /// let elder_ray = ElderRay::new(13);
+    /// assert!(elder_ray.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::ElderRay;
+    ///
+    /// let elder_ray = ElderRay::new(0);
+    ///
+    /// assert!(elder_ray.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_input(period)
+    }
+
+    /// Create a new Elder Ray Index instance with the conventional period of 13
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ElderRay;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let elder_ray = ElderRay::standard();
+    /// assert_eq!(elder_ray.period(), 13);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(13).unwrap()
+    }
+}
+
+impl Default for ElderRay {
+    /// Create a new Elder Ray Index with the conventional period of 13
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for ElderRay<I> {
+    /// Input is anything with a high, low and close, by default a tuple of (high, low, close)
+    type Input = I;
+    /// Output is the bull power and bear power
+    type Output = ElderRayOutput;
+}
+
+impl<I: HasHighLow + HasClose> Validate for ElderRay<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for ElderRay<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+        let ema = self.ema.execute(close, execution_context);
+        let result = ElderRayOutput {
+            bull_power: high - ema,
+            bear_power: low - ema,
+        };
+        match execution_context {
+            ExecutionContext::Apply => self.current = result,
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl<I: HasHighLow + HasClose> Current for ElderRay<I> {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for ElderRay<I> {
+    fn warmup_remaining(&self) -> usize {
+        self.ema.warmup_remaining()
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for ElderRay<I> {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Candle;
+
+    #[test]
+    fn test_apply() {
+        let mut elder_ray = ElderRay::new(3).unwrap();
+        assert_eq!(elder_ray.apply((3.0, 1.0, 2.0)), (1.0, -1.0).into());
+        assert_eq!(elder_ray.apply((6.0, 3.0, 4.0)), (3.0, 0.0).into());
+        assert_eq!(elder_ray.apply((4.0, 1.0, 1.0)), (2.0, -1.0).into());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut elder_ray = ElderRay::new(3).unwrap();
+        assert_eq!(elder_ray.apply((3.0, 1.0, 2.0)), (1.0, -1.0).into());
+        assert_eq!(elder_ray.evaluate((6.0, 3.0, 4.0)), (3.0, 0.0).into());
+        assert_eq!(elder_ray.evaluate((4.0, 1.0, 1.0)), (2.5, -0.5).into());
+        assert_eq!(elder_ray.apply((6.0, 3.0, 4.0)), (3.0, 0.0).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut elder_ray = ElderRay::new(3).unwrap();
+        assert_eq!(elder_ray.current(), (0.0, 0.0).into());
+        assert_eq!(elder_ray.apply((3.0, 1.0, 2.0)), (1.0, -1.0).into());
+        assert_eq!(elder_ray.apply((6.0, 3.0, 4.0)), (3.0, 0.0).into());
+        elder_ray.evaluate((4.0, 1.0, 1.0));
+        assert_eq!(elder_ray.current(), (3.0, 0.0).into());
+    }
+
+    #[test]
+    fn test_default() {
+        let mut elder_ray = ElderRay::default();
+        assert_eq!(elder_ray.apply((3.0, 1.0, 2.0)), (1.0, -1.0).into());
+        assert_eq!(elder_ray.apply((9.0, 5.0, 9.0)), (6.0, 2.0).into());
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let elder_ray = ElderRay::new(0);
+        assert!(elder_ray.is_err());
+    }
+
+    #[test]
+    fn test_for_input() {
+        let mut elder_ray = ElderRay::new(3).unwrap();
+        let mut candles = ElderRay::<Candle>::for_input(3).unwrap();
+        for (high, low, close) in [(3.0, 1.0, 2.0), (6.0, 3.0, 4.0), (4.0, 1.0, 1.0)] {
+            let candle = Candle { open: close, high, low, close, volume: 0.0, timestamp: 0 };
+            assert_eq!(candles.evaluate(candle), elder_ray.evaluate((high, low, close)));
+            assert_eq!(candles.apply(candle), elder_ray.apply((high, low, close)));
+        }
+        assert_eq!(candles.current(), (2.0, -1.0).into());
+    }
+}
@@ -1,11 +1,15 @@
-use indicato_rs_proc::{Apply, Evaluate};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
 
 use crate::{
     fin_error::{FinError, FinErrorType},
-    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, IoState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, MovingAverage, Numeric,
+        Periodic, Validate, WarmUp,
+    },
+    validation,
 };
 
-fn calculate_emas(input: f64, k: f64, current: f64, is_new: bool) -> f64 {
+fn calculate_emas<T: Numeric>(input: T, k: T, current: T, is_new: bool) -> T {
     match is_new {
         true => input,
         false => (input - current) * k + current,
@@ -53,6 +57,10 @@ fn calculate_emas(input: f64, k: f64, current: f64, is_new: bool) -> f64 {
 /// <br>
 /// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `p` is the period of the exponential moving average and `i` is the input.
 ///
+/// The aggregation is generic over the [`Numeric`] type of its inputs and defaults to `f64`, with the `rust_decimal`
+/// feature it can also aggregate `rust_decimal::Decimal` prices exactly.
+/// `new` creates an `f64` aggregation, other types are created with [`for_type`](ExponentialMovingAverage::for_type).
+///
 /// # Example Usage
 /// ```
 /// use indicato_rs::signals::ExponentialMovingAverage;
@@ -75,15 +83,19 @@ fn calculate_emas(input: f64, k: f64, current: f64, is_new: bool) -> f64 {
 /// assert_eq!(ema.current(), 4.25);
 /// ````
 ///
-#[derive(Apply, Evaluate)]
-pub struct ExponentialMovingAverage {
-    current: f64,
-    k: f64,
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExponentialMovingAverage<T: Numeric = f64> {
+    /// The period of the Exponential Moving Average aggregation
+    period: usize,
+    current: T,
+    k: T,
     is_new: bool,
 }
 
-impl ExponentialMovingAverage {
-    /// Create a new Exponential Moving Average instance
+impl<T: Numeric> ExponentialMovingAverage<T> {
+    /// Create a new Exponential Moving Average instance taking inputs of type `T`, such as `f32` or, with the
+    /// `rust_decimal` feature, `rust_decimal::Decimal`
     /// # Arguments
     /// * `period` - The period of the Exponential Moving Average aggregation, must be greater than 0
     ///
@@ -92,7 +104,7 @@ impl ExponentialMovingAverage {
     /// use indicato_rs::signals::ExponentialMovingAverage;
     /// use indicato_rs::traits::{Apply, Evaluate, Current};
     ///
-    /// let ema = ExponentialMovingAverage::new(3);
+    /// let ema = ExponentialMovingAverage::<f32>::for_type(3);
     /// assert!(ema.is_ok());
     /// ```
     /// # Errors
@@ -100,32 +112,66 @@ impl ExponentialMovingAverage {
     /// ```
     /// use indicato_rs::signals::ExponentialMovingAverage;
     ///
-    /// let ema = ExponentialMovingAverage::new(0);
+    /// let ema = ExponentialMovingAverage::<f32>::for_type(0);
     ///
     /// assert!(ema.is_err());
     /// ```
-    pub fn new(period: usize) -> Result<Self, FinError> {
+    pub fn for_type(period: usize) -> Result<Self, FinError> {
         match period {
             0 => Err(FinError::new(
                 FinErrorType::InvalidInput,
                 "Period must be greater than 0",
             )),
             _ => Ok(Self {
-                k: 2.0 / (period + 1) as f64,
-                current: 0.0,
+                period,
+                k: T::from_count(2) / T::from_count(period + 1),
+                current: T::zero(),
                 is_new: true,
             }),
         }
     }
 }
 
-impl IoState for ExponentialMovingAverage {
-    type Input = f64;
-    type Output = f64;
+impl ExponentialMovingAverage {
+    /// Create a new Exponential Moving Average instance
+    /// # Arguments
+    /// * `period` - The period of the Exponential Moving Average aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ExponentialMovingAverage;
+    /// use indicato_rs::traits::{Apply, Evaluate, Current};
+    ///
+    /// let ema = ExponentialMovingAverage::new(3);
+    /// assert!(ema.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::ExponentialMovingAverage;
+    ///
+    /// let ema = ExponentialMovingAverage::new(0);
+    ///
+    /// assert!(ema.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
+impl<T: Numeric> IoState for ExponentialMovingAverage<T> {
+    type Input = T;
+    type Output = T;
+}
+
+impl<T: Numeric> Validate for ExponentialMovingAverage<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
 }
 
-impl Executable for ExponentialMovingAverage {
-    fn execute(&mut self, input: f64, execution_context: &ExecutionContext) -> Self::Output {
+impl<T: Numeric> Executable for ExponentialMovingAverage<T> {
+    fn execute(&mut self, input: T, execution_context: &ExecutionContext) -> Self::Output {
         let result = calculate_emas(input, self.k, self.current, self.is_new);
         match execution_context {
             ExecutionContext::Apply => {
@@ -138,12 +184,30 @@ impl Executable for ExponentialMovingAverage {
     }
 }
 
-impl Current for ExponentialMovingAverage {
-    fn current(&self) -> f64 {
+impl<T: Numeric> Current for ExponentialMovingAverage<T> {
+    fn current(&self) -> T {
         self.current
     }
 }
 
+impl<T: Numeric> WarmUp for ExponentialMovingAverage<T> {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.is_new)
+    }
+}
+
+impl<T: Numeric> Periodic for ExponentialMovingAverage<T> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Numeric> MovingAverage for ExponentialMovingAverage<T> {
+    fn with_period(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -184,6 +248,18 @@ mod tests {
         assert!(ema.is_err());
     }
 
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut ema = ExponentialMovingAverage::<Decimal>::for_type(3).unwrap();
+        assert_eq!(ema.apply(Decimal::new(1, 1)), Decimal::new(1, 1));
+        assert_eq!(ema.apply(Decimal::new(2, 1)), Decimal::new(15, 2));
+        assert_eq!(ema.evaluate(Decimal::new(3, 1)), Decimal::new(225, 3));
+        assert_eq!(ema.current(), Decimal::new(15, 2));
+    }
+
     #[test]
     fn zero_ema_input() {
         let mut ema = ExponentialMovingAverage::new(3).unwrap();
@@ -0,0 +1,242 @@
+use std::{fmt, sync::Arc};
+
+use indicato_rs_proc::{Apply, Evaluate};
+
+use crate::{
+    fin_error::FinError,
+    inspect::{SignalState, ToState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+};
+
+/// A predicate deciding which inputs are applied by a [`Filtered`] signal.
+struct Predicate<I>(Arc<dyn Fn(&I) -> bool + Send + Sync>);
+
+impl<I> Clone for Predicate<I> {
+    fn clone(&self) -> Self {
+        Self(Arc::clone(&self.0))
+    }
+}
+
+impl<I> fmt::Debug for Predicate<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Predicate").finish_non_exhaustive()
+    }
+}
+
+/// # Filtered
+/// Container for a signal that only takes the inputs a predicate holds for
+///
+/// Each input the predicate holds for is passed to the signal, any other input is skipped and the current value of the
+/// signal is output, so bad prints in a real world feed such as bars without volume or auction prints don't reach the
+/// signal. Skipped inputs are not validated by [`try_apply`](crate::traits::TryApply::try_apply), as they are never
+/// applied.
+///
+/// The predicate can't be serialized, so unlike most signals this isn't serializable with the `serde` feature.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Filtered, VolumeWeightedMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a Volume Weighted Moving Average with a period of 2 that skips bars without volume
+/// let vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+/// let mut filtered = Filtered::new(vwma, |(_, volume): &(f64, f64)| *volume > 0.0);
+///
+/// // apply some values and check their output
+/// assert_eq!(filtered.apply((10.0, 100.0)), 10.0);
+/// assert_eq!(filtered.apply((20.0, 0.0)), 10.0);
+/// assert_eq!(filtered.apply((20.0, 100.0)), 15.0);
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(filtered.evaluate((30.0, 0.0)), 15.0);
+/// assert_eq!(filtered.evaluate((30.0, 100.0)), 25.0);
+///
+/// // fetch the current value of the signal
+/// assert_eq!(filtered.current(), 15.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate)]
+pub struct Filtered<S>
+where
+    S: Executable + Current,
+{
+    signal: S,
+    predicate: Predicate<S::Input>,
+    /// The number of inputs that have been skipped.
+    skipped: usize,
+}
+
+impl<S> IoState for Filtered<S>
+where
+    S: Executable + Current,
+{
+    type Input = S::Input;
+    type Output = S::Output;
+}
+
+impl<S> Filtered<S>
+where
+    S: Executable + Current,
+{
+    /// Create a new Filtered instance
+    /// # Arguments
+    /// * `signal` - The signal to pass the inputs to
+    /// * `predicate` - Whether an input is passed to the signal, the input is skipped when it returns `false`
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{Filtered, SimpleMovingAverage};
+    /// use indicato_rs::traits::Apply;
+    ///
+    /// let mut filtered = Filtered::new(SimpleMovingAverage::new(2).unwrap(), |price: &f64| *price > 0.0);
+    /// filtered.apply(2.0);
+    /// assert_eq!(filtered.apply(0.0), 2.0);
+    /// assert_eq!(filtered.skipped(), 1);
+    /// ```
+    pub fn new(signal: S, predicate: impl Fn(&S::Input) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            signal,
+            predicate: Predicate(Arc::new(predicate)),
+            skipped: 0,
+        }
+    }
+
+    /// The signal the inputs are passed to
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// The number of inputs that have been skipped
+    pub fn skipped(&self) -> usize {
+        self.skipped
+    }
+}
+
+impl<S> Validate for Filtered<S>
+where
+    S: Executable + Current + Validate,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        match (self.predicate.0)(input) {
+            true => self.signal.validate(input),
+            false => Ok(()),
+        }
+    }
+}
+
+impl<S> Executable for Filtered<S>
+where
+    S: Executable + Current,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match (self.predicate.0)(&input) {
+            true => self.signal.execute(input, execution_context),
+            false => {
+                if let ExecutionContext::Apply = execution_context {
+                    self.skipped += 1;
+                }
+                self.signal.current()
+            }
+        }
+    }
+}
+
+impl<S> Current for Filtered<S>
+where
+    S: Executable + Current,
+{
+    fn current(&self) -> Self::Output {
+        self.signal.current()
+    }
+}
+
+impl<S> WarmUp for Filtered<S>
+where
+    S: Executable + Current + WarmUp,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.signal.warmup_remaining()
+    }
+}
+
+impl<S> Periodic for Filtered<S>
+where
+    S: Executable + Current + Periodic,
+{
+    fn period(&self) -> usize {
+        self.signal.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.signal.effective_lookback()
+    }
+}
+
+impl<S> Inspect for Filtered<S>
+where
+    S: Executable + Current + Inspect,
+{
+    fn inspect(&self) -> SignalState {
+        SignalState::new("Filtered")
+            .field("signal", self.signal.to_state())
+            .field("skipped", self.skipped.to_state())
+    }
+
+    fn name(&self) -> &'static str {
+        "Filtered"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{CumulativeSum, RelativeStrengthIndex, VolumeWeightedMovingAverage};
+    use crate::traits::TryApply;
+
+    #[test]
+    fn test_skipped() {
+        let mut sum = Filtered::new(CumulativeSum::new(), |value: &f64| value.is_finite());
+        assert_eq!(sum.apply(1.0), 1.0);
+        assert_eq!(sum.apply(f64::NAN), 1.0);
+        assert_eq!(sum.apply(f64::INFINITY), 1.0);
+        assert_eq!(sum.apply(2.0), 3.0);
+        assert_eq!(sum.skipped(), 2);
+        assert_eq!(sum.evaluate(f64::NAN), 3.0);
+        assert_eq!(sum.skipped(), 2);
+    }
+
+    #[test]
+    fn test_warming_up_outputs() {
+        // a skipped input passes through the output of the signal while it is warming up
+        let mut rsi = Filtered::new(RelativeStrengthIndex::new(2, 0).unwrap(), |price: &f64| *price != 0.0);
+        assert_eq!(rsi.warmup_remaining(), 3);
+        assert_eq!(rsi.apply(10.0), None);
+        assert_eq!(rsi.apply(0.0), None);
+        assert_eq!(rsi.warmup_remaining(), 2);
+    }
+
+    #[test]
+    fn test_validate() {
+        // bars without volume are skipped rather than rejected
+        let mut vwma = Filtered::new(VolumeWeightedMovingAverage::new(2).unwrap(), |(_, volume): &(f64, f64)| {
+            *volume != 0.0
+        });
+        assert_eq!(vwma.try_apply((10.0, 100.0)).unwrap(), 10.0);
+        assert_eq!(vwma.try_apply((12.0, 0.0)).unwrap(), 10.0);
+        assert!(vwma.try_apply((12.0, -1.0)).is_err());
+    }
+
+    #[test]
+    fn test_inspect() {
+        let mut sum = Filtered::new(CumulativeSum::new(), |value: &f64| *value > 0.0);
+        sum.apply(-1.0);
+        let state = sum.inspect();
+        assert_eq!(state.name, "Filtered");
+        assert_eq!(state.get("skipped"), Some(&crate::inspect::StateValue::Count(1)));
+    }
+}
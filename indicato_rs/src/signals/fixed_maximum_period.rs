@@ -0,0 +1,160 @@
+use indicato_rs_proc::{Apply, Evaluate};
+use num_traits::Float;
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    inspect::{SignalState, ToState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::fixed_window::FixedWindow;
+
+/// # Fixed Maximum Period
+/// Container for Maximum Period aggregation with a period fixed at compile time
+///
+/// Produces the same outputs as a [`MaximumPeriod`](super::MaximumPeriod) with a period of `N`, but keeps its values in
+/// a fixed-size array rather than a `VecDeque`, so it never allocates.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::FixedMaximumPeriod;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Maximum Period with a period of 3
+/// let mut max = FixedMaximumPeriod::<3>::new().unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(max.apply(1.0), 1.0);
+/// assert_eq!(max.apply(3.0), 3.0);
+/// assert_eq!(max.apply(2.0), 3.0);
+/// assert_eq!(max.apply(1.0), 3.0);
+/// assert_eq!(max.apply(0.0), 2.0);
+///
+/// // evaluate some values, these won't affect the internal state of the Maximum Period
+/// assert_eq!(max.evaluate(5.0), 5.0);
+///
+/// // fetch the current value of the Maximum Period
+/// assert_eq!(max.current(), 2.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedMaximumPeriod<const N: usize, T: Float = f64> {
+    values: FixedWindow<T, N>,
+}
+
+impl<const N: usize, T: Float> FixedMaximumPeriod<N, T> {
+    /// Create a new Fixed Maximum Period instance with a period of `N`
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::FixedMaximumPeriod;
+    ///
+    /// let max = FixedMaximumPeriod::<20>::new();
+    /// assert!(max.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if `N` is 0
+    /// ```
+    /// use indicato_rs::signals::FixedMaximumPeriod;
+    ///
+    /// let max = FixedMaximumPeriod::<0>::new();
+    ///
+    /// assert!(max.is_err());
+    /// ```
+    pub fn new() -> Result<Self, FinError> {
+        match N {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                values: FixedWindow::new(),
+            }),
+        }
+    }
+}
+
+impl<const N: usize, T: Float> IoState for FixedMaximumPeriod<N, T> {
+    type Input = T;
+    type Output = T;
+}
+
+impl<const N: usize, T: Float> Validate for FixedMaximumPeriod<N, T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<const N: usize, T: Float> Executable for FixedMaximumPeriod<N, T> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push(input);
+                self.current()
+            }
+            ExecutionContext::Evaluate => self
+                .values
+                .iter_with(input)
+                .fold(T::min_value(), |acc, x| acc.max(x)),
+        }
+    }
+}
+
+impl<const N: usize, T: Float> Current for FixedMaximumPeriod<N, T> {
+    fn current(&self) -> Self::Output {
+        self.values.iter().fold(T::min_value(), |acc, x| acc.max(x))
+    }
+}
+
+impl<const N: usize, T: Float> WarmUp for FixedMaximumPeriod<N, T> {
+    fn warmup_remaining(&self) -> usize {
+        N - self.values.len()
+    }
+}
+
+impl<const N: usize, T: Float> Periodic for FixedMaximumPeriod<N, T> {
+    fn period(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize, T: Float + ToState> Inspect for FixedMaximumPeriod<N, T> {
+    fn inspect(&self) -> SignalState {
+        SignalState::new("FixedMaximumPeriod").field("values", self.values.to_state())
+    }
+
+    fn name(&self) -> &'static str {
+        "FixedMaximumPeriod"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::MaximumPeriod;
+
+    #[test]
+    fn test_matches_maximum_period() {
+        let mut fixed = FixedMaximumPeriod::<3>::new().unwrap();
+        let mut max = MaximumPeriod::new(3).unwrap();
+        for input in [1.0, 2.0, 3.0, 2.0, 1.0, 0.0, 4.0, -1.0, -2.0, -3.0] {
+            assert_eq!(fixed.evaluate(input), max.evaluate(input));
+            assert_eq!(fixed.apply(input), max.apply(input));
+            assert_eq!(fixed.current(), max.current());
+            assert_eq!(fixed.warmup_remaining(), max.warmup_remaining());
+        }
+        assert_eq!(fixed.period(), 3);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(FixedMaximumPeriod::<0>::new().is_err());
+    }
+}
@@ -0,0 +1,191 @@
+use indicato_rs_proc::{Apply, Evaluate};
+
+use crate::{
+    deque_math::compensated_sum,
+    fin_error::{FinError, FinErrorType},
+    inspect::{SignalState, ToState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Numeric, Periodic,
+        Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::fixed_window::FixedWindow;
+
+/// The compensated mean of the values, 0 when empty.
+fn mean<T: Numeric>(values: impl Iterator<Item = T> + Clone) -> T {
+    match values.clone().count() {
+        0 => T::zero(),
+        count => compensated_sum(values) / T::from_count(count),
+    }
+}
+
+/// # Fixed Simple Moving Average
+/// Container for Simple Moving Average (SMA) aggregation with a period fixed at compile time
+///
+/// Produces the same outputs as a [`SimpleMovingAverage`](super::SimpleMovingAverage) with a period of `N`, but keeps
+/// its values in a fixed-size array rather than a `VecDeque`, so it never allocates.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::FixedSimpleMovingAverage;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Simple Moving Average with a period of 3
+/// let mut sma = FixedSimpleMovingAverage::<3>::new().unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(sma.apply(1.0), 1.0);
+/// assert_eq!(sma.apply(2.0), 1.5);
+/// assert_eq!(sma.apply(3.0), 2.0);
+/// assert_eq!(sma.apply(4.0), 3.0);
+///
+/// // evaluate some values, these won't affect the internal state of the SMA
+/// assert_eq!(sma.evaluate(5.0), 4.0);
+///
+/// // fetch the current value of the SMA
+/// assert_eq!(sma.current(), 3.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixedSimpleMovingAverage<const N: usize, T: Numeric = f64> {
+    values: FixedWindow<T, N>,
+}
+
+impl<const N: usize, T: Numeric> IoState for FixedSimpleMovingAverage<N, T> {
+    type Input = T;
+    type Output = T;
+}
+
+impl<const N: usize, T: Numeric> FixedSimpleMovingAverage<N, T> {
+    /// Create a new Fixed Simple Moving Average instance with a period of `N`
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::FixedSimpleMovingAverage;
+    ///
+    /// let sma = FixedSimpleMovingAverage::<20>::new();
+    /// assert!(sma.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if `N` is 0
+    /// ```
+    /// use indicato_rs::signals::FixedSimpleMovingAverage;
+    ///
+    /// let sma = FixedSimpleMovingAverage::<0>::new();
+    ///
+    /// assert!(sma.is_err());
+    /// ```
+    pub fn new() -> Result<Self, FinError> {
+        match N {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                values: FixedWindow::new(),
+            }),
+        }
+    }
+}
+
+impl<const N: usize, T: Numeric> Validate for FixedSimpleMovingAverage<N, T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<const N: usize, T: Numeric> Executable for FixedSimpleMovingAverage<N, T> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push(input);
+                mean(self.values.iter())
+            }
+            ExecutionContext::Evaluate => mean(self.values.iter_with(input)),
+        }
+    }
+}
+
+impl<const N: usize, T: Numeric> Current for FixedSimpleMovingAverage<N, T> {
+    fn current(&self) -> Self::Output {
+        mean(self.values.iter())
+    }
+}
+
+impl<const N: usize, T: Numeric> WarmUp for FixedSimpleMovingAverage<N, T> {
+    fn warmup_remaining(&self) -> usize {
+        N - self.values.len()
+    }
+}
+
+impl<const N: usize, T: Numeric> Periodic for FixedSimpleMovingAverage<N, T> {
+    fn period(&self) -> usize {
+        N
+    }
+}
+
+impl<const N: usize, T: Numeric + ToState> Inspect for FixedSimpleMovingAverage<N, T> {
+    fn inspect(&self) -> SignalState {
+        SignalState::new("FixedSimpleMovingAverage").field("values", self.values.to_state())
+    }
+
+    fn name(&self) -> &'static str {
+        "FixedSimpleMovingAverage"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::inspect::StateValue;
+    use crate::signals::SimpleMovingAverage;
+
+    #[test]
+    fn test_matches_simple_moving_average() {
+        let mut fixed = FixedSimpleMovingAverage::<3>::new().unwrap();
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        for input in [1.0, 2.0, 3.0, 4.0, 5.0, 2.5, 0.1, 7.0] {
+            assert_eq!(fixed.evaluate(input), sma.evaluate(input));
+            assert_eq!(fixed.apply(input), sma.apply(input));
+            assert_eq!(fixed.current(), sma.current());
+            assert_eq!(fixed.warmup_remaining(), sma.warmup_remaining());
+        }
+        assert_eq!(fixed.period(), 3);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(FixedSimpleMovingAverage::<0>::new().is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut sma = FixedSimpleMovingAverage::<2>::new().unwrap();
+        sma.apply(1.0);
+        sma.apply(2.0);
+        sma.apply(3.0);
+        let snapshot = serde_json::to_string(&sma).unwrap();
+        let mut restored: FixedSimpleMovingAverage<2> = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(restored.current(), sma.current());
+        assert_eq!(restored.apply(5.0), sma.apply(5.0));
+    }
+
+    #[test]
+    fn test_inspect() {
+        let mut sma = FixedSimpleMovingAverage::<2>::new().unwrap();
+        sma.apply(1.0);
+        sma.apply(2.0);
+        sma.apply(3.0);
+        assert_eq!(
+            sma.inspect().get("values"),
+            Some(&StateValue::List(vec![StateValue::Number(2.0), StateValue::Number(3.0)]))
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use num_traits::Zero;
+
+use crate::inspect::{StateValue, ToState};
+
+/// A ring buffer holding the last `N` values in a fixed-size array, used by the const-generic signals in place of a
+/// `VecDeque` so they don't allocate.
+#[derive(Debug, Clone)]
+pub(crate) struct FixedWindow<T, const N: usize> {
+    values: [T; N],
+    /// The index of the oldest value.
+    start: usize,
+    len: usize,
+}
+
+impl<T: Copy + Zero, const N: usize> FixedWindow<T, N> {
+    pub(crate) fn new() -> Self {
+        Self {
+            values: [T::zero(); N],
+            start: 0,
+            len: 0,
+        }
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Pushes a value to the back of the window, dropping the oldest value once the window is full.
+    pub(crate) fn push(&mut self, value: T) {
+        match self.len < N {
+            true => {
+                self.values[(self.start + self.len) % N] = value;
+                self.len += 1;
+            }
+            false => {
+                self.values[self.start] = value;
+                self.start = (self.start + 1) % N;
+            }
+        }
+    }
+
+    /// The values from oldest to newest.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = T> + Clone + '_ {
+        (0..self.len).map(move |i| self.values[(self.start + i) % N])
+    }
+
+    /// The values from oldest to newest as they would be after pushing `value`, without modifying the window.
+    pub(crate) fn iter_with(&self, value: T) -> impl Iterator<Item = T> + Clone + '_ {
+        self.iter()
+            .skip(usize::from(self.len == N))
+            .chain(std::iter::once(value))
+    }
+}
+
+impl<T: Copy + Zero + ToState, const N: usize> ToState for FixedWindow<T, N> {
+    fn to_state(&self) -> StateValue {
+        StateValue::List(self.iter().map(|value| value.to_state()).collect())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Copy + Zero + serde::Serialize, const N: usize> serde::Serialize for FixedWindow<T, N> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T, const N: usize> serde::Deserialize<'de> for FixedWindow<T, N>
+where
+    T: Copy + Zero + serde::Deserialize<'de>,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        if values.len() > N {
+            return Err(serde::de::Error::invalid_length(
+                values.len(),
+                &"at most the window size of values",
+            ));
+        }
+        let mut window = Self::new();
+        values.into_iter().for_each(|value| window.push(value));
+        Ok(window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_push() {
+        let mut window = FixedWindow::<f64, 3>::new();
+        window.push(1.0);
+        window.push(2.0);
+        assert_eq!(window.iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+        window.push(3.0);
+        window.push(4.0);
+        assert_eq!(window.len(), 3);
+        assert_eq!(window.iter().collect::<Vec<_>>(), vec![2.0, 3.0, 4.0]);
+        assert_eq!(window.iter_with(5.0).collect::<Vec<_>>(), vec![3.0, 4.0, 5.0]);
+        assert_eq!(window.iter().collect::<Vec<_>>(), vec![2.0, 3.0, 4.0]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut window = FixedWindow::<f64, 2>::new();
+        window.push(1.0);
+        window.push(2.0);
+        window.push(3.0);
+        let snapshot = serde_json::to_string(&window).unwrap();
+        assert_eq!(snapshot, "[2.0,3.0]");
+        let restored: FixedWindow<f64, 2> = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(restored.iter().collect::<Vec<_>>(), vec![2.0, 3.0]);
+        assert!(serde_json::from_str::<FixedWindow<f64, 2>>("[1.0,2.0,3.0]").is_err());
+    }
+}
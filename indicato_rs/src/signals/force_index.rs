@@ -0,0 +1,232 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::FinError,
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::ExponentialMovingAverage;
+
+/// # Force Index
+/// Container for Force Index aggregation
+///
+/// The Force Index measures the force behind a price move by multiplying the change in close by the volume, this
+/// is then smoothed by an Exponential Moving Average. The first entry will produce `None` as there is no previous
+/// close, after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>EMA</mi>
+///         <mo>(</mo>
+///         <mrow><mo stretchy="true" form="prefix">(</mo>
+///             <msub>
+///                 <mi>c</mi>
+///                 <mn>n</mn>
+///             </msub>
+///             <mo>−</mo>
+///             <msub>
+///                 <mi>c</mi>
+///                 <mn>n-1</mn>
+///             </msub>
+///         <mo stretchy="true" form="postfix">)</mo></mrow>
+///         <mo>⋅</mo>
+///         <msub>
+///             <mi>v</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `EMA` is the Exponential Moving Average,
+/// `c` is the close value and `v` is the volume.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::ForceIndex;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Force Index with a period of 3
+/// let mut force_index = ForceIndex::new(3).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(force_index.apply((10.0, 100.0)), None);
+/// assert_eq!(force_index.apply((12.0, 100.0)), Some(200.0));
+/// assert_eq!(force_index.apply((11.0, 200.0)), Some(0.0));
+///
+/// // evaluate some values, these won't affect the internal state of the Force Index
+/// assert_eq!(force_index.evaluate((13.0, 100.0)), Some(100.0));
+///
+/// // fetch the current value of the Force Index
+/// assert_eq!(force_index.current(), Some(0.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ForceIndex {
+    ema: ExponentialMovingAverage,
+    /// The previous close value.
+    previous: Option<f64>,
+    current: Option<f64>,
+}
+
+impl ForceIndex {
+    /// Create a new Force Index instance
+    /// # Arguments
+    /// * `period` - The period of the Exponential Moving Average used to smooth the force, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ForceIndex;
+    ///
+    /// let force_index = ForceIndex::new(13);
+    /// assert!(force_index.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::ForceIndex;
+    ///
+    /// let force_index = ForceIndex::new(0);
+    ///
+    /// assert!(force_index.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Ok(Self {
+            ema: ExponentialMovingAverage::new(period)?,
+            previous: None,
+            current: None,
+        })
+    }
+
+    /// Create a new Force Index instance with the conventional period of 13
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::ForceIndex;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let force_index = ForceIndex::standard();
+    /// assert_eq!(force_index.period(), 13);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(13).unwrap()
+    }
+}
+
+impl Default for ForceIndex {
+    /// Create a new Force Index with the conventional period of 13
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl IoState for ForceIndex {
+    /// Input is a tuple of (close, volume)
+    type Input = (f64, f64);
+    type Output = Option<f64>;
+}
+
+impl Validate for ForceIndex {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (close, volume) = *input;
+        validation::value(close, "Close")?;
+        validation::volume(volume)
+    }
+}
+
+impl Executable for ForceIndex {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (close, volume) = input;
+        let result = self.previous.map(|previous| {
+            self.ema
+                .execute((close - previous) * volume, execution_context)
+        });
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.previous = Some(close);
+                self.current = result;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl Current for ForceIndex {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for ForceIndex {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none()) + self.ema.warmup_remaining()
+    }
+}
+
+impl Periodic for ForceIndex {
+    fn period(&self) -> usize {
+        self.ema.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut force_index = ForceIndex::new(3).unwrap();
+        assert_eq!(force_index.apply((10.0, 100.0)), None);
+        assert_eq!(force_index.apply((12.0, 100.0)), Some(200.0));
+        assert_eq!(force_index.apply((11.0, 200.0)), Some(0.0));
+        assert_eq!(force_index.apply((13.0, 100.0)), Some(100.0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut force_index = ForceIndex::new(3).unwrap();
+        assert_eq!(force_index.evaluate((10.0, 100.0)), None);
+        assert_eq!(force_index.apply((10.0, 100.0)), None);
+        assert_eq!(force_index.evaluate((12.0, 100.0)), Some(200.0));
+        assert_eq!(force_index.evaluate((8.0, 100.0)), Some(-200.0));
+        assert_eq!(force_index.apply((12.0, 100.0)), Some(200.0));
+        assert_eq!(force_index.evaluate((11.0, 200.0)), Some(0.0));
+        assert_eq!(force_index.apply((11.0, 200.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut force_index = ForceIndex::new(3).unwrap();
+        assert_eq!(force_index.current(), None);
+        assert_eq!(force_index.apply((10.0, 100.0)), None);
+        assert_eq!(force_index.current(), None);
+        assert_eq!(force_index.apply((12.0, 100.0)), Some(200.0));
+        assert_eq!(force_index.current(), Some(200.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let force_index = ForceIndex::new(0);
+        assert!(force_index.is_err());
+    }
+}
@@ -0,0 +1,338 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+fn range<'a>(values: impl Iterator<Item = &'a f64>) -> f64 {
+    let (max, min) = values.fold((f64::MIN, f64::MAX), |(max, min), &x| {
+        (max.max(x), min.min(x))
+    });
+    max - min
+}
+
+/// Calculates the adaptive smoothing factor from the fractal dimension of a full window.
+fn fractal_alpha(values: &VecDeque<f64>, period: usize) -> f64 {
+    let half = period / 2;
+    let n1 = range(values.range(..half)) / half as f64;
+    let n2 = range(values.range(half..)) / half as f64;
+    let n3 = range(values.iter()) / period as f64;
+    let dimension = match n1 + n2 > 0.0 && n3 > 0.0 {
+        true => ((n1 + n2).ln() - n3.ln()) / 2.0_f64.ln(),
+        false => 1.0,
+    };
+    (-4.6 * (dimension - 1.0)).exp().clamp(0.01, 1.0)
+}
+
+/// # Fractal Adaptive Moving Average
+/// Container for Fractal Adaptive Moving Average (FRAMA) aggregation
+///
+/// The Fractal Adaptive Moving Average is an exponential moving average where the smoothing factor adapts to the
+/// fractal dimension of the inputs over the period. Trending inputs have a fractal dimension close to 1 and are
+/// followed closely, while choppy inputs have a fractal dimension close to 2 and are heavily smoothed.
+///
+/// The period is split into two halves and the fractal dimension is estimated from the range of each half and of the whole period:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <mi>D</mi>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mi>ln</mi>
+///                 <mo>(</mo>
+///                 <msub><mi>N</mi><mn>1</mn></msub>
+///                 <mo>+</mo>
+///                 <msub><mi>N</mi><mn>2</mn></msub>
+///                 <mo>)</mo>
+///                 <mo>−</mo>
+///                 <mi>ln</mi>
+///                 <mo>(</mo>
+///                 <msub><mi>N</mi><mn>3</mn></msub>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <mi>ln</mi>
+///                 <mo>(</mo>
+///                 <mn>2</mn>
+///                 <mo>)</mo>
+///             </mrow>
+///         </mfrac>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <mi>α</mi>
+///         <mo>=</mo>
+///         <msup>
+///             <mi>e</mi>
+///             <mrow>
+///                 <mo>−</mo>
+///                 <mn>4.6</mn>
+///                 <mo>(</mo>
+///                 <mi>D</mi>
+///                 <mo>−</mo>
+///                 <mn>1</mn>
+///                 <mo>)</mo>
+///             </mrow>
+///         </msup>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>α</mi>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <mo>(</mo>
+///         <mn>1</mn>
+///         <mo>−</mo>
+///         <mi>α</mi>
+///         <mo>)</mo>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `N1` and `N2` are the ranges of the first and second half of the period divided by half the period, `N3` is the
+/// range of the whole period divided by the period, `D` is the fractal dimension, `α` is the smoothing factor clamped
+/// between 0.01 and 1, `o` is the output, `n` is the current step and `i` is the input.
+///
+/// The first `p - 1` entries will produce `None` as the period is being filled, during this time the previous output
+/// is taken to be the previous input.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::FractalAdaptiveMovingAverage;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Fractal Adaptive Moving Average with a period of 4
+/// let mut frama = FractalAdaptiveMovingAverage::new(4).unwrap();
+///
+/// // trending inputs are followed exactly
+/// assert_eq!(frama.apply(1.0), None);
+/// assert_eq!(frama.apply(2.0), None);
+/// assert_eq!(frama.apply(3.0), None);
+/// assert_eq!(frama.apply(4.0), Some(4.0));
+/// assert_eq!(frama.apply(5.0), Some(5.0));
+///
+/// // evaluate some values, these won't affect the internal state of the FRAMA
+/// assert_eq!(frama.evaluate(6.0), Some(6.0));
+///
+/// // fetch the current value of the FRAMA
+/// assert_eq!(frama.current(), Some(5.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FractalAdaptiveMovingAverage {
+    period: usize,
+    values: VecDeque<f64>,
+    /// The previous output, or the previous input while the period is being filled.
+    previous: f64,
+    current: Option<f64>,
+}
+
+impl IoState for FractalAdaptiveMovingAverage {
+    type Input = f64;
+    type Output = Option<f64>;
+}
+
+impl FractalAdaptiveMovingAverage {
+    /// Create a new Fractal Adaptive Moving Average instance
+    /// # Arguments
+    /// * `period` - The period of the fractal dimension estimate, must be an even number greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::FractalAdaptiveMovingAverage;
+    ///
+    /// let frama = FractalAdaptiveMovingAverage::new(16);
+    /// assert!(frama.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0 or odd
+    /// ```
+    /// use indicato_rs::signals::FractalAdaptiveMovingAverage;
+    ///
+    /// assert!(FractalAdaptiveMovingAverage::new(0).is_err());
+    /// assert!(FractalAdaptiveMovingAverage::new(15).is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ if !period.is_multiple_of(2) => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be even",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+                previous: 0.0,
+                current: None,
+            }),
+        }
+    }
+
+    fn calculate(&self, values: &VecDeque<f64>, input: f64) -> Option<f64> {
+        if values.len() < self.period {
+            return None;
+        }
+        let alpha = fractal_alpha(values, self.period);
+        Some(alpha * input + (1.0 - alpha) * self.previous)
+    }
+}
+
+impl Validate for FractalAdaptiveMovingAverage {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for FractalAdaptiveMovingAverage {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                let result = self.calculate(&self.values, input);
+                self.previous = result.unwrap_or(input);
+                self.current = result;
+                result
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(input);
+                if values.len() > self.period {
+                    values.pop_front();
+                }
+                self.calculate(&values, input)
+            }
+        }
+    }
+}
+
+impl Current for FractalAdaptiveMovingAverage {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for FractalAdaptiveMovingAverage {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for FractalAdaptiveMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut frama = FractalAdaptiveMovingAverage::new(4).unwrap();
+        assert_eq!(frama.apply(1.0), None);
+        assert_eq!(frama.apply(3.0), None);
+        assert_eq!(frama.apply(2.0), None);
+        assert_abs_diff_eq!(
+            frama.apply(4.0).unwrap(),
+            2.2964059486409587,
+            epsilon = 10e-7
+        );
+        assert_abs_diff_eq!(frama.apply(3.0).unwrap(), 3.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(
+            frama.apply(5.0).unwrap(),
+            3.2964059486409587,
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_choppy() {
+        let mut frama = FractalAdaptiveMovingAverage::new(4).unwrap();
+        assert_eq!(frama.apply(2.0), None);
+        assert_eq!(frama.apply(1.0), None);
+        assert_eq!(frama.apply(2.0), None);
+        assert_abs_diff_eq!(
+            frama.apply(1.0).unwrap(),
+            1.9899481642553665,
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut frama = FractalAdaptiveMovingAverage::new(4).unwrap();
+        assert_eq!(frama.apply(1.0), None);
+        assert_eq!(frama.apply(3.0), None);
+        assert_eq!(frama.evaluate(2.0), None);
+        assert_eq!(frama.apply(2.0), None);
+        assert_abs_diff_eq!(
+            frama.evaluate(4.0).unwrap(),
+            2.2964059486409587,
+            epsilon = 10e-7
+        );
+        assert_abs_diff_eq!(
+            frama.apply(4.0).unwrap(),
+            2.2964059486409587,
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_current() {
+        let mut frama = FractalAdaptiveMovingAverage::new(4).unwrap();
+        assert_eq!(frama.current(), None);
+        frama.apply(1.0);
+        frama.apply(2.0);
+        frama.apply(3.0);
+        assert_eq!(frama.current(), None);
+        frama.apply(4.0);
+        frama.evaluate(10.0);
+        assert_eq!(frama.current(), Some(4.0));
+    }
+
+    #[test]
+    fn test_flatline() {
+        let mut frama = FractalAdaptiveMovingAverage::new(2).unwrap();
+        assert_eq!(frama.apply(3.0), None);
+        assert_eq!(frama.apply(3.0), Some(3.0));
+        assert_eq!(frama.apply(3.0), Some(3.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(FractalAdaptiveMovingAverage::new(0).is_err());
+        assert!(FractalAdaptiveMovingAverage::new(3).is_err());
+    }
+}
@@ -0,0 +1,303 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, WarmUp},
+};
+
+/// How a [`GapFilled`] signal handles a gap in its inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GapPolicy {
+    /// Nothing is applied for the gap, the signal carries on from the next valid value.
+    Skip,
+    /// The last valid value is applied in place of each missing value.
+    ForwardFill,
+    /// The missing values are held until the next valid value, then the values on the line between the last valid
+    /// value and the next are applied in place of each missing value, by timestamp.
+    Interpolate,
+    /// The signal is reset to the state it was created in, and warms up again from the next valid value.
+    Reset,
+}
+
+crate::inspect::debug_state!(GapPolicy);
+
+/// # Gap Filled
+/// Container for a signal fed from irregular data with missing values
+///
+/// Each input is a value, which is missing when it is `None` or NaN, and its timestamp. When an interval is given, the
+/// timestamps are expected to be that far apart and a longer step is a gap with a missing value at each interval
+/// skipped. Missing values are handled by the [`GapPolicy`] rather than being aggregated, so they can't corrupt the
+/// windows of the signal.
+///
+/// An input with a missing value outputs the current value of the signal, unless it was forward filled. Forward filling
+/// and interpolating only start once there has been a valid value.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{GapFilled, GapPolicy, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a Simple Moving Average with a period of 2 over values expected every 60 seconds
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut filled = GapFilled::new(sma, GapPolicy::ForwardFill, Some(60)).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(filled.apply((Some(1.0), 0)), 1.0);
+/// assert_eq!(filled.apply((None, 60)), 1.0);
+///
+/// // the value at 120 is filled with 1.0 before 4.0 is applied
+/// assert_eq!(filled.apply((Some(4.0), 180)), 2.5);
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(filled.evaluate((Some(f64::NAN), 240)), 4.0);
+///
+/// // fetch the current value of the signal
+/// assert_eq!(filled.current(), 2.5);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GapFilled<S>
+where
+    S: Executable<Input = f64> + Current + Clone,
+{
+    signal: S,
+    /// The signal as it was created, restored by the reset policy.
+    initial: S,
+    policy: GapPolicy,
+    /// The expected step between timestamps, a longer step is a gap.
+    interval: Option<i64>,
+    /// The timestamp of the previous input.
+    previous_timestamp: Option<i64>,
+    /// The timestamp and value of the previous valid input.
+    previous: Option<(i64, f64)>,
+    /// The timestamps of the missing values waiting on the next valid value to be interpolated.
+    pending: Vec<i64>,
+}
+
+impl<S> IoState for GapFilled<S>
+where
+    S: Executable<Input = f64> + Current + Clone,
+{
+    /// Input is a tuple of (value, timestamp)
+    type Input = (Option<f64>, i64);
+    type Output = S::Output;
+}
+
+impl<S> GapFilled<S>
+where
+    S: Executable<Input = f64> + Current + Clone,
+{
+    /// Create a new Gap Filled instance
+    /// # Arguments
+    /// * `signal` - The signal to feed
+    /// * `policy` - How gaps in the inputs are handled
+    /// * `interval` - The expected step between timestamps, must be greater than 0. Without an interval only missing
+    ///   values are gaps.
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{ExponentialMovingAverage, GapFilled, GapPolicy};
+    ///
+    /// let filled = GapFilled::new(ExponentialMovingAverage::new(10).unwrap(), GapPolicy::Interpolate, Some(1));
+    /// assert!(filled.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the interval is not greater than 0
+    /// ```
+    /// use indicato_rs::signals::{ExponentialMovingAverage, GapFilled, GapPolicy};
+    ///
+    /// let filled = GapFilled::new(ExponentialMovingAverage::new(10).unwrap(), GapPolicy::Skip, Some(0));
+    ///
+    /// assert!(filled.is_err());
+    /// ```
+    pub fn new(signal: S, policy: GapPolicy, interval: Option<i64>) -> Result<Self, FinError> {
+        match interval {
+            Some(interval) if interval <= 0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Interval must be greater than 0",
+            )),
+            _ => Ok(Self {
+                initial: signal.clone(),
+                signal,
+                policy,
+                interval,
+                previous_timestamp: None,
+                previous: None,
+                pending: Vec::new(),
+            }),
+        }
+    }
+
+    /// The signal being fed
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// The policy handling gaps in the inputs
+    pub fn policy(&self) -> GapPolicy {
+        self.policy
+    }
+
+    /// The timestamps of the intervals skipped between the previous timestamp and `timestamp`.
+    fn skipped(&self, timestamp: i64) -> Vec<i64> {
+        match (self.interval, self.previous_timestamp) {
+            (Some(interval), Some(previous)) if timestamp - previous > interval => {
+                ((previous + interval)..timestamp).step_by(interval as usize).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Applies the values on the line from the previous valid value to `value` for each pending timestamp.
+    fn interpolate(&mut self, value: f64, timestamp: i64) {
+        if let Some((previous_timestamp, previous)) = self.previous {
+            let span = (timestamp - previous_timestamp) as f64;
+            for pending in std::mem::take(&mut self.pending) {
+                let fraction = match span == 0.0 {
+                    true => 0.0,
+                    false => (pending - previous_timestamp) as f64 / span,
+                };
+                self.signal
+                    .execute(previous + (value - previous) * fraction, &ExecutionContext::Apply);
+            }
+        }
+    }
+}
+
+impl<S> Executable for GapFilled<S>
+where
+    S: Executable<Input = f64> + Current + Clone,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        if let ExecutionContext::Evaluate = execution_context {
+            return self.clone().execute(input, &ExecutionContext::Apply);
+        }
+        let (value, timestamp) = input;
+        let value = value.filter(|value| !value.is_nan());
+        let skipped = self.skipped(timestamp);
+        self.previous_timestamp = Some(timestamp);
+        match self.policy {
+            GapPolicy::Skip => {}
+            GapPolicy::ForwardFill => {
+                if let Some((_, previous)) = self.previous {
+                    skipped.iter().for_each(|_| {
+                        self.signal.execute(previous, &ExecutionContext::Apply);
+                    });
+                }
+            }
+            GapPolicy::Interpolate => {
+                if self.previous.is_some() {
+                    self.pending.extend(skipped);
+                    if value.is_none() {
+                        self.pending.push(timestamp);
+                    }
+                }
+            }
+            GapPolicy::Reset => {
+                if value.is_none() || !skipped.is_empty() {
+                    self.signal = self.initial.clone();
+                }
+            }
+        }
+        match (value, self.policy, self.previous) {
+            (Some(value), _, _) => {
+                self.interpolate(value, timestamp);
+                self.previous = Some((timestamp, value));
+                self.signal.execute(value, &ExecutionContext::Apply)
+            }
+            (None, GapPolicy::ForwardFill, Some((_, previous))) => {
+                self.signal.execute(previous, &ExecutionContext::Apply)
+            }
+            (None, _, _) => self.signal.current(),
+        }
+    }
+}
+
+impl<S> Current for GapFilled<S>
+where
+    S: Executable<Input = f64> + Current + Clone,
+{
+    fn current(&self) -> Self::Output {
+        self.signal.current()
+    }
+}
+
+impl<S> WarmUp for GapFilled<S>
+where
+    S: Executable<Input = f64> + Current + WarmUp + Clone,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.signal.warmup_remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{CumulativeSum, SimpleMovingAverage};
+
+    fn filled(policy: GapPolicy) -> GapFilled<CumulativeSum<f64>> {
+        GapFilled::new(CumulativeSum::new(), policy, Some(10)).unwrap()
+    }
+
+    #[test]
+    fn test_skip() {
+        let mut sum = filled(GapPolicy::Skip);
+        assert_eq!(sum.apply((Some(1.0), 0)), 1.0);
+        assert_eq!(sum.apply((None, 10)), 1.0);
+        assert_eq!(sum.apply((Some(f64::NAN), 20)), 1.0);
+        assert_eq!(sum.apply((Some(2.0), 50)), 3.0);
+        assert_eq!(sum.current(), 3.0);
+    }
+
+    #[test]
+    fn test_forward_fill() {
+        let mut sum = filled(GapPolicy::ForwardFill);
+        assert_eq!(sum.apply((None, 0)), 0.0);
+        assert_eq!(sum.apply((Some(1.0), 10)), 1.0);
+        assert_eq!(sum.apply((None, 20)), 2.0);
+        // 30 and 40 are filled with 1.0
+        assert_eq!(sum.apply((Some(2.0), 50)), 6.0);
+    }
+
+    #[test]
+    fn test_interpolate() {
+        let mut sum = filled(GapPolicy::Interpolate);
+        assert_eq!(sum.apply((Some(1.0), 0)), 1.0);
+        assert_eq!(sum.apply((None, 10)), 1.0);
+        // 10 and 20 are interpolated as 2.0 and 3.0
+        assert_eq!(sum.apply((Some(4.0), 30)), 10.0);
+        assert_eq!(sum.evaluate((None, 40)), 10.0);
+        assert_eq!(sum.apply((Some(4.0), 40)), 14.0);
+    }
+
+    #[test]
+    fn test_reset() {
+        let mut sma = GapFilled::new(SimpleMovingAverage::new(2).unwrap(), GapPolicy::Reset, None).unwrap();
+        assert_eq!(sma.apply((Some(1.0), 0)), 1.0);
+        assert_eq!(sma.apply((Some(3.0), 100)), 2.0);
+        assert_eq!(sma.warmup_remaining(), 0);
+        assert_eq!(sma.apply((None, 200)), 0.0);
+        assert_eq!(sma.warmup_remaining(), 2);
+        assert_eq!(sma.apply((Some(5.0), 300)), 5.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut sum = filled(GapPolicy::ForwardFill);
+        sum.apply((Some(1.0), 0));
+        assert_eq!(sum.evaluate((Some(2.0), 30)), 5.0);
+        assert_eq!(sum.current(), 1.0);
+        assert_eq!(sum.apply((Some(2.0), 10)), 3.0);
+    }
+
+    #[test]
+    fn test_invalid_interval() {
+        assert!(GapFilled::new(CumulativeSum::new(), GapPolicy::Skip, Some(-1)).is_err());
+    }
+}
@@ -0,0 +1,299 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequePairMathExtF64,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// # Half Life
+/// Container for mean reversion Half Life aggregation
+///
+/// Estimates the number of steps a deviation from the mean takes to halve, modelling the inputs as an Ornstein
+/// Uhlenbeck process. The change of each input is regressed against the previous input over the period, a negative
+/// slope `b` meaning deviations are pulled back towards the mean. The first `p` entries will produce `None` as the
+/// period is being filled, after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mo>−</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mi>ln</mi>
+///                 <mo>(</mo>
+///                 <mn>2</mn>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mi>b</mi>
+///         </mfrac>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <mi>b</mi>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mi>cov</mi>
+///                 <mo>(</mo>
+///                 <msub><mi>i</mi><mn>k-1</mn></msub>
+///                 <mo>,</mo>
+///                 <msub><mi>i</mi><mn>k</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>i</mi><mn>k-1</mn></msub>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <mi>var</mi>
+///                 <mo>(</mo>
+///                 <msub><mi>i</mi><mn>k-1</mn></msub>
+///                 <mo>)</mo>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `k` ranges over the last `p` steps, `p` is the period and `i`
+/// is the input. If the slope is not negative the inputs are not mean reverting and the output will be infinite.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::HalfLife;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+/// use approx::assert_abs_diff_eq;
+///
+/// // create a new Half Life with a period of 3
+/// let mut half_life = HalfLife::new(3).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(half_life.apply(16.0), None);
+/// assert_eq!(half_life.apply(8.0), None);
+/// assert_eq!(half_life.apply(4.0), None);
+/// assert_abs_diff_eq!(half_life.apply(2.0).unwrap(), 2.0 * 2.0_f64.ln(), epsilon = 10e-7);
+///
+/// // evaluate some values, these won't affect the internal state of the Half Life
+/// assert_abs_diff_eq!(half_life.evaluate(2.0).unwrap(), 14.0 / 9.0 * 2.0_f64.ln(), epsilon = 10e-7);
+///
+/// // fetch the current value of the Half Life
+/// assert_abs_diff_eq!(half_life.current().unwrap(), 2.0 * 2.0_f64.ln(), epsilon = 10e-7);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HalfLife {
+    period: usize,
+    previous: Option<f64>,
+    /// The (previous input, change) pairs over the period.
+    values: VecDeque<(f64, f64)>,
+}
+
+impl HalfLife {
+    /// Create a new Half Life instance
+    /// # Arguments
+    /// * `period` - The number of changes the regression is calculated over, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::HalfLife;
+    ///
+    /// let half_life = HalfLife::new(60);
+    /// assert!(half_life.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::HalfLife;
+    ///
+    /// let half_life = HalfLife::new(0);
+    ///
+    /// assert!(half_life.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                previous: None,
+                values: VecDeque::with_capacity(period + 1),
+            }),
+        }
+    }
+
+    fn half_life(&self, values: &VecDeque<(f64, f64)>) -> Option<f64> {
+        if values.len() < self.period {
+            return None;
+        }
+        let (lagged_variance, _) = values.variances();
+        let slope = match lagged_variance == 0.0 {
+            true => 0.0,
+            false => values.covariance() / lagged_variance,
+        };
+        match slope < 0.0 {
+            true => Some(-(2.0_f64.ln()) / slope),
+            false => Some(f64::INFINITY),
+        }
+    }
+}
+
+impl IoState for HalfLife {
+    type Input = f64;
+    type Output = Option<f64>;
+}
+
+impl Validate for HalfLife {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for HalfLife {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let pair = self.previous.map(|previous| (previous, input - previous));
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.previous = Some(input);
+                if let Some(pair) = pair {
+                    self.values.push_back(pair);
+                    if self.values.len() > self.period {
+                        self.values.pop_front();
+                    }
+                }
+                self.half_life(&self.values)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                if let Some(pair) = pair {
+                    values.push_back(pair);
+                    if values.len() > self.period {
+                        values.pop_front();
+                    }
+                }
+                self.half_life(&values)
+            }
+        }
+    }
+}
+
+impl Current for HalfLife {
+    fn current(&self) -> Self::Output {
+        self.half_life(&self.values)
+    }
+}
+
+impl WarmUp for HalfLife {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none()) + self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for HalfLife {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply() {
+        let mut half_life = HalfLife::new(3).unwrap();
+        assert_eq!(half_life.apply(16.0), None);
+        assert_eq!(half_life.apply(8.0), None);
+        assert_eq!(half_life.apply(4.0), None);
+        assert_abs_diff_eq!(
+            half_life.apply(2.0).unwrap(),
+            2.0 * 2.0_f64.ln(),
+            epsilon = 10e-7
+        );
+        assert_abs_diff_eq!(
+            half_life.apply(1.0).unwrap(),
+            2.0 * 2.0_f64.ln(),
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_oscillating() {
+        let mut half_life = HalfLife::new(4).unwrap();
+        for value in [1.0, -1.0, 1.0, -1.0] {
+            half_life.apply(value);
+        }
+        assert_abs_diff_eq!(
+            half_life.apply(1.0).unwrap(),
+            2.0_f64.ln() / 2.0,
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_not_mean_reverting() {
+        let mut half_life = HalfLife::new(3).unwrap();
+        for value in [1.0, 2.0, 4.0] {
+            half_life.apply(value);
+        }
+        assert_eq!(half_life.apply(8.0), Some(f64::INFINITY));
+
+        let mut half_life = HalfLife::new(2).unwrap();
+        for value in [3.0, 3.0] {
+            half_life.apply(value);
+        }
+        assert_eq!(half_life.apply(3.0), Some(f64::INFINITY));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut half_life = HalfLife::new(2).unwrap();
+        assert_eq!(half_life.evaluate(8.0), None);
+        assert_eq!(half_life.apply(8.0), None);
+        assert_eq!(half_life.apply(4.0), None);
+        assert_eq!(half_life.evaluate(0.0), Some(f64::INFINITY));
+        let evaluated = half_life.evaluate(2.0).unwrap();
+        assert_abs_diff_eq!(evaluated, 2.0 * 2.0_f64.ln(), epsilon = 10e-7);
+        assert_eq!(half_life.apply(2.0), Some(evaluated));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut half_life = HalfLife::new(2).unwrap();
+        assert_eq!(half_life.current(), None);
+        for value in [8.0, 4.0, 2.0] {
+            half_life.apply(value);
+        }
+        half_life.evaluate(100.0);
+        assert_abs_diff_eq!(
+            half_life.current().unwrap(),
+            2.0 * 2.0_f64.ln(),
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let half_life = HalfLife::new(0);
+        assert!(half_life.is_err());
+    }
+}
@@ -0,0 +1,283 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::{DequeMathExt, DequeMathExtF64},
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// Output of the [`HampelFilter`] signal.
+///
+/// Tuples of (value, is_outlier) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HampelFilterOutput {
+    /// The filtered value, the input or the median of the period when the input is an outlier.
+    pub value: f64,
+    /// Whether the input is an outlier and was replaced by the median.
+    pub is_outlier: bool,
+}
+
+impl From<(f64, bool)> for HampelFilterOutput {
+    fn from((value, is_outlier): (f64, bool)) -> Self {
+        Self { value, is_outlier }
+    }
+}
+
+impl From<HampelFilterOutput> for (f64, bool) {
+    fn from(output: HampelFilterOutput) -> Self {
+        (output.value, output.is_outlier)
+    }
+}
+
+/// The factor scaling the median absolute deviation to a consistent estimate of the standard deviation of normally
+/// distributed inputs.
+const MAD_SCALE: f64 = 1.4826;
+
+/// # Hampel Filter
+/// Container for Hampel Filter aggregation
+///
+/// The Hampel filter rejects outliers by comparing each input against the rolling median of the period including the
+/// input. Inputs deviating from the median by more than `k` scaled median absolute deviations are flagged as outliers
+/// and replaced by the median, all other inputs are passed through unchanged. The aggregation will begin producing
+/// values immediately, with the period being filled as values are applied, the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mo>{</mo>
+///         <mtable>
+///             <mtr>
+///                 <mtd>
+///                     <msub><mi>m</mi><mn>n</mn></msub>
+///                 </mtd>
+///                 <mtd>if</mtd>
+///                 <mtd>
+///                     <mrow>
+///                         <mo>|</mo>
+///                         <msub><mi>i</mi><mn>n</mn></msub>
+///                         <mo>−</mo>
+///                         <msub><mi>m</mi><mn>n</mn></msub>
+///                         <mo>|</mo>
+///                         <mo>></mo>
+///                         <mi>k</mi>
+///                         <mo>⋅</mo>
+///                         <mn>1.4826</mn>
+///                         <mo>⋅</mo>
+///                         <msub><mi>MAD</mi><mn>n</mn></msub>
+///                     </mrow>
+///                 </mtd>
+///             </mtr>
+///             <mtr>
+///                 <mtd>
+///                     <msub><mi>i</mi><mn>n</mn></msub>
+///                 </mtd>
+///                 <mtd>otherwise</mtd>
+///             </mtr>
+///         </mtable>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `m` is the median of the inputs over the period, `MAD` is the
+/// median absolute deviation of the inputs over the period from `m`, `k` is the threshold and `i` is the input. When
+/// every input over the period is equal to the median the deviation is 0, so any input differing from the median is
+/// flagged.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{HampelFilter, HampelFilterOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Hampel Filter with a period of 5 and a threshold of 3
+/// let mut hampel = HampelFilter::new(5, 3.0).unwrap();
+///
+/// // apply some values and check their (filtered value, is outlier) output
+/// assert_eq!(hampel.apply(1.0), HampelFilterOutput { value: 1.0, is_outlier: false });
+/// assert_eq!(hampel.apply(2.0), (2.0, false).into());
+/// assert_eq!(hampel.apply(3.0), (3.0, false).into());
+/// assert_eq!(hampel.apply(2.0), (2.0, false).into());
+/// assert_eq!(hampel.apply(100.0), (2.0, true).into());
+///
+/// // evaluate some values, these won't affect the internal state of the Hampel Filter
+/// assert_eq!(hampel.evaluate(2.5), (2.5, false).into());
+///
+/// // fetch the current value of the Hampel Filter
+/// assert_eq!(hampel.current(), (2.0, true).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HampelFilter {
+    period: usize,
+    threshold: f64,
+    values: VecDeque<f64>,
+    current: HampelFilterOutput,
+}
+
+impl HampelFilter {
+    /// Create a new Hampel Filter instance
+    /// # Arguments
+    /// * `period` - The period of the rolling median, must be greater than 0
+    /// * `threshold` - The number of scaled median absolute deviations an input may deviate from the median before it
+    ///   is an outlier, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::HampelFilter;
+    ///
+    /// let hampel = HampelFilter::new(7, 3.0);
+    /// assert!(hampel.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0 or the threshold is not greater than 0
+    /// ```
+    /// use indicato_rs::signals::HampelFilter;
+    ///
+    /// assert!(HampelFilter::new(0, 3.0).is_err());
+    /// assert!(HampelFilter::new(7, 0.0).is_err());
+    /// ```
+    pub fn new(period: usize, threshold: f64) -> Result<Self, FinError> {
+        if period == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            ));
+        }
+        if threshold <= 0.0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Threshold must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            period,
+            threshold,
+            values: VecDeque::with_capacity(period + 1),
+            current: HampelFilterOutput::default(),
+        })
+    }
+}
+
+impl IoState for HampelFilter {
+    type Input = f64;
+    /// Output is the filtered value and whether the input is an outlier
+    type Output = HampelFilterOutput;
+}
+
+impl Validate for HampelFilter {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for HampelFilter {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let mut values = match execution_context {
+            ExecutionContext::Apply => std::mem::take(&mut self.values),
+            ExecutionContext::Evaluate => self.values.clone(),
+        };
+        values.push_back(input);
+        if values.len() > self.period {
+            values.pop_front();
+        }
+        let median = values.median();
+        let limit = self.threshold * MAD_SCALE * values.median_absolute_deviation();
+        let result = match (input - median).abs() > limit {
+            true => HampelFilterOutput { value: median, is_outlier: true },
+            false => HampelFilterOutput { value: input, is_outlier: false },
+        };
+        if let ExecutionContext::Apply = execution_context {
+            self.values = values;
+            self.current = result;
+        }
+        result
+    }
+}
+
+impl Current for HampelFilter {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for HampelFilter {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for HampelFilter {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut hampel = HampelFilter::new(5, 3.0).unwrap();
+        assert_eq!(hampel.apply(1.0), (1.0, false).into());
+        assert_eq!(hampel.apply(2.0), (2.0, false).into());
+        assert_eq!(hampel.apply(3.0), (3.0, false).into());
+        assert_eq!(hampel.apply(2.0), (2.0, false).into());
+        assert_eq!(hampel.apply(100.0), (2.0, true).into());
+        assert_eq!(hampel.apply(-50.0), (2.0, true).into());
+        assert_eq!(hampel.apply(3.0), (3.0, false).into());
+    }
+
+    #[test]
+    fn test_flat_window() {
+        let mut hampel = HampelFilter::new(3, 3.0).unwrap();
+        assert_eq!(hampel.apply(5.0), (5.0, false).into());
+        assert_eq!(hampel.apply(5.0), (5.0, false).into());
+        assert_eq!(hampel.apply(5.1), (5.0, true).into());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut hampel = HampelFilter::new(5, 3.0).unwrap();
+        for value in [1.0, 2.0, 3.0, 2.0] {
+            hampel.apply(value);
+        }
+        assert_eq!(hampel.evaluate(100.0), (2.0, true).into());
+        assert_eq!(hampel.evaluate(2.5), (2.5, false).into());
+        assert_eq!(hampel.apply(100.0), (2.0, true).into());
+        assert_eq!(hampel.evaluate(2.5), (2.5, false).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut hampel = HampelFilter::new(5, 3.0).unwrap();
+        assert_eq!(hampel.current(), (0.0, false).into());
+        for value in [1.0, 2.0, 3.0, 2.0, 100.0] {
+            hampel.apply(value);
+        }
+        hampel.evaluate(2.5);
+        assert_eq!(hampel.current(), (2.0, true).into());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(HampelFilter::new(0, 3.0).is_err());
+        assert!(HampelFilter::new(5, 0.0).is_err());
+        assert!(HampelFilter::new(5, -1.0).is_err());
+    }
+}
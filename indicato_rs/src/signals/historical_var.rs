@@ -0,0 +1,239 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::{DequeMathExtF64, QuantileInterpolation},
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// # Historical Value at Risk
+/// Container for rolling Historical Value at Risk aggregation
+///
+/// The historical Value at Risk is the empirical quantile of the returns over the period at one minus the
+/// confidence level, linearly interpolating between the closest ranks. The first `p - 1` entries will produce
+/// `None` as the period is being filled, after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>Q</mi>
+///             <mrow><mn>1</mn><mo>−</mo><mi>c</mi></mrow>
+///         </msub>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n-p+1</mn>
+///         </msub>
+///         <mo>..</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period, `Q` is the empirical quantile, `c` is the
+/// confidence level and `i` is the input return. The output is expressed as a return, so losses are negative.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::HistoricalVar;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Historical VaR with a period of 5 and a confidence level of 75%
+/// let mut var = HistoricalVar::new(5, 0.75).unwrap();
+///
+/// // apply some returns and check their output
+/// assert_eq!(var.apply(0.01), None);
+/// assert_eq!(var.apply(-0.02), None);
+/// assert_eq!(var.apply(0.03), None);
+/// assert_eq!(var.apply(-0.04), None);
+/// assert_eq!(var.apply(0.05), Some(-0.02));
+///
+/// // evaluate some values, these won't affect the internal state of the Historical VaR
+/// assert_eq!(var.evaluate(-0.06), Some(-0.04));
+///
+/// // fetch the current value of the Historical VaR
+/// assert_eq!(var.current(), Some(-0.02));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoricalVar {
+    period: usize,
+    quantile: f64,
+    values: VecDeque<f64>,
+}
+
+impl HistoricalVar {
+    /// Create a new Historical VaR instance
+    /// # Arguments
+    /// * `period` - The number of returns the quantile is calculated over, must be greater than 0
+    /// * `confidence` - The confidence level, such as 0.95 for the 5% quantile, must be between 0 and 1 exclusive
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::HistoricalVar;
+    ///
+    /// let var = HistoricalVar::new(250, 0.95);
+    /// assert!(var.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0 or the confidence level is not between 0 and 1 exclusive
+    /// ```
+    /// use indicato_rs::signals::HistoricalVar;
+    ///
+    /// let var = HistoricalVar::new(0, 0.95);
+    /// assert!(var.is_err());
+    ///
+    /// let var = HistoricalVar::new(250, 1.0);
+    /// assert!(var.is_err());
+    /// ```
+    pub fn new(period: usize, confidence: f64) -> Result<Self, FinError> {
+        if period == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            ));
+        }
+        if confidence <= 0.0 || confidence >= 1.0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Confidence must be between 0 and 1",
+            ));
+        }
+        Ok(Self {
+            period,
+            quantile: 1.0 - confidence,
+            values: VecDeque::with_capacity(period + 1),
+        })
+    }
+
+    fn value_at_risk(&self, values: &VecDeque<f64>) -> Option<f64> {
+        match values.len() < self.period {
+            true => None,
+            false => Some(values.quantile(self.quantile, QuantileInterpolation::Linear)),
+        }
+    }
+}
+
+impl IoState for HistoricalVar {
+    type Input = f64;
+    type Output = Option<f64>;
+}
+
+impl Validate for HistoricalVar {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for HistoricalVar {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                self.value_at_risk(&self.values)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(input);
+                if values.len() > self.period {
+                    values.pop_front();
+                }
+                self.value_at_risk(&values)
+            }
+        }
+    }
+}
+
+impl Current for HistoricalVar {
+    fn current(&self) -> Self::Output {
+        self.value_at_risk(&self.values)
+    }
+}
+
+impl WarmUp for HistoricalVar {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for HistoricalVar {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply() {
+        let mut var = HistoricalVar::new(3, 0.5).unwrap();
+        assert_eq!(var.apply(1.0), None);
+        assert_eq!(var.apply(-2.0), None);
+        assert_eq!(var.apply(3.0), Some(1.0));
+        assert_eq!(var.apply(4.0), Some(3.0));
+        assert_eq!(var.apply(-5.0), Some(3.0));
+    }
+
+    #[test]
+    fn test_interpolation() {
+        let mut var = HistoricalVar::new(5, 0.95).unwrap();
+        for value in [1.0, 2.0, 3.0, 4.0] {
+            assert_eq!(var.apply(value), None);
+        }
+        assert_abs_diff_eq!(var.apply(5.0).unwrap(), 1.2, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut var = HistoricalVar::new(2, 0.5).unwrap();
+        assert_eq!(var.evaluate(1.0), None);
+        assert_eq!(var.apply(1.0), None);
+        assert_eq!(var.evaluate(3.0), Some(2.0));
+        assert_eq!(var.evaluate(-1.0), Some(0.0));
+        assert_eq!(var.apply(3.0), Some(2.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut var = HistoricalVar::new(2, 0.5).unwrap();
+        assert_eq!(var.current(), None);
+        var.apply(1.0);
+        var.apply(3.0);
+        var.evaluate(-1.0);
+        assert_eq!(var.current(), Some(2.0));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(HistoricalVar::new(0, 0.95).is_err());
+        assert!(HistoricalVar::new(250, 0.0).is_err());
+        assert!(HistoricalVar::new(250, 1.0).is_err());
+        assert!(HistoricalVar::new(250, -0.5).is_err());
+    }
+}
@@ -0,0 +1,292 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequeMathExt,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        chained_warmup, Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic,
+        Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::{ReturnMode, Returns};
+
+/// # Historical Volatility
+/// Container for Historical Volatility aggregation
+///
+/// The historical volatility is the standard deviation of the log returns over the period, scaled by the square root
+/// of the annualization factor. The first `p` entries will produce `None` as the period of returns is being filled,
+/// after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msqrt>
+///             <mi>a</mi>
+///         </msqrt>
+///         <mo>×</mo>
+///         <mi>σ</mi>
+///         <mo>(</mo>
+///         <mi>ln</mi>
+///         <mfrac>
+///             <msub>
+///                 <mi>i</mi>
+///                 <mi>k</mi>
+///             </msub>
+///             <msub>
+///                 <mi>i</mi>
+///                 <mrow><mi>k</mi><mo>−</mo><mn>1</mn></mrow>
+///             </msub>
+///         </mfrac>
+///         <mo>)</mo>
+///         <mspace width="1em"/>
+///         <mi>k</mi>
+///         <mo>∈</mo>
+///         <mo>{</mo>
+///         <mn>n-p+1</mn>
+///         <mo>..</mo>
+///         <mn>n</mn>
+///         <mo>}</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period, `a` is the annualization factor, `σ` is the
+/// population standard deviation and `i` is the input. Inputs are expected to be positive prices.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::HistoricalVolatility;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Historical Volatility with a period of 2 and an annualization factor of 4
+/// let mut hv = HistoricalVolatility::new(2, 4.0).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(hv.apply(1.0), None);
+/// assert_eq!(hv.apply(2.0), None);
+/// assert_eq!(hv.apply(4.0), Some(0.0));
+/// assert_eq!(hv.apply(4.0), Some(2.0_f64.ln()));
+///
+/// // evaluate some values, these won't affect the internal state of the Historical Volatility
+/// assert_eq!(hv.evaluate(4.0), Some(0.0));
+///
+/// // fetch the current value of the Historical Volatility
+/// assert_eq!(hv.current(), Some(2.0_f64.ln()));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoricalVolatility {
+    period: usize,
+    scale: f64,
+    log_returns: Returns,
+    returns: VecDeque<f64>,
+}
+
+impl HistoricalVolatility {
+    /// Create a new Historical Volatility instance
+    /// # Arguments
+    /// * `period` - The number of returns the standard deviation is calculated over, must be greater than 0
+    /// * `annualization` - The number of periods in a year, such as 252 for daily trading data, must be greater
+    ///   than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::HistoricalVolatility;
+    ///
+    /// let hv = HistoricalVolatility::new(20, 252.0);
+    /// assert!(hv.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0 or the annualization factor is not greater than 0
+    /// ```
+    /// use indicato_rs::signals::HistoricalVolatility;
+    ///
+    /// let hv = HistoricalVolatility::new(0, 252.0);
+    /// assert!(hv.is_err());
+    ///
+    /// let hv = HistoricalVolatility::new(20, 0.0);
+    /// assert!(hv.is_err());
+    /// ```
+    pub fn new(period: usize, annualization: f64) -> Result<Self, FinError> {
+        if period == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            ));
+        }
+        if annualization <= 0.0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Annualization factor must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            period,
+            scale: annualization.sqrt(),
+            log_returns: Returns::new(ReturnMode::Log),
+            returns: VecDeque::with_capacity(period + 1),
+        })
+    }
+
+    fn volatility(&self, returns: &VecDeque<f64>) -> Option<f64> {
+        match returns.len() < self.period {
+            true => None,
+            false => Some(returns.standard_deviation() * self.scale),
+        }
+    }
+
+    /// Create a new Historical Volatility instance with the conventional period of 20 returns, annualized for 252 trading days
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::HistoricalVolatility;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let volatility = HistoricalVolatility::standard();
+    /// assert_eq!(volatility.period(), 20);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(20, 252.0).unwrap()
+    }
+}
+
+impl Default for HistoricalVolatility {
+    /// Create a new Historical Volatility with the conventional period of 20 returns, annualized for 252 trading days
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl IoState for HistoricalVolatility {
+    type Input = f64;
+    type Output = Option<f64>;
+}
+
+impl Validate for HistoricalVolatility {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for HistoricalVolatility {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let log_return = self.log_returns.execute(input, execution_context);
+        match execution_context {
+            ExecutionContext::Apply => {
+                if let Some(log_return) = log_return {
+                    self.returns.push_back(log_return);
+                    if self.returns.len() > self.period {
+                        self.returns.pop_front();
+                    }
+                }
+                self.volatility(&self.returns)
+            }
+            ExecutionContext::Evaluate => {
+                let mut returns = self.returns.clone();
+                if let Some(log_return) = log_return {
+                    returns.push_back(log_return);
+                    if returns.len() > self.period {
+                        returns.pop_front();
+                    }
+                }
+                self.volatility(&returns)
+            }
+        }
+    }
+}
+
+impl Current for HistoricalVolatility {
+    fn current(&self) -> Self::Output {
+        self.volatility(&self.returns)
+    }
+}
+
+impl WarmUp for HistoricalVolatility {
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(
+            self.log_returns.warmup_remaining(),
+            self.period.saturating_sub(self.returns.len()),
+        )
+    }
+}
+
+impl Periodic for HistoricalVolatility {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+    use std::f64::consts::E;
+
+    #[test]
+    fn test_apply() {
+        let mut hv = HistoricalVolatility::new(3, 4.0).unwrap();
+        assert_eq!(hv.apply(1.0), None);
+        assert_eq!(hv.apply(E), None);
+        assert_eq!(hv.apply(E), None);
+        assert_abs_diff_eq!(
+            hv.apply(E * E).unwrap(),
+            2.0 * (2.0_f64 / 9.0).sqrt(),
+            epsilon = 10e-7
+        );
+        assert_abs_diff_eq!(
+            hv.apply(E * E * E).unwrap(),
+            2.0 * (2.0_f64 / 9.0).sqrt(),
+            epsilon = 10e-7
+        );
+        assert_abs_diff_eq!(hv.apply(E.powi(4)).unwrap(), 0.0, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut hv = HistoricalVolatility::new(2, 4.0).unwrap();
+        assert_eq!(hv.evaluate(1.0), None);
+        assert_eq!(hv.apply(1.0), None);
+        assert_eq!(hv.evaluate(2.0), None);
+        assert_eq!(hv.apply(2.0), None);
+        assert_eq!(hv.evaluate(4.0), Some(0.0));
+        assert_eq!(hv.evaluate(2.0), Some(2.0_f64.ln()));
+        assert_eq!(hv.apply(4.0), Some(0.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut hv = HistoricalVolatility::new(2, 1.0).unwrap();
+        assert_eq!(hv.current(), None);
+        hv.apply(1.0);
+        hv.apply(2.0);
+        assert_eq!(hv.current(), None);
+        hv.apply(4.0);
+        hv.evaluate(2.0);
+        assert_eq!(hv.current(), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(HistoricalVolatility::new(0, 252.0).is_err());
+        assert!(HistoricalVolatility::new(20, 0.0).is_err());
+        assert!(HistoricalVolatility::new(20, -1.0).is_err());
+    }
+}
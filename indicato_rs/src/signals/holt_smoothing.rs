@@ -0,0 +1,307 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+    validation,
+};
+
+/// Output of the [`HoltSmoothing`] signal.
+///
+/// Tuples of (level, trend) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HoltSmoothingOutput {
+    /// The smoothed level of the inputs.
+    pub level: f64,
+    /// The smoothed change in the level per step.
+    pub trend: f64,
+}
+
+impl From<(f64, f64)> for HoltSmoothingOutput {
+    fn from((level, trend): (f64, f64)) -> Self {
+        Self { level, trend }
+    }
+}
+
+impl From<HoltSmoothingOutput> for (f64, f64) {
+    fn from(output: HoltSmoothingOutput) -> Self {
+        (output.level, output.trend)
+    }
+}
+
+/// # Holt Smoothing
+/// Container for Holt double exponential smoothing aggregation
+///
+/// Holt smoothing tracks a level and a trend, each exponentially smoothed, which allows it to forecast a trending
+/// series. The first input seeds the level with a trend of 0, the second input seeds the trend as the difference
+/// between the first two inputs, after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>α</mi>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <mo>(</mo>
+///         <mn>1</mn>
+///         <mo>−</mo>
+///         <mi>α</mi>
+///         <mo>)</mo>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>b</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>)</mo>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>b</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>β</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>−</mo>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>)</mo>
+///         <mo>+</mo>
+///         <mo>(</mo>
+///         <mn>1</mn>
+///         <mo>−</mo>
+///         <mi>β</mi>
+///         <mo>)</mo>
+///         <msub>
+///             <mi>b</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `n` is the current step, `l` is the level, `b` is the trend, `α` is the level smoothing factor, `β` is the
+/// trend smoothing factor and `i` is the input. The forecast `k` steps ahead is `l + kb`.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{HoltSmoothing, HoltSmoothingOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Holt Smoothing with level and trend smoothing factors of 0.5
+/// let mut holt = HoltSmoothing::new(0.5, 0.5).unwrap();
+///
+/// // apply some values and check their (level, trend) output
+/// assert_eq!(holt.apply(1.0), HoltSmoothingOutput { level: 1.0, trend: 0.0 });
+/// assert_eq!(holt.apply(3.0), (3.0, 2.0).into());
+/// assert_eq!(holt.apply(4.0), (4.5, 1.75).into());
+///
+/// // evaluate some values, these won't affect the internal state of the Holt Smoothing
+/// assert_eq!(holt.evaluate(6.25), (6.25, 1.75).into());
+///
+/// // fetch the current value of the Holt Smoothing and forecast 2 steps ahead
+/// assert_eq!(holt.current(), (4.5, 1.75).into());
+/// assert_eq!(holt.forecast(2), 8.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HoltSmoothing {
+    alpha: f64,
+    beta: f64,
+    level: Option<f64>,
+    trend: Option<f64>,
+}
+
+impl HoltSmoothing {
+    /// Create a new Holt Smoothing instance
+    /// # Arguments
+    /// * `alpha` - The level smoothing factor, must be greater than 0 and at most 1
+    /// * `beta` - The trend smoothing factor, must be greater than 0 and at most 1
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::HoltSmoothing;
+    ///
+    /// let holt = HoltSmoothing::new(0.8, 0.2);
+    /// assert!(holt.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if either smoothing factor is not greater than 0 and at most 1
+    /// ```
+    /// use indicato_rs::signals::HoltSmoothing;
+    ///
+    /// let holt = HoltSmoothing::new(0.8, 0.0);
+    ///
+    /// assert!(holt.is_err());
+    /// ```
+    pub fn new(alpha: f64, beta: f64) -> Result<Self, FinError> {
+        let is_valid = |factor: f64| factor > 0.0 && factor <= 1.0;
+        match is_valid(alpha) && is_valid(beta) {
+            true => Ok(Self {
+                alpha,
+                beta,
+                level: None,
+                trend: None,
+            }),
+            false => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Smoothing factors must be greater than 0 and at most 1",
+            )),
+        }
+    }
+
+    /// Forecast the value `steps` ahead of the current step
+    /// # Arguments
+    /// * `steps` - The number of steps ahead to forecast, 0 is the current level
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::HoltSmoothing;
+    /// use indicato_rs::traits::Apply;
+    ///
+    /// let mut holt = HoltSmoothing::new(0.5, 0.5).unwrap();
+    /// holt.apply(1.0);
+    /// holt.apply(3.0);
+    ///
+    /// assert_eq!(holt.forecast(0), 3.0);
+    /// assert_eq!(holt.forecast(3), 9.0);
+    /// ```
+    pub fn forecast(&self, steps: usize) -> f64 {
+        let HoltSmoothingOutput { level, trend } = self.current();
+        level + steps as f64 * trend
+    }
+}
+
+impl IoState for HoltSmoothing {
+    type Input = f64;
+    /// Output is the level and trend
+    type Output = HoltSmoothingOutput;
+}
+
+impl Validate for HoltSmoothing {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for HoltSmoothing {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (level, trend) = match (self.level, self.trend) {
+            (None, _) => (input, None),
+            (Some(level), None) => (input, Some(input - level)),
+            (Some(level), Some(trend)) => {
+                let next = self.alpha * input + (1.0 - self.alpha) * (level + trend);
+                (
+                    next,
+                    Some(self.beta * (next - level) + (1.0 - self.beta) * trend),
+                )
+            }
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.level = Some(level);
+                self.trend = trend;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        HoltSmoothingOutput {
+            level,
+            trend: trend.unwrap_or(0.0),
+        }
+    }
+}
+
+impl Current for HoltSmoothing {
+    fn current(&self) -> Self::Output {
+        HoltSmoothingOutput {
+            level: self.level.unwrap_or(0.0),
+            trend: self.trend.unwrap_or(0.0),
+        }
+    }
+}
+
+impl WarmUp for HoltSmoothing {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.level.is_none()) + usize::from(self.trend.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut holt = HoltSmoothing::new(0.5, 0.5).unwrap();
+        assert_eq!(holt.apply(1.0), (1.0, 0.0).into());
+        assert_eq!(holt.apply(3.0), (3.0, 2.0).into());
+        assert_eq!(holt.apply(4.0), (4.5, 1.75).into());
+        assert_eq!(holt.apply(6.25), (6.25, 1.75).into());
+    }
+
+    #[test]
+    fn test_apply_linear() {
+        let mut holt = HoltSmoothing::new(0.3, 0.1).unwrap();
+        for input in 0..10 {
+            holt.apply(2.0 * input as f64);
+        }
+        assert_eq!(holt.current(), (18.0, 2.0).into());
+        assert_eq!(holt.forecast(5), 28.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut holt = HoltSmoothing::new(0.5, 0.5).unwrap();
+        assert_eq!(holt.evaluate(1.0), (1.0, 0.0).into());
+        assert_eq!(holt.apply(1.0), (1.0, 0.0).into());
+        assert_eq!(holt.evaluate(3.0), (3.0, 2.0).into());
+        assert_eq!(holt.evaluate(5.0), (5.0, 4.0).into());
+        assert_eq!(holt.apply(3.0), (3.0, 2.0).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut holt = HoltSmoothing::new(0.5, 0.5).unwrap();
+        assert_eq!(holt.current(), (0.0, 0.0).into());
+        assert_eq!(holt.forecast(3), 0.0);
+        holt.apply(1.0);
+        holt.apply(3.0);
+        holt.evaluate(10.0);
+        assert_eq!(holt.current(), (3.0, 2.0).into());
+        assert_eq!(holt.forecast(1), 5.0);
+    }
+
+    #[test]
+    fn test_invalid_factors() {
+        assert!(HoltSmoothing::new(0.0, 0.5).is_err());
+        assert!(HoltSmoothing::new(0.5, 0.0).is_err());
+        assert!(HoltSmoothing::new(1.5, 0.5).is_err());
+        assert!(HoltSmoothing::new(0.5, 1.5).is_err());
+        assert!(HoltSmoothing::new(1.0, 1.0).is_ok());
+    }
+}
@@ -0,0 +1,415 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequeMathExt,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// Output of the [`HoltWinters`] signal.
+///
+/// Tuples of (level, trend, seasonal) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HoltWintersOutput {
+    /// The smoothed level of the inputs with the seasonal component removed.
+    pub level: f64,
+    /// The smoothed change in the level per step.
+    pub trend: f64,
+    /// The seasonal component of the current step.
+    pub seasonal: f64,
+}
+
+impl From<(f64, f64, f64)> for HoltWintersOutput {
+    fn from((level, trend, seasonal): (f64, f64, f64)) -> Self {
+        Self {
+            level,
+            trend,
+            seasonal,
+        }
+    }
+}
+
+impl From<HoltWintersOutput> for (f64, f64, f64) {
+    fn from(output: HoltWintersOutput) -> Self {
+        (output.level, output.trend, output.seasonal)
+    }
+}
+
+/// The way the seasonal component of a [`HoltWinters`] combines with the level and trend.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Seasonality {
+    /// The seasonal component is added to the level, for seasonal variation of a constant size.
+    Additive,
+    /// The level is scaled by the seasonal component, for seasonal variation proportional to the level.
+    Multiplicative,
+}
+
+crate::inspect::debug_state!(Seasonality);
+
+/// # Holt Winters
+/// Container for Holt Winters triple exponential smoothing aggregation
+///
+/// Holt Winters smoothing extends [`HoltSmoothing`](super::HoltSmoothing) with a seasonal component for each step
+/// of the season. The first `m` entries will produce `None` as the first season is collected, the level is then
+/// seeded as the mean of the first season with a trend of 0, and the seasonal components as each input's deviation
+/// from the level. After which the following formula is applied for additive seasonality:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///     <mtable>
+///     <mtr><mtd>
+///         <msub><mi>l</mi><mn>n</mn></msub>
+///         <mo>=</mo>
+///         <mi>α</mi>
+///         <mo>(</mo>
+///         <msub><mi>i</mi><mn>n</mn></msub>
+///         <mo>−</mo>
+///         <msub><mi>s</mi><mn>n-m</mn></msub>
+///         <mo>)</mo>
+///         <mo>+</mo>
+///         <mo>(</mo><mn>1</mn><mo>−</mo><mi>α</mi><mo>)</mo>
+///         <mo>(</mo>
+///         <msub><mi>l</mi><mn>n-1</mn></msub>
+///         <mo>+</mo>
+///         <msub><mi>b</mi><mn>n-1</mn></msub>
+///         <mo>)</mo>
+///     </mtd></mtr>
+///     <mtr><mtd>
+///         <msub><mi>b</mi><mn>n</mn></msub>
+///         <mo>=</mo>
+///         <mi>β</mi>
+///         <mo>(</mo>
+///         <msub><mi>l</mi><mn>n</mn></msub>
+///         <mo>−</mo>
+///         <msub><mi>l</mi><mn>n-1</mn></msub>
+///         <mo>)</mo>
+///         <mo>+</mo>
+///         <mo>(</mo><mn>1</mn><mo>−</mo><mi>β</mi><mo>)</mo>
+///         <msub><mi>b</mi><mn>n-1</mn></msub>
+///     </mtd></mtr>
+///     <mtr><mtd>
+///         <msub><mi>s</mi><mn>n</mn></msub>
+///         <mo>=</mo>
+///         <mi>γ</mi>
+///         <mo>(</mo>
+///         <msub><mi>i</mi><mn>n</mn></msub>
+///         <mo>−</mo>
+///         <msub><mi>l</mi><mn>n</mn></msub>
+///         <mo>)</mo>
+///         <mo>+</mo>
+///         <mo>(</mo><mn>1</mn><mo>−</mo><mi>γ</mi><mo>)</mo>
+///         <msub><mi>s</mi><mn>n-m</mn></msub>
+///     </mtd></mtr>
+///     </mtable>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `n` is the current step, `m` is the season length, `l` is the level, `b` is the trend, `s` is the seasonal
+/// component, `α`, `β` and `γ` are the level, trend and seasonal smoothing factors and `i` is the input. For
+/// multiplicative seasonality the subtractions of the seasonal component and level are divisions, and inputs are
+/// expected to be positive.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{HoltWinters, HoltWintersOutput, Seasonality};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new additive Holt Winters with a season length of 2 and smoothing factors of 0.5
+/// let mut holt_winters = HoltWinters::new(0.5, 0.5, 0.5, 2, Seasonality::Additive).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(holt_winters.apply(1.0), None);
+/// assert_eq!(holt_winters.apply(3.0), Some(HoltWintersOutput { level: 2.0, trend: 0.0, seasonal: 1.0 }));
+/// assert_eq!(holt_winters.apply(2.0), Some((2.5, 0.25, -0.75).into()));
+///
+/// // evaluate some values, these won't affect the internal state of the Holt Winters
+/// assert!(holt_winters.evaluate(4.0).is_some());
+///
+/// // fetch the current value of the Holt Winters and forecast the next steps
+/// assert_eq!(holt_winters.current(), Some((2.5, 0.25, -0.75).into()));
+/// assert_eq!(holt_winters.forecast(1), Some(3.75));
+/// assert_eq!(holt_winters.forecast(2), Some(2.25));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HoltWinters {
+    alpha: f64,
+    beta: f64,
+    gamma: f64,
+    season_length: usize,
+    seasonality: Seasonality,
+    /// The inputs of the first season, until the first season is complete.
+    seed: VecDeque<f64>,
+    /// The (level, trend) once the first season is complete.
+    state: Option<(f64, f64)>,
+    /// The seasonal components, the front is the component for the next step.
+    seasonals: VecDeque<f64>,
+}
+
+impl HoltWinters {
+    /// Create a new Holt Winters instance
+    /// # Arguments
+    /// * `alpha` - The level smoothing factor, must be greater than 0 and at most 1
+    /// * `beta` - The trend smoothing factor, must be greater than 0 and at most 1
+    /// * `gamma` - The seasonal smoothing factor, must be greater than 0 and at most 1
+    /// * `season_length` - The number of steps in a season, must be greater than 0
+    /// * `seasonality` - Whether the seasonal component is additive or multiplicative
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{HoltWinters, Seasonality};
+    ///
+    /// let holt_winters = HoltWinters::new(0.5, 0.1, 0.1, 12, Seasonality::Multiplicative);
+    /// assert!(holt_winters.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if any smoothing factor is not greater than 0 and at most 1, or the season length is 0
+    /// ```
+    /// use indicato_rs::signals::{HoltWinters, Seasonality};
+    ///
+    /// assert!(HoltWinters::new(0.5, 0.1, 0.1, 0, Seasonality::Additive).is_err());
+    /// assert!(HoltWinters::new(0.5, 0.1, 1.1, 12, Seasonality::Additive).is_err());
+    /// ```
+    pub fn new(
+        alpha: f64,
+        beta: f64,
+        gamma: f64,
+        season_length: usize,
+        seasonality: Seasonality,
+    ) -> Result<Self, FinError> {
+        if season_length == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Season length must be greater than 0",
+            ));
+        }
+        let is_valid = |factor: f64| factor > 0.0 && factor <= 1.0;
+        if !(is_valid(alpha) && is_valid(beta) && is_valid(gamma)) {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Smoothing factors must be greater than 0 and at most 1",
+            ));
+        }
+        Ok(Self {
+            alpha,
+            beta,
+            gamma,
+            season_length,
+            seasonality,
+            seed: VecDeque::with_capacity(season_length),
+            state: None,
+            seasonals: VecDeque::with_capacity(season_length + 1),
+        })
+    }
+
+    /// Forecast the value `steps` ahead of the current step, `None` until the first season is complete
+    /// # Arguments
+    /// * `steps` - The number of steps ahead to forecast, 0 is the smoothed value of the current step
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{HoltWinters, Seasonality};
+    /// use indicato_rs::traits::Apply;
+    ///
+    /// let mut holt_winters = HoltWinters::new(0.5, 0.5, 0.5, 2, Seasonality::Additive).unwrap();
+    /// assert_eq!(holt_winters.forecast(1), None);
+    ///
+    /// holt_winters.apply(1.0);
+    /// holt_winters.apply(3.0);
+    /// assert_eq!(holt_winters.forecast(1), Some(1.0));
+    /// assert_eq!(holt_winters.forecast(2), Some(3.0));
+    /// ```
+    pub fn forecast(&self, steps: usize) -> Option<f64> {
+        let (level, trend) = self.state?;
+        let seasonal = self.seasonals[(steps + self.season_length - 1) % self.season_length];
+        let projected = level + steps as f64 * trend;
+        Some(match self.seasonality {
+            Seasonality::Additive => projected + seasonal,
+            Seasonality::Multiplicative => projected * seasonal,
+        })
+    }
+
+    fn deseasonalize(&self, value: f64, seasonal: f64) -> f64 {
+        match self.seasonality {
+            Seasonality::Additive => value - seasonal,
+            Seasonality::Multiplicative => value / seasonal,
+        }
+    }
+}
+
+impl IoState for HoltWinters {
+    type Input = f64;
+    /// Output is the level, trend and seasonal component, `None` until the first season is complete
+    type Output = Option<HoltWintersOutput>;
+}
+
+impl Validate for HoltWinters {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for HoltWinters {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let mut seasonals = match execution_context {
+            ExecutionContext::Apply => std::mem::take(&mut self.seasonals),
+            ExecutionContext::Evaluate => self.seasonals.clone(),
+        };
+        let state = match self.state {
+            None => {
+                let mut seed = self.seed.clone();
+                seed.push_back(input);
+                if seed.len() < self.season_length {
+                    if let ExecutionContext::Apply = execution_context {
+                        self.seed = seed;
+                    }
+                    return None;
+                }
+                let level = seed.mean();
+                seasonals = seed
+                    .iter()
+                    .map(|value| self.deseasonalize(*value, level))
+                    .collect();
+                if let ExecutionContext::Apply = execution_context {
+                    self.seed.clear();
+                }
+                (level, 0.0)
+            }
+            Some((level, trend)) => {
+                let seasonal = seasonals.pop_front().unwrap_or_default();
+                let next = self.alpha * self.deseasonalize(input, seasonal)
+                    + (1.0 - self.alpha) * (level + trend);
+                let trend = self.beta * (next - level) + (1.0 - self.beta) * trend;
+                seasonals.push_back(
+                    self.gamma * self.deseasonalize(input, next) + (1.0 - self.gamma) * seasonal,
+                );
+                (next, trend)
+            }
+        };
+        let result = seasonals
+            .back()
+            .map(|seasonal| (state.0, state.1, *seasonal).into());
+        if let ExecutionContext::Apply = execution_context {
+            self.state = Some(state);
+            self.seasonals = seasonals;
+        }
+        result
+    }
+}
+
+impl Current for HoltWinters {
+    fn current(&self) -> Self::Output {
+        let (level, trend) = self.state?;
+        self.seasonals
+            .back()
+            .map(|seasonal| (level, trend, *seasonal).into())
+    }
+}
+
+impl WarmUp for HoltWinters {
+    fn warmup_remaining(&self) -> usize {
+        match self.state {
+            Some(_) => 0,
+            None => self.season_length - self.seed.len(),
+        }
+    }
+}
+
+impl Periodic for HoltWinters {
+    fn period(&self) -> usize {
+        self.season_length
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply_additive() {
+        let mut holt_winters = HoltWinters::new(0.5, 0.5, 0.5, 2, Seasonality::Additive).unwrap();
+        assert_eq!(holt_winters.apply(1.0), None);
+        assert_eq!(holt_winters.apply(3.0), Some((2.0, 0.0, 1.0).into()));
+        assert_eq!(holt_winters.apply(2.0), Some((2.5, 0.25, -0.75).into()));
+        assert_eq!(holt_winters.forecast(1), Some(3.75));
+        assert_eq!(holt_winters.forecast(2), Some(2.25));
+        assert_eq!(holt_winters.forecast(3), Some(4.25));
+    }
+
+    #[test]
+    fn test_apply_multiplicative() {
+        let mut holt_winters =
+            HoltWinters::new(0.5, 0.5, 0.5, 2, Seasonality::Multiplicative).unwrap();
+        assert_eq!(holt_winters.apply(1.0), None);
+        assert_eq!(holt_winters.apply(3.0), Some((2.0, 0.0, 1.5).into()));
+        let HoltWintersOutput { level, trend, seasonal } = holt_winters.apply(2.0).unwrap();
+        assert_eq!(level, 3.0);
+        assert_eq!(trend, 0.5);
+        assert_abs_diff_eq!(seasonal, 7.0 / 12.0, epsilon = 10e-7);
+        assert_eq!(holt_winters.forecast(1), Some(5.25));
+    }
+
+    #[test]
+    fn test_repeating_season() {
+        let mut holt_winters = HoltWinters::new(0.3, 0.1, 0.2, 3, Seasonality::Additive).unwrap();
+        for _ in 0..5 {
+            for value in [1.0, 5.0, 3.0] {
+                holt_winters.apply(value);
+            }
+        }
+        let HoltWintersOutput { level, trend, seasonal } = holt_winters.current().unwrap();
+        assert_abs_diff_eq!(level, 3.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(trend, 0.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(seasonal, 0.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(holt_winters.forecast(1).unwrap(), 1.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(holt_winters.forecast(2).unwrap(), 5.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(holt_winters.forecast(3).unwrap(), 3.0, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut holt_winters = HoltWinters::new(0.5, 0.5, 0.5, 2, Seasonality::Additive).unwrap();
+        assert_eq!(holt_winters.evaluate(1.0), None);
+        assert_eq!(holt_winters.apply(1.0), None);
+        assert_eq!(holt_winters.evaluate(3.0), Some((2.0, 0.0, 1.0).into()));
+        assert_eq!(holt_winters.evaluate(5.0), Some((3.0, 0.0, 2.0).into()));
+        assert_eq!(holt_winters.apply(3.0), Some((2.0, 0.0, 1.0).into()));
+        assert_eq!(holt_winters.evaluate(2.0), Some((2.5, 0.25, -0.75).into()));
+        assert_eq!(holt_winters.evaluate(2.0), Some((2.5, 0.25, -0.75).into()));
+        assert_eq!(holt_winters.apply(2.0), Some((2.5, 0.25, -0.75).into()));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut holt_winters = HoltWinters::new(0.5, 0.5, 0.5, 2, Seasonality::Additive).unwrap();
+        assert_eq!(holt_winters.current(), None);
+        holt_winters.apply(1.0);
+        assert_eq!(holt_winters.current(), None);
+        holt_winters.apply(3.0);
+        holt_winters.evaluate(10.0);
+        assert_eq!(holt_winters.current(), Some((2.0, 0.0, 1.0).into()));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(HoltWinters::new(0.5, 0.5, 0.5, 0, Seasonality::Additive).is_err());
+        assert!(HoltWinters::new(0.0, 0.5, 0.5, 4, Seasonality::Additive).is_err());
+        assert!(HoltWinters::new(0.5, 1.5, 0.5, 4, Seasonality::Additive).is_err());
+        assert!(HoltWinters::new(0.5, 0.5, 0.0, 4, Seasonality::Multiplicative).is_err());
+    }
+}
@@ -0,0 +1,542 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    types::{HasClose, HasHighLow},
+    validation,
+};
+
+use super::{MaximumPeriod, MinimumPeriod};
+
+fn midpoint(high: f64, low: f64) -> f64 {
+    (high + low) / 2.0
+}
+
+/// Output of the [`IchimokuCloud`] signal, each line is `None` until enough values have been applied to produce it.
+#[derive(Debug, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IchimokuCloudOutput {
+    /// The conversion line, midpoint of the high and low over the tenkan period.
+    pub tenkan_sen: Option<f64>,
+    /// The base line, midpoint of the high and low over the kijun period.
+    pub kijun_sen: Option<f64>,
+    /// The first leading span, average of the tenkan-sen and kijun-sen calculated `displacement` periods ago.
+    pub senkou_span_a: Option<f64>,
+    /// The second leading span, midpoint of the high and low over the senkou B period calculated `displacement` periods ago.
+    pub senkou_span_b: Option<f64>,
+    /// The lagging span, the current close which is plotted `displacement` periods in the past.
+    pub chikou_span: Option<f64>,
+}
+
+/// # Ichimoku Cloud
+/// Container for Ichimoku Cloud (Ichimoku Kinko Hyo) aggregation
+///
+/// The Ichimoku Cloud is made up of five lines, the tenkan-sen and kijun-sen are the midpoint of the highest high
+/// and lowest low over their respective periods:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub>
+///                     <mi>h</mi>
+///                     <mn>max</mn>
+///                 </msub>
+///                 <mo>+</mo>
+///                 <msub>
+///                     <mi>l</mi>
+///                     <mn>min</mn>
+///                 </msub>
+///             </mrow>
+///             <mn>2</mn>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `h` is the high value and `l` is the low value over the period.
+///
+/// The senkou span A is the average of the tenkan-sen and kijun-sen, and the senkou span B is the midpoint over the
+/// senkou B period, both of these are displaced forward by `displacement` periods. The value returned for the current
+/// step is therefore the value calculated `displacement` steps ago, which forms the cloud at the current step.
+/// The chikou span is the current close, which is plotted `displacement` periods in the past.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::IchimokuCloud;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Ichimoku Cloud with tenkan, kijun and senkou B periods of 2, 3 and 4 displaced by 2
+/// let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+///
+/// // apply some values, lines are None until their periods have been filled
+/// assert_eq!(ichimoku.apply((10.0, 8.0, 9.0)).tenkan_sen, None);
+/// assert_eq!(ichimoku.apply((11.0, 9.0, 10.0)).tenkan_sen, Some(9.5));
+/// assert_eq!(ichimoku.apply((12.0, 10.0, 11.0)).kijun_sen, Some(10.0));
+/// assert_eq!(ichimoku.apply((13.0, 11.0, 12.0)).senkou_span_a, None);
+///
+/// // the cloud at this step was calculated two steps ago
+/// let output = ichimoku.apply((14.0, 12.0, 13.0));
+/// assert_eq!(output.senkou_span_a, Some(10.25));
+/// assert_eq!(output.senkou_span_b, None);
+///
+/// // evaluate some values, these won't affect the internal state of the Ichimoku Cloud
+/// assert_eq!(ichimoku.evaluate((15.0, 13.0, 14.0)).senkou_span_b, Some(10.5));
+///
+/// // fetch the current value of the Ichimoku Cloud
+/// assert_eq!(ichimoku.current(), output);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct IchimokuCloud<I: HasHighLow + HasClose = (f64, f64, f64)> {
+    tenkan_period: usize,
+    kijun_period: usize,
+    senkou_b_period: usize,
+    displacement: usize,
+    tenkan_high: MaximumPeriod,
+    tenkan_low: MinimumPeriod,
+    kijun_high: MaximumPeriod,
+    kijun_low: MinimumPeriod,
+    senkou_b_high: MaximumPeriod,
+    senkou_b_low: MinimumPeriod,
+    /// The leading spans calculated over the last `displacement` steps, the front is the cloud for the next step.
+    senkou_spans: VecDeque<(Option<f64>, Option<f64>)>,
+    /// The number of values that have been applied.
+    count: usize,
+    current: IchimokuCloudOutput,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: HasHighLow + HasClose> IchimokuCloud<I> {
+    /// Create a new Ichimoku Cloud instance taking inputs of type `I`, such as a [`Candle`](crate::types::Candle) or
+    /// a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `tenkan_period` - The period of the conversion line, typically 9
+    /// * `kijun_period` - The period of the base line, typically 26
+    /// * `senkou_b_period` - The period of the second leading span, typically 52
+    /// * `displacement` - The number of periods the leading spans are displaced forward, typically 26
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::IchimokuCloud;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let ichimoku = IchimokuCloud::<Candle>::for_input(9, 26, 52, 26);
+    /// assert!(ichimoku.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    pub fn for_input(
+        tenkan_period: usize,
+        kijun_period: usize,
+        senkou_b_period: usize,
+        displacement: usize,
+    ) -> Result<Self, FinError> {
+        match (tenkan_period, kijun_period, senkou_b_period, displacement) {
+            (0, _, _, _) | (_, 0, _, _) | (_, _, 0, _) | (_, _, _, 0) => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Periods must be greater than 0",
+            )),
+            _ => Ok(Self {
+                tenkan_period,
+                kijun_period,
+                senkou_b_period,
+                displacement,
+                tenkan_high: MaximumPeriod::new(tenkan_period)?,
+                tenkan_low: MinimumPeriod::new(tenkan_period)?,
+                kijun_high: MaximumPeriod::new(kijun_period)?,
+                kijun_low: MinimumPeriod::new(kijun_period)?,
+                senkou_b_high: MaximumPeriod::new(senkou_b_period)?,
+                senkou_b_low: MinimumPeriod::new(senkou_b_period)?,
+                senkou_spans: VecDeque::with_capacity(displacement + 1),
+                count: 0,
+                current: IchimokuCloudOutput {
+                    tenkan_sen: None,
+                    kijun_sen: None,
+                    senkou_span_a: None,
+                    senkou_span_b: None,
+                    chikou_span: None,
+                },
+                input: PhantomData,
+            }),
+        }
+    }
+}
+
+impl IchimokuCloud {
+    /// Create a new Ichimoku Cloud instance
+    /// # Arguments
+    /// * `tenkan_period` - The period of the conversion line, typically 9
+    /// * `kijun_period` - The period of the base line, typically 26
+    /// * `senkou_b_period` - The period of the second leading span, typically 52
+    /// * `displacement` - The number of periods the leading spans are displaced forward, typically 26
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::IchimokuCloud;
+    ///
+    /// let ichimoku = IchimokuCloud::new(9, 26, 52, 26);
+    /// assert!(ichimoku.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    /// ```
+    /// use indicato_rs::signals::IchimokuCloud;
+    ///
+    /// let ichimoku = IchimokuCloud::new(9, 0, 52, 26);
+    ///
+    /// assert!(ichimoku.is_err());
+    /// ```
+    pub fn new(
+        tenkan_period: usize,
+        kijun_period: usize,
+        senkou_b_period: usize,
+        displacement: usize,
+    ) -> Result<Self, FinError> {
+        Self::for_input(tenkan_period, kijun_period, senkou_b_period, displacement)
+    }
+
+    /// Create a new Ichimoku Cloud instance with the conventional periods of 9, 26 and 52, displaced by 26
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::IchimokuCloud;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let ichimoku = IchimokuCloud::standard();
+    /// assert_eq!(ichimoku.period(), 52);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(9, 26, 52, 26).unwrap()
+    }
+
+    /// Create a new [`IchimokuCloudBuilder`] starting from the conventional periods of 9, 26 and 52, displaced by 26
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::IchimokuCloud;
+    ///
+    /// let ichimoku = IchimokuCloud::builder().tenkan_period(7).kijun_period(22).build();
+    /// assert!(ichimoku.is_ok());
+    /// ```
+    pub fn builder() -> IchimokuCloudBuilder {
+        IchimokuCloudBuilder::default()
+    }
+}
+
+impl Default for IchimokuCloud {
+    /// Create a new Ichimoku Cloud with the conventional periods of 9, 26 and 52, displaced by 26
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Builder for an [`IchimokuCloud`], created with [`IchimokuCloud::builder`].
+///
+/// Any period that isn't set keeps its conventional value, the periods are validated when the Ichimoku Cloud is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IchimokuCloudBuilder {
+    tenkan_period: usize,
+    kijun_period: usize,
+    senkou_b_period: usize,
+    displacement: usize,
+}
+
+impl IchimokuCloudBuilder {
+    /// Set the period of the conversion line
+    pub fn tenkan_period(mut self, tenkan_period: usize) -> Self {
+        self.tenkan_period = tenkan_period;
+        self
+    }
+
+    /// Set the period of the base line
+    pub fn kijun_period(mut self, kijun_period: usize) -> Self {
+        self.kijun_period = kijun_period;
+        self
+    }
+
+    /// Set the period of the second leading span
+    pub fn senkou_b_period(mut self, senkou_b_period: usize) -> Self {
+        self.senkou_b_period = senkou_b_period;
+        self
+    }
+
+    /// Set the number of periods the leading spans are displaced forward
+    pub fn displacement(mut self, displacement: usize) -> Self {
+        self.displacement = displacement;
+        self
+    }
+
+    /// Build the Ichimoku Cloud
+    ///
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    /// ```
+    /// use indicato_rs::signals::IchimokuCloud;
+    ///
+    /// let ichimoku = IchimokuCloud::builder().displacement(0).build();
+    ///
+    /// assert!(ichimoku.is_err());
+    /// ```
+    pub fn build(self) -> Result<IchimokuCloud, FinError> {
+        IchimokuCloud::new(
+            self.tenkan_period,
+            self.kijun_period,
+            self.senkou_b_period,
+            self.displacement,
+        )
+    }
+}
+
+impl Default for IchimokuCloudBuilder {
+    /// Create a new Ichimoku Cloud builder with the conventional periods of 9, 26 and 52, displaced by 26
+    fn default() -> Self {
+        Self {
+            tenkan_period: 9,
+            kijun_period: 26,
+            senkou_b_period: 52,
+            displacement: 26,
+        }
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for IchimokuCloud<I> {
+    /// Input is anything with a high, low and close, by default a tuple of (high, low, close)
+    type Input = I;
+    /// Output is the five lines of the Ichimoku Cloud
+    type Output = IchimokuCloudOutput;
+}
+
+impl<I: HasHighLow + HasClose> Validate for IchimokuCloud<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for IchimokuCloud<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+        let count = self.count + 1;
+
+        let tenkan = midpoint(
+            self.tenkan_high.execute(high, execution_context),
+            self.tenkan_low.execute(low, execution_context),
+        );
+        let kijun = midpoint(
+            self.kijun_high.execute(high, execution_context),
+            self.kijun_low.execute(low, execution_context),
+        );
+        let senkou_b = midpoint(
+            self.senkou_b_high.execute(high, execution_context),
+            self.senkou_b_low.execute(low, execution_context),
+        );
+
+        let tenkan_sen = (count >= self.tenkan_period).then_some(tenkan);
+        let kijun_sen = (count >= self.kijun_period).then_some(kijun);
+        let senkou_span_a = match (tenkan_sen, kijun_sen) {
+            (Some(tenkan_sen), Some(kijun_sen)) => Some((tenkan_sen + kijun_sen) / 2.0),
+            _ => None,
+        };
+        let senkou_span_b = (count >= self.senkou_b_period).then_some(senkou_b);
+
+        let (displaced_a, displaced_b) = match self.senkou_spans.len() == self.displacement {
+            true => self.senkou_spans.front().copied().unwrap_or((None, None)),
+            false => (None, None),
+        };
+
+        let output = IchimokuCloudOutput {
+            tenkan_sen,
+            kijun_sen,
+            senkou_span_a: displaced_a,
+            senkou_span_b: displaced_b,
+            chikou_span: Some(close),
+        };
+
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.count = count;
+                self.senkou_spans.push_back((senkou_span_a, senkou_span_b));
+                if self.senkou_spans.len() > self.displacement {
+                    self.senkou_spans.pop_front();
+                }
+                self.current = output;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        output
+    }
+}
+
+impl<I: HasHighLow + HasClose> Current for IchimokuCloud<I> {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for IchimokuCloud<I> {
+    fn warmup_remaining(&self) -> usize {
+        let longest = self
+            .tenkan_period
+            .max(self.kijun_period)
+            .max(self.senkou_b_period);
+        (longest + self.displacement).saturating_sub(self.count)
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for IchimokuCloud<I> {
+    fn period(&self) -> usize {
+        self.tenkan_period
+            .max(self.kijun_period)
+            .max(self.senkou_b_period)
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period() + self.displacement
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Candle;
+
+    fn apply_series(ichimoku: &mut IchimokuCloud) -> Vec<IchimokuCloudOutput> {
+        [
+            (10.0, 8.0, 9.0),
+            (11.0, 9.0, 10.0),
+            (12.0, 10.0, 11.0),
+            (13.0, 11.0, 12.0),
+            (14.0, 12.0, 13.0),
+            (15.0, 13.0, 14.0),
+        ]
+        .into_iter()
+        .map(|input| ichimoku.apply(input))
+        .collect()
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+        let outputs = apply_series(&mut ichimoku);
+
+        let tenkan: Vec<_> = outputs.iter().map(|o| o.tenkan_sen).collect();
+        assert_eq!(tenkan, vec![None, Some(9.5), Some(10.5), Some(11.5), Some(12.5), Some(13.5)]);
+
+        let kijun: Vec<_> = outputs.iter().map(|o| o.kijun_sen).collect();
+        assert_eq!(kijun, vec![None, None, Some(10.0), Some(11.0), Some(12.0), Some(13.0)]);
+
+        let span_a: Vec<_> = outputs.iter().map(|o| o.senkou_span_a).collect();
+        assert_eq!(span_a, vec![None, None, None, None, Some(10.25), Some(11.25)]);
+
+        let span_b: Vec<_> = outputs.iter().map(|o| o.senkou_span_b).collect();
+        assert_eq!(span_b, vec![None, None, None, None, None, Some(10.5)]);
+
+        let chikou: Vec<_> = outputs.iter().map(|o| o.chikou_span).collect();
+        assert_eq!(chikou, vec![Some(9.0), Some(10.0), Some(11.0), Some(12.0), Some(13.0), Some(14.0)]);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+        assert_eq!(ichimoku.apply((10.0, 8.0, 9.0)).tenkan_sen, None);
+        assert_eq!(ichimoku.evaluate((11.0, 9.0, 10.0)).tenkan_sen, Some(9.5));
+        assert_eq!(ichimoku.evaluate((13.0, 9.0, 10.0)).tenkan_sen, Some(10.5));
+        assert_eq!(ichimoku.apply((11.0, 9.0, 10.0)).tenkan_sen, Some(9.5));
+        assert_eq!(ichimoku.apply((12.0, 10.0, 11.0)).kijun_sen, Some(10.0));
+        assert_eq!(ichimoku.apply((13.0, 11.0, 12.0)).senkou_span_b, None);
+        let evaluated = ichimoku.evaluate((14.0, 12.0, 13.0));
+        let applied = ichimoku.apply((14.0, 12.0, 13.0));
+        assert_eq!(evaluated, applied);
+        assert_eq!(applied.senkou_span_a, Some(10.25));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+        assert_eq!(ichimoku.current().tenkan_sen, None);
+        assert_eq!(ichimoku.current().chikou_span, None);
+        let outputs = apply_series(&mut ichimoku);
+        ichimoku.evaluate((20.0, 1.0, 5.0));
+        assert_eq!(ichimoku.current(), outputs[5]);
+    }
+
+    #[test]
+    fn test_default() {
+        let mut ichimoku = IchimokuCloud::default();
+        for x in 0..77 {
+            let output = ichimoku.apply((x as f64 + 1.0, x as f64, x as f64 + 0.5));
+            assert_eq!(output.senkou_span_b, None);
+        }
+        let output = ichimoku.apply((78.0, 77.0, 77.5));
+        assert_eq!(output.tenkan_sen, Some(73.5));
+        assert_eq!(output.kijun_sen, Some(65.0));
+        assert_eq!(output.senkou_span_a, Some(43.25));
+        assert_eq!(output.senkou_span_b, Some(26.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(IchimokuCloud::new(0, 26, 52, 26).is_err());
+        assert!(IchimokuCloud::new(9, 0, 52, 26).is_err());
+        assert!(IchimokuCloud::new(9, 26, 0, 26).is_err());
+        assert!(IchimokuCloud::new(9, 26, 52, 0).is_err());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+        assert_eq!(ichimoku.warmup_remaining(), 6);
+        for x in 0..8 {
+            let output = ichimoku.apply((x as f64 + 1.0, x as f64, x as f64 + 0.5));
+            assert_eq!(ichimoku.is_ready(), output.senkou_span_b.is_some());
+        }
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut built = IchimokuCloud::builder()
+            .tenkan_period(2)
+            .kijun_period(3)
+            .senkou_b_period(4)
+            .displacement(2)
+            .build()
+            .unwrap();
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+        for input in [(3.0, 1.0, 2.0), (5.0, 2.0, 4.0), (6.0, 4.0, 5.0), (4.0, 2.0, 3.0)] {
+            assert_eq!(built.apply(input), ichimoku.apply(input));
+        }
+        assert_eq!(IchimokuCloud::builder().build().unwrap().period(), 52);
+        assert!(IchimokuCloud::builder().kijun_period(0).build().is_err());
+    }
+
+    #[test]
+    fn test_for_input() {
+        let mut ichimoku = IchimokuCloud::new(2, 3, 4, 2).unwrap();
+        let mut candles = IchimokuCloud::<Candle>::for_input(2, 3, 4, 2).unwrap();
+        for close in [9.0, 10.0, 11.0, 12.0, 13.0, 14.0] {
+            let (high, low) = (close + 1.0, close - 1.0);
+            let candle = Candle { open: close, high, low, close, volume: 0.0, timestamp: 0 };
+            assert_eq!(candles.evaluate(candle), ichimoku.evaluate((high, low, close)));
+            assert_eq!(candles.apply(candle), ichimoku.apply((high, low, close)));
+        }
+        assert_eq!(candles.current(), ichimoku.current());
+        assert_eq!(candles.current().senkou_span_b, Some(10.5));
+    }
+}
@@ -0,0 +1,332 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+    validation,
+};
+
+/// Output of the [`KalmanTrend`] signal.
+///
+/// Tuples of (level, slope) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KalmanTrendOutput {
+    /// The estimated level of the inputs.
+    pub level: f64,
+    /// The estimated change in the level per step.
+    pub slope: f64,
+}
+
+impl From<(f64, f64)> for KalmanTrendOutput {
+    fn from((level, slope): (f64, f64)) -> Self {
+        Self { level, slope }
+    }
+}
+
+impl From<KalmanTrendOutput> for (f64, f64) {
+    fn from(output: KalmanTrendOutput) -> Self {
+        (output.level, output.slope)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct KalmanState {
+    level: f64,
+    slope: f64,
+    /// The symmetric 2x2 estimate covariance, stored as the (level, covariance, slope) entries.
+    covariance: (f64, f64, f64),
+}
+
+/// # Kalman Trend
+/// Container for Kalman Filter trend estimation
+///
+/// A one-dimensional Kalman filter using a local linear trend model, where the level advances by the slope each
+/// step and both are disturbed by the process noise, while the input is the level disturbed by the measurement
+/// noise. Each step predicts the level and slope then corrects them towards the input:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>s</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>K</mi>
+///             <mn>l</mn>
+///         </msub>
+///         <msub>
+///             <mi>y</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>s</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>s</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>K</mi>
+///             <mn>s</mn>
+///         </msub>
+///         <msub>
+///             <mi>y</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>y</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>−</mo>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>s</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `n` is the current step, `l` is the level, `s` is the slope, `i` is the input, `y` is the innovation and
+/// `K` is the Kalman gain calculated from the estimate covariance, process noise and measurement noise. The first
+/// input seeds the level with a slope of 0 and an estimate covariance of the measurement noise, so the aggregation
+/// will begin producing values immediately.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{KalmanTrend, KalmanTrendOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Kalman Trend with a process and measurement noise of 1
+/// let mut kalman = KalmanTrend::new(1.0, 1.0).unwrap();
+///
+/// // apply some values and check their (level, slope) output
+/// assert_eq!(kalman.apply(1.0), KalmanTrendOutput { level: 1.0, slope: 0.0 });
+/// assert_eq!(kalman.apply(2.0), (1.75, 0.25).into());
+///
+/// // evaluate some values, these won't affect the internal state of the Kalman Trend
+/// let KalmanTrendOutput { level, slope } = kalman.evaluate(3.0);
+/// assert!((level - 2.8).abs() < 1e-9 && (slope - 0.65).abs() < 1e-9);
+///
+/// // fetch the current value of the Kalman Trend
+/// assert_eq!(kalman.current(), (1.75, 0.25).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KalmanTrend {
+    process_noise: f64,
+    measurement_noise: f64,
+    state: Option<KalmanState>,
+}
+
+impl KalmanTrend {
+    /// Create a new Kalman Trend instance
+    /// # Arguments
+    /// * `process_noise` - The variance of the level and slope disturbances each step, must be greater than 0
+    /// * `measurement_noise` - The variance of the input around the level, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::KalmanTrend;
+    ///
+    /// let kalman = KalmanTrend::new(0.01, 1.0);
+    /// assert!(kalman.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if either noise is not greater than 0
+    /// ```
+    /// use indicato_rs::signals::KalmanTrend;
+    ///
+    /// let kalman = KalmanTrend::new(0.01, 0.0);
+    ///
+    /// assert!(kalman.is_err());
+    /// ```
+    pub fn new(process_noise: f64, measurement_noise: f64) -> Result<Self, FinError> {
+        match process_noise > 0.0 && measurement_noise > 0.0 {
+            true => Ok(Self {
+                process_noise,
+                measurement_noise,
+                state: None,
+            }),
+            false => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Noise must be greater than 0",
+            )),
+        }
+    }
+
+    fn step(&self, input: f64) -> KalmanState {
+        let KalmanState {
+            level,
+            slope,
+            covariance: (p_level, p_cross, p_slope),
+        } = match self.state {
+            Some(state) => state,
+            None => {
+                return KalmanState {
+                    level: input,
+                    slope: 0.0,
+                    covariance: (self.measurement_noise, 0.0, self.measurement_noise),
+                }
+            }
+        };
+        let predicted_level = level + slope;
+        let p_level = p_level + 2.0 * p_cross + p_slope + self.process_noise;
+        let p_cross = p_cross + p_slope;
+        let p_slope = p_slope + self.process_noise;
+        let innovation_variance = p_level + self.measurement_noise;
+        let gain_level = p_level / innovation_variance;
+        let gain_slope = p_cross / innovation_variance;
+        let innovation = input - predicted_level;
+        KalmanState {
+            level: predicted_level + gain_level * innovation,
+            slope: slope + gain_slope * innovation,
+            covariance: (
+                (1.0 - gain_level) * p_level,
+                (1.0 - gain_level) * p_cross,
+                p_slope - gain_slope * p_cross,
+            ),
+        }
+    }
+}
+
+impl IoState for KalmanTrend {
+    type Input = f64;
+    /// Output is the level and slope
+    type Output = KalmanTrendOutput;
+}
+
+impl Validate for KalmanTrend {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for KalmanTrend {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let state = self.step(input);
+        match execution_context {
+            ExecutionContext::Apply => self.state = Some(state),
+            ExecutionContext::Evaluate => {}
+        }
+        KalmanTrendOutput {
+            level: state.level,
+            slope: state.slope,
+        }
+    }
+}
+
+impl Current for KalmanTrend {
+    fn current(&self) -> Self::Output {
+        self.state
+            .map_or(KalmanTrendOutput::default(), |state| KalmanTrendOutput {
+                level: state.level,
+                slope: state.slope,
+            })
+    }
+}
+
+impl WarmUp for KalmanTrend {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.state.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply() {
+        let mut kalman = KalmanTrend::new(1.0, 1.0).unwrap();
+        let expected = [
+            (1.0, 0.0),
+            (1.75, 0.25),
+            (2.8, 0.65),
+            (3.9009009009009006, 0.882882882882883),
+            (4.961538461538462, 0.9743589743589747),
+            (5.988587731811697, 1.001426533523538),
+        ];
+        for (input, (level, slope)) in [1.0, 2.0, 3.0, 4.0, 5.0, 6.0].into_iter().zip(expected) {
+            let output = kalman.apply(input);
+            assert_abs_diff_eq!(output.level, level, epsilon = 10e-7);
+            assert_abs_diff_eq!(output.slope, slope, epsilon = 10e-7);
+        }
+    }
+
+    #[test]
+    fn test_converges_to_trend() {
+        let mut kalman = KalmanTrend::new(0.01, 1.0).unwrap();
+        let mut output = KalmanTrendOutput::default();
+        for input in 1..60 {
+            output = kalman.apply(input as f64);
+        }
+        assert_abs_diff_eq!(output.level, 59.0, epsilon = 10e-6);
+        assert_abs_diff_eq!(output.slope, 1.0, epsilon = 10e-6);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut kalman = KalmanTrend::new(1.0, 1.0).unwrap();
+        assert_eq!(kalman.evaluate(5.0), (5.0, 0.0).into());
+        assert_eq!(kalman.apply(1.0), (1.0, 0.0).into());
+        assert_eq!(kalman.evaluate(2.0), (1.75, 0.25).into());
+        assert_eq!(kalman.evaluate(2.0), (1.75, 0.25).into());
+        assert_eq!(kalman.apply(2.0), (1.75, 0.25).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut kalman = KalmanTrend::new(1.0, 1.0).unwrap();
+        assert_eq!(kalman.current(), (0.0, 0.0).into());
+        kalman.apply(1.0);
+        kalman.apply(2.0);
+        kalman.evaluate(3.0);
+        assert_eq!(kalman.current(), (1.75, 0.25).into());
+    }
+
+    #[test]
+    fn test_invalid_noise() {
+        assert!(KalmanTrend::new(0.0, 1.0).is_err());
+        assert!(KalmanTrend::new(1.0, 0.0).is_err());
+        assert!(KalmanTrend::new(-1.0, 1.0).is_err());
+    }
+}
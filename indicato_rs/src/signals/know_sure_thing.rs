@@ -0,0 +1,494 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        chained_warmup, Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState,
+        Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::{RateOfChange, SimpleMovingAverage};
+
+/// Output of the [`KnowSureThing`] signal, each line is `None` until enough values have been applied to produce it.
+///
+/// Tuples of (kst, signal) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KnowSureThingOutput {
+    /// The Know Sure Thing, the weighted sum of the smoothed rates of change.
+    pub kst: Option<f64>,
+    /// The signal line, the Simple Moving Average of the Know Sure Thing.
+    pub signal: Option<f64>,
+}
+
+impl From<(Option<f64>, Option<f64>)> for KnowSureThingOutput {
+    fn from((kst, signal): (Option<f64>, Option<f64>)) -> Self {
+        Self { kst, signal }
+    }
+}
+
+impl From<KnowSureThingOutput> for (Option<f64>, Option<f64>) {
+    fn from(output: KnowSureThingOutput) -> Self {
+        (output.kst, output.signal)
+    }
+}
+
+/// The Know Sure Thing and its signal line are passed on once both are available, such as to a
+/// [`Crossover`](super::Crossover).
+impl ChainValue<(f64, f64)> for KnowSureThingOutput {
+    fn into_input(self) -> Option<(f64, f64)> {
+        self.kst.zip(self.signal)
+    }
+}
+
+/// A single smoothed and weighted rate of change component of the Know Sure Thing.
+#[derive(Debug, Clone, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct KnowSureThingComponent {
+    roc: RateOfChange,
+    sma: SimpleMovingAverage,
+    smoothing_period: usize,
+    /// The number of rate of change values that have been applied to the smoothing aggregation.
+    count: usize,
+    weight: f64,
+}
+
+impl KnowSureThingComponent {
+    fn new(roc_period: usize, smoothing_period: usize, weight: f64) -> Result<Self, FinError> {
+        Ok(Self {
+            roc: RateOfChange::new(roc_period)?,
+            sma: SimpleMovingAverage::new(smoothing_period)?,
+            smoothing_period,
+            count: 0,
+            weight,
+        })
+    }
+
+    fn execute(&mut self, input: f64, execution_context: &ExecutionContext) -> Option<f64> {
+        let roc = self.roc.execute(input, execution_context)?;
+        let smoothed = self.sma.execute(roc, execution_context);
+        let count = self.count + 1;
+        match execution_context {
+            ExecutionContext::Apply => self.count = count,
+            ExecutionContext::Evaluate => {}
+        }
+        (count >= self.smoothing_period).then_some(smoothed * self.weight)
+    }
+
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(
+            self.roc.warmup_remaining(),
+            self.smoothing_period.saturating_sub(self.count),
+        )
+    }
+}
+
+/// # Know Sure Thing
+/// Container for Know Sure Thing (KST) aggregation
+///
+/// The Know Sure Thing is a momentum oscillator made up of four rate of change components, each of which is smoothed
+/// by a Simple Moving Average and weighted by 1, 2, 3 and 4 respectively:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <munderover>
+///             <mo>∑</mo>
+///             <mi>k=1</mi>
+///             <mn>4</mn>
+///         </munderover>
+///         <mi>k</mi>
+///         <mo>⋅</mo>
+///         <msub>
+///             <mi>SMA</mi>
+///             <mi>k</mi>
+///         </msub>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>ROC</mi>
+///             <mi>k</mi>
+///         </msub>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `SMA` is the Simple Moving Average, `ROC` is the Rate of Change and `i` is the input.
+///
+/// The signal line is a Simple Moving Average of the Know Sure Thing. As each component warms up at a different rate the
+/// Know Sure Thing will produce `None` until every component's rate of change and smoothing periods have been filled, and
+/// the signal line will produce `None` until its period has been filled with Know Sure Thing values.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{KnowSureThing, KnowSureThingOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Know Sure Thing with rate of change periods of 1, 2, 3 and 4, no smoothing and a signal period of 2
+/// let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], 2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(kst.apply(10.0), (None, None).into());
+/// assert_eq!(kst.apply(10.0), (None, None).into());
+/// assert_eq!(kst.apply(10.0), (None, None).into());
+/// assert_eq!(kst.apply(10.0), (None, None).into());
+/// assert_eq!(kst.apply(20.0), (Some(1000.0), None).into());
+/// assert_eq!(kst.apply(20.0), KnowSureThingOutput { kst: Some(900.0), signal: Some(950.0) });
+///
+/// // evaluate some values, these won't affect the internal state of the KST
+/// assert_eq!(kst.evaluate(10.0), (Some(-150.0), Some(375.0)).into());
+///
+/// // fetch the current value of the KST
+/// assert_eq!(kst.current(), (Some(900.0), Some(950.0)).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KnowSureThing {
+    components: [KnowSureThingComponent; 4],
+    signal: SimpleMovingAverage,
+    signal_period: usize,
+    /// The number of Know Sure Thing values that have been applied to the signal line.
+    signal_count: usize,
+    current: KnowSureThingOutput,
+}
+
+impl KnowSureThing {
+    /// Create a new Know Sure Thing instance
+    /// # Arguments
+    /// * `roc_periods` - The periods of the four Rate of Change components, typically `[10, 15, 20, 30]`
+    /// * `smoothing_periods` - The periods of the Simple Moving Averages smoothing each component, typically `[10, 10, 10, 15]`
+    /// * `signal_period` - The period of the Simple Moving Average signal line, typically 9
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::KnowSureThing;
+    ///
+    /// let kst = KnowSureThing::new([10, 15, 20, 30], [10, 10, 10, 15], 9);
+    /// assert!(kst.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    /// ```
+    /// use indicato_rs::signals::KnowSureThing;
+    ///
+    /// let kst = KnowSureThing::new([10, 15, 20, 0], [10, 10, 10, 15], 9);
+    ///
+    /// assert!(kst.is_err());
+    /// ```
+    pub fn new(
+        roc_periods: [usize; 4],
+        smoothing_periods: [usize; 4],
+        signal_period: usize,
+    ) -> Result<Self, FinError> {
+        if roc_periods.contains(&0) || smoothing_periods.contains(&0) || signal_period == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Periods must be greater than 0",
+            ));
+        }
+        Ok(Self {
+            components: [
+                KnowSureThingComponent::new(roc_periods[0], smoothing_periods[0], 1.0)?,
+                KnowSureThingComponent::new(roc_periods[1], smoothing_periods[1], 2.0)?,
+                KnowSureThingComponent::new(roc_periods[2], smoothing_periods[2], 3.0)?,
+                KnowSureThingComponent::new(roc_periods[3], smoothing_periods[3], 4.0)?,
+            ],
+            signal: SimpleMovingAverage::new(signal_period)?,
+            signal_period,
+            signal_count: 0,
+            current: KnowSureThingOutput::default(),
+        })
+    }
+
+    /// Create a new Know Sure Thing instance with the conventional rate of change periods of 10, 15, 20 and 30, smoothing
+    /// periods of 10, 10, 10 and 15 and a signal period of 9
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::KnowSureThing;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let kst = KnowSureThing::standard();
+    /// assert_eq!(kst.period(), 30);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new([10, 15, 20, 30], [10, 10, 10, 15], 9).unwrap()
+    }
+
+    /// Create a new [`KnowSureThingBuilder`] starting from the conventional rate of change periods of 10, 15, 20 and 30,
+    /// smoothing periods of 10, 10, 10 and 15 and a signal period of 9
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::KnowSureThing;
+    ///
+    /// let kst = KnowSureThing::builder().signal_period(12).build();
+    /// assert!(kst.is_ok());
+    /// ```
+    pub fn builder() -> KnowSureThingBuilder {
+        KnowSureThingBuilder::default()
+    }
+}
+
+impl Default for KnowSureThing {
+    /// Create a new Know Sure Thing with the conventional rate of change periods of 10, 15, 20 and 30, smoothing
+    /// periods of 10, 10, 10 and 15 and a signal period of 9
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+/// Builder for a [`KnowSureThing`], created with [`KnowSureThing::builder`].
+///
+/// Any period that isn't set keeps its conventional value, the periods are validated when the Know Sure Thing is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnowSureThingBuilder {
+    roc_periods: [usize; 4],
+    smoothing_periods: [usize; 4],
+    signal_period: usize,
+}
+
+impl KnowSureThingBuilder {
+    /// Set the periods of the four Rate of Change components
+    pub fn roc_periods(mut self, roc_periods: [usize; 4]) -> Self {
+        self.roc_periods = roc_periods;
+        self
+    }
+
+    /// Set the periods of the Simple Moving Averages smoothing each component
+    pub fn smoothing_periods(mut self, smoothing_periods: [usize; 4]) -> Self {
+        self.smoothing_periods = smoothing_periods;
+        self
+    }
+
+    /// Set the period of the Simple Moving Average signal line
+    pub fn signal_period(mut self, signal_period: usize) -> Self {
+        self.signal_period = signal_period;
+        self
+    }
+
+    /// Build the Know Sure Thing
+    ///
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    /// ```
+    /// use indicato_rs::signals::KnowSureThing;
+    ///
+    /// let kst = KnowSureThing::builder().smoothing_periods([10, 0, 10, 15]).build();
+    ///
+    /// assert!(kst.is_err());
+    /// ```
+    pub fn build(self) -> Result<KnowSureThing, FinError> {
+        KnowSureThing::new(self.roc_periods, self.smoothing_periods, self.signal_period)
+    }
+}
+
+impl Default for KnowSureThingBuilder {
+    /// Create a new Know Sure Thing builder with the conventional rate of change periods of 10, 15, 20 and 30,
+    /// smoothing periods of 10, 10, 10 and 15 and a signal period of 9
+    fn default() -> Self {
+        Self {
+            roc_periods: [10, 15, 20, 30],
+            smoothing_periods: [10, 10, 10, 15],
+            signal_period: 9,
+        }
+    }
+}
+
+impl IoState for KnowSureThing {
+    type Input = f64;
+    /// Output is the Know Sure Thing and its signal line
+    type Output = KnowSureThingOutput;
+}
+
+impl Validate for KnowSureThing {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for KnowSureThing {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let components = self
+            .components
+            .each_mut()
+            .map(|component| component.execute(input, execution_context));
+        let kst = components.into_iter().sum::<Option<f64>>();
+        let signal = kst.and_then(|kst| {
+            let signal = self.signal.execute(kst, execution_context);
+            let signal_count = self.signal_count + 1;
+            match execution_context {
+                ExecutionContext::Apply => self.signal_count = signal_count,
+                ExecutionContext::Evaluate => {}
+            }
+            (signal_count >= self.signal_period).then_some(signal)
+        });
+        let output = KnowSureThingOutput { kst, signal };
+        match execution_context {
+            ExecutionContext::Apply => self.current = output,
+            ExecutionContext::Evaluate => {}
+        }
+        output
+    }
+}
+
+impl Current for KnowSureThing {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for KnowSureThing {
+    fn warmup_remaining(&self) -> usize {
+        let components = self
+            .components
+            .iter()
+            .map(|component| component.warmup_remaining())
+            .max()
+            .unwrap_or_default();
+        chained_warmup(
+            components,
+            self.signal_period.saturating_sub(self.signal_count),
+        )
+    }
+}
+
+impl Periodic for KnowSureThing {
+    fn period(&self) -> usize {
+        self.components
+            .iter()
+            .map(|component| component.roc.period())
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        let components = self
+            .components
+            .iter()
+            .map(|component| component.roc.effective_lookback() + component.smoothing_period - 1)
+            .max()
+            .unwrap_or_default();
+        components + self.signal_period - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [2, 2, 2, 2], 2).unwrap();
+        for _ in 0..5 {
+            assert_eq!(kst.apply(10.0), (None, None).into());
+        }
+        assert_eq!(kst.apply(20.0), (Some(500.0), None).into());
+        let KnowSureThingOutput { kst: value, signal } = kst.apply(10.0);
+        assert_abs_diff_eq!(value.unwrap(), 475.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(signal.unwrap(), 487.5, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], 2).unwrap();
+        for _ in 0..4 {
+            assert_eq!(kst.evaluate(10.0), (None, None).into());
+            assert_eq!(kst.apply(10.0), (None, None).into());
+        }
+        assert_eq!(kst.evaluate(20.0), (Some(1000.0), None).into());
+        assert_eq!(kst.apply(20.0), (Some(1000.0), None).into());
+        assert_eq!(kst.evaluate(20.0), (Some(900.0), Some(950.0)).into());
+        assert_eq!(kst.evaluate(10.0), (Some(-50.0), Some(475.0)).into());
+        assert_eq!(kst.apply(20.0), (Some(900.0), Some(950.0)).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], 2).unwrap();
+        assert_eq!(kst.current(), (None, None).into());
+        for _ in 0..4 {
+            kst.apply(10.0);
+        }
+        assert_eq!(kst.current(), (None, None).into());
+        kst.apply(20.0);
+        assert_eq!(kst.current(), (Some(1000.0), None).into());
+        kst.apply(20.0);
+        kst.evaluate(10.0);
+        assert_eq!(kst.current(), (Some(900.0), Some(950.0)).into());
+    }
+
+    #[test]
+    fn test_default() {
+        let mut kst = KnowSureThing::default();
+        for _ in 0..44 {
+            assert_eq!(kst.apply(10.0), (None, None).into());
+        }
+        assert_eq!(kst.apply(10.0), (Some(0.0), None).into());
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(KnowSureThing::new([0, 15, 20, 30], [10, 10, 10, 15], 9).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 30], [10, 0, 10, 15], 9).is_err());
+        assert!(KnowSureThing::new([10, 15, 20, 30], [10, 10, 10, 15], 0).is_err());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [2, 2, 2, 2], 2).unwrap();
+        assert_eq!(kst.warmup_remaining(), 7);
+        for _ in 0..6 {
+            kst.apply(10.0);
+            assert!(!kst.is_ready());
+        }
+        assert_eq!(kst.warmup_remaining(), 1);
+        let signal = kst.apply(10.0).signal;
+        assert!(signal.is_some());
+        assert!(kst.is_ready());
+    }
+
+    #[test]
+    fn test_periodic() {
+        let kst = KnowSureThing::default();
+        assert_eq!(kst.period(), 30);
+        assert_eq!(kst.effective_lookback(), 53);
+        assert_eq!(kst.effective_lookback(), kst.warmup_remaining());
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut built = KnowSureThing::builder()
+            .roc_periods([1, 2, 3, 4])
+            .smoothing_periods([1, 1, 1, 1])
+            .signal_period(2)
+            .build()
+            .unwrap();
+        let mut kst = KnowSureThing::new([1, 2, 3, 4], [1, 1, 1, 1], 2).unwrap();
+        for input in [10.0, 10.0, 10.0, 10.0, 20.0, 20.0, 15.0] {
+            assert_eq!(built.apply(input), kst.apply(input));
+        }
+        assert_eq!(KnowSureThing::builder().build().unwrap().period(), 30);
+        assert!(KnowSureThing::builder().signal_period(0).build().is_err());
+    }
+}
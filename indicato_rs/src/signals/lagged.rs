@@ -0,0 +1,263 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        chained_warmup, warmed_up, Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState,
+        Periodic, Validate, WarmUp,
+    },
+};
+
+/// # Lagged
+/// Container for delaying the outputs of a signal by a number of steps
+///
+/// Each input is applied to the signal and the output from `lag` inputs earlier is returned, `None` until there have
+/// been more outputs than the lag since the signal warmed up. This displaces an indicator, such as the spans of an
+/// Ichimoku cloud or the detrended price oscillator, or lines a signal up against its own earlier values. The outputs
+/// of a signal that is still warming up are dropped, so the lagged signal is ready after the lag once the signal is.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Lagged, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a Simple Moving Average with a period of 2 delayed by 2 steps
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut lagged = Lagged::new(sma, 2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(lagged.apply(2.0), None);
+/// assert_eq!(lagged.apply(4.0), None);
+/// assert_eq!(lagged.apply(6.0), None);
+/// assert_eq!(lagged.apply(8.0), Some(3.0));
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(lagged.evaluate(10.0), Some(5.0));
+///
+/// // fetch the current value of the signal
+/// assert_eq!(lagged.current(), Some(3.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: serde::Serialize, S::Output: serde::Serialize",
+        deserialize = "S: serde::Deserialize<'de>, S::Output: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Lagged<S>
+where
+    S: Executable + WarmUp,
+    S::Output: Clone,
+{
+    signal: S,
+    /// The number of steps the outputs are delayed by.
+    lag: usize,
+    /// The last `lag` outputs of the signal since it warmed up, oldest first.
+    outputs: VecDeque<S::Output>,
+    current: Option<S::Output>,
+}
+
+impl<S> IoState for Lagged<S>
+where
+    S: Executable + WarmUp,
+    S::Output: Clone,
+{
+    type Input = S::Input;
+    /// Output is the output of the signal `lag` inputs earlier, if there has been one
+    type Output = Option<S::Output>;
+}
+
+impl<S> Lagged<S>
+where
+    S: Executable + WarmUp,
+    S::Output: Clone,
+{
+    /// Create a new Lagged instance
+    /// # Arguments
+    /// * `signal` - The signal to delay
+    /// * `lag` - The number of steps to delay the outputs by, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{Lagged, RelativeStrengthIndex};
+    ///
+    /// let lagged = Lagged::new(RelativeStrengthIndex::standard(), 26);
+    /// assert!(lagged.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the lag is 0
+    /// ```
+    /// use indicato_rs::signals::{Lagged, RelativeStrengthIndex};
+    ///
+    /// let lagged = Lagged::new(RelativeStrengthIndex::standard(), 0);
+    ///
+    /// assert!(lagged.is_err());
+    /// ```
+    pub fn new(signal: S, lag: usize) -> Result<Self, FinError> {
+        match lag {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Lag must be greater than 0",
+            )),
+            _ => Ok(Self {
+                signal,
+                lag,
+                outputs: VecDeque::with_capacity(lag + 1),
+                current: None,
+            }),
+        }
+    }
+
+    /// The signal being delayed
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// The number of steps the outputs are delayed by
+    pub fn lag(&self) -> usize {
+        self.lag
+    }
+}
+
+impl<S> Validate for Lagged<S>
+where
+    S: Executable + WarmUp + Validate,
+    S::Output: Clone,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        self.signal.validate(input)
+    }
+}
+
+impl<S> Executable for Lagged<S>
+where
+    S: Executable + WarmUp,
+    S::Output: Clone,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let output = self.signal.execute(input, execution_context);
+        match execution_context {
+            ExecutionContext::Apply => {
+                if warmed_up(&self.signal, execution_context) {
+                    self.outputs.push_back(output);
+                }
+                if self.outputs.len() > self.lag {
+                    self.current = self.outputs.pop_front();
+                }
+                self.current.clone()
+            }
+            ExecutionContext::Evaluate => match self.outputs.len() == self.lag {
+                true => self.outputs.front().cloned(),
+                false => None,
+            },
+        }
+    }
+}
+
+impl<S> Current for Lagged<S>
+where
+    S: Executable + WarmUp,
+    S::Output: Clone,
+{
+    fn current(&self) -> Self::Output {
+        self.current.clone()
+    }
+}
+
+impl<S> WarmUp for Lagged<S>
+where
+    S: Executable + WarmUp,
+    S::Output: Clone,
+{
+    fn warmup_remaining(&self) -> usize {
+        match self.current {
+            Some(_) => 0,
+            None => chained_warmup(self.signal.warmup_remaining(), self.lag + 1 - self.outputs.len()),
+        }
+    }
+}
+
+impl<S> Periodic for Lagged<S>
+where
+    S: Executable + WarmUp + Periodic,
+    S::Output: Clone,
+{
+    fn period(&self) -> usize {
+        self.signal.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.signal.effective_lookback() + self.lag
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{CumulativeSum, Momentum, SimpleMovingAverage};
+
+    #[test]
+    fn test_apply() {
+        let mut lagged = Lagged::new(CumulativeSum::new(), 1).unwrap();
+        assert_eq!(lagged.apply(1.0), None);
+        assert_eq!(lagged.apply(2.0), Some(1.0));
+        assert_eq!(lagged.apply(3.0), Some(3.0));
+        assert_eq!(lagged.current(), Some(3.0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut lagged = Lagged::new(CumulativeSum::new(), 2).unwrap();
+        assert_eq!(lagged.evaluate(1.0), None);
+        lagged.apply(1.0);
+        assert_eq!(lagged.evaluate(1.0), None);
+        lagged.apply(2.0);
+        assert_eq!(lagged.evaluate(5.0), Some(1.0));
+        assert_eq!(lagged.evaluate(5.0), Some(1.0));
+        assert_eq!(lagged.current(), None);
+        assert_eq!(lagged.apply(5.0), Some(1.0));
+        assert_eq!(lagged.apply(5.0), Some(3.0));
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut lagged = Lagged::new(SimpleMovingAverage::new(2).unwrap(), 2).unwrap();
+        assert_eq!(lagged.warmup_remaining(), 4);
+        let mut remaining = Vec::new();
+        for _ in 0..5 {
+            lagged.apply(1.0);
+            remaining.push(lagged.warmup_remaining());
+        }
+        assert_eq!(remaining, vec![3, 2, 1, 0, 0]);
+        assert_eq!(lagged.effective_lookback(), 4);
+    }
+
+    #[test]
+    fn test_warming_up_outputs() {
+        // the outputs of a signal that is warming up are dropped
+        let mut lagged = Lagged::new(Momentum::new(1).unwrap(), 1).unwrap();
+        assert_eq!(lagged.apply(1.0), None);
+        assert_eq!(lagged.apply(3.0), None);
+        assert_eq!(lagged.apply(4.0), Some(Some(2.0)));
+    }
+
+    #[test]
+    fn test_ready_at_lookback() {
+        let mut lagged = Lagged::new(SimpleMovingAverage::new(3).unwrap(), 2).unwrap();
+        let lookback = lagged.effective_lookback();
+        for step in 1..=8 {
+            assert_eq!(lagged.warmup_remaining(), lookback.saturating_sub(step - 1));
+            let output = lagged.apply(step as f64);
+            assert_eq!(output.is_some(), step >= lookback);
+        }
+        assert_eq!(lagged.current(), Some(5.0));
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequeMathExtF64,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// Output of the [`LinearRegression`] signal.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRegressionOutput {
+    /// The change in value per step of the fitted line.
+    pub slope: f64,
+    /// The value of the fitted line at the oldest input in the period.
+    pub intercept: f64,
+    /// The value of the fitted line at the current step, also known as the Least Squares Moving Average.
+    pub value: f64,
+    /// The coefficient of determination of the fitted line.
+    pub r_squared: f64,
+    /// The standard error of the estimate, the typical distance of the inputs from the fitted line.
+    pub standard_error: f64,
+}
+
+fn linear_regression(values: &VecDeque<f64>, period: usize) -> Option<LinearRegressionOutput> {
+    if values.len() < period {
+        return None;
+    }
+    let fit = values.linear_regression();
+    Some(LinearRegressionOutput {
+        slope: fit.slope,
+        intercept: fit.intercept,
+        value: fit.intercept + fit.slope * (period - 1) as f64,
+        r_squared: fit.r_squared,
+        standard_error: fit.standard_error,
+    })
+}
+
+/// # Linear Regression
+/// Container for rolling Linear Regression aggregation
+///
+/// A least squares line is fitted to the inputs over the period against their step, where the oldest input
+/// in the period is at step 0 and the current input is at step `p - 1`:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <mi>m</mi>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mi>x=0</mi>
+///                     <mi>p-1</mi>
+///                 </munderover>
+///                 <mo>(</mo><mi>x</mi><mo>−</mo><mover><mi>x</mi><mo>¯</mo></mover><mo>)</mo>
+///                 <mo>(</mo><msub><mi>i</mi><mi>x</mi></msub><mo>−</mo><mover><mi>i</mi><mo>¯</mo></mover><mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mi>x=0</mi>
+///                     <mi>p-1</mi>
+///                 </munderover>
+///                 <msup>
+///                     <mrow><mo>(</mo><mi>x</mi><mo>−</mo><mover><mi>x</mi><mo>¯</mo></mover><mo>)</mo></mrow>
+///                     <mn>2</mn>
+///                 </msup>
+///             </mrow>
+///         </mfrac>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <mi>b</mi>
+///         <mo>=</mo>
+///         <mover><mi>i</mi><mo>¯</mo></mover>
+///         <mo>−</mo>
+///         <mi>m</mi>
+///         <mover><mi>x</mi><mo>¯</mo></mover>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>b</mi>
+///         <mo>+</mo>
+///         <mi>m</mi>
+///         <mo>(</mo><mi>p</mi><mo>−</mo><mn>1</mn><mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `m` is the slope, `b` is the intercept, `o` is the value of the line at the current step, `n` is the current step,
+/// `p` is the period, `x` is the step within the period and `i` is the input.
+///
+/// The first `p - 1` entries will produce `None` as the period is being filled.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::LinearRegression;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Linear Regression with a period of 3
+/// let mut lr = LinearRegression::new(3).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(lr.apply(1.0), None);
+/// assert_eq!(lr.apply(3.0), None);
+/// let output = lr.apply(5.0).unwrap();
+/// assert_eq!(output.slope, 2.0);
+/// assert_eq!(output.intercept, 1.0);
+/// assert_eq!(output.value, 5.0);
+/// assert_eq!(output.r_squared, 1.0);
+/// assert_eq!(output.standard_error, 0.0);
+///
+/// // evaluate some values, these won't affect the internal state of the Linear Regression
+/// assert_eq!(lr.evaluate(3.0).unwrap().slope, 0.0);
+///
+/// // fetch the current value of the Linear Regression
+/// assert_eq!(lr.current(), Some(output));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinearRegression {
+    period: usize,
+    values: VecDeque<f64>,
+}
+
+impl IoState for LinearRegression {
+    type Input = f64;
+    type Output = Option<LinearRegressionOutput>;
+}
+
+impl LinearRegression {
+    /// Create a new Linear Regression instance
+    /// # Arguments
+    /// * `period` - The period of the Linear Regression aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::LinearRegression;
+    ///
+    /// let lr = LinearRegression::new(14);
+    /// assert!(lr.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::LinearRegression;
+    ///
+    /// let lr = LinearRegression::new(0);
+    ///
+    /// assert!(lr.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+            }),
+        }
+    }
+}
+
+impl Validate for LinearRegression {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for LinearRegression {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                linear_regression(&self.values, self.period)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(input);
+                if values.len() > self.period {
+                    values.pop_front();
+                }
+                linear_regression(&values, self.period)
+            }
+        }
+    }
+}
+
+impl Current for LinearRegression {
+    fn current(&self) -> Self::Output {
+        linear_regression(&self.values, self.period)
+    }
+}
+
+impl WarmUp for LinearRegression {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for LinearRegression {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut lr = LinearRegression::new(3).unwrap();
+        assert_eq!(lr.apply(1.0), None);
+        assert_eq!(lr.apply(3.0), None);
+        assert_eq!(lr.apply(5.0).unwrap().value, 5.0);
+        let output = lr.apply(3.0).unwrap();
+        assert_eq!(output.slope, 0.0);
+        assert_abs_diff_eq!(output.intercept, 11.0 / 3.0, epsilon = 10e-7);
+        assert_abs_diff_eq!(output.value, 11.0 / 3.0, epsilon = 10e-7);
+        assert_eq!(output.r_squared, 0.0);
+        assert_abs_diff_eq!(output.standard_error, (24.0_f64 / 9.0).sqrt(), epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut lr = LinearRegression::new(3).unwrap();
+        assert_eq!(lr.apply(1.0), None);
+        assert_eq!(lr.evaluate(3.0), None);
+        assert_eq!(lr.apply(3.0), None);
+        assert_eq!(lr.evaluate(5.0).unwrap().value, 5.0);
+        assert_eq!(lr.evaluate(0.0).unwrap().slope, -0.5);
+        assert_eq!(lr.apply(5.0).unwrap().slope, 2.0);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut lr = LinearRegression::new(3).unwrap();
+        assert_eq!(lr.current(), None);
+        assert_eq!(lr.apply(1.0), None);
+        assert_eq!(lr.apply(3.0), None);
+        assert_eq!(lr.current(), None);
+        let output = lr.apply(5.0);
+        assert_eq!(lr.current(), output);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let lr = LinearRegression::new(0);
+        assert!(lr.is_err());
+    }
+}
@@ -1,9 +1,12 @@
 use std::collections::VecDeque;
 
-use indicato_rs_proc::{Apply, Evaluate};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+use num_traits::Float;
+#[cfg(feature = "simd")]
+use crate::simd;
 
 use crate::{
-    deque_math::DequeMathExtF64, fin_error::{FinError, FinErrorType}, traits::{Apply, Current, Evaluate, Executable, ExecutionContext, IoState}
+    deque_math::DequeMathExt, fin_error::{FinError, FinErrorType}, traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp}, validation
 };
 
 /// # Minimum Period
@@ -59,6 +62,10 @@ use crate::{
 /// </math>
 /// <br>
 /// Where `o` is the output, `n` is the current step, `p` is the period, `H` is the Heaviside function, and `i` is the input.
+///
+/// The aggregation is generic over the float type of its inputs and defaults to `f64`.
+/// `new` creates an `f64` aggregation, other types are created with [`for_type`](MinimumPeriod::for_type).
+///
 /// # Example Usage
 /// ```
 /// use indicato_rs::signals::MinimumPeriod;
@@ -82,14 +89,15 @@ use crate::{
 /// // Fetch the current value of the MinimumPeriod
 /// assert_eq!(min.current(), 1.0);
 /// ```
-#[derive(Apply, Evaluate)]
-pub struct MinimumPeriod {
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MinimumPeriod<T: Float + 'static = f64> {
     period: usize,
-    values: VecDeque<f64>,
+    values: VecDeque<T>,
 }
 
-impl MinimumPeriod {
-    /// Create a new MinimumPeriod signal with a given period
+impl<T: Float + 'static> MinimumPeriod<T> {
+    /// Create a new MinimumPeriod signal with a given period taking inputs of type `T`, such as `f32`
     /// # Errors
     /// Will return an error if the period is 0
     /// # Arguments
@@ -99,15 +107,15 @@ impl MinimumPeriod {
     /// use indicato_rs::signals::MinimumPeriod;
     /// use indicato_rs::traits::{Apply, Evaluate, Current};
     /// 
-    /// let mut min = MinimumPeriod::new(3);
+    /// let mut min = MinimumPeriod::<f32>::for_type(3);
     /// assert!(min.is_ok());
     /// ```
     /// ```
     /// use indicato_rs::signals::MinimumPeriod;
-    /// let min = MinimumPeriod::new(0);
+    /// let min = MinimumPeriod::<f32>::for_type(0);
     /// assert!(min.is_err());
     /// ```
-    pub fn new(period: usize) -> Result<Self, FinError> {
+    pub fn for_type(period: usize) -> Result<Self, FinError> {
         match period {
             0 => Err(FinError::new(
                 FinErrorType::InvalidInput,
@@ -121,12 +129,42 @@ impl MinimumPeriod {
     }
 }
 
-impl IoState for MinimumPeriod {
-    type Input = f64;
-    type Output = f64;
+impl MinimumPeriod {
+    /// Create a new MinimumPeriod signal with a given period
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// # Arguments
+    /// * `period` - The period of the MinimumPeriod signal, must be greater than 0
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::MinimumPeriod;
+    /// use indicato_rs::traits::{Apply, Evaluate, Current};
+    /// 
+    /// let mut min = MinimumPeriod::new(3);
+    /// assert!(min.is_ok());
+    /// ```
+    /// ```
+    /// use indicato_rs::signals::MinimumPeriod;
+    /// let min = MinimumPeriod::new(0);
+    /// assert!(min.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
+impl<T: Float + 'static> IoState for MinimumPeriod<T> {
+    type Input = T;
+    type Output = T;
 }
 
-impl Executable for MinimumPeriod {
+impl<T: Float + 'static> Validate for MinimumPeriod<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<T: Float + 'static> Executable for MinimumPeriod<T> {
     fn execute(
         &mut self,
         input: Self::Input,
@@ -143,22 +181,50 @@ impl Executable for MinimumPeriod {
             ExecutionContext::Evaluate => self
                 .values
                 .iter()
-                .skip(1)
-                .fold(f64::MAX, |acc, &x| acc.min(x))
+                .skip((self.values.len() + 1).saturating_sub(self.period))
+                .fold(T::max_value(), |acc, &x| acc.min(x))
                 .min(input),
         }
     }
+
+    /// With the `simd` feature the minimum of an `f64` window is found four windows at a time, without rescanning
+    /// each window.
+    fn execute_slice(&mut self, inputs: &[T]) -> Vec<T> {
+        #[cfg(feature = "simd")]
+        if let Some(outputs) =
+            simd::apply_f64_windows(&mut self.values, self.period, inputs, simd::window_minima, DequeMathExt::min)
+        {
+            return outputs;
+        }
+        inputs
+            .iter()
+            .map(|&input| self.execute(input, &ExecutionContext::Apply))
+            .collect()
+    }
 }
 
-impl Current for MinimumPeriod {
+impl<T: Float + 'static> Current for MinimumPeriod<T> {
     fn current(&self) -> Self::Output {
         self.values.min()
     }
 }
 
+impl<T: Float + 'static> WarmUp for MinimumPeriod<T> {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl<T: Float + 'static> Periodic for MinimumPeriod<T> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::traits::ApplyBatch;
 
     #[test]
     fn test_minimum_period_apply() {
@@ -196,9 +262,28 @@ mod tests {
         assert_eq!(min.current(), 1.0);
     }
 
+    #[test]
+    fn test_minimum_period_evaluate_partial_window() {
+        let mut min = MinimumPeriod::new(3).unwrap();
+        assert_eq!(min.apply(1.0), 1.0);
+        assert_eq!(min.evaluate(5.0), 1.0);
+        assert_eq!(min.apply(4.0), 1.0);
+        assert_eq!(min.evaluate(5.0), 1.0);
+    }
+
     #[test]
     fn test_invalid_period() {
         let min = MinimumPeriod::new(0);
         assert!(min.is_err());
     }
+
+    #[test]
+    fn test_apply_slice() {
+        let inputs = (0..37).map(|i| f64::from(i * 7 % 13) - 6.5).collect::<Vec<f64>>();
+        let mut min = MinimumPeriod::new(4).unwrap();
+        let mut expected = min.clone();
+        let outputs = inputs.iter().map(|&input| expected.apply(input)).collect::<Vec<f64>>();
+        assert_eq!(min.apply_slice(&inputs), outputs);
+        assert_eq!(min.evaluate(2.0), expected.evaluate(2.0));
+    }
 }
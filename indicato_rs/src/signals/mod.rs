@@ -11,7 +11,10 @@ mod wilders_smoothing;
 pub use wilders_smoothing::WildersSmoothing;
 
 mod moving_average_convergence_divergence;
-pub use moving_average_convergence_divergence::MovingAverageConvergenceDivergence;
+pub use moving_average_convergence_divergence::{
+    GenericMovingAverageConvergenceDivergence, MovingAverageConvergenceDivergence,
+    MovingAverageConvergenceDivergenceBuilder,
+};
 
 mod maximum_period;
 pub use maximum_period::MaximumPeriod;
@@ -23,4 +26,202 @@ mod stochastic_momentum_oscillator;
 pub use stochastic_momentum_oscillator::StochasticMomentumOscillator;
 
 mod bollinger_bands;
-pub use bollinger_bands::BollingerBands;
\ No newline at end of file
+pub use bollinger_bands::{
+    BollingerBands, BollingerBandsBuilder, BollingerBandsExtended, BollingerBandsExtendedOutput, BollingerOutput,
+};
+
+mod ichimoku_cloud;
+pub use ichimoku_cloud::{IchimokuCloud, IchimokuCloudBuilder, IchimokuCloudOutput};
+
+mod momentum;
+pub use momentum::Momentum;
+
+mod weighted_moving_average;
+pub use weighted_moving_average::WeightedMovingAverage;
+
+mod chaikin_money_flow;
+pub use chaikin_money_flow::ChaikinMoneyFlow;
+
+mod money_flow_index;
+pub use money_flow_index::MoneyFlowIndex;
+
+mod elder_ray;
+pub use elder_ray::{ElderRay, ElderRayOutput};
+
+mod force_index;
+pub use force_index::ForceIndex;
+
+mod rate_of_change;
+pub use rate_of_change::RateOfChange;
+
+mod know_sure_thing;
+pub use know_sure_thing::{KnowSureThing, KnowSureThingBuilder, KnowSureThingOutput};
+
+mod linear_regression;
+pub use linear_regression::{LinearRegression, LinearRegressionOutput};
+
+mod moving_average_envelope;
+pub use moving_average_envelope::{MovingAverageEnvelope, MovingAverageEnvelopeOutput};
+
+mod moving_average_type;
+pub use moving_average_type::MovingAverageType;
+
+mod fractal_adaptive_moving_average;
+pub use fractal_adaptive_moving_average::FractalAdaptiveMovingAverage;
+
+mod ease_of_movement;
+pub use ease_of_movement::{EaseOfMovement, EaseOfMovementInput};
+
+mod volume_index;
+pub use volume_index::{NegativeVolumeIndex, PositiveVolumeIndex, VolumeIndexOutput};
+
+mod price_volume_trend;
+pub use price_volume_trend::PriceVolumeTrend;
+
+mod stochastic_oscillator;
+pub use stochastic_oscillator::{StochasticOscillator, StochasticOscillatorOutput};
+
+mod smi_ergodic;
+pub use smi_ergodic::{SmiErgodic, SmiErgodicOutput};
+
+mod pivot_detector;
+pub use pivot_detector::{Pivot, PivotDetector};
+
+mod zig_zag;
+pub use zig_zag::{ZigZag, ZigZagDirection, ZigZagLeg, ZigZagThreshold};
+
+mod volume_weighted_moving_average;
+pub use volume_weighted_moving_average::VolumeWeightedMovingAverage;
+
+mod historical_volatility;
+pub use historical_volatility::HistoricalVolatility;
+
+mod rolling_beta;
+pub use rolling_beta::RollingBeta;
+
+mod rolling_correlation;
+pub use rolling_correlation::RollingCorrelation;
+
+mod historical_var;
+pub use historical_var::HistoricalVar;
+
+mod percentile_rank;
+pub use percentile_rank::PercentileRank;
+
+mod rolling_median;
+pub use rolling_median::RollingMedian;
+
+mod session_volume_weighted_average_price;
+pub use session_volume_weighted_average_price::{SessionBoundary, SessionVolumeWeightedAveragePrice};
+
+mod cumulative_sum;
+pub use cumulative_sum::CumulativeSum;
+
+mod kalman_trend;
+pub use kalman_trend::{KalmanTrend, KalmanTrendOutput};
+
+mod average_directional_index;
+pub use average_directional_index::AverageDirectionalIndex;
+
+mod adxr;
+pub use adxr::Adxr;
+
+mod moving_average_ribbon;
+pub use moving_average_ribbon::{MovingAverageRibbon, MovingAverageRibbonOutput};
+
+mod holt_smoothing;
+pub use holt_smoothing::{HoltSmoothing, HoltSmoothingOutput};
+
+mod holt_winters;
+pub use holt_winters::{HoltWinters, HoltWintersOutput, Seasonality};
+
+mod hampel_filter;
+pub use hampel_filter::{HampelFilter, HampelFilterOutput};
+
+mod savitzky_golay;
+pub use savitzky_golay::SavitzkyGolay;
+
+mod super_smoother;
+pub use super_smoother::SuperSmoother;
+
+mod time_weighted_average_price;
+pub use time_weighted_average_price::TimeWeightedAveragePrice;
+
+mod returns;
+pub use returns::{ReturnMode, Returns};
+
+mod cumulative_return;
+pub use cumulative_return::CumulativeReturn;
+
+mod half_life;
+pub use half_life::HalfLife;
+
+mod crossover;
+pub use crossover::{Crossover, CrossoverEvent};
+
+mod alerts;
+pub use alerts::{Alert, Alerts, Threshold};
+
+mod lagged;
+pub use lagged::Lagged;
+
+mod difference;
+pub use difference::Difference;
+
+mod normalized;
+pub use normalized::{Normalization, Normalized};
+
+mod smoothed;
+pub use smoothed::Smoothed;
+
+mod seeded;
+pub use seeded::Seeded;
+
+mod filtered;
+pub use filtered::Filtered;
+
+mod rolling_apply;
+pub use rolling_apply::RollingApply;
+
+mod boxed_signal;
+pub use boxed_signal::BoxedSignal;
+
+mod shared_signal;
+pub use shared_signal::SharedSignal;
+
+mod gap_filled;
+pub use gap_filled::{GapFilled, GapPolicy};
+
+#[cfg(feature = "chrono")]
+mod timed;
+#[cfg(feature = "chrono")]
+pub use timed::Timed;
+
+#[cfg(feature = "chrono")]
+mod time_decayed_moving_average;
+#[cfg(feature = "chrono")]
+pub use time_decayed_moving_average::TimeDecayedMovingAverage;
+
+mod fixed_window;
+
+mod fixed_simple_moving_average;
+pub use fixed_simple_moving_average::FixedSimpleMovingAverage;
+
+mod fixed_maximum_period;
+pub use fixed_maximum_period::FixedMaximumPeriod;
+
+mod fixed_minimum_period;
+pub use fixed_minimum_period::FixedMinimumPeriod;
+
+mod signal_set;
+pub use signal_set::SignalSet;
+
+#[cfg(feature = "rayon")]
+mod parallel_signal_set;
+#[cfg(feature = "rayon")]
+pub use parallel_signal_set::ParallelSignalSet;
+
+/// Construct signals by name from a map of parameters, for configuration driven systems.
+pub mod registry;
+
+pub use crate::types::HighLowClose;
@@ -0,0 +1,237 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+use num_traits::Float;
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// # Momentum
+/// Container for Momentum aggregation
+///
+/// The momentum is the difference between the current input and the input `p` steps ago, the first `p` entries
+/// will produce `None` as there is no value to compare against yet. After which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>−</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n-p</mn>
+///         </msub>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period of the momentum and `i` is the input.
+///
+/// The aggregation is generic over the float type of its inputs and defaults to `f64`.
+/// `new` creates an `f64` aggregation, other types are created with [`for_type`](Momentum::for_type).
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::Momentum;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Momentum with a period of 2
+/// let mut momentum = Momentum::new(2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(momentum.apply(1.0), None);
+/// assert_eq!(momentum.apply(3.0), None);
+/// assert_eq!(momentum.apply(4.0), Some(3.0));
+/// assert_eq!(momentum.apply(2.0), Some(-1.0));
+///
+/// // evaluate some values, these won't affect the internal state of the Momentum
+/// assert_eq!(momentum.evaluate(8.0), Some(4.0));
+///
+/// // fetch the current value of the Momentum
+/// assert_eq!(momentum.current(), Some(-1.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Momentum<T: Float = f64> {
+    period: usize,
+    /// The last `period + 1` inputs, the front is the input `period` steps before the back.
+    values: VecDeque<T>,
+}
+
+impl<T: Float> IoState for Momentum<T> {
+    type Input = T;
+    type Output = Option<T>;
+}
+
+impl<T: Float> Momentum<T> {
+    /// Create a new Momentum instance taking inputs of type `T`, such as `f32`
+    /// # Arguments
+    /// * `period` - The number of steps to look back when calculating the momentum, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::Momentum;
+    ///
+    /// let momentum = Momentum::<f32>::for_type(10);
+    /// assert!(momentum.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::Momentum;
+    ///
+    /// let momentum = Momentum::<f32>::for_type(0);
+    ///
+    /// assert!(momentum.is_err());
+    /// ```
+    pub fn for_type(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 2),
+            }),
+        }
+    }
+}
+
+impl Momentum {
+    /// Create a new Momentum instance
+    /// # Arguments
+    /// * `period` - The number of steps to look back when calculating the momentum, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::Momentum;
+    ///
+    /// let momentum = Momentum::new(10);
+    /// assert!(momentum.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::Momentum;
+    ///
+    /// let momentum = Momentum::new(0);
+    ///
+    /// assert!(momentum.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
+impl<T: Float> Validate for Momentum<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<T: Float> Executable for Momentum<T> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let result = match self.values.len() >= self.period {
+            true => Some(input - self.values[self.values.len() - self.period]),
+            false => None,
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period + 1 {
+                    self.values.pop_front();
+                }
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl<T: Float> Current for Momentum<T> {
+    fn current(&self) -> Self::Output {
+        match self.values.len() > self.period {
+            true => Some(self.values[self.values.len() - 1] - self.values[0]),
+            false => None,
+        }
+    }
+}
+
+impl<T: Float> WarmUp for Momentum<T> {
+    fn warmup_remaining(&self) -> usize {
+        (self.period + 1).saturating_sub(self.values.len())
+    }
+}
+
+impl<T: Float> Periodic for Momentum<T> {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut momentum = Momentum::new(2).unwrap();
+        assert_eq!(momentum.apply(1.0), None);
+        assert_eq!(momentum.apply(3.0), None);
+        assert_eq!(momentum.apply(4.0), Some(3.0));
+        assert_eq!(momentum.apply(2.0), Some(-1.0));
+        assert_eq!(momentum.apply(2.0), Some(-2.0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut momentum = Momentum::new(2).unwrap();
+        assert_eq!(momentum.apply(1.0), None);
+        assert_eq!(momentum.evaluate(3.0), None);
+        assert_eq!(momentum.apply(3.0), None);
+        assert_eq!(momentum.evaluate(4.0), Some(3.0));
+        assert_eq!(momentum.apply(4.0), Some(3.0));
+        assert_eq!(momentum.evaluate(8.0), Some(5.0));
+        assert_eq!(momentum.apply(2.0), Some(-1.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut momentum = Momentum::new(2).unwrap();
+        assert_eq!(momentum.current(), None);
+        assert_eq!(momentum.apply(1.0), None);
+        assert_eq!(momentum.apply(3.0), None);
+        assert_eq!(momentum.current(), None);
+        assert_eq!(momentum.apply(4.0), Some(3.0));
+        assert_eq!(momentum.current(), Some(3.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let momentum = Momentum::new(0);
+        assert!(momentum.is_err());
+    }
+}
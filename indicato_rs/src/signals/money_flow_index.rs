@@ -0,0 +1,399 @@
+use std::{collections::VecDeque, marker::PhantomData};
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    types::{HasClose, HasHighLow, HasVolume},
+    validation,
+};
+
+fn positive_negative(typical_price: f64, previous: f64, volume: f64) -> (f64, f64) {
+    let raw_money_flow = typical_price * volume;
+    match typical_price.partial_cmp(&previous) {
+        Some(std::cmp::Ordering::Greater) => (raw_money_flow, 0.0),
+        Some(std::cmp::Ordering::Less) => (0.0, raw_money_flow),
+        _ => (0.0, 0.0),
+    }
+}
+
+/// Adds a money flow to a running (sum, count) of the nonzero money flows in one direction and removes the money flow
+/// leaving the period. Once no nonzero money flows remain the sum is exactly 0, so it can't drift from rounding.
+fn roll((sum, count): (f64, usize), flow: f64, evicted: f64) -> (f64, usize) {
+    match (count + usize::from(flow != 0.0)).saturating_sub(usize::from(evicted != 0.0)) {
+        0 => (0.0, 0),
+        count => ((sum + flow - evicted).max(0.0), count),
+    }
+}
+
+fn money_flow_index(positive: f64, negative: f64) -> f64 {
+    match positive + negative == 0.0 {
+        true => 50.0,
+        false => 100.0 * positive / (positive + negative),
+    }
+}
+
+/// # Money Flow Index
+/// Container for Money Flow Index (MFI) aggregation
+///
+/// The money flow index is a volume weighted relative strength index, the typical price of each input is multiplied
+/// by its volume to produce the raw money flow. When the typical price rises from the previous input the raw money flow
+/// is positive, when it falls the raw money flow is negative, otherwise it is discarded.
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mn>100</mn>
+///         <mo>⋅</mo>
+///         <mfrac>
+///             <msub><mi>P</mi><mn>n</mn></msub>
+///             <mrow>
+///                 <msub><mi>P</mi><mn>n</mn></msub>
+///                 <mo>+</mo>
+///                 <msub><mi>N</mi><mn>n</mn></msub>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `P` is the sum of positive money flow over the period and `N` is
+/// the sum of negative money flow over the period. The typical price is the average of the high, low and close.
+/// If there is no money flow over the period the output is 50.
+///
+/// The first `p` entries will produce `None` as the money flow requires a previous typical price.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::MoneyFlowIndex;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Money Flow Index with a period of 2
+/// let mut mfi = MoneyFlowIndex::new(2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(mfi.apply((3.0, 1.0, 2.0, 100.0)), None);
+/// assert_eq!(mfi.apply((4.0, 2.0, 3.0, 100.0)), None);
+/// assert_eq!(mfi.apply((3.0, 1.0, 2.0, 150.0)), Some(50.0));
+/// assert_eq!(mfi.apply((5.0, 3.0, 4.0, 300.0)), Some(80.0));
+///
+/// // evaluate some values, these won't affect the internal state of the MFI
+/// assert_eq!(mfi.evaluate((2.0, 0.0, 1.0, 300.0)), Some(80.0));
+///
+/// // fetch the current value of the MFI
+/// assert_eq!(mfi.current(), Some(80.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MoneyFlowIndex<I: HasHighLow + HasClose + HasVolume = (f64, f64, f64, f64)> {
+    period: usize,
+    /// The positive and negative money flow of each input over the period.
+    flows: VecDeque<(f64, f64)>,
+    /// The sum of the positive money flows over the period.
+    positive_sum: f64,
+    /// The sum of the negative money flows over the period.
+    negative_sum: f64,
+    /// The number of inputs over the period with a positive money flow.
+    positive_count: usize,
+    /// The number of inputs over the period with a negative money flow.
+    negative_count: usize,
+    /// The previous typical price.
+    previous: Option<f64>,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> IoState for MoneyFlowIndex<I> {
+    /// Input is anything with a high, low, close and volume, by default a tuple of (high, low, close, volume)
+    type Input = I;
+    type Output = Option<f64>;
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> MoneyFlowIndex<I> {
+    /// Create a new Money Flow Index instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the Money Flow Index aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::MoneyFlowIndex;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let mfi = MoneyFlowIndex::<Candle>::for_input(14);
+    /// assert!(mfi.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    pub fn for_input(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                flows: VecDeque::with_capacity(period + 1),
+                positive_sum: 0.0,
+                negative_sum: 0.0,
+                positive_count: 0,
+                negative_count: 0,
+                previous: None,
+                input: PhantomData,
+            }),
+        }
+    }
+}
+
+impl MoneyFlowIndex {
+    /// Create a new Money Flow Index instance
+    /// # Arguments
+    /// * `period` - The period of the Money Flow Index aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::MoneyFlowIndex;
+    ///
+    /// let mfi = MoneyFlowIndex::new(14);
+    /// assert!(mfi.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::MoneyFlowIndex;
+    ///
+    /// let mfi = MoneyFlowIndex::new(0);
+    ///
+    /// assert!(mfi.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_input(period)
+    }
+
+    /// Create a new Money Flow Index instance with the conventional period of 14
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::MoneyFlowIndex;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let mfi = MoneyFlowIndex::standard();
+    /// assert_eq!(mfi.period(), 14);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(14).unwrap()
+    }
+}
+
+impl Default for MoneyFlowIndex {
+    /// Create a new Money Flow Index with the conventional period of 14
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Validate for MoneyFlowIndex<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (high, low, close, volume) = (input.high(), input.low(), input.close(), input.volume());
+        validation::value(close, "Close")?;
+        validation::volume(volume)?;
+        validation::high_low(high, low)
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Executable for MoneyFlowIndex<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (high, low, close, volume) = (input.high(), input.low(), input.close(), input.volume());
+        let typical_price = (high + low + close) / 3.0;
+        let previous = match self.previous {
+            None => {
+                match execution_context {
+                    ExecutionContext::Apply => self.previous = Some(typical_price),
+                    ExecutionContext::Evaluate => {}
+                }
+                return None;
+            }
+            Some(previous) => previous,
+        };
+        let (positive, negative) = positive_negative(typical_price, previous, volume);
+        let (evicted_positive, evicted_negative) = match self.flows.len() < self.period {
+            true => (0.0, 0.0),
+            false => self.flows.front().copied().unwrap_or((0.0, 0.0)),
+        };
+        let (positive_sum, positive_count) =
+            roll((self.positive_sum, self.positive_count), positive, evicted_positive);
+        let (negative_sum, negative_count) =
+            roll((self.negative_sum, self.negative_count), negative, evicted_negative);
+        let output = (self.flows.len() + 1 >= self.period).then(|| money_flow_index(positive_sum, negative_sum));
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.previous = Some(typical_price);
+                self.flows.push_back((positive, negative));
+                if self.flows.len() > self.period {
+                    self.flows.pop_front();
+                }
+                self.positive_sum = positive_sum;
+                self.negative_sum = negative_sum;
+                self.positive_count = positive_count;
+                self.negative_count = negative_count;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        output
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Current for MoneyFlowIndex<I> {
+    fn current(&self) -> Self::Output {
+        (self.flows.len() >= self.period).then(|| money_flow_index(self.positive_sum, self.negative_sum))
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> WarmUp for MoneyFlowIndex<I> {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none()) + self.period.saturating_sub(self.flows.len())
+    }
+}
+
+impl<I: HasHighLow + HasClose + HasVolume> Periodic for MoneyFlowIndex<I> {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use approx::assert_abs_diff_eq;
+
+    use super::*;
+    use crate::traits::{TryApply, TryEvaluate};
+    use crate::types::Candle;
+
+    #[test]
+    fn test_apply() {
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+        assert_eq!(mfi.apply((3.0, 1.0, 2.0, 100.0)), None);
+        assert_eq!(mfi.apply((4.0, 2.0, 3.0, 100.0)), None);
+        assert_eq!(mfi.apply((3.0, 1.0, 2.0, 150.0)), Some(50.0));
+        assert_eq!(mfi.apply((5.0, 3.0, 4.0, 300.0)), Some(80.0));
+        assert_abs_diff_eq!(
+            mfi.apply((2.0, 0.0, 1.0, 100.0)).unwrap(),
+            100.0 * 1200.0 / 1300.0,
+            epsilon = 10e-7
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+        assert_eq!(mfi.evaluate((3.0, 1.0, 2.0, 100.0)), None);
+        assert_eq!(mfi.apply((3.0, 1.0, 2.0, 100.0)), None);
+        assert_eq!(mfi.evaluate((4.0, 2.0, 3.0, 100.0)), None);
+        assert_eq!(mfi.apply((4.0, 2.0, 3.0, 100.0)), None);
+        assert_eq!(mfi.evaluate((3.0, 1.0, 2.0, 150.0)), Some(50.0));
+        assert_eq!(mfi.evaluate((5.0, 3.0, 4.0, 100.0)), Some(100.0));
+        assert_eq!(mfi.apply((3.0, 1.0, 2.0, 150.0)), Some(50.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+        assert_eq!(mfi.current(), None);
+        assert_eq!(mfi.apply((3.0, 1.0, 2.0, 100.0)), None);
+        assert_eq!(mfi.apply((4.0, 2.0, 3.0, 100.0)), None);
+        assert_eq!(mfi.current(), None);
+        assert_eq!(mfi.apply((3.0, 1.0, 2.0, 150.0)), Some(50.0));
+        assert_eq!(mfi.current(), Some(50.0));
+    }
+
+    #[test]
+    fn test_flatline() {
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+        assert_eq!(mfi.apply((3.0, 3.0, 3.0, 100.0)), None);
+        assert_eq!(mfi.apply((3.0, 3.0, 3.0, 100.0)), None);
+        assert_eq!(mfi.apply((3.0, 3.0, 3.0, 100.0)), Some(50.0));
+    }
+
+    #[test]
+    fn test_running_sums() {
+        // the running sums match summing the money flows over the period
+        let mut mfi = MoneyFlowIndex::new(3).unwrap();
+        let mut flows = VecDeque::new();
+        let mut previous = None;
+        for i in 0..40 {
+            let close = 10.0 + f64::from(i * 7 % 11) * 0.1;
+            let volume = 100.0 + f64::from(i % 5) * 0.3;
+            let output = mfi.apply((close + 0.5, close - 0.5, close, volume));
+            if let Some(previous) = previous {
+                flows.push_back(positive_negative(close, previous, volume));
+                if flows.len() > 3 {
+                    flows.pop_front();
+                }
+            }
+            previous = Some(close);
+            let positive: f64 = flows.iter().map(|flow| flow.0).sum();
+            let negative: f64 = flows.iter().map(|flow| flow.1).sum();
+            match flows.len() {
+                3 => assert_abs_diff_eq!(output.unwrap(), money_flow_index(positive, negative), epsilon = 10e-7),
+                _ => assert_eq!(output, None),
+            }
+        }
+    }
+
+    #[test]
+    fn test_sums_return_to_zero() {
+        // once the moving money flows leave the period a flat line is exactly 50
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+        for (close, volume) in [(1.0, 0.1), (1.1, 0.2), (1.3, 0.7), (1.2, 0.3), (1.2, 1.0), (1.2, 1.0)] {
+            mfi.apply((close, close, close, volume));
+        }
+        assert_eq!(mfi.current(), Some(50.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let mfi = MoneyFlowIndex::new(0);
+        assert!(mfi.is_err());
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+        assert_eq!(mfi.try_apply((3.0, 1.0, 2.0, 100.0)).unwrap(), None);
+        assert!(mfi.try_apply((4.0, 2.0, 3.0, -100.0)).is_err());
+        assert!(mfi.try_apply((1.0, 2.0, 3.0, 100.0)).is_err());
+        assert!(mfi.try_evaluate((4.0, 2.0, f64::NAN, 100.0)).is_err());
+        assert_eq!(mfi.try_apply((4.0, 2.0, 3.0, 100.0)).unwrap(), None);
+        assert_eq!(mfi.try_apply((3.0, 1.0, 2.0, 150.0)).unwrap(), Some(50.0));
+    }
+
+    #[test]
+    fn test_for_input() {
+        let mut mfi = MoneyFlowIndex::new(2).unwrap();
+        let mut candles = MoneyFlowIndex::<Candle>::for_input(2).unwrap();
+        for (high, low, close, volume) in [(3.0, 1.0, 2.0, 100.0), (4.0, 2.0, 3.0, 100.0), (3.0, 1.0, 2.0, 150.0)] {
+            let candle = Candle { open: close, high, low, close, volume, timestamp: 0 };
+            assert_eq!(candles.evaluate(candle), mfi.evaluate((high, low, close, volume)));
+            assert_eq!(candles.apply(candle), mfi.apply((high, low, close, volume)));
+        }
+        assert_eq!(candles.current(), Some(50.0));
+        let inverted = Candle { open: 1.0, high: 1.0, low: 2.0, close: 1.0, volume: 1.0, timestamp: 0 };
+        assert!(candles.try_apply(inverted).is_err());
+    }
+}
@@ -2,10 +2,15 @@ use indicato_rs_proc::{Apply, Evaluate};
 
 use crate::{
     fin_error::{FinError, FinErrorType},
-    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, IoState},
+    inspect::{SignalState, ToState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, MovingAverage, Periodic, Validate,
+        WarmUp,
+    },
+    validation,
 };
 
-use super::ExponentialMovingAverage;
+use super::{ExponentialMovingAverage, Smoothed};
 
 /// # Moving Average Convergence Divergence
 /// Container for Moving Average Convergence Divergence (MACD) aggregation
@@ -52,7 +57,14 @@ use super::ExponentialMovingAverage;
 /// <br>
 /// Where `o` is the output, `n` is the current step, `EMA` is the Exponential Moving Average, `S` is the short period, `L` is the long period and `i` is the input.
 ///
-/// _NB._ This will not produce a signal line, you will need to produce your own signal line from the MACD output.
+/// The moving averages can be any [`MovingAverage`] with the [`GenericMovingAverageConvergenceDivergence`], such as
+/// Simple Moving Averages or a zero lag moving average implementing the trait, the MACD of Exponential Moving Averages
+/// is the [`MovingAverageConvergenceDivergence`].
+///
+/// _NB._ The MACD itself does not produce a signal line.
+/// [`standard`](GenericMovingAverageConvergenceDivergence::standard) and the
+/// [`MovingAverageConvergenceDivergenceBuilder`] wrap it in a [`Smoothed`] with an Exponential Moving Average signal
+/// line, and other signal lines can be added with [`Smoothed`] directly.
 ///
 /// # Example Usage
 /// ```
@@ -76,24 +88,39 @@ use super::ExponentialMovingAverage;
 /// // fetch the current value of the MACD
 /// assert_abs_diff_eq!(macd.current(),  0.3488, epsilon = 10e-7);
 /// ```
-#[derive(Apply, Evaluate)]
-pub struct MovingAverageConvergenceDivergence {
-    short_ema: ExponentialMovingAverage,
-    long_ema: ExponentialMovingAverage,
+#[derive(Debug, Clone, Apply, Evaluate)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64>,
+{
+    #[cfg_attr(feature = "serde", serde(alias = "short_ema"))]
+    short_average: M,
+    #[cfg_attr(feature = "serde", serde(alias = "long_ema"))]
+    long_average: M,
 }
 
-impl IoState for MovingAverageConvergenceDivergence {
+/// The [`GenericMovingAverageConvergenceDivergence`] of Exponential Moving Averages.
+pub type MovingAverageConvergenceDivergence = GenericMovingAverageConvergenceDivergence<ExponentialMovingAverage>;
+
+impl<M> IoState for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64>,
+{
     type Input = f64;
     type Output = f64;
 }
 
-impl MovingAverageConvergenceDivergence {
+impl<M> GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64>,
+{
     /// Create a new Moving Average Convergence Divergence (MACD) aggregation
     ///
     /// # Arguments
     ///
-    /// * `short_period` - The period for the short Exponential Moving Average
-    /// * `long_period` - The period for the long Exponential Moving Average
+    /// * `short_period` - The period for the short moving average
+    /// * `long_period` - The period for the long moving average
     ///
     /// _NB._ Both periods must be greater than 0, there is no requirement for the short period to be less than the long period.
     ///
@@ -116,6 +143,17 @@ impl MovingAverageConvergenceDivergence {
     /// 
     /// assert!(macd.is_err());
     /// ```
+    ///
+    /// The moving averages of a [`GenericMovingAverageConvergenceDivergence`] are chosen by its type parameter
+    /// ```
+    /// use indicato_rs::signals::{GenericMovingAverageConvergenceDivergence, SimpleMovingAverage};
+    /// use indicato_rs::traits::Apply;
+    ///
+    /// let mut macd = GenericMovingAverageConvergenceDivergence::<SimpleMovingAverage>::new(2, 4).unwrap();
+    /// macd.apply(3.0);
+    /// macd.apply(5.0);
+    /// assert_eq!(macd.apply(7.0), 1.0);
+    /// ```
     pub fn new(short_period: usize, long_period: usize) -> Result<Self, FinError> {
         match (short_period, long_period) {
             (0, _) | (_, 0) => Err(FinError::new(
@@ -123,24 +161,196 @@ impl MovingAverageConvergenceDivergence {
                 "Periods must be greater than 0",
             )),
             _ => Ok(Self {
-                short_ema: ExponentialMovingAverage::new(short_period)?,
-                long_ema: ExponentialMovingAverage::new(long_period)?,
+                short_average: M::with_period(short_period)?,
+                long_average: M::with_period(long_period)?,
             }),
         }
     }
+
+    /// Create a new Moving Average Convergence Divergence instance with the conventional short and long periods of 12
+    /// and 26, and its conventional signal line, the 9 period Exponential Moving Average of the MACD.
+    ///
+    /// The output is a tuple of (MACD, signal line), the signal line is passed on from the first input.
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::MovingAverageConvergenceDivergence;
+    /// use indicato_rs::traits::{Apply, Periodic};
+    ///
+    /// let mut macd = MovingAverageConvergenceDivergence::standard();
+    /// assert_eq!(macd.period(), 26);
+    ///
+    /// // the MACD and its signal line both start at zero
+    /// assert_eq!(macd.apply(3.0), (0.0, Some(0.0)));
+    /// let (line, signal) = macd.apply(4.0);
+    /// assert!(line > signal.unwrap());
+    /// ```
+    pub fn standard() -> Smoothed<Self, ExponentialMovingAverage> {
+        Smoothed::new(Self::new(12, 26).unwrap(), 9).unwrap()
+    }
 }
 
-impl Current for MovingAverageConvergenceDivergence {
+impl MovingAverageConvergenceDivergence {
+
+    /// Create a new [`MovingAverageConvergenceDivergenceBuilder`] starting from the conventional short, long and signal
+    /// periods of 12, 26 and 9
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::MovingAverageConvergenceDivergence;
+    ///
+    /// let macd = MovingAverageConvergenceDivergence::builder()
+    ///     .short_period(8)
+    ///     .long_period(17)
+    ///     .signal_period(9)
+    ///     .build();
+    /// assert!(macd.is_ok());
+    /// ```
+    pub fn builder() -> MovingAverageConvergenceDivergenceBuilder {
+        MovingAverageConvergenceDivergenceBuilder::default()
+    }
+}
+
+impl<M> Default for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64>,
+{
+    /// Create a new Moving Average Convergence Divergence with the conventional short and long periods of 12 and 26,
+    /// without a signal line
+    fn default() -> Self {
+        Self::new(12, 26).unwrap()
+    }
+}
+
+/// Builder for a [`MovingAverageConvergenceDivergence`] with its signal line, created with
+/// [`MovingAverageConvergenceDivergence::builder`].
+///
+/// The MACD is built wrapped in a [`Smoothed`] with an Exponential Moving Average signal line, so its output is a tuple
+/// of (MACD, signal line). Any period that isn't set keeps its conventional value, the periods are validated when the
+/// MACD is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MovingAverageConvergenceDivergenceBuilder {
+    short_period: usize,
+    long_period: usize,
+    signal_period: usize,
+}
+
+impl MovingAverageConvergenceDivergenceBuilder {
+    /// Set the period of the short Exponential Moving Average
+    pub fn short_period(mut self, short_period: usize) -> Self {
+        self.short_period = short_period;
+        self
+    }
+
+    /// Set the period of the long Exponential Moving Average
+    pub fn long_period(mut self, long_period: usize) -> Self {
+        self.long_period = long_period;
+        self
+    }
+
+    /// Set the period of the Exponential Moving Average signal line
+    pub fn signal_period(mut self, signal_period: usize) -> Self {
+        self.signal_period = signal_period;
+        self
+    }
+
+    /// Build the MACD with its signal line
+    ///
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    /// ```
+    /// use indicato_rs::signals::MovingAverageConvergenceDivergence;
+    ///
+    /// let macd = MovingAverageConvergenceDivergence::builder().short_period(0).build();
+    /// assert!(macd.is_err());
+    ///
+    /// let macd = MovingAverageConvergenceDivergence::builder().signal_period(0).build();
+    /// assert!(macd.is_err());
+    /// ```
+    pub fn build(self) -> Result<Smoothed<MovingAverageConvergenceDivergence, ExponentialMovingAverage>, FinError> {
+        if self.signal_period == 0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Signal period must be greater than 0",
+            ));
+        }
+        let macd = MovingAverageConvergenceDivergence::new(self.short_period, self.long_period)?;
+        Smoothed::new(macd, self.signal_period)
+    }
+}
+
+impl Default for MovingAverageConvergenceDivergenceBuilder {
+    /// Create a new MACD builder with the conventional short, long and signal periods of 12, 26 and 9
+    fn default() -> Self {
+        Self {
+            short_period: 12,
+            long_period: 26,
+            signal_period: 9,
+        }
+    }
+}
+
+impl<M> Current for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64> + Current,
+{
     fn current(&self) -> Self::Output {
-        self.short_ema.current() - self.long_ema.current()
+        self.short_average.current() - self.long_average.current()
+    }
+}
+
+impl<M> WarmUp for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64> + WarmUp,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.short_average
+            .warmup_remaining()
+            .max(self.long_average.warmup_remaining())
     }
 }
 
-impl Executable for MovingAverageConvergenceDivergence {
+impl<M> Periodic for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64> + Periodic,
+{
+    fn period(&self) -> usize {
+        self.short_average.period().max(self.long_average.period())
+    }
+}
+
+impl<M> Validate for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64>,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<M> Executable for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64>,
+{
     fn execute(&mut self, input: f64, execution_context: &ExecutionContext) -> Self::Output {
-        let short_ema = self.short_ema.execute(input, execution_context);
-        let long_ema = self.long_ema.execute(input, execution_context);
-        short_ema - long_ema
+        let short_average = self.short_average.execute(input, execution_context);
+        let long_average = self.long_average.execute(input, execution_context);
+        short_average - long_average
+    }
+}
+
+impl<M> Inspect for GenericMovingAverageConvergenceDivergence<M>
+where
+    M: MovingAverage<Input = f64, Output = f64> + ToState,
+{
+    fn inspect(&self) -> SignalState {
+        SignalState::new("MovingAverageConvergenceDivergence")
+            .field("short_average", self.short_average.to_state())
+            .field("long_average", self.long_average.to_state())
+    }
+
+    fn name(&self) -> &'static str {
+        "MovingAverageConvergenceDivergence"
     }
 }
 
@@ -149,6 +359,7 @@ mod test {
     use approx::assert_abs_diff_eq;
 
     use super::*;
+    use crate::signals::{SimpleMovingAverage, WeightedMovingAverage};
 
     #[test]
     fn test_macd() {
@@ -170,4 +381,88 @@ mod test {
         assert!(MovingAverageConvergenceDivergence::new(0, 1).is_err());
         assert!(MovingAverageConvergenceDivergence::new(1, 0).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_standard() {
+        let mut standard = MovingAverageConvergenceDivergence::standard();
+        let mut macd = MovingAverageConvergenceDivergence::new(12, 26).unwrap();
+        let mut signal = ExponentialMovingAverage::new(9).unwrap();
+        for input in [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 8.0, 7.0] {
+            let line = macd.apply(input);
+            assert_eq!(standard.apply(input), (line, Some(signal.apply(line))));
+        }
+        assert_eq!(standard.signal().current(), macd.current());
+    }
+
+    #[test]
+    fn test_moving_average_types() {
+        let mut sma = GenericMovingAverageConvergenceDivergence::<SimpleMovingAverage>::new(2, 4).unwrap();
+        let mut wma = GenericMovingAverageConvergenceDivergence::<WeightedMovingAverage>::new(2, 4).unwrap();
+        for input in [3.0, 4.8, 6.3] {
+            sma.apply(input);
+            wma.apply(input);
+        }
+        assert_abs_diff_eq!(sma.apply(5.0), 0.875, epsilon = 10e-7);
+        assert_abs_diff_eq!(wma.apply(5.0), 0.2833333, epsilon = 10e-7);
+        assert_eq!(sma.warmup_remaining(), 0);
+        assert_eq!(sma.period(), 4);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let macd = MovingAverageConvergenceDivergence::new(2, 4).unwrap();
+        let sma = GenericMovingAverageConvergenceDivergence::<SimpleMovingAverage>::new(2, 4).unwrap();
+        assert_eq!(macd.inspect().name, "MovingAverageConvergenceDivergence");
+        assert_eq!(sma.inspect().name, "MovingAverageConvergenceDivergence");
+        assert!(sma.inspect().get("short_average").is_some());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_previous_fields() {
+        // snapshots taken before the moving averages were generic name them as EMAs
+        let mut macd = MovingAverageConvergenceDivergence::new(2, 4).unwrap();
+        macd.apply(3.0);
+        macd.apply(4.8);
+        let snapshot = serde_json::to_string(&macd)
+            .unwrap()
+            .replace("short_average", "short_ema")
+            .replace("long_average", "long_ema");
+        let mut restored: MovingAverageConvergenceDivergence = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(restored.apply(6.3), macd.apply(6.3));
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut built = MovingAverageConvergenceDivergence::builder()
+            .short_period(2)
+            .long_period(4)
+            .signal_period(3)
+            .build()
+            .unwrap();
+        let mut macd = MovingAverageConvergenceDivergence::new(2, 4).unwrap();
+        let mut signal = ExponentialMovingAverage::new(3).unwrap();
+        for input in [3.0, 4.8, 6.3, 5.0] {
+            let line = macd.apply(input);
+            assert_eq!(built.apply(input), (line, Some(signal.apply(line))));
+        }
+        assert!(MovingAverageConvergenceDivergence::builder()
+            .long_period(0)
+            .build()
+            .is_err());
+        let error = MovingAverageConvergenceDivergence::builder()
+            .signal_period(0)
+            .build()
+            .unwrap_err();
+        assert_eq!(error.message, "Signal period must be greater than 0");
+    }
+
+    #[test]
+    fn test_builder_default() {
+        let mut built = MovingAverageConvergenceDivergence::builder().build().unwrap();
+        let mut standard = MovingAverageConvergenceDivergence::standard();
+        for input in [3.0, 4.8, 6.3, 5.0] {
+            assert_eq!(built.apply(input), standard.apply(input));
+        }
+    }
+}
@@ -0,0 +1,268 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::{moving_average_type::TypedMovingAverage, MovingAverageType};
+
+/// Output of the [`MovingAverageEnvelope`] signal.
+///
+/// Tuples of (upper, center, lower) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MovingAverageEnvelopeOutput {
+    /// The upper band.
+    pub upper: f64,
+    /// The center line, the moving average of the inputs.
+    pub center: f64,
+    /// The lower band.
+    pub lower: f64,
+}
+
+impl From<(f64, f64, f64)> for MovingAverageEnvelopeOutput {
+    fn from((upper, center, lower): (f64, f64, f64)) -> Self {
+        Self {
+            upper,
+            center,
+            lower,
+        }
+    }
+}
+
+impl From<MovingAverageEnvelopeOutput> for (f64, f64, f64) {
+    fn from(output: MovingAverageEnvelopeOutput) -> Self {
+        (output.upper, output.center, output.lower)
+    }
+}
+
+/// # Moving Average Envelope
+/// Container for Moving Average Envelope aggregation
+///
+/// The envelope is made up of a moving average center line with upper and lower bands placed a fixed percentage
+/// above and below it. The aggregation will begin producing values immediately, the following formulas are applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>u</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>MA</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///         <mo>⋅</mo>
+///         <mrow><mo stretchy="true" form="prefix">(</mo>
+///             <mn>1</mn>
+///             <mo>+</mo>
+///             <mfrac>
+///                 <mi>k</mi>
+///                 <mn>100</mn>
+///             </mfrac>
+///         <mo stretchy="true" form="postfix">)</mo></mrow>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>MA</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///         <mo>⋅</mo>
+///         <mrow><mo stretchy="true" form="prefix">(</mo>
+///             <mn>1</mn>
+///             <mo>−</mo>
+///             <mfrac>
+///                 <mi>k</mi>
+///                 <mn>100</mn>
+///             </mfrac>
+///         <mo stretchy="true" form="postfix">)</mo></mrow>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `u` is the upper band, `l` is the lower band, `n` is the current step, `MA` is the chosen moving average,
+/// `k` is the percentage offset of the bands and `i` is the input.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{MovingAverageEnvelope, MovingAverageEnvelopeOutput, MovingAverageType};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Moving Average Envelope around a 2 period Simple Moving Average with bands 10% either side
+/// let mut envelope = MovingAverageEnvelope::new(2, 10.0, MovingAverageType::Simple).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(envelope.apply(10.0), MovingAverageEnvelopeOutput { upper: 11.0, center: 10.0, lower: 9.0 });
+/// assert_eq!(envelope.apply(30.0), (22.0, 20.0, 18.0).into());
+///
+/// // evaluate some values, these won't affect the internal state of the envelope
+/// assert_eq!(envelope.evaluate(50.0), (44.0, 40.0, 36.0).into());
+///
+/// // fetch the current value of the envelope
+/// assert_eq!(envelope.current(), (22.0, 20.0, 18.0).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MovingAverageEnvelope {
+    average: TypedMovingAverage,
+    /// The percentage offset of the bands from the center line.
+    percent: f64,
+}
+
+impl MovingAverageEnvelope {
+    /// Create a new Moving Average Envelope instance
+    /// # Arguments
+    /// * `period` - The period of the moving average, must be greater than 0
+    /// * `percent` - The percentage offset of the upper and lower bands from the moving average, must not be negative
+    /// * `average_type` - The type of moving average used for the center line
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{MovingAverageEnvelope, MovingAverageType};
+    ///
+    /// let envelope = MovingAverageEnvelope::new(20, 2.5, MovingAverageType::Exponential);
+    /// assert!(envelope.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0 or the percent is negative
+    /// ```
+    /// use indicato_rs::signals::{MovingAverageEnvelope, MovingAverageType};
+    ///
+    /// assert!(MovingAverageEnvelope::new(0, 2.5, MovingAverageType::Simple).is_err());
+    /// assert!(MovingAverageEnvelope::new(20, -2.5, MovingAverageType::Simple).is_err());
+    /// ```
+    pub fn new(
+        period: usize,
+        percent: f64,
+        average_type: MovingAverageType,
+    ) -> Result<Self, FinError> {
+        if percent < 0.0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Percent must not be negative",
+            ));
+        }
+        let average = TypedMovingAverage::new(period, average_type)?;
+        Ok(Self { average, percent })
+    }
+
+    fn bands(&self, center: f64) -> MovingAverageEnvelopeOutput {
+        let offset = center * self.percent / 100.0;
+        MovingAverageEnvelopeOutput {
+            upper: center + offset,
+            center,
+            lower: center - offset,
+        }
+    }
+}
+
+impl IoState for MovingAverageEnvelope {
+    type Input = f64;
+    /// Output is the upper band, moving average and lower band
+    type Output = MovingAverageEnvelopeOutput;
+}
+
+impl Validate for MovingAverageEnvelope {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for MovingAverageEnvelope {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let center = self.average.execute(input, execution_context);
+        self.bands(center)
+    }
+}
+
+impl Current for MovingAverageEnvelope {
+    fn current(&self) -> Self::Output {
+        let center = self.average.current();
+        self.bands(center)
+    }
+}
+
+impl WarmUp for MovingAverageEnvelope {
+    fn warmup_remaining(&self) -> usize {
+        self.average.warmup_remaining()
+    }
+}
+
+impl Periodic for MovingAverageEnvelope {
+    fn period(&self) -> usize {
+        self.average.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.average.effective_lookback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_simple() {
+        let mut envelope = MovingAverageEnvelope::new(2, 10.0, MovingAverageType::Simple).unwrap();
+        assert_eq!(envelope.apply(10.0), (11.0, 10.0, 9.0).into());
+        assert_eq!(envelope.apply(30.0), (22.0, 20.0, 18.0).into());
+        assert_eq!(envelope.apply(50.0), (44.0, 40.0, 36.0).into());
+    }
+
+    #[test]
+    fn test_apply_exponential() {
+        let mut envelope =
+            MovingAverageEnvelope::new(3, 50.0, MovingAverageType::Exponential).unwrap();
+        assert_eq!(envelope.apply(2.0), (3.0, 2.0, 1.0).into());
+        assert_eq!(envelope.apply(6.0), (6.0, 4.0, 2.0).into());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut envelope = MovingAverageEnvelope::new(2, 10.0, MovingAverageType::Simple).unwrap();
+        assert_eq!(envelope.apply(10.0), (11.0, 10.0, 9.0).into());
+        assert_eq!(envelope.evaluate(30.0), (22.0, 20.0, 18.0).into());
+        assert_eq!(envelope.evaluate(50.0), (33.0, 30.0, 27.0).into());
+        assert_eq!(envelope.apply(30.0), (22.0, 20.0, 18.0).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut envelope =
+            MovingAverageEnvelope::new(3, 50.0, MovingAverageType::Exponential).unwrap();
+        assert_eq!(envelope.apply(2.0), (3.0, 2.0, 1.0).into());
+        assert_eq!(envelope.apply(6.0), (6.0, 4.0, 2.0).into());
+        envelope.evaluate(100.0);
+        assert_eq!(envelope.current(), (6.0, 4.0, 2.0).into());
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(MovingAverageEnvelope::new(0, 10.0, MovingAverageType::Simple).is_err());
+        assert!(MovingAverageEnvelope::new(0, 10.0, MovingAverageType::Exponential).is_err());
+        assert!(MovingAverageEnvelope::new(3, -1.0, MovingAverageType::Simple).is_err());
+    }
+}
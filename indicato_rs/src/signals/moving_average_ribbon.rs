@@ -0,0 +1,327 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::{moving_average_type::TypedMovingAverage, MovingAverageType};
+
+/// Output of the [`MovingAverageRibbon`] signal.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MovingAverageRibbonOutput {
+    /// The value of each moving average, in order of ascending period.
+    pub values: Vec<f64>,
+    /// The alignment of the ribbon, 1 when every moving average is above the next longer one, -1 when every moving
+    /// average is below the next longer one.
+    pub alignment: f64,
+}
+
+fn ribbon(values: Vec<f64>) -> MovingAverageRibbonOutput {
+    let pairs = values.len().saturating_sub(1);
+    let alignment = match pairs {
+        0 => 0.0,
+        _ => {
+            values
+                .windows(2)
+                .map(|pair| match pair[0].partial_cmp(&pair[1]) {
+                    Some(std::cmp::Ordering::Greater) => 1.0,
+                    Some(std::cmp::Ordering::Less) => -1.0,
+                    _ => 0.0,
+                })
+                .sum::<f64>()
+                / pairs as f64
+        }
+    };
+    MovingAverageRibbonOutput { values, alignment }
+}
+
+/// # Moving Average Ribbon
+/// Container for Moving Average Ribbon aggregation
+///
+/// A ribbon applies each input to a set of moving averages of the same type with different periods, producing the
+/// value of each along with an alignment score of how well the ribbon is ordered:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>a</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mn>1</mn>
+///             <mrow><mi>m</mi><mo>−</mo><mn>1</mn></mrow>
+///         </mfrac>
+///         <munderover>
+///             <mo>∑</mo>
+///             <mrow><mi>k</mi><mo>=</mo><mn>1</mn></mrow>
+///             <mrow><mi>m</mi><mo>−</mo><mn>1</mn></mrow>
+///         </munderover>
+///         <mi>sgn</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>MA</mi>
+///             <mi>k</mi>
+///         </msub>
+///         <mo>−</mo>
+///         <msub>
+///             <mi>MA</mi>
+///             <mrow><mi>k</mi><mo>+</mo><mn>1</mn></mrow>
+///         </msub>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `a` is the alignment, `n` is the current step, `m` is the number of moving averages and `MA` are the
+/// moving averages in order of ascending period. An alignment of 1 is a fully bullish ribbon, -1 is a fully bearish
+/// ribbon, and a ribbon of a single moving average has an alignment of 0.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{MovingAverageRibbon, MovingAverageType};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new ribbon of Simple Moving Averages with periods of 1, 2 and 3
+/// let mut ribbon = MovingAverageRibbon::stepped(1, 1, 3, MovingAverageType::Simple).unwrap();
+///
+/// // apply some values and check their output
+/// ribbon.apply(2.0);
+/// ribbon.apply(3.0);
+/// let output = ribbon.apply(4.0);
+/// assert_eq!(output.values, vec![4.0, 3.5, 3.0]);
+/// assert_eq!(output.alignment, 1.0);
+///
+/// // evaluate some values, these won't affect the internal state of the ribbon
+/// assert_eq!(ribbon.evaluate(1.0).alignment, -1.0);
+///
+/// // fetch the current value of the ribbon
+/// assert_eq!(ribbon.current(), output);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MovingAverageRibbon {
+    /// The moving averages in order of ascending period.
+    averages: Vec<TypedMovingAverage>,
+}
+
+impl MovingAverageRibbon {
+    /// Create a new Moving Average Ribbon instance from a set of periods
+    /// # Arguments
+    /// * `periods` - The periods of the moving averages, must not be empty and each must be greater than 0
+    /// * `average_type` - The type of the moving averages
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{MovingAverageRibbon, MovingAverageType};
+    ///
+    /// let ribbon = MovingAverageRibbon::new(&[8, 13, 21, 34, 55], MovingAverageType::Exponential);
+    /// assert!(ribbon.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if there are no periods or any period is 0
+    /// ```
+    /// use indicato_rs::signals::{MovingAverageRibbon, MovingAverageType};
+    ///
+    /// assert!(MovingAverageRibbon::new(&[], MovingAverageType::Simple).is_err());
+    /// assert!(MovingAverageRibbon::new(&[10, 0], MovingAverageType::Simple).is_err());
+    /// ```
+    pub fn new(periods: &[usize], average_type: MovingAverageType) -> Result<Self, FinError> {
+        if periods.is_empty() {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Periods must not be empty",
+            ));
+        }
+        let mut periods = periods.to_vec();
+        periods.sort_unstable();
+        Ok(Self {
+            averages: periods
+                .into_iter()
+                .map(|period| TypedMovingAverage::new(period, average_type))
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+
+    /// Create a new Moving Average Ribbon instance with evenly stepped periods
+    /// # Arguments
+    /// * `first_period` - The period of the shortest moving average, must be greater than 0
+    /// * `step` - The difference in period between consecutive moving averages
+    /// * `count` - The number of moving averages, must be greater than 0
+    /// * `average_type` - The type of the moving averages
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{MovingAverageRibbon, MovingAverageType};
+    ///
+    /// // periods of 10, 20, ..., 100
+    /// let ribbon = MovingAverageRibbon::stepped(10, 10, 10, MovingAverageType::Simple);
+    /// assert!(ribbon.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the first period or count is 0
+    /// ```
+    /// use indicato_rs::signals::{MovingAverageRibbon, MovingAverageType};
+    ///
+    /// assert!(MovingAverageRibbon::stepped(10, 10, 0, MovingAverageType::Simple).is_err());
+    /// ```
+    pub fn stepped(
+        first_period: usize,
+        step: usize,
+        count: usize,
+        average_type: MovingAverageType,
+    ) -> Result<Self, FinError> {
+        let periods = (0..count)
+            .map(|index| first_period + index * step)
+            .collect::<Vec<_>>();
+        Self::new(&periods, average_type)
+    }
+}
+
+impl IoState for MovingAverageRibbon {
+    type Input = f64;
+    type Output = MovingAverageRibbonOutput;
+}
+
+impl Validate for MovingAverageRibbon {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for MovingAverageRibbon {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        ribbon(
+            self.averages
+                .iter_mut()
+                .map(|average| average.execute(input, execution_context))
+                .collect(),
+        )
+    }
+}
+
+impl Current for MovingAverageRibbon {
+    fn current(&self) -> Self::Output {
+        ribbon(
+            self.averages
+                .iter()
+                .map(|average| average.current())
+                .collect(),
+        )
+    }
+}
+
+impl WarmUp for MovingAverageRibbon {
+    fn warmup_remaining(&self) -> usize {
+        self.averages
+            .iter()
+            .map(|average| average.warmup_remaining())
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl Periodic for MovingAverageRibbon {
+    fn period(&self) -> usize {
+        self.averages
+            .iter()
+            .map(|average| average.period())
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut ribbon = MovingAverageRibbon::new(&[3, 1, 2], MovingAverageType::Simple).unwrap();
+        assert_eq!(
+            ribbon.apply(1.0),
+            MovingAverageRibbonOutput {
+                values: vec![1.0, 1.0, 1.0],
+                alignment: 0.0,
+            }
+        );
+        assert_eq!(ribbon.apply(2.0).values, vec![2.0, 1.5, 1.5]);
+        let output = ribbon.apply(3.0);
+        assert_eq!(output.values, vec![3.0, 2.5, 2.0]);
+        assert_eq!(output.alignment, 1.0);
+        assert_eq!(ribbon.apply(4.0).alignment, 1.0);
+        let output = ribbon.apply(1.0);
+        assert_eq!(output.values, vec![1.0, 2.5, 8.0 / 3.0]);
+        assert_eq!(output.alignment, -1.0);
+    }
+
+    #[test]
+    fn test_apply_mixed_alignment() {
+        let mut ribbon = MovingAverageRibbon::stepped(1, 1, 3, MovingAverageType::Simple).unwrap();
+        ribbon.apply(3.0);
+        ribbon.apply(1.0);
+        let output = ribbon.apply(2.0);
+        assert_eq!(output.values, vec![2.0, 1.5, 2.0]);
+        assert_eq!(output.alignment, 0.0);
+    }
+
+    #[test]
+    fn test_apply_exponential() {
+        let mut ribbon =
+            MovingAverageRibbon::stepped(1, 2, 2, MovingAverageType::Exponential).unwrap();
+        assert_eq!(ribbon.apply(2.0).values, vec![2.0, 2.0]);
+        let output = ribbon.apply(5.0);
+        assert_eq!(output.values, vec![5.0, 3.5]);
+        assert_eq!(output.alignment, 1.0);
+    }
+
+    #[test]
+    fn test_single_average() {
+        let mut ribbon = MovingAverageRibbon::new(&[2], MovingAverageType::Simple).unwrap();
+        assert_eq!(ribbon.apply(2.0).alignment, 0.0);
+        assert_eq!(ribbon.apply(4.0).values, vec![3.0]);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut ribbon = MovingAverageRibbon::stepped(1, 1, 2, MovingAverageType::Simple).unwrap();
+        assert_eq!(ribbon.apply(1.0).values, vec![1.0, 1.0]);
+        assert_eq!(ribbon.evaluate(3.0).values, vec![3.0, 2.0]);
+        assert_eq!(ribbon.evaluate(-1.0).values, vec![-1.0, 0.0]);
+        assert_eq!(ribbon.apply(3.0).values, vec![3.0, 2.0]);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut ribbon = MovingAverageRibbon::stepped(1, 1, 2, MovingAverageType::Simple).unwrap();
+        ribbon.apply(1.0);
+        ribbon.apply(3.0);
+        ribbon.evaluate(-1.0);
+        assert_eq!(
+            ribbon.current(),
+            MovingAverageRibbonOutput {
+                values: vec![3.0, 2.0],
+                alignment: 1.0,
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_periods() {
+        assert!(MovingAverageRibbon::new(&[], MovingAverageType::Simple).is_err());
+        assert!(MovingAverageRibbon::new(&[0, 10], MovingAverageType::Exponential).is_err());
+        assert!(MovingAverageRibbon::stepped(0, 10, 3, MovingAverageType::Simple).is_err());
+        assert!(MovingAverageRibbon::stepped(10, 10, 0, MovingAverageType::Simple).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+use crate::{
+    fin_error::FinError,
+    inspect::{StateValue, ToState},
+    traits::{Current, Executable, ExecutionContext, IoState, Periodic, Validate, WarmUp},
+    validation,
+};
+
+use super::{ExponentialMovingAverage, SimpleMovingAverage};
+
+/// The moving average used by signals that allow it to be chosen, such as the center line of a
+/// [`MovingAverageEnvelope`](super::MovingAverageEnvelope) or the lines of a
+/// [`MovingAverageRibbon`](super::MovingAverageRibbon).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MovingAverageType {
+    /// A [`SimpleMovingAverage`].
+    Simple,
+    /// An [`ExponentialMovingAverage`].
+    Exponential,
+}
+
+/// A moving average of a [`MovingAverageType`] chosen at runtime.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) enum TypedMovingAverage {
+    Simple(SimpleMovingAverage),
+    Exponential(ExponentialMovingAverage),
+}
+
+impl TypedMovingAverage {
+    pub(crate) fn new(period: usize, average_type: MovingAverageType) -> Result<Self, FinError> {
+        Ok(match average_type {
+            MovingAverageType::Simple => Self::Simple(SimpleMovingAverage::new(period)?),
+            MovingAverageType::Exponential => {
+                Self::Exponential(ExponentialMovingAverage::new(period)?)
+            }
+        })
+    }
+}
+
+impl ToState for TypedMovingAverage {
+    fn to_state(&self) -> StateValue {
+        match self {
+            Self::Simple(sma) => sma.to_state(),
+            Self::Exponential(ema) => ema.to_state(),
+        }
+    }
+}
+
+impl IoState for TypedMovingAverage {
+    type Input = f64;
+    type Output = f64;
+}
+
+impl Validate for TypedMovingAverage {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for TypedMovingAverage {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match self {
+            Self::Simple(sma) => sma.execute(input, execution_context),
+            Self::Exponential(ema) => ema.execute(input, execution_context),
+        }
+    }
+}
+
+impl Current for TypedMovingAverage {
+    fn current(&self) -> Self::Output {
+        match self {
+            Self::Simple(sma) => sma.current(),
+            Self::Exponential(ema) => ema.current(),
+        }
+    }
+}
+
+impl WarmUp for TypedMovingAverage {
+    fn warmup_remaining(&self) -> usize {
+        match self {
+            Self::Simple(sma) => sma.warmup_remaining(),
+            Self::Exponential(ema) => ema.warmup_remaining(),
+        }
+    }
+}
+
+impl Periodic for TypedMovingAverage {
+    fn period(&self) -> usize {
+        match self {
+            Self::Simple(sma) => sma.period(),
+            Self::Exponential(ema) => ema.period(),
+        }
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::{DequeMathExt, DequeMathExtF64},
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        chained_warmup, warmed_up, Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState,
+        Periodic, Validate, WarmUp,
+    },
+};
+
+/// How a [`Normalized`] signal rescales the outputs of its signal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Normalization {
+    /// The position of the output between the minimum and maximum of the window, from 0 at the minimum to 1 at the
+    /// maximum, 0.5 when every output in the window is the same.
+    MinMax,
+    /// The number of population standard deviations the output is from the mean of the window, 0 when every output in
+    /// the window is the same.
+    ZScore,
+}
+
+crate::inspect::debug_state!(Normalization);
+
+impl Normalization {
+    /// The rescaled value of the back of the window.
+    fn scale(&self, values: &VecDeque<f64>) -> f64 {
+        match self {
+            Self::MinMax => {
+                let range = values.value_range();
+                match (values.back(), range > 0.0) {
+                    (Some(value), true) => (value - values.min()) / range,
+                    _ => 0.5,
+                }
+            }
+            Self::ZScore => values.zscore_last(),
+        }
+    }
+}
+
+/// # Normalized
+/// Container for rescaling the outputs of a signal against a rolling window of its outputs
+///
+/// Each input is applied to the signal and its output is rescaled by the [`Normalization`] against the last `period`
+/// outputs, including itself, so indicators on different scales such as an RSI and a MACD can be combined into a
+/// composite score. Outputs while the signal is warming up are skipped, so the output is `None` until the window has
+/// been filled with outputs since the signal warmed up.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Normalization, Normalized, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // rescale a Simple Moving Average with a period of 2 between the minimum and maximum of its last 3 outputs
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut normalized = Normalized::new(sma, Normalization::MinMax, 3).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(normalized.apply(2.0), None);
+/// assert_eq!(normalized.apply(4.0), None);
+/// assert_eq!(normalized.apply(6.0), None);
+/// assert_eq!(normalized.apply(2.0), Some(0.5));
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(normalized.evaluate(6.0), Some(0.0));
+///
+/// // fetch the current value of the signal
+/// assert_eq!(normalized.current(), Some(0.5));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Normalized<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    signal: S,
+    normalization: Normalization,
+    period: usize,
+    /// The last `period` outputs of the signal.
+    values: VecDeque<f64>,
+    current: Option<f64>,
+}
+
+impl<S> IoState for Normalized<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    type Input = S::Input;
+    /// Output is the rescaled output of the signal, once the window has been filled
+    type Output = Option<f64>;
+}
+
+impl<S> Normalized<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    /// Create a new Normalized instance
+    /// # Arguments
+    /// * `signal` - The signal to rescale the outputs of
+    /// * `normalization` - How the outputs are rescaled
+    /// * `period` - The number of outputs in the window the outputs are rescaled against, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{Normalization, Normalized, RelativeStrengthIndex};
+    ///
+    /// let normalized = Normalized::new(RelativeStrengthIndex::standard(), Normalization::ZScore, 50);
+    /// assert!(normalized.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::{Normalization, Normalized, RelativeStrengthIndex};
+    ///
+    /// let normalized = Normalized::new(RelativeStrengthIndex::standard(), Normalization::ZScore, 0);
+    ///
+    /// assert!(normalized.is_err());
+    /// ```
+    pub fn new(signal: S, normalization: Normalization, period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                signal,
+                normalization,
+                period,
+                values: VecDeque::with_capacity(period + 1),
+                current: None,
+            }),
+        }
+    }
+
+    /// The signal the outputs are rescaled of
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// How the outputs are rescaled
+    pub fn normalization(&self) -> Normalization {
+        self.normalization
+    }
+}
+
+impl<S> Validate for Normalized<S>
+where
+    S: Executable + WarmUp + Validate,
+    S::Output: ChainValue<f64>,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        self.signal.validate(input)
+    }
+}
+
+impl<S> Executable for Normalized<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let output = self.signal.execute(input, execution_context).into_input();
+        let Some(value) = output.filter(|_| warmed_up(&self.signal, execution_context)) else {
+            if let ExecutionContext::Apply = execution_context {
+                self.current = None;
+            }
+            return None;
+        };
+        let mut values = match execution_context {
+            ExecutionContext::Apply => std::mem::take(&mut self.values),
+            ExecutionContext::Evaluate => self.values.clone(),
+        };
+        values.push_back(value);
+        if values.len() > self.period {
+            values.pop_front();
+        }
+        let result = match values.len() < self.period {
+            true => None,
+            false => Some(self.normalization.scale(&values)),
+        };
+        if let ExecutionContext::Apply = execution_context {
+            self.values = values;
+            self.current = result;
+        }
+        result
+    }
+}
+
+impl<S> Current for Normalized<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<S> WarmUp for Normalized<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ChainValue<f64>,
+{
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(
+            self.signal.warmup_remaining(),
+            self.period - self.values.len(),
+        )
+    }
+}
+
+impl<S> Periodic for Normalized<S>
+where
+    S: Executable + WarmUp + Periodic,
+    S::Output: ChainValue<f64>,
+{
+    fn period(&self) -> usize {
+        self.period.max(self.signal.period())
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.signal.effective_lookback() + self.period - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{CumulativeSum, Momentum, SimpleMovingAverage};
+
+    #[test]
+    fn test_min_max() {
+        let mut normalized = Normalized::new(CumulativeSum::new(), Normalization::MinMax, 2).unwrap();
+        assert_eq!(normalized.apply(1.0), None);
+        assert_eq!(normalized.apply(1.0), Some(1.0));
+        assert_eq!(normalized.apply(-2.0), Some(0.0));
+        // a flat window is in the middle
+        assert_eq!(normalized.apply(0.0), Some(0.5));
+    }
+
+    #[test]
+    fn test_z_score() {
+        let mut normalized = Normalized::new(CumulativeSum::new(), Normalization::ZScore, 4).unwrap();
+        let outputs: Vec<_> = [2.0, 2.0, 0.0, 4.0].into_iter().map(|value| normalized.apply(value)).collect();
+        // the window of 2, 4, 4, 8 has a mean of 4.5 and a variance of 4.75
+        assert_eq!(outputs[..3], [None, None, None]);
+        assert!((outputs[3].unwrap() - 3.5 / 4.75_f64.sqrt()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_warming_up_outputs() {
+        // the `None` outputs of the momentum are skipped
+        let mut normalized = Normalized::new(Momentum::new(1).unwrap(), Normalization::MinMax, 2).unwrap();
+        assert_eq!(normalized.warmup_remaining(), 3);
+        assert_eq!(normalized.apply(1.0), None);
+        assert_eq!(normalized.apply(2.0), None);
+        assert_eq!(normalized.apply(4.0), Some(1.0));
+        assert_eq!(normalized.warmup_remaining(), 0);
+        assert_eq!(normalized.evaluate(4.0), Some(0.0));
+        assert_eq!(normalized.current(), Some(1.0));
+    }
+
+    #[test]
+    fn test_lookback() {
+        let sma = SimpleMovingAverage::new(3).unwrap();
+        let normalized = Normalized::new(sma, Normalization::MinMax, 5).unwrap();
+        assert_eq!(normalized.period(), 5);
+        assert_eq!(normalized.effective_lookback(), 7);
+    }
+
+    #[test]
+    fn test_ready_at_lookback() {
+        // the partial window averages of the moving average are skipped
+        let sma = SimpleMovingAverage::new(3).unwrap();
+        let mut normalized = Normalized::new(sma, Normalization::MinMax, 4).unwrap();
+        let lookback = normalized.effective_lookback();
+        assert_eq!(lookback, 6);
+        for step in 1..=8 {
+            assert_eq!(normalized.warmup_remaining(), lookback.saturating_sub(step - 1));
+            let output = normalized.apply(step as f64);
+            assert_eq!(output.is_some(), step >= lookback);
+        }
+        assert_eq!(normalized.current(), Some(1.0));
+    }
+}
@@ -0,0 +1,219 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+use rayon::prelude::*;
+
+use crate::traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, WarmUp};
+
+/// # Parallel Signal Set
+/// Container for many independent signals driven by the same inputs, spread across threads with rayon
+///
+/// Each input is passed to every signal in parallel and the outputs are returned in the order the signals were added.
+/// Signals with different types can be stored together by using a signal type that wraps them, such as an enum of the
+/// signals needed.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{ParallelSignalSet, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a set of Simple Moving Averages with periods of 1 to 3
+/// let mut set = ParallelSignalSet::new(
+///     (1..=3).map(|period| SimpleMovingAverage::new(period).unwrap()).collect(),
+/// );
+///
+/// // apply some values to every signal and check their output
+/// assert_eq!(set.apply(3.0), vec![3.0, 3.0, 3.0]);
+/// assert_eq!(set.apply(6.0), vec![6.0, 4.5, 4.5]);
+///
+/// // evaluate some values, these won't affect the internal state of the signals
+/// assert_eq!(set.evaluate(9.0), vec![9.0, 7.5, 6.0]);
+///
+/// // fetch the current value of every signal
+/// assert_eq!(set.current(), vec![6.0, 4.5, 4.5]);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParallelSignalSet<S>
+where
+    S: Executable + Send,
+    S::Input: Clone + Sync,
+    S::Output: Send,
+{
+    signals: Vec<S>,
+}
+
+impl<S> IoState for ParallelSignalSet<S>
+where
+    S: Executable + Send,
+    S::Input: Clone + Sync,
+    S::Output: Send,
+{
+    type Input = S::Input;
+    /// Output is the output of each signal, in the order the signals were added
+    type Output = Vec<S::Output>;
+}
+
+impl<S> ParallelSignalSet<S>
+where
+    S: Executable + Send,
+    S::Input: Clone + Sync,
+    S::Output: Send,
+{
+    /// Create a new Parallel Signal Set instance
+    /// # Arguments
+    /// * `signals` - The signals to drive, their outputs are returned in the same order
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{ParallelSignalSet, RelativeStrengthIndex};
+    ///
+    /// let set = ParallelSignalSet::new(vec![RelativeStrengthIndex::standard(); 100]);
+    /// assert_eq!(set.len(), 100);
+    /// ```
+    pub fn new(signals: Vec<S>) -> Self {
+        Self { signals }
+    }
+
+    /// Add a signal to the end of the set
+    pub fn push(&mut self, signal: S) {
+        self.signals.push(signal);
+    }
+
+    /// The signals in the set, in the order they were added
+    pub fn signals(&self) -> &[S] {
+        &self.signals
+    }
+
+    /// The number of signals in the set
+    pub fn len(&self) -> usize {
+        self.signals.len()
+    }
+
+    /// Whether the set has no signals
+    pub fn is_empty(&self) -> bool {
+        self.signals.is_empty()
+    }
+
+    /// Applies every input in order to each signal and returns the outputs of each signal, each signal working through
+    /// the whole batch on its own thread.
+    ///
+    /// This is the same as applying each input to the set in turn, but only synchronises the threads once for the
+    /// batch. The outputs are grouped by signal rather than by input.
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{Momentum, ParallelSignalSet};
+    ///
+    /// let mut set = ParallelSignalSet::new(vec![Momentum::new(1).unwrap(), Momentum::new(2).unwrap()]);
+    /// let outputs = set.apply_slice_by_signal(&[1.0, 2.0, 4.0]);
+    /// assert_eq!(outputs[0], vec![None, Some(1.0), Some(2.0)]);
+    /// assert_eq!(outputs[1], vec![None, None, Some(3.0)]);
+    /// ```
+    pub fn apply_slice_by_signal(&mut self, inputs: &[S::Input]) -> Vec<Vec<S::Output>> {
+        self.signals
+            .par_iter_mut()
+            .map(|signal| signal.execute_slice(inputs))
+            .collect()
+    }
+}
+
+impl<S> Executable for ParallelSignalSet<S>
+where
+    S: Executable + Send,
+    S::Input: Clone + Sync,
+    S::Output: Send,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        self.signals
+            .par_iter_mut()
+            .map(|signal| signal.execute(input.clone(), execution_context))
+            .collect()
+    }
+}
+
+impl<S> Current for ParallelSignalSet<S>
+where
+    S: Executable + Current + Send,
+    S::Input: Clone + Sync,
+    S::Output: Send,
+{
+    fn current(&self) -> Self::Output {
+        self.signals.iter().map(Current::current).collect()
+    }
+}
+
+impl<S> WarmUp for ParallelSignalSet<S>
+where
+    S: Executable + WarmUp + Send,
+    S::Input: Clone + Sync,
+    S::Output: Send,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.signals
+            .iter()
+            .map(WarmUp::warmup_remaining)
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{ExponentialMovingAverage, RelativeStrengthIndex};
+
+    #[test]
+    fn test_matches_sequential() {
+        let signals = (1..=16)
+            .map(|period| RelativeStrengthIndex::new(period, 0).unwrap())
+            .collect::<Vec<_>>();
+        let mut sequential = signals.clone();
+        let mut set = ParallelSignalSet::new(signals);
+        for input in [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 8.0, 7.0, 9.0, 6.0, 5.0, 7.0] {
+            let expected = sequential
+                .iter_mut()
+                .map(|signal| signal.apply(input))
+                .collect::<Vec<_>>();
+            assert_eq!(set.apply(input), expected);
+        }
+        assert_eq!(
+            set.current(),
+            sequential.iter().map(Current::current).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_apply_slice_by_signal() {
+        let signals = vec![
+            ExponentialMovingAverage::new(2).unwrap(),
+            ExponentialMovingAverage::new(3).unwrap(),
+        ];
+        let mut sequential = ParallelSignalSet::new(signals.clone());
+        let mut set = ParallelSignalSet::new(signals);
+        let inputs = [2.0, 4.0, 8.0, 1.0];
+        let by_input = inputs
+            .iter()
+            .map(|&input| sequential.apply(input))
+            .collect::<Vec<_>>();
+        let by_signal = set.apply_slice_by_signal(&inputs);
+        for (i, outputs) in by_input.iter().enumerate() {
+            assert_eq!(outputs, &vec![by_signal[0][i], by_signal[1][i]]);
+        }
+        assert_eq!(set.current(), sequential.current());
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut set = ParallelSignalSet::new(vec![
+            RelativeStrengthIndex::new(2, 0).unwrap(),
+            RelativeStrengthIndex::new(4, 0).unwrap(),
+        ]);
+        assert_eq!(set.warmup_remaining(), 5);
+        set.apply(1.0);
+        assert_eq!(set.warmup_remaining(), 4);
+        assert!(ParallelSignalSet::<RelativeStrengthIndex>::new(vec![]).is_ready());
+    }
+}
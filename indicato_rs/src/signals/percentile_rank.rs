@@ -0,0 +1,233 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequeMathExtF64,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// # Percentile Rank
+/// Container for rolling Percentile Rank aggregation
+///
+/// The percentile rank is the percentage of the previous `p` inputs that are strictly less than the current input.
+/// The first `p` entries will produce `None` as the period of previous inputs is being filled, after which the
+/// following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mn>100</mn>
+///         <mo>×</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mo>|</mo>
+///                 <mo>{</mo>
+///                 <mi>k</mi>
+///                 <mo>∈</mo>
+///                 <mo>{</mo>
+///                 <mn>n-p</mn>
+///                 <mo>..</mo>
+///                 <mn>n-1</mn>
+///                 <mo>}</mo>
+///                 <mo>:</mo>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mi>k</mi>
+///                 </msub>
+///                 <mo>&lt;</mo>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mn>n</mn>
+///                 </msub>
+///                 <mo>}</mo>
+///                 <mo>|</mo>
+///             </mrow>
+///             <mi>p</mi>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period and `i` is the input.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::PercentileRank;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Percentile Rank with a period of 2
+/// let mut rank = PercentileRank::new(2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(rank.apply(1.0), None);
+/// assert_eq!(rank.apply(3.0), None);
+/// assert_eq!(rank.apply(2.0), Some(50.0));
+/// assert_eq!(rank.apply(4.0), Some(100.0));
+///
+/// // evaluate some values, these won't affect the internal state of the Percentile Rank
+/// assert_eq!(rank.evaluate(1.0), Some(0.0));
+///
+/// // fetch the current value of the Percentile Rank
+/// assert_eq!(rank.current(), Some(100.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PercentileRank {
+    period: usize,
+    /// The previous `period` inputs.
+    values: VecDeque<f64>,
+    current: Option<f64>,
+}
+
+impl PercentileRank {
+    /// Create a new Percentile Rank instance
+    /// # Arguments
+    /// * `period` - The number of previous inputs the current input is ranked against, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::PercentileRank;
+    ///
+    /// let rank = PercentileRank::new(100);
+    /// assert!(rank.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::PercentileRank;
+    ///
+    /// let rank = PercentileRank::new(0);
+    ///
+    /// assert!(rank.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+                current: None,
+            }),
+        }
+    }
+}
+
+impl IoState for PercentileRank {
+    type Input = f64;
+    type Output = Option<f64>;
+}
+
+impl Validate for PercentileRank {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for PercentileRank {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let result = match self.values.len() < self.period {
+            true => None,
+            false => Some(self.values.percent_rank(input)),
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                self.current = result;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl Current for PercentileRank {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for PercentileRank {
+    fn warmup_remaining(&self) -> usize {
+        match self.current {
+            Some(_) => 0,
+            None => self.period + 1 - self.values.len(),
+        }
+    }
+}
+
+impl Periodic for PercentileRank {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut rank = PercentileRank::new(4).unwrap();
+        assert_eq!(rank.apply(3.0), None);
+        assert_eq!(rank.apply(1.0), None);
+        assert_eq!(rank.apply(4.0), None);
+        assert_eq!(rank.apply(2.0), None);
+        assert_eq!(rank.apply(3.0), Some(50.0));
+        assert_eq!(rank.apply(0.0), Some(0.0));
+        assert_eq!(rank.apply(5.0), Some(100.0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut rank = PercentileRank::new(2).unwrap();
+        assert_eq!(rank.apply(1.0), None);
+        assert_eq!(rank.evaluate(3.0), None);
+        assert_eq!(rank.apply(3.0), None);
+        assert_eq!(rank.evaluate(2.0), Some(50.0));
+        assert_eq!(rank.evaluate(0.0), Some(0.0));
+        assert_eq!(rank.apply(4.0), Some(100.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut rank = PercentileRank::new(2).unwrap();
+        assert_eq!(rank.current(), None);
+        rank.apply(1.0);
+        rank.apply(3.0);
+        assert_eq!(rank.current(), None);
+        rank.apply(2.0);
+        rank.evaluate(4.0);
+        assert_eq!(rank.current(), Some(50.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let rank = PercentileRank::new(0);
+        assert!(rank.is_err());
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// A confirmed swing high or swing low produced by the [`PivotDetector`] signal.
+#[derive(Debug, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Pivot {
+    /// The high of a swing high, or the low of a swing low.
+    pub price: f64,
+    /// The number of steps before the current step at which the pivot occurred.
+    pub offset: usize,
+}
+
+/// # Pivot Detector
+/// Container for swing high and swing low detection
+///
+/// A bar is a swing high when its high is strictly greater than the highs of the `l` bars before it and the `r` bars
+/// after it, and a swing low when its low is strictly less than the lows of the `l` bars before it and the `r` bars
+/// after it:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>h</mi>
+///             <mn>n-r</mn>
+///         </msub>
+///         <mo>&gt;</mo>
+///         <msub>
+///             <mi>h</mi>
+///             <mn>k</mn>
+///         </msub>
+///         <mspace width="1em"/>
+///         <mo>∀</mo>
+///         <mi>k</mi>
+///         <mo>∈</mo>
+///         <mo>{</mo>
+///         <mn>n-r-l</mn>
+///         <mo>..</mo>
+///         <mn>n</mn>
+///         <mo>}</mo>
+///         <mo>∖</mo>
+///         <mo>{</mo>
+///         <mn>n-r</mn>
+///         <mo>}</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `n` is the current step, `l` is the left strength, `r` is the right strength and `h` is the high, the
+/// swing low condition is the same with the lows and the inequality reversed.
+///
+/// A pivot can only be confirmed once the `r` bars after it have arrived, so pivots are reported retrospectively
+/// with the offset of the pivot bar from the current step, which is always `r`. The first `l + r` entries will
+/// produce no pivots as the window is being filled.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Pivot, PivotDetector};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Pivot Detector with a left strength of 2 and right strength of 1
+/// let mut pivots = PivotDetector::new(2, 1).unwrap();
+///
+/// // apply some (high, low) values and check their output
+/// assert_eq!(pivots.apply((1.0, 0.0)), (None, None));
+/// assert_eq!(pivots.apply((2.0, 1.0)), (None, None));
+/// assert_eq!(pivots.apply((5.0, 3.0)), (None, None));
+/// assert_eq!(pivots.apply((3.0, 2.0)), (Some(Pivot { price: 5.0, offset: 1 }), None));
+///
+/// // evaluate some values, these won't affect the internal state of the Pivot Detector
+/// assert_eq!(pivots.evaluate((6.0, 2.0)), (None, None));
+///
+/// // fetch the current value of the Pivot Detector
+/// assert_eq!(pivots.current(), (Some(Pivot { price: 5.0, offset: 1 }), None));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PivotDetector {
+    left: usize,
+    right: usize,
+    values: VecDeque<(f64, f64)>,
+    current: (Option<Pivot>, Option<Pivot>),
+}
+
+impl PivotDetector {
+    /// Create a new Pivot Detector instance
+    /// # Arguments
+    /// * `left` - The number of bars before a pivot that it must exceed, must be greater than 0
+    /// * `right` - The number of bars after a pivot that it must exceed, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::PivotDetector;
+    ///
+    /// let pivots = PivotDetector::new(5, 5);
+    /// assert!(pivots.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if either strength is 0
+    /// ```
+    /// use indicato_rs::signals::PivotDetector;
+    ///
+    /// let pivots = PivotDetector::new(5, 0);
+    ///
+    /// assert!(pivots.is_err());
+    /// ```
+    pub fn new(left: usize, right: usize) -> Result<Self, FinError> {
+        match left == 0 || right == 0 {
+            true => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Strengths must be greater than 0",
+            )),
+            false => Ok(Self {
+                left,
+                right,
+                values: VecDeque::with_capacity(left + right + 2),
+                current: (None, None),
+            }),
+        }
+    }
+
+    fn detect(&self, values: &VecDeque<(f64, f64)>) -> (Option<Pivot>, Option<Pivot>) {
+        if values.len() < self.left + self.right + 1 {
+            return (None, None);
+        }
+        let (high, low) = values[self.left];
+        let mut others = values
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| *index != self.left)
+            .map(|(_, value)| value);
+        let is_high = others.clone().all(|(other_high, _)| high > *other_high);
+        let is_low = others.all(|(_, other_low)| low < *other_low);
+        let pivot = |price| Pivot {
+            price,
+            offset: self.right,
+        };
+        (is_high.then(|| pivot(high)), is_low.then(|| pivot(low)))
+    }
+}
+
+impl IoState for PivotDetector {
+    /// Input is a tuple of (high, low)
+    type Input = (f64, f64);
+    /// Output is a tuple of (swing high, swing low)
+    type Output = (Option<Pivot>, Option<Pivot>);
+}
+
+impl Validate for PivotDetector {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (high, low) = *input;
+        validation::high_low(high, low)
+    }
+}
+
+impl Executable for PivotDetector {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.left + self.right + 1 {
+                    self.values.pop_front();
+                }
+                self.current = self.detect(&self.values);
+                self.current
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(input);
+                if values.len() > self.left + self.right + 1 {
+                    values.pop_front();
+                }
+                self.detect(&values)
+            }
+        }
+    }
+}
+
+impl Current for PivotDetector {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for PivotDetector {
+    fn warmup_remaining(&self) -> usize {
+        (self.left + self.right + 1).saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for PivotDetector {
+    fn period(&self) -> usize {
+        self.left + self.right + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pivot(price: f64) -> Option<Pivot> {
+        Some(Pivot { price, offset: 1 })
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut pivots = PivotDetector::new(2, 1).unwrap();
+        assert_eq!(pivots.apply((1.0, 0.0)), (None, None));
+        assert_eq!(pivots.apply((2.0, 1.0)), (None, None));
+        assert_eq!(pivots.apply((5.0, 3.0)), (None, None));
+        assert_eq!(pivots.apply((3.0, 2.0)), (pivot(5.0), None));
+        assert_eq!(pivots.apply((4.0, 1.0)), (None, None));
+        assert_eq!(pivots.apply((2.0, 0.5)), (None, None));
+        assert_eq!(pivots.apply((6.0, 2.0)), (None, pivot(0.5)));
+    }
+
+    #[test]
+    fn test_equal_values_are_not_pivots() {
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+        assert_eq!(pivots.apply((2.0, 1.0)), (None, None));
+        assert_eq!(pivots.apply((2.0, 1.0)), (None, None));
+        assert_eq!(pivots.apply((1.0, 2.0)), (None, None));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+        assert_eq!(pivots.apply((1.0, 1.0)), (None, None));
+        assert_eq!(pivots.apply((2.0, 0.0)), (None, None));
+        assert_eq!(pivots.evaluate((1.0, 1.0)), (pivot(2.0), pivot(0.0)));
+        assert_eq!(pivots.evaluate((3.0, -1.0)), (None, None));
+        assert_eq!(pivots.apply((1.0, 1.0)), (pivot(2.0), pivot(0.0)));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut pivots = PivotDetector::new(1, 1).unwrap();
+        assert_eq!(pivots.current(), (None, None));
+        pivots.apply((1.0, 1.0));
+        pivots.apply((2.0, 0.0));
+        pivots.apply((1.0, 1.0));
+        pivots.evaluate((3.0, -1.0));
+        assert_eq!(pivots.current(), (pivot(2.0), pivot(0.0)));
+    }
+
+    #[test]
+    fn test_invalid_strength() {
+        assert!(PivotDetector::new(0, 1).is_err());
+        assert!(PivotDetector::new(1, 0).is_err());
+    }
+}
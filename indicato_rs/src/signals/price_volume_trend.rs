@@ -0,0 +1,185 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::fin_error::FinError;
+use crate::traits::{
+    Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp,
+};
+use crate::validation;
+
+/// # Price Volume Trend
+/// Container for Price Volume Trend (PVT) aggregation
+///
+/// The Price Volume Trend is a cumulative total of the volume weighted by the percentage change in close. The first
+/// entry seeds the previous close and produces 0, after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>v</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>⋅</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub><mi>c</mi><mn>n</mn></msub>
+///                 <mo>−</mo>
+///                 <msub><mi>c</mi><mn>n-1</mn></msub>
+///             </mrow>
+///             <msub><mi>c</mi><mn>n-1</mn></msub>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `c` is the close value and `v` is the volume.
+/// If the previous close is 0 the trend is left unchanged.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::PriceVolumeTrend;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Price Volume Trend
+/// let mut pvt = PriceVolumeTrend::new();
+///
+/// // apply some values and check their output
+/// assert_eq!(pvt.apply((10.0, 100.0)), 0.0);
+/// assert_eq!(pvt.apply((11.0, 200.0)), 20.0);
+/// assert_eq!(pvt.apply((5.5, 100.0)), -30.0);
+///
+/// // evaluate some values, these won't affect the internal state of the PVT
+/// assert_eq!(pvt.evaluate((11.0, 100.0)), 70.0);
+///
+/// // fetch the current value of the PVT
+/// assert_eq!(pvt.current(), -30.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PriceVolumeTrend {
+    current: f64,
+    /// The previous close value.
+    previous: Option<f64>,
+}
+
+impl PriceVolumeTrend {
+    /// Create a new Price Volume Trend instance
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::PriceVolumeTrend;
+    /// use indicato_rs::traits::Current;
+    ///
+    /// let pvt = PriceVolumeTrend::new();
+    /// assert_eq!(pvt.current(), 0.0);
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            current: 0.0,
+            previous: None,
+        }
+    }
+}
+
+impl IoState for PriceVolumeTrend {
+    /// Input is a tuple of (close, volume)
+    type Input = (f64, f64);
+    type Output = f64;
+}
+
+impl Validate for PriceVolumeTrend {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (close, volume) = *input;
+        validation::value(close, "Close")?;
+        validation::volume(volume)
+    }
+}
+
+impl Executable for PriceVolumeTrend {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (close, volume) = input;
+        let result = match self.previous {
+            Some(previous) if previous != 0.0 => {
+                self.current + volume * (close - previous) / previous
+            }
+            _ => self.current,
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.current = result;
+                self.previous = Some(close);
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl Current for PriceVolumeTrend {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for PriceVolumeTrend {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut pvt = PriceVolumeTrend::new();
+        assert_eq!(pvt.apply((10.0, 100.0)), 0.0);
+        assert_eq!(pvt.apply((11.0, 200.0)), 20.0);
+        assert_eq!(pvt.apply((5.5, 100.0)), -30.0);
+        assert_eq!(pvt.apply((11.0, 100.0)), 70.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut pvt = PriceVolumeTrend::new();
+        assert_eq!(pvt.evaluate((10.0, 100.0)), 0.0);
+        assert_eq!(pvt.apply((10.0, 100.0)), 0.0);
+        assert_eq!(pvt.evaluate((11.0, 200.0)), 20.0);
+        assert_eq!(pvt.evaluate((5.0, 200.0)), -100.0);
+        assert_eq!(pvt.apply((11.0, 200.0)), 20.0);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut pvt = PriceVolumeTrend::default();
+        assert_eq!(pvt.current(), 0.0);
+        pvt.apply((10.0, 100.0));
+        pvt.apply((11.0, 200.0));
+        pvt.evaluate((5.0, 200.0));
+        assert_eq!(pvt.current(), 20.0);
+    }
+
+    #[test]
+    fn test_zero_previous_close() {
+        let mut pvt = PriceVolumeTrend::new();
+        assert_eq!(pvt.apply((0.0, 100.0)), 0.0);
+        assert_eq!(pvt.apply((10.0, 100.0)), 0.0);
+        assert_eq!(pvt.apply((20.0, 100.0)), 100.0);
+    }
+}
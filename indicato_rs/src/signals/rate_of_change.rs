@@ -0,0 +1,268 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+use num_traits::Float;
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+fn rate_of_change<T: Float>(input: T, previous: T) -> T {
+    match previous.is_zero() {
+        true => T::zero(),
+        false => T::from(100.0).unwrap_or_else(T::nan) * (input - previous) / previous,
+    }
+}
+
+/// # Rate of Change
+/// Container for Rate of Change (ROC) aggregation
+///
+/// The rate of change is the percentage change between the current input and the input `p` steps ago, the first `p`
+/// entries will produce `None` as there is no value to compare against yet. After which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mn>100</mn>
+///         <mo>⋅</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mn>n</mn>
+///                 </msub>
+///                 <mo>−</mo>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mn>n-p</mn>
+///                 </msub>
+///             </mrow>
+///             <msub>
+///                 <mi>i</mi>
+///                 <mn>n-p</mn>
+///             </msub>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period of the rate of change and `i` is the input.
+/// If the input `p` steps ago is 0 the output will be 0.
+///
+/// The aggregation is generic over the float type of its inputs and defaults to `f64`.
+/// `new` creates an `f64` aggregation, other types are created with [`for_type`](RateOfChange::for_type).
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::RateOfChange;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Rate of Change with a period of 2
+/// let mut roc = RateOfChange::new(2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(roc.apply(10.0), None);
+/// assert_eq!(roc.apply(20.0), None);
+/// assert_eq!(roc.apply(15.0), Some(50.0));
+/// assert_eq!(roc.apply(10.0), Some(-50.0));
+///
+/// // evaluate some values, these won't affect the internal state of the ROC
+/// assert_eq!(roc.evaluate(30.0), Some(100.0));
+///
+/// // fetch the current value of the ROC
+/// assert_eq!(roc.current(), Some(-50.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateOfChange<T: Float = f64> {
+    period: usize,
+    /// The last `period + 1` inputs, the front is the input `period` steps before the back.
+    values: VecDeque<T>,
+}
+
+impl<T: Float> IoState for RateOfChange<T> {
+    type Input = T;
+    type Output = Option<T>;
+}
+
+impl<T: Float> RateOfChange<T> {
+    /// Create a new Rate of Change instance taking inputs of type `T`, such as `f32`
+    /// # Arguments
+    /// * `period` - The number of steps to look back when calculating the rate of change, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::RateOfChange;
+    ///
+    /// let roc = RateOfChange::<f32>::for_type(10);
+    /// assert!(roc.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::RateOfChange;
+    ///
+    /// let roc = RateOfChange::<f32>::for_type(0);
+    ///
+    /// assert!(roc.is_err());
+    /// ```
+    pub fn for_type(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 2),
+            }),
+        }
+    }
+}
+
+impl RateOfChange {
+    /// Create a new Rate of Change instance
+    /// # Arguments
+    /// * `period` - The number of steps to look back when calculating the rate of change, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::RateOfChange;
+    ///
+    /// let roc = RateOfChange::new(10);
+    /// assert!(roc.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::RateOfChange;
+    ///
+    /// let roc = RateOfChange::new(0);
+    ///
+    /// assert!(roc.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
+impl<T: Float> Validate for RateOfChange<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<T: Float> Executable for RateOfChange<T> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let result = match self.values.len() >= self.period {
+            true => Some(rate_of_change(
+                input,
+                self.values[self.values.len() - self.period],
+            )),
+            false => None,
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period + 1 {
+                    self.values.pop_front();
+                }
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl<T: Float> Current for RateOfChange<T> {
+    fn current(&self) -> Self::Output {
+        match self.values.len() > self.period {
+            true => Some(rate_of_change(
+                self.values[self.values.len() - 1],
+                self.values[0],
+            )),
+            false => None,
+        }
+    }
+}
+
+impl<T: Float> WarmUp for RateOfChange<T> {
+    fn warmup_remaining(&self) -> usize {
+        (self.period + 1).saturating_sub(self.values.len())
+    }
+}
+
+impl<T: Float> Periodic for RateOfChange<T> {
+    fn period(&self) -> usize {
+        self.period
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.period + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut roc = RateOfChange::new(2).unwrap();
+        assert_eq!(roc.apply(10.0), None);
+        assert_eq!(roc.apply(20.0), None);
+        assert_eq!(roc.apply(15.0), Some(50.0));
+        assert_eq!(roc.apply(10.0), Some(-50.0));
+        assert_eq!(roc.apply(30.0), Some(100.0));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut roc = RateOfChange::new(2).unwrap();
+        assert_eq!(roc.apply(10.0), None);
+        assert_eq!(roc.evaluate(20.0), None);
+        assert_eq!(roc.apply(20.0), None);
+        assert_eq!(roc.evaluate(5.0), Some(-50.0));
+        assert_eq!(roc.apply(15.0), Some(50.0));
+        assert_eq!(roc.evaluate(30.0), Some(50.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut roc = RateOfChange::new(2).unwrap();
+        assert_eq!(roc.current(), None);
+        assert_eq!(roc.apply(10.0), None);
+        assert_eq!(roc.apply(20.0), None);
+        assert_eq!(roc.current(), None);
+        assert_eq!(roc.apply(15.0), Some(50.0));
+        assert_eq!(roc.current(), Some(50.0));
+    }
+
+    #[test]
+    fn test_zero_previous() {
+        let mut roc = RateOfChange::new(1).unwrap();
+        assert_eq!(roc.apply(0.0), None);
+        assert_eq!(roc.apply(10.0), Some(0.0));
+        assert_eq!(roc.current(), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let roc = RateOfChange::new(0);
+        assert!(roc.is_err());
+    }
+}
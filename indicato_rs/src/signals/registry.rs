@@ -0,0 +1,428 @@
+use std::collections::HashMap;
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::Combine,
+};
+
+use super::{
+    BoxedSignal, CumulativeSum, ExponentialMovingAverage, FractalAdaptiveMovingAverage, HalfLife,
+    HistoricalVar, HistoricalVolatility, MaximumPeriod, MinimumPeriod, Momentum,
+    MovingAverageConvergenceDivergence, PercentileRank, RateOfChange, RelativeStrengthIndex,
+    RollingMedian, SavitzkyGolay, SimpleMovingAverage, SuperSmoother, WeightedMovingAverage,
+    WildersSmoothing,
+};
+
+/// The named parameters a signal is constructed from, such as `period`.
+pub type Parameters = HashMap<String, f64>;
+
+/// A signal constructed by the registry, signals that produce a value for every input are wrapped in `Some` so every
+/// signal has the same output type.
+pub type RegisteredSignal = BoxedSignal<f64, Option<f64>>;
+
+/// A function constructing a signal from its parameters.
+pub type SignalConstructor =
+    Box<dyn Fn(&Parameters) -> Result<RegisteredSignal, FinError> + Send + Sync>;
+
+fn parameter(parameters: &Parameters, name: &str) -> Result<f64, FinError> {
+    parameters.get(name).copied().ok_or_else(|| {
+        FinError::new(
+            FinErrorType::InvalidInput,
+            &format!("Missing parameter `{}`", name),
+        )
+    })
+}
+
+fn count(parameters: &Parameters, name: &str) -> Result<usize, FinError> {
+    let value = parameter(parameters, name)?;
+    match value >= 0.0 && value.fract() == 0.0 {
+        true => Ok(value as usize),
+        false => Err(FinError::new(
+            FinErrorType::InvalidInput,
+            &format!("Parameter `{}` must be a whole number", name),
+        )),
+    }
+}
+
+fn optional_count(parameters: &Parameters, name: &str, default: usize) -> Result<usize, FinError> {
+    match parameters.contains_key(name) {
+        true => count(parameters, name),
+        false => Ok(default),
+    }
+}
+
+/// # Signal Registry
+/// Constructs signals from a name and a map of parameters
+///
+/// Configuration driven systems can instantiate signals without knowing which signals are used at compile time. The
+/// registry is created with the built in signals that take a single `f64` input, further signals can be added with
+/// [`register`](SignalRegistry::register).
+///
+/// | Name | Parameters |
+/// |------|------------|
+/// | `cumulative_sum` | `decay` (optional, defaults to 1) |
+/// | `ema` | `period` |
+/// | `frama` | `period` |
+/// | `half_life` | `period` |
+/// | `historical_var` | `period`, `confidence` |
+/// | `historical_volatility` | `period`, `annualization` |
+/// | `macd` | `short_period`, `long_period` |
+/// | `max` | `period` |
+/// | `min` | `period` |
+/// | `momentum` | `period` |
+/// | `percentile_rank` | `period` |
+/// | `roc` | `period` |
+/// | `rolling_median` | `period` |
+/// | `rsi` | `period`, `seed_period` (optional, defaults to 0) |
+/// | `savitzky_golay` | `window`, `order` |
+/// | `sma` | `period` |
+/// | `super_smoother` | `period` |
+/// | `wilders` | `period` |
+/// | `wma` | `period` |
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::registry::{Parameters, SignalRegistry};
+/// use indicato_rs::traits::{Apply, Current};
+///
+/// let registry = SignalRegistry::new();
+///
+/// // create a signal from its name and parameters
+/// let parameters = Parameters::from([("period".to_string(), 2.0)]);
+/// let mut sma = registry.create("sma", &parameters).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(sma.apply(1.0), Some(1.0));
+/// assert_eq!(sma.apply(3.0), Some(2.0));
+/// assert_eq!(sma.current(), Some(2.0));
+///
+/// // unknown names and missing parameters are errors
+/// assert!(registry.create("unknown", &parameters).is_err());
+/// assert!(registry.create("macd", &parameters).is_err());
+/// ```
+pub struct SignalRegistry {
+    constructors: HashMap<String, SignalConstructor>,
+}
+
+impl SignalRegistry {
+    /// Create a new Signal Registry containing the built in signals
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::registry::SignalRegistry;
+    ///
+    /// let registry = SignalRegistry::new();
+    /// assert!(registry.names().contains(&"rsi"));
+    /// ```
+    pub fn new() -> Self {
+        let mut registry = Self::empty();
+        registry.register("cumulative_sum", |parameters| {
+            let sum = match parameters.contains_key("decay") {
+                true => CumulativeSum::with_decay(parameter(parameters, "decay")?)?,
+                false => CumulativeSum::new(),
+            };
+            Ok(BoxedSignal::new(sum.map(Some)))
+        });
+        registry.register("ema", |parameters| {
+            let ema = ExponentialMovingAverage::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(ema.map(Some)))
+        });
+        registry.register("frama", |parameters| {
+            let frama = FractalAdaptiveMovingAverage::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(frama))
+        });
+        registry.register("half_life", |parameters| {
+            let half_life = HalfLife::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(half_life))
+        });
+        registry.register("historical_var", |parameters| {
+            let var = HistoricalVar::new(
+                count(parameters, "period")?,
+                parameter(parameters, "confidence")?,
+            )?;
+            Ok(BoxedSignal::new(var))
+        });
+        registry.register("historical_volatility", |parameters| {
+            let volatility = HistoricalVolatility::new(
+                count(parameters, "period")?,
+                parameter(parameters, "annualization")?,
+            )?;
+            Ok(BoxedSignal::new(volatility))
+        });
+        registry.register("macd", |parameters| {
+            let macd = MovingAverageConvergenceDivergence::new(
+                count(parameters, "short_period")?,
+                count(parameters, "long_period")?,
+            )?;
+            Ok(BoxedSignal::new(macd.map(Some)))
+        });
+        registry.register("max", |parameters| {
+            let max = MaximumPeriod::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(max.map(Some)))
+        });
+        registry.register("min", |parameters| {
+            let min = MinimumPeriod::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(min.map(Some)))
+        });
+        registry.register("momentum", |parameters| {
+            let momentum = Momentum::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(momentum))
+        });
+        registry.register("percentile_rank", |parameters| {
+            let rank = PercentileRank::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(rank))
+        });
+        registry.register("roc", |parameters| {
+            let roc = RateOfChange::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(roc))
+        });
+        registry.register("rolling_median", |parameters| {
+            let median = RollingMedian::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(median.map(Some)))
+        });
+        registry.register("rsi", |parameters| {
+            let rsi = RelativeStrengthIndex::new(
+                count(parameters, "period")?,
+                optional_count(parameters, "seed_period", 0)?,
+            )?;
+            Ok(BoxedSignal::new(rsi))
+        });
+        registry.register("savitzky_golay", |parameters| {
+            let filter =
+                SavitzkyGolay::new(count(parameters, "window")?, count(parameters, "order")?)?;
+            Ok(BoxedSignal::new(filter))
+        });
+        registry.register("sma", |parameters| {
+            let sma = SimpleMovingAverage::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(sma.map(Some)))
+        });
+        registry.register("super_smoother", |parameters| {
+            let smoother = SuperSmoother::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(smoother.map(Some)))
+        });
+        registry.register("wilders", |parameters| {
+            let wilders = WildersSmoothing::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(wilders))
+        });
+        registry.register("wma", |parameters| {
+            let wma = WeightedMovingAverage::new(count(parameters, "period")?)?;
+            Ok(BoxedSignal::new(wma.map(Some)))
+        });
+        registry
+    }
+
+    /// Create a new Signal Registry without any signals
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::registry::SignalRegistry;
+    ///
+    /// let registry = SignalRegistry::empty();
+    /// assert!(registry.names().is_empty());
+    /// ```
+    pub fn empty() -> Self {
+        Self {
+            constructors: HashMap::new(),
+        }
+    }
+
+    /// Register a constructor under a name, replacing any constructor already registered under that name
+    /// # Arguments
+    /// * `name` - The name the signal is created with
+    /// * `constructor` - Constructs the signal from its parameters
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{registry::{Parameters, SignalRegistry}, BoxedSignal, Momentum};
+    /// use indicato_rs::traits::Apply;
+    ///
+    /// let mut registry = SignalRegistry::empty();
+    /// registry.register("change", |_| Ok(BoxedSignal::new(Momentum::new(1)?)));
+    ///
+    /// let mut change = registry.create("change", &Parameters::new()).unwrap();
+    /// assert_eq!(change.apply(1.0), None);
+    /// assert_eq!(change.apply(4.0), Some(3.0));
+    /// ```
+    pub fn register<F>(&mut self, name: &str, constructor: F)
+    where
+        F: Fn(&Parameters) -> Result<RegisteredSignal, FinError> + Send + Sync + 'static,
+    {
+        self.constructors
+            .insert(name.to_string(), Box::new(constructor));
+    }
+
+    /// Create the signal registered under a name from its parameters
+    /// # Arguments
+    /// * `name` - The name the signal is registered under
+    /// * `parameters` - The parameters of the signal, counts such as periods must be whole numbers
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::registry::{Parameters, SignalRegistry};
+    ///
+    /// let parameters = Parameters::from([("period".to_string(), 14.0)]);
+    /// let rsi = SignalRegistry::new().create("rsi", &parameters);
+    /// assert!(rsi.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if no signal is registered under the name, a parameter is missing or invalid, or the
+    /// signal can't be created from the parameters
+    /// ```
+    /// use indicato_rs::signals::registry::{Parameters, SignalRegistry};
+    ///
+    /// let parameters = Parameters::from([("period".to_string(), 2.5)]);
+    /// let sma = SignalRegistry::new().create("sma", &parameters);
+    ///
+    /// assert!(sma.is_err());
+    /// ```
+    pub fn create(
+        &self,
+        name: &str,
+        parameters: &Parameters,
+    ) -> Result<RegisteredSignal, FinError> {
+        match self.constructors.get(name) {
+            Some(constructor) => constructor(parameters),
+            None => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                &format!("Unknown signal `{}`", name),
+            )),
+        }
+    }
+
+    /// The names of the registered signals in alphabetical order
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.constructors.keys().map(String::as_str).collect();
+        names.sort();
+        names
+    }
+}
+
+impl Default for SignalRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::{Apply, Current, Evaluate, WarmUp};
+
+    fn parameters(values: &[(&str, f64)]) -> Parameters {
+        values
+            .iter()
+            .map(|(name, value)| (name.to_string(), *value))
+            .collect()
+    }
+
+    #[test]
+    fn test_create_builtins() {
+        let registry = SignalRegistry::new();
+        let all = parameters(&[
+            ("period", 4.0),
+            ("confidence", 0.95),
+            ("annualization", 252.0),
+            ("short_period", 2.0),
+            ("long_period", 4.0),
+            ("window", 5.0),
+            ("order", 2.0),
+        ]);
+        for name in registry.names() {
+            assert!(registry.create(name, &all).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_apply() {
+        let registry = SignalRegistry::new();
+        let mut rsi = registry
+            .create("rsi", &parameters(&[("period", 2.0)]))
+            .unwrap();
+        let mut rsi_direct = RelativeStrengthIndex::new(2, 0).unwrap();
+        for value in [1.0, 2.0, 1.5, 3.0, 2.0] {
+            assert_eq!(rsi.apply(value), rsi_direct.apply(value));
+        }
+        assert_eq!(rsi.evaluate(4.0), rsi_direct.evaluate(4.0));
+        assert_eq!(rsi.current(), rsi_direct.current());
+    }
+
+    #[test]
+    fn test_optional_parameters() {
+        let registry = SignalRegistry::new();
+        let mut sum = registry
+            .create("cumulative_sum", &Parameters::new())
+            .unwrap();
+        assert_eq!(sum.apply(1.0), Some(1.0));
+        assert_eq!(sum.apply(1.0), Some(2.0));
+        let mut decayed = registry
+            .create("cumulative_sum", &parameters(&[("decay", 0.5)]))
+            .unwrap();
+        assert_eq!(decayed.apply(1.0), Some(1.0));
+        assert_eq!(decayed.apply(1.0), Some(1.5));
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        let registry = SignalRegistry::new();
+        let missing = registry.create("historical_var", &parameters(&[("period", 3.0)]));
+        assert_eq!(
+            missing.err().unwrap().message,
+            "Missing parameter `confidence`"
+        );
+        let negative = registry.create("sma", &parameters(&[("period", -1.0)]));
+        assert_eq!(
+            negative.err().unwrap().message,
+            "Parameter `period` must be a whole number"
+        );
+        assert!(registry
+            .create("sma", &parameters(&[("period", 0.0)]))
+            .is_err());
+        let unknown = registry.create("unknown", &Parameters::new());
+        assert_eq!(unknown.err().unwrap().message, "Unknown signal `unknown`");
+    }
+
+    #[test]
+    fn test_warmup_matches_outputs() {
+        let registry = SignalRegistry::new();
+        let all = parameters(&[
+            ("period", 4.0),
+            ("confidence", 0.95),
+            ("annualization", 252.0),
+            ("window", 5.0),
+            ("order", 2.0),
+        ]);
+        let names = [
+            "frama",
+            "historical_var",
+            "historical_volatility",
+            "momentum",
+            "percentile_rank",
+            "roc",
+            "rsi",
+            "savitzky_golay",
+            "wilders",
+        ];
+        for name in names {
+            let mut signal = registry.create(name, &all).unwrap();
+            for step in 0..12 {
+                let remaining = signal.warmup_remaining();
+                let output = signal.apply((step as f64 * 0.7).sin() + 2.0);
+                assert_eq!(signal.warmup_remaining(), remaining.saturating_sub(1), "{}", name);
+                assert_eq!(signal.is_ready(), output.is_some(), "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_register() {
+        let mut registry = SignalRegistry::empty();
+        assert!(registry
+            .create("sma", &parameters(&[("period", 2.0)]))
+            .is_err());
+        registry.register("sma", |_| {
+            Ok(BoxedSignal::new(SimpleMovingAverage::new(2)?.map(Some)))
+        });
+        assert_eq!(registry.names(), vec!["sma"]);
+        assert!(registry.create("sma", &Parameters::new()).is_ok());
+    }
+}
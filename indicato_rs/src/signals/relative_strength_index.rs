@@ -1,8 +1,11 @@
-use indicato_rs_proc::{Apply, Evaluate};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
 
 use crate::{
     fin_error::{FinError, FinErrorType},
-    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, IoState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
 };
 
 fn up_down(input: f64, previous: f64) -> (f64, f64) {
@@ -205,7 +208,8 @@ fn up_down(input: f64, previous: f64) -> (f64, f64) {
 /// assert_eq!(rsi.current(), Some(100.0));
 /// ```
 
-#[derive(Apply, Evaluate)]
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RelativeStrengthIndex {
     /// Even though the RSI is available from the first value after the period parameter, additional values
     /// can be used to seed the RSI. This is added to the period to prevent values from being produced until
@@ -267,6 +271,33 @@ impl RelativeStrengthIndex {
             }),
         }
     }
+
+    /// Create a new Relative Strength Index instance with the conventional period of 14 and no additional seed period
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::RelativeStrengthIndex;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let rsi = RelativeStrengthIndex::standard();
+    /// assert_eq!(rsi.period(), 14);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(14, 0).unwrap()
+    }
+}
+
+impl Default for RelativeStrengthIndex {
+    /// Create a new Relative Strength Index with the conventional period of 14 and no additional seed period
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl Validate for RelativeStrengthIndex {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
 }
 
 impl Executable for RelativeStrengthIndex {
@@ -337,6 +368,26 @@ impl Current for RelativeStrengthIndex {
     }
 }
 
+impl WarmUp for RelativeStrengthIndex {
+    fn warmup_remaining(&self) -> usize {
+        let seeding = match self.is_seeded {
+            true => 0,
+            false => (self.seed_period + 1).saturating_sub(self.seed_values).max(1),
+        };
+        seeding.max(usize::from(self.previous.is_none()) + self.up_ws.warmup_remaining())
+    }
+}
+
+impl Periodic for RelativeStrengthIndex {
+    fn period(&self) -> usize {
+        self.up_ws.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.seed_period + 1
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -405,6 +456,28 @@ mod tests {
     }
 
     #[test]
+    fn test_periodic() {
+        let rsi = RelativeStrengthIndex::new(14, 3).unwrap();
+        assert_eq!(rsi.period(), 14);
+        assert_eq!(rsi.effective_lookback(), 18);
+        assert_eq!(rsi.effective_lookback(), rsi.warmup_remaining());
+    }
+
+    #[test]
+    fn test_standard() {
+        let mut standard = RelativeStrengthIndex::standard();
+        let mut rsi = RelativeStrengthIndex::new(14, 0).unwrap();
+        let inputs = [
+            1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 8.0, 7.0, 9.0, 6.0, 5.0, 7.0, 8.0, 9.0, 11.0, 10.0,
+        ];
+        for input in inputs {
+            assert_eq!(standard.apply(input), rsi.apply(input));
+        }
+        assert_eq!(RelativeStrengthIndex::default().period(), 14);
+    }
+
+    #[test]
+    #[allow(clippy::excessive_precision)]
     fn test_rsi_data() {
         let mut rsi = RelativeStrengthIndex::new(14, 0).unwrap();
         rsi.apply(10.92521440760443900);
@@ -0,0 +1,187 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::fin_error::FinError;
+use crate::traits::{
+    Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp,
+};
+use crate::validation;
+
+/// The way a [`Returns`] measures the change between consecutive prices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ReturnMode {
+    /// The relative change in price, `i_n / i_{n-1} - 1`.
+    Simple,
+    /// The natural logarithm of the ratio of prices, `ln(i_n / i_{n-1})`, which sums across steps.
+    Log,
+}
+
+crate::inspect::debug_state!(ReturnMode);
+
+/// # Returns
+/// Container for Returns aggregation
+///
+/// The per step return of a price stream, giving volatility, drawdown and risk signals a consistent returns stream to
+/// consume. The first entry will produce `None` as there is no previous price, after which the following formula is
+/// applied for simple returns:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <msub>
+///                 <mi>i</mi>
+///                 <mn>n</mn>
+///             </msub>
+///             <msub>
+///                 <mi>i</mi>
+///                 <mn>n-1</mn>
+///             </msub>
+///         </mfrac>
+///         <mo>−</mo>
+///         <mn>1</mn>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step and `i` is the input. Log returns are the natural logarithm of the
+/// ratio instead. Inputs are expected to be positive prices.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{ReturnMode, Returns};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new simple Returns
+/// let mut returns = Returns::new(ReturnMode::Simple);
+///
+/// // apply some values and check their output
+/// assert_eq!(returns.apply(100.0), None);
+/// assert_eq!(returns.apply(125.0), Some(0.25));
+/// assert_eq!(returns.apply(62.5), Some(-0.5));
+///
+/// // evaluate some values, these won't affect the internal state of the Returns
+/// assert_eq!(returns.evaluate(93.75), Some(0.5));
+///
+/// // fetch the current value of the Returns
+/// assert_eq!(returns.current(), Some(-0.5));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Returns {
+    mode: ReturnMode,
+    previous: Option<f64>,
+    current: Option<f64>,
+}
+
+impl Returns {
+    /// Create a new Returns instance
+    /// # Arguments
+    /// * `mode` - Whether simple or log returns are produced
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{ReturnMode, Returns};
+    ///
+    /// let returns = Returns::new(ReturnMode::Log);
+    /// ```
+    pub fn new(mode: ReturnMode) -> Self {
+        Self {
+            mode,
+            previous: None,
+            current: None,
+        }
+    }
+}
+
+impl IoState for Returns {
+    type Input = f64;
+    type Output = Option<f64>;
+}
+
+impl Validate for Returns {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for Returns {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let result = self.previous.map(|previous| match self.mode {
+            ReturnMode::Simple => input / previous - 1.0,
+            ReturnMode::Log => (input / previous).ln(),
+        });
+        if let ExecutionContext::Apply = execution_context {
+            self.previous = Some(input);
+            self.current = result;
+        }
+        result
+    }
+}
+
+impl Current for Returns {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for Returns {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none()) + usize::from(self.current.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::E;
+
+    #[test]
+    fn test_apply_simple() {
+        let mut returns = Returns::new(ReturnMode::Simple);
+        assert_eq!(returns.apply(4.0), None);
+        assert_eq!(returns.apply(5.0), Some(0.25));
+        assert_eq!(returns.apply(2.5), Some(-0.5));
+        assert_eq!(returns.apply(2.5), Some(0.0));
+    }
+
+    #[test]
+    fn test_apply_log() {
+        let mut returns = Returns::new(ReturnMode::Log);
+        assert_eq!(returns.apply(1.0), None);
+        assert_eq!(returns.apply(E), Some(1.0));
+        assert_eq!(returns.apply(1.0), Some(-1.0));
+        assert_eq!(returns.apply(4.0), Some(4.0_f64.ln()));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut returns = Returns::new(ReturnMode::Simple);
+        assert_eq!(returns.evaluate(100.0), None);
+        assert_eq!(returns.apply(100.0), None);
+        assert_eq!(returns.evaluate(150.0), Some(0.5));
+        assert_eq!(returns.evaluate(50.0), Some(-0.5));
+        assert_eq!(returns.apply(125.0), Some(0.25));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut returns = Returns::new(ReturnMode::Simple);
+        assert_eq!(returns.current(), None);
+        returns.apply(100.0);
+        assert_eq!(returns.current(), None);
+        returns.apply(150.0);
+        returns.evaluate(10.0);
+        assert_eq!(returns.current(), Some(0.5));
+    }
+}
@@ -0,0 +1,219 @@
+use std::{collections::VecDeque, fmt, sync::Arc};
+
+use indicato_rs_proc::{Apply, Evaluate};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    inspect::{SignalState, ToState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+type WindowFn = dyn Fn(&VecDeque<f64>) -> f64 + Send + Sync;
+
+/// A function calculating the output of a [`RollingApply`] from its window.
+#[derive(Clone)]
+struct WindowFunction(Arc<WindowFn>);
+
+impl fmt::Debug for WindowFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("WindowFunction").finish_non_exhaustive()
+    }
+}
+
+/// # Rolling Apply
+/// Container for a custom aggregation over a rolling window
+///
+/// The last `p` inputs are held in a window, oldest first, and the output is calculated by a function of the window,
+/// so bespoke indicators can be built without a new signal type. The function is called with the window as it fills,
+/// like the [`SimpleMovingAverage`](super::SimpleMovingAverage) the first `p - 1` outputs are calculated from fewer
+/// than `p` inputs. The [`DequeMathExtF64`](crate::deque_math::DequeMathExtF64) statistics can be used in the
+/// function.
+///
+/// The function can't be serialized, so unlike most signals this isn't serializable with the `serde` feature.
+///
+/// # Example Usage
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use indicato_rs::deque_math::DequeMathExtF64;
+/// use indicato_rs::signals::RollingApply;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a rolling range of the last 3 inputs
+/// let mut range = RollingApply::new(3, |window: &VecDeque<f64>| window.value_range()).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(range.apply(2.0), 0.0);
+/// assert_eq!(range.apply(5.0), 3.0);
+/// assert_eq!(range.apply(4.0), 3.0);
+/// assert_eq!(range.apply(6.0), 2.0);
+///
+/// // evaluate some values, these won't affect the internal state of the window
+/// assert_eq!(range.evaluate(0.0), 6.0);
+///
+/// // fetch the current value of the window
+/// assert_eq!(range.current(), 2.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate)]
+pub struct RollingApply {
+    period: usize,
+    /// The last `period` inputs.
+    values: VecDeque<f64>,
+    function: WindowFunction,
+}
+
+impl IoState for RollingApply {
+    type Input = f64;
+    type Output = f64;
+}
+
+impl RollingApply {
+    /// Create a new Rolling Apply instance
+    /// # Arguments
+    /// * `period` - The number of inputs in the window, must be greater than 0
+    /// * `function` - The function calculating the output from the window
+    ///
+    /// # Example
+    /// ```
+    /// use std::collections::VecDeque;
+    ///
+    /// use indicato_rs::signals::RollingApply;
+    ///
+    /// // the change across the window
+    /// let change = RollingApply::new(10, |window: &VecDeque<f64>| window[window.len() - 1] - window[0]);
+    /// assert!(change.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use std::collections::VecDeque;
+    ///
+    /// use indicato_rs::signals::RollingApply;
+    ///
+    /// let change = RollingApply::new(0, |window: &VecDeque<f64>| window[window.len() - 1] - window[0]);
+    ///
+    /// assert!(change.is_err());
+    /// ```
+    pub fn new(
+        period: usize,
+        function: impl Fn(&VecDeque<f64>) -> f64 + Send + Sync + 'static,
+    ) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+                function: WindowFunction(Arc::new(function)),
+            }),
+        }
+    }
+
+    /// The inputs in the window, oldest first
+    pub fn window(&self) -> &VecDeque<f64> {
+        &self.values
+    }
+}
+
+impl Validate for RollingApply {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for RollingApply {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        self.values.push_back(input);
+        let removed = match self.values.len() > self.period {
+            true => self.values.pop_front(),
+            false => None,
+        };
+        let result = (self.function.0)(&self.values);
+        if let ExecutionContext::Evaluate = execution_context {
+            // restore the window rather than cloning it for each evaluation
+            self.values.pop_back();
+            if let Some(removed) = removed {
+                self.values.push_front(removed);
+            }
+        }
+        result
+    }
+}
+
+impl Current for RollingApply {
+    fn current(&self) -> Self::Output {
+        (self.function.0)(&self.values)
+    }
+}
+
+impl WarmUp for RollingApply {
+    fn warmup_remaining(&self) -> usize {
+        self.period - self.values.len()
+    }
+}
+
+impl Periodic for RollingApply {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl Inspect for RollingApply {
+    fn inspect(&self) -> SignalState {
+        SignalState::new("RollingApply")
+            .field("period", self.period.to_state())
+            .field("values", self.values.to_state())
+    }
+
+    fn name(&self) -> &'static str {
+        "RollingApply"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deque_math::DequeMathExt;
+    use crate::signals::SimpleMovingAverage;
+
+    #[test]
+    fn test_matches_sma() {
+        let mut mean = RollingApply::new(3, |window: &VecDeque<f64>| window.mean()).unwrap();
+        let mut sma = SimpleMovingAverage::new(3).unwrap();
+        for input in [1.0, 4.0, 2.0, 8.0, 5.0, 7.0] {
+            assert_eq!(mean.apply(input), sma.apply(input));
+            assert_eq!(mean.warmup_remaining(), sma.warmup_remaining());
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut first = RollingApply::new(2, |window: &VecDeque<f64>| window[0]).unwrap();
+        assert_eq!(first.evaluate(1.0), 1.0);
+        assert!(first.window().is_empty());
+        first.apply(1.0);
+        first.apply(2.0);
+        assert_eq!(first.evaluate(3.0), 2.0);
+        assert_eq!(first.window(), &VecDeque::from([1.0, 2.0]));
+        assert_eq!(first.apply(3.0), 2.0);
+        assert_eq!(first.current(), 2.0);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let mut sum = RollingApply::new(2, |window: &VecDeque<f64>| window.iter().sum()).unwrap();
+        sum.apply(1.0);
+        let state = sum.inspect();
+        assert_eq!(state.name, "RollingApply");
+        assert_eq!(state.get("period"), Some(&crate::inspect::StateValue::Count(2)));
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequePairMathExtF64,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// # Rolling Beta
+/// Container for rolling Beta aggregation
+///
+/// The beta is the covariance of the asset returns with the benchmark returns divided by the variance of the
+/// benchmark returns over the period. The first `p - 1` entries will produce `None` as the period is being filled,
+/// after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mi>Cov</mi>
+///                 <mo>(</mo>
+///                 <mi>a</mi>
+///                 <mo>,</mo>
+///                 <mi>b</mi>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <mi>Var</mi>
+///                 <mo>(</mo>
+///                 <mi>b</mi>
+///                 <mo>)</mo>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period, `a` is the asset returns over the period
+/// and `b` is the benchmark returns over the period. If the benchmark variance is 0 the output will be 0.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::RollingBeta;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Rolling Beta with a period of 2
+/// let mut beta = RollingBeta::new(2).unwrap();
+///
+/// // apply some (asset return, benchmark return) values and check their output
+/// assert_eq!(beta.apply((2.0, 1.0)), None);
+/// assert_eq!(beta.apply((4.0, 2.0)), Some(2.0));
+/// assert_eq!(beta.apply((1.0, 3.0)), Some(-3.0));
+///
+/// // evaluate some values, these won't affect the internal state of the Rolling Beta
+/// assert_eq!(beta.evaluate((1.0, 1.0)), Some(0.0));
+///
+/// // fetch the current value of the Rolling Beta
+/// assert_eq!(beta.current(), Some(-3.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingBeta {
+    period: usize,
+    values: VecDeque<(f64, f64)>,
+}
+
+impl RollingBeta {
+    /// Create a new Rolling Beta instance
+    /// # Arguments
+    /// * `period` - The period of the Rolling Beta aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::RollingBeta;
+    ///
+    /// let beta = RollingBeta::new(60);
+    /// assert!(beta.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::RollingBeta;
+    ///
+    /// let beta = RollingBeta::new(0);
+    ///
+    /// assert!(beta.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+            }),
+        }
+    }
+
+    fn beta(&self, values: &VecDeque<(f64, f64)>) -> Option<f64> {
+        if values.len() < self.period {
+            return None;
+        }
+        let (_, benchmark_variance) = values.variances();
+        match benchmark_variance == 0.0 {
+            true => Some(0.0),
+            false => Some(values.covariance() / benchmark_variance),
+        }
+    }
+}
+
+impl IoState for RollingBeta {
+    /// Input is a tuple of (asset return, benchmark return)
+    type Input = (f64, f64);
+    type Output = Option<f64>;
+}
+
+impl Validate for RollingBeta {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (asset, benchmark) = *input;
+        validation::value(asset, "Asset return")?;
+        validation::value(benchmark, "Benchmark return")
+    }
+}
+
+impl Executable for RollingBeta {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                self.beta(&self.values)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(input);
+                if values.len() > self.period {
+                    values.pop_front();
+                }
+                self.beta(&values)
+            }
+        }
+    }
+}
+
+impl Current for RollingBeta {
+    fn current(&self) -> Self::Output {
+        self.beta(&self.values)
+    }
+}
+
+impl WarmUp for RollingBeta {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for RollingBeta {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply() {
+        let mut beta = RollingBeta::new(3).unwrap();
+        assert_eq!(beta.apply((2.0, 1.0)), None);
+        assert_eq!(beta.apply((4.0, 2.0)), None);
+        assert_abs_diff_eq!(beta.apply((9.0, 3.0)).unwrap(), 3.5, epsilon = 10e-7);
+        assert_abs_diff_eq!(beta.apply((1.0, 4.0)).unwrap(), -1.5, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut beta = RollingBeta::new(2).unwrap();
+        assert_eq!(beta.evaluate((2.0, 1.0)), None);
+        assert_eq!(beta.apply((2.0, 1.0)), None);
+        assert_eq!(beta.evaluate((4.0, 2.0)), Some(2.0));
+        assert_eq!(beta.evaluate((1.0, 2.0)), Some(-1.0));
+        assert_eq!(beta.apply((4.0, 2.0)), Some(2.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut beta = RollingBeta::new(2).unwrap();
+        assert_eq!(beta.current(), None);
+        beta.apply((2.0, 1.0));
+        beta.apply((4.0, 2.0));
+        beta.evaluate((1.0, 2.0));
+        assert_eq!(beta.current(), Some(2.0));
+    }
+
+    #[test]
+    fn test_flat_benchmark() {
+        let mut beta = RollingBeta::new(2).unwrap();
+        assert_eq!(beta.apply((2.0, 1.0)), None);
+        assert_eq!(beta.apply((4.0, 1.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let beta = RollingBeta::new(0);
+        assert!(beta.is_err());
+    }
+}
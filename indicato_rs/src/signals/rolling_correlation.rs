@@ -0,0 +1,233 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequePairMathExtF64,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// # Rolling Correlation
+/// Container for rolling Pearson Correlation aggregation
+///
+/// The correlation is the covariance of the two series divided by the product of their standard deviations over
+/// the period. The first `p - 1` entries will produce `None` as the period is being filled, after which the
+/// following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <mi>Cov</mi>
+///                 <mo>(</mo>
+///                 <mi>x</mi>
+///                 <mo>,</mo>
+///                 <mi>y</mi>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <msub>
+///                     <mi>σ</mi>
+///                     <mi>x</mi>
+///                 </msub>
+///                 <msub>
+///                     <mi>σ</mi>
+///                     <mi>y</mi>
+///                 </msub>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period, `x` and `y` are the two input series over
+/// the period and `σ` is the standard deviation. If either series has a variance of 0 the output will be 0.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::RollingCorrelation;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Rolling Correlation with a period of 2
+/// let mut correlation = RollingCorrelation::new(2).unwrap();
+///
+/// // apply some (x, y) values and check their output
+/// assert_eq!(correlation.apply((1.0, 2.0)), None);
+/// assert_eq!(correlation.apply((2.0, 4.0)), Some(1.0));
+/// assert_eq!(correlation.apply((3.0, 1.0)), Some(-1.0));
+///
+/// // evaluate some values, these won't affect the internal state of the Rolling Correlation
+/// assert_eq!(correlation.evaluate((4.0, 1.0)), Some(0.0));
+///
+/// // fetch the current value of the Rolling Correlation
+/// assert_eq!(correlation.current(), Some(-1.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingCorrelation {
+    period: usize,
+    values: VecDeque<(f64, f64)>,
+}
+
+impl RollingCorrelation {
+    /// Create a new Rolling Correlation instance
+    /// # Arguments
+    /// * `period` - The period of the Rolling Correlation aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::RollingCorrelation;
+    ///
+    /// let correlation = RollingCorrelation::new(30);
+    /// assert!(correlation.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::RollingCorrelation;
+    ///
+    /// let correlation = RollingCorrelation::new(0);
+    ///
+    /// assert!(correlation.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+            }),
+        }
+    }
+
+    fn correlation(&self, values: &VecDeque<(f64, f64)>) -> Option<f64> {
+        match values.len() < self.period {
+            true => None,
+            false => Some(values.correlation()),
+        }
+    }
+}
+
+impl IoState for RollingCorrelation {
+    /// Input is a tuple of (x, y)
+    type Input = (f64, f64);
+    type Output = Option<f64>;
+}
+
+impl Validate for RollingCorrelation {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (x, y) = *input;
+        validation::value(x, "X")?;
+        validation::value(y, "Y")
+    }
+}
+
+impl Executable for RollingCorrelation {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                self.correlation(&self.values)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(input);
+                if values.len() > self.period {
+                    values.pop_front();
+                }
+                self.correlation(&values)
+            }
+        }
+    }
+}
+
+impl Current for RollingCorrelation {
+    fn current(&self) -> Self::Output {
+        self.correlation(&self.values)
+    }
+}
+
+impl WarmUp for RollingCorrelation {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for RollingCorrelation {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply() {
+        let mut correlation = RollingCorrelation::new(3).unwrap();
+        assert_eq!(correlation.apply((1.0, 2.0)), None);
+        assert_eq!(correlation.apply((2.0, 4.0)), None);
+        assert_abs_diff_eq!(
+            correlation.apply((3.0, 9.0)).unwrap(),
+            7.0 / 52.0_f64.sqrt(),
+            epsilon = 10e-7
+        );
+        assert_abs_diff_eq!(correlation.apply((4.0, 4.0)).unwrap(), 0.0, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut correlation = RollingCorrelation::new(2).unwrap();
+        assert_eq!(correlation.evaluate((1.0, 2.0)), None);
+        assert_eq!(correlation.apply((1.0, 2.0)), None);
+        assert_eq!(correlation.evaluate((2.0, 4.0)), Some(1.0));
+        assert_eq!(correlation.evaluate((2.0, 1.0)), Some(-1.0));
+        assert_eq!(correlation.apply((2.0, 4.0)), Some(1.0));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut correlation = RollingCorrelation::new(2).unwrap();
+        assert_eq!(correlation.current(), None);
+        correlation.apply((1.0, 2.0));
+        correlation.apply((2.0, 4.0));
+        correlation.evaluate((3.0, 1.0));
+        assert_eq!(correlation.current(), Some(1.0));
+    }
+
+    #[test]
+    fn test_flat_series() {
+        let mut correlation = RollingCorrelation::new(2).unwrap();
+        assert_eq!(correlation.apply((2.0, 1.0)), None);
+        assert_eq!(correlation.apply((4.0, 1.0)), Some(0.0));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let correlation = RollingCorrelation::new(0);
+        assert!(correlation.is_err());
+    }
+}
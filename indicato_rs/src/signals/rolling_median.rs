@@ -0,0 +1,246 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+fn median(sorted: &[f64]) -> f64 {
+    let len = sorted.len();
+    match len {
+        0 => 0.0,
+        _ if len % 2 == 1 => sorted[len / 2],
+        _ => (sorted[len / 2 - 1] + sorted[len / 2]) / 2.0,
+    }
+}
+
+fn insert_sorted(sorted: &mut Vec<f64>, value: f64) {
+    let index = sorted.partition_point(|x| x.total_cmp(&value).is_lt());
+    sorted.insert(index, value);
+}
+
+fn remove_sorted(sorted: &mut Vec<f64>, value: f64) {
+    let index = sorted.partition_point(|x| x.total_cmp(&value).is_lt());
+    sorted.remove(index);
+}
+
+/// # Rolling Median
+/// Container for Rolling Median aggregation
+///
+/// The rolling median is a robust alternative to the Simple Moving Average that is unaffected by single outliers.
+/// The inputs over the period are kept in sorted order, so each step is a binary search and a shift rather than a
+/// sort of the whole period. The aggregation will begin producing values immediately, with the period being filled
+/// as values are applied, the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mi>median</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n-p+1</mn>
+///         </msub>
+///         <mo>..</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period and `i` is the input. When there are an even
+/// number of inputs the median is the mean of the two middle inputs.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::RollingMedian;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Rolling Median with a period of 3
+/// let mut median = RollingMedian::new(3).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(median.apply(1.0), 1.0);
+/// assert_eq!(median.apply(3.0), 2.0);
+/// assert_eq!(median.apply(100.0), 3.0);
+/// assert_eq!(median.apply(2.0), 3.0);
+///
+/// // evaluate some values, these won't affect the internal state of the Rolling Median
+/// assert_eq!(median.evaluate(1.0), 2.0);
+///
+/// // fetch the current value of the Rolling Median
+/// assert_eq!(median.current(), 3.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RollingMedian {
+    period: usize,
+    /// The inputs over the period in the order they were applied.
+    values: VecDeque<f64>,
+    /// The inputs over the period in ascending order.
+    sorted: Vec<f64>,
+}
+
+impl RollingMedian {
+    /// Create a new Rolling Median instance
+    /// # Arguments
+    /// * `period` - The period of the Rolling Median aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::RollingMedian;
+    ///
+    /// let median = RollingMedian::new(21);
+    /// assert!(median.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::RollingMedian;
+    ///
+    /// let median = RollingMedian::new(0);
+    ///
+    /// assert!(median.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+                sorted: Vec::with_capacity(period + 1),
+            }),
+        }
+    }
+}
+
+impl IoState for RollingMedian {
+    type Input = f64;
+    type Output = f64;
+}
+
+impl Validate for RollingMedian {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for RollingMedian {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                insert_sorted(&mut self.sorted, input);
+                if self.values.len() > self.period {
+                    if let Some(oldest) = self.values.pop_front() {
+                        remove_sorted(&mut self.sorted, oldest);
+                    }
+                }
+                median(&self.sorted)
+            }
+            ExecutionContext::Evaluate => {
+                let mut sorted = self.sorted.clone();
+                insert_sorted(&mut sorted, input);
+                if self.values.len() >= self.period {
+                    remove_sorted(&mut sorted, self.values[0]);
+                }
+                median(&sorted)
+            }
+        }
+    }
+}
+
+impl Current for RollingMedian {
+    fn current(&self) -> Self::Output {
+        median(&self.sorted)
+    }
+}
+
+impl WarmUp for RollingMedian {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for RollingMedian {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut median = RollingMedian::new(3).unwrap();
+        assert_eq!(median.apply(1.0), 1.0);
+        assert_eq!(median.apply(3.0), 2.0);
+        assert_eq!(median.apply(100.0), 3.0);
+        assert_eq!(median.apply(2.0), 3.0);
+        assert_eq!(median.apply(2.0), 2.0);
+        assert_eq!(median.apply(-5.0), 2.0);
+        assert_eq!(median.apply(-4.0), -4.0);
+    }
+
+    #[test]
+    fn test_apply_even_period() {
+        let mut median = RollingMedian::new(4).unwrap();
+        assert_eq!(median.apply(4.0), 4.0);
+        assert_eq!(median.apply(1.0), 2.5);
+        assert_eq!(median.apply(3.0), 3.0);
+        assert_eq!(median.apply(2.0), 2.5);
+        assert_eq!(median.apply(10.0), 2.5);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut median = RollingMedian::new(3).unwrap();
+        assert_eq!(median.evaluate(5.0), 5.0);
+        assert_eq!(median.apply(1.0), 1.0);
+        assert_eq!(median.apply(3.0), 2.0);
+        assert_eq!(median.evaluate(100.0), 3.0);
+        assert_eq!(median.apply(100.0), 3.0);
+        assert_eq!(median.evaluate(2.0), 3.0);
+        assert_eq!(median.evaluate(200.0), 100.0);
+        assert_eq!(median.apply(2.0), 3.0);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut median = RollingMedian::new(3).unwrap();
+        assert_eq!(median.current(), 0.0);
+        median.apply(1.0);
+        median.apply(3.0);
+        median.evaluate(100.0);
+        assert_eq!(median.current(), 2.0);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let median = RollingMedian::new(0);
+        assert!(median.is_err());
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// Solve the linear system `matrix ⋅ x = rhs` by Gaussian elimination with partial pivoting.
+fn solve(mut matrix: Vec<Vec<f64>>, mut rhs: Vec<f64>) -> Vec<f64> {
+    let size = rhs.len();
+    for column in 0..size {
+        let pivot = (column..size)
+            .max_by(|a, b| {
+                matrix[*a][column]
+                    .abs()
+                    .total_cmp(&matrix[*b][column].abs())
+            })
+            .unwrap_or(column);
+        matrix.swap(column, pivot);
+        rhs.swap(column, pivot);
+        let pivot_row = matrix[column].clone();
+        for row in column + 1..size {
+            let factor = matrix[row][column] / pivot_row[column];
+            for (value, pivot_value) in matrix[row][column..].iter_mut().zip(&pivot_row[column..]) {
+                *value -= factor * pivot_value;
+            }
+            rhs[row] -= factor * rhs[column];
+        }
+    }
+    let mut solution = vec![0.0; size];
+    for row in (0..size).rev() {
+        let sum = (row + 1..size).fold(0.0, |acc, k| acc + matrix[row][k] * solution[k]);
+        solution[row] = (rhs[row] - sum) / matrix[row][row];
+    }
+    solution
+}
+
+/// The convolution coefficients evaluating the least squares polynomial of `order` over `window` inputs at the most
+/// recent input, the first coefficient is for the oldest input.
+fn coefficients(window: usize, order: usize) -> Vec<f64> {
+    // positions are scaled to [-1, 0] to keep the normal equations well conditioned
+    let scale = (window - 1).max(1) as f64;
+    let positions = (0..window)
+        .map(|j| (j as f64 - (window - 1) as f64) / scale)
+        .collect::<Vec<f64>>();
+    let normal = (0..=order)
+        .map(|a| {
+            (0..=order)
+                .map(|b| positions.iter().map(|x| x.powi((a + b) as i32)).sum())
+                .collect()
+        })
+        .collect();
+    let mut unit = vec![0.0; order + 1];
+    unit[0] = 1.0;
+    let weights = solve(normal, unit);
+    positions
+        .iter()
+        .map(|x| {
+            weights
+                .iter()
+                .enumerate()
+                .map(|(power, weight)| weight * x.powi(power as i32))
+                .sum()
+        })
+        .collect()
+}
+
+/// # Savitzky Golay
+/// Container for Savitzky Golay aggregation
+///
+/// The Savitzky Golay filter fits a least squares polynomial over the window and outputs its value at the most recent
+/// input, smoothing noise while following curvature with much less lag than a moving average of the same length.
+/// The fit reduces to a fixed weighting of the window, so the convolution coefficients are computed once at
+/// construction. The first `w - 1` entries will produce `None` as the window is filled, after which the following
+/// formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <munderover>
+///             <mo>∑</mo>
+///             <mrow><mi>j</mi><mo>=</mo><mn>0</mn></mrow>
+///             <mrow><mi>w</mi><mo>−</mo><mn>1</mn></mrow>
+///         </munderover>
+///         <msub>
+///             <mi>c</mi>
+///             <mi>j</mi>
+///         </msub>
+///         <msub>
+///             <mi>i</mi>
+///             <mrow><mi>n</mi><mo>−</mo><mi>w</mi><mo>+</mo><mn>1</mn><mo>+</mo><mi>j</mi></mrow>
+///         </msub>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `w` is the window length, `c` are the convolution coefficients of
+/// a polynomial of order `d` evaluated at the most recent input and `i` is the input. An order of 0 is the Simple Moving
+/// Average of the window.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::SavitzkyGolay;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+/// use approx::assert_abs_diff_eq;
+///
+/// // create a new Savitzky Golay with a window length of 3 and a polynomial order of 1
+/// let mut savitzky_golay = SavitzkyGolay::new(3, 1).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(savitzky_golay.apply(1.0), None);
+/// assert_eq!(savitzky_golay.apply(2.0), None);
+/// assert_abs_diff_eq!(savitzky_golay.apply(4.0).unwrap(), 23.0 / 6.0, epsilon = 10e-7);
+///
+/// // evaluate some values, these won't affect the internal state of the Savitzky Golay
+/// assert_abs_diff_eq!(savitzky_golay.evaluate(6.0).unwrap(), 6.0, epsilon = 10e-7);
+///
+/// // fetch the current value of the Savitzky Golay
+/// assert_abs_diff_eq!(savitzky_golay.current().unwrap(), 23.0 / 6.0, epsilon = 10e-7);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavitzkyGolay {
+    window: usize,
+    coefficients: Vec<f64>,
+    values: VecDeque<f64>,
+}
+
+impl SavitzkyGolay {
+    /// Create a new Savitzky Golay instance
+    /// # Arguments
+    /// * `window` - The number of inputs the polynomial is fitted over, must be greater than the order
+    /// * `order` - The order of the fitted polynomial
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SavitzkyGolay;
+    ///
+    /// let savitzky_golay = SavitzkyGolay::new(11, 2);
+    /// assert!(savitzky_golay.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the window length is not greater than the order
+    /// ```
+    /// use indicato_rs::signals::SavitzkyGolay;
+    ///
+    /// assert!(SavitzkyGolay::new(0, 0).is_err());
+    /// assert!(SavitzkyGolay::new(3, 3).is_err());
+    /// ```
+    pub fn new(window: usize, order: usize) -> Result<Self, FinError> {
+        if window <= order {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Window length must be greater than the polynomial order",
+            ));
+        }
+        Ok(Self {
+            window,
+            coefficients: coefficients(window, order),
+            values: VecDeque::with_capacity(window + 1),
+        })
+    }
+
+    /// The convolution coefficients applied to the window, the first coefficient is for the oldest input
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SavitzkyGolay;
+    ///
+    /// let savitzky_golay = SavitzkyGolay::new(4, 0).unwrap();
+    /// assert_eq!(savitzky_golay.coefficients(), &[0.25, 0.25, 0.25, 0.25]);
+    /// ```
+    pub fn coefficients(&self) -> &[f64] {
+        &self.coefficients
+    }
+
+    fn convolve(&self, values: &VecDeque<f64>) -> Option<f64> {
+        match values.len() == self.window {
+            true => Some(
+                values
+                    .iter()
+                    .zip(self.coefficients.iter())
+                    .map(|(value, coefficient)| value * coefficient)
+                    .sum(),
+            ),
+            false => None,
+        }
+    }
+}
+
+impl IoState for SavitzkyGolay {
+    type Input = f64;
+    type Output = Option<f64>;
+}
+
+impl Validate for SavitzkyGolay {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for SavitzkyGolay {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let mut values = match execution_context {
+            ExecutionContext::Apply => std::mem::take(&mut self.values),
+            ExecutionContext::Evaluate => self.values.clone(),
+        };
+        values.push_back(input);
+        if values.len() > self.window {
+            values.pop_front();
+        }
+        let result = self.convolve(&values);
+        if let ExecutionContext::Apply = execution_context {
+            self.values = values;
+        }
+        result
+    }
+}
+
+impl Current for SavitzkyGolay {
+    fn current(&self) -> Self::Output {
+        self.convolve(&self.values)
+    }
+}
+
+impl WarmUp for SavitzkyGolay {
+    fn warmup_remaining(&self) -> usize {
+        self.window.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for SavitzkyGolay {
+    fn period(&self) -> usize {
+        self.window
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_coefficients() {
+        let savitzky_golay = SavitzkyGolay::new(3, 1).unwrap();
+        for (coefficient, expected) in
+            savitzky_golay
+                .coefficients()
+                .iter()
+                .zip([-1.0 / 6.0, 1.0 / 3.0, 5.0 / 6.0])
+        {
+            assert_abs_diff_eq!(*coefficient, expected, epsilon = 10e-7);
+        }
+        let savitzky_golay = SavitzkyGolay::new(1, 0).unwrap();
+        assert_eq!(savitzky_golay.coefficients(), &[1.0]);
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut savitzky_golay = SavitzkyGolay::new(3, 1).unwrap();
+        assert_eq!(savitzky_golay.apply(1.0), None);
+        assert_eq!(savitzky_golay.apply(2.0), None);
+        assert_abs_diff_eq!(
+            savitzky_golay.apply(4.0).unwrap(),
+            23.0 / 6.0,
+            epsilon = 10e-7
+        );
+        assert_abs_diff_eq!(savitzky_golay.apply(3.0).unwrap(), 3.5, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_polynomial_is_reproduced() {
+        let mut savitzky_golay = SavitzkyGolay::new(7, 2).unwrap();
+        let mut output = None;
+        for n in 0..10 {
+            let x = n as f64;
+            output = savitzky_golay.apply(0.5 * x * x - 3.0 * x + 2.0);
+        }
+        assert_abs_diff_eq!(output.unwrap(), 15.5, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_order_zero_is_mean() {
+        let mut savitzky_golay = SavitzkyGolay::new(4, 0).unwrap();
+        for value in [1.0, 2.0, 3.0] {
+            assert_eq!(savitzky_golay.apply(value), None);
+        }
+        assert_abs_diff_eq!(savitzky_golay.apply(6.0).unwrap(), 3.0, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut savitzky_golay = SavitzkyGolay::new(3, 1).unwrap();
+        assert_eq!(savitzky_golay.apply(1.0), None);
+        assert_eq!(savitzky_golay.evaluate(2.0), None);
+        assert_eq!(savitzky_golay.apply(2.0), None);
+        let evaluated = savitzky_golay.evaluate(4.0).unwrap();
+        assert_abs_diff_eq!(evaluated, 23.0 / 6.0, epsilon = 10e-7);
+        assert_eq!(savitzky_golay.current(), None);
+        assert_eq!(savitzky_golay.apply(4.0), Some(evaluated));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut savitzky_golay = SavitzkyGolay::new(3, 1).unwrap();
+        assert_eq!(savitzky_golay.current(), None);
+        savitzky_golay.apply(1.0);
+        savitzky_golay.apply(2.0);
+        let applied = savitzky_golay.apply(4.0);
+        assert_eq!(savitzky_golay.current(), applied);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(SavitzkyGolay::new(0, 0).is_err());
+        assert!(SavitzkyGolay::new(3, 3).is_err());
+        assert!(SavitzkyGolay::new(3, 5).is_err());
+    }
+}
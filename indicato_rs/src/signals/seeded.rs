@@ -0,0 +1,201 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::FinError,
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, ReadyValue, Validate,
+        WarmUp,
+    },
+    types::SignalValue,
+};
+
+/// # Seeded
+/// Container for giving the outputs of a signal as a [`SignalValue`]
+///
+/// Each output is [`SignalValue::Seeding`] with the number of inputs remaining while the signal is warming up, and
+/// [`SignalValue::Ready`] with the value once it is warmed up, whether the signal outputs `None` while warming up, like
+/// the [`RelativeStrengthIndex`](super::RelativeStrengthIndex), or outputs values from the first input, like the
+/// [`SimpleMovingAverage`](super::SimpleMovingAverage). Signals of both kinds can then be handled the same way.
+///
+/// The warm up is taken from [`WarmUp`], so signals that output `None` for reasons other than warming up, such as the
+/// [`PivotDetector`](super::PivotDetector) between pivots, give [`SignalValue::Seeding`] with 0 remaining for those
+/// outputs.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Seeded, SimpleMovingAverage};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+/// use indicato_rs::types::SignalValue;
+///
+/// // create a Simple Moving Average with a period of 2
+/// let mut seeded = Seeded::new(SimpleMovingAverage::new(2).unwrap());
+///
+/// // apply some values and check their output
+/// assert_eq!(seeded.apply(2.0), SignalValue::Seeding { remaining: 1 });
+/// assert_eq!(seeded.apply(4.0), SignalValue::Ready(3.0));
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(seeded.evaluate(8.0), SignalValue::Ready(6.0));
+///
+/// // fetch the current value of the signal
+/// assert_eq!(seeded.current(), SignalValue::Ready(3.0));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Seeded<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ReadyValue,
+{
+    signal: S,
+}
+
+impl<S> IoState for Seeded<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ReadyValue,
+{
+    type Input = S::Input;
+    /// Output is the value of the signal once it is warmed up, or the number of inputs remaining until it is
+    type Output = SignalValue<<S::Output as ReadyValue>::Value>;
+}
+
+impl<S> Seeded<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ReadyValue,
+{
+    /// Create a new Seeded instance
+    /// # Arguments
+    /// * `signal` - The signal to give the outputs of
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{RelativeStrengthIndex, Seeded};
+    /// use indicato_rs::traits::Apply;
+    /// use indicato_rs::types::SignalValue;
+    ///
+    /// let mut seeded = Seeded::new(RelativeStrengthIndex::new(2, 0).unwrap());
+    /// assert_eq!(seeded.apply(1.0), SignalValue::Seeding { remaining: 2 });
+    /// ```
+    pub fn new(signal: S) -> Self {
+        Self { signal }
+    }
+
+    /// The signal the outputs are given of
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// The output as a signal value, with `remaining` inputs until the signal is warmed up.
+    fn seed(output: S::Output, remaining: usize) -> SignalValue<<S::Output as ReadyValue>::Value> {
+        match (remaining, output.into_ready()) {
+            (0, Some(value)) => SignalValue::Ready(value),
+            _ => SignalValue::Seeding { remaining },
+        }
+    }
+}
+
+impl<S> Validate for Seeded<S>
+where
+    S: Executable + WarmUp + Validate,
+    S::Output: ReadyValue,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        self.signal.validate(input)
+    }
+}
+
+impl<S> Executable for Seeded<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ReadyValue,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let output = self.signal.execute(input, execution_context);
+        // an evaluated input counts towards the warm up as if it had been applied
+        let remaining = match execution_context {
+            ExecutionContext::Apply => self.signal.warmup_remaining(),
+            ExecutionContext::Evaluate => self.signal.warmup_remaining().saturating_sub(1),
+        };
+        Self::seed(output, remaining)
+    }
+}
+
+impl<S> Current for Seeded<S>
+where
+    S: Executable + WarmUp + Current,
+    S::Output: ReadyValue,
+{
+    fn current(&self) -> Self::Output {
+        Self::seed(self.signal.current(), self.signal.warmup_remaining())
+    }
+}
+
+impl<S> WarmUp for Seeded<S>
+where
+    S: Executable + WarmUp,
+    S::Output: ReadyValue,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.signal.warmup_remaining()
+    }
+}
+
+impl<S> Periodic for Seeded<S>
+where
+    S: Executable + WarmUp + Periodic,
+    S::Output: ReadyValue,
+{
+    fn period(&self) -> usize {
+        self.signal.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.signal.effective_lookback()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{BoxedSignal, RelativeStrengthIndex, SimpleMovingAverage};
+
+    #[test]
+    fn test_uniform_outputs() {
+        // an RSI outputs `None` and an SMA outputs partial averages while warming up, both are seeding
+        let mut signals: Vec<BoxedSignal<f64, SignalValue<f64>>> = vec![
+            BoxedSignal::new(Seeded::new(RelativeStrengthIndex::new(2, 0).unwrap())),
+            BoxedSignal::new(Seeded::new(SimpleMovingAverage::new(3).unwrap())),
+        ];
+        let mut outputs = Vec::new();
+        for input in [10.0, 9.0, 8.0] {
+            outputs.push(signals.iter_mut().map(|signal| signal.apply(input)).collect::<Vec<_>>());
+        }
+        assert_eq!(outputs[0], vec![SignalValue::Seeding { remaining: 2 }; 2]);
+        assert_eq!(outputs[1], vec![SignalValue::Seeding { remaining: 1 }; 2]);
+        assert_eq!(outputs[2], vec![SignalValue::Ready(0.0), SignalValue::Ready(9.0)]);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut seeded = Seeded::new(RelativeStrengthIndex::new(2, 0).unwrap());
+        seeded.apply(10.0);
+        assert_eq!(seeded.evaluate(9.0), SignalValue::Seeding { remaining: 1 });
+        seeded.apply(9.0);
+        assert_eq!(seeded.evaluate(8.0), SignalValue::Ready(0.0));
+        assert_eq!(seeded.current(), SignalValue::Seeding { remaining: 1 });
+    }
+
+    #[test]
+    fn test_signal_value() {
+        let seeding: SignalValue<f64> = SignalValue::Seeding { remaining: 3 };
+        assert_eq!(seeding.map(|value| value * 2.0), SignalValue::Seeding { remaining: 3 });
+        assert_eq!(SignalValue::Ready(1.0).remaining(), 0);
+        assert!(SignalValue::Ready(1.0).is_ready());
+    }
+}
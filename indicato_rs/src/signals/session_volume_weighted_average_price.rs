@@ -0,0 +1,490 @@
+use std::{fmt, sync::Arc};
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+    validation,
+};
+
+/// Decides when a [`SessionVolumeWeightedAveragePrice`] starts a new session.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "CheckedSessionBoundary"))]
+pub enum SessionBoundary {
+    /// A new session starts after the given number of bars.
+    Bars(usize),
+    /// A new session starts each time the timestamp reaches the reset time of a new interval, such as each day.
+    ///
+    /// The sessions are the intervals of `length` starting `offset` after the epoch, both in the units of the
+    /// timestamps. For timestamps in seconds a `length` of 86400 with an `offset` of 0 starts a new session at
+    /// midnight UTC, and an `offset` of 46800 starts it at 13:00 UTC. The offset is fixed, so the reset time does not
+    /// follow daylight saving, holidays or half days, which need a [`Custom`](SessionBoundary::Custom) boundary.
+    Interval {
+        /// The length of each session, must be greater than 0.
+        length: i64,
+        /// The time after the epoch at which sessions start, the UTC offset of the reset time.
+        offset: i64,
+    },
+    /// A new session starts when the predicate returns true, it is given the (previous, current) timestamps.
+    ///
+    /// The predicate cannot be serialized, so a signal using this boundary fails to serialize with the `serde`
+    /// feature.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Custom(Arc<dyn Fn(i64, i64) -> bool + Send + Sync>),
+}
+
+impl fmt::Debug for SessionBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bars(bars) => f.debug_tuple("Bars").field(bars).finish(),
+            Self::Interval { length, offset } => f
+                .debug_struct("Interval")
+                .field("length", length)
+                .field("offset", offset)
+                .finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").finish_non_exhaustive(),
+        }
+    }
+}
+
+crate::inspect::debug_state!(SessionBoundary);
+
+impl SessionBoundary {
+    /// Create a session boundary starting a new session after the given number of bars
+    /// # Arguments
+    /// * `bars` - The number of bars in each session, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SessionBoundary;
+    ///
+    /// // start a new session every 390 one minute bars, the length of a New York trading day
+    /// let boundary = SessionBoundary::bars(390);
+    /// assert!(matches!(boundary, SessionBoundary::Bars(390)));
+    /// ```
+    pub fn bars(bars: usize) -> Self {
+        Self::Bars(bars)
+    }
+
+    /// Create a session boundary starting a new session each interval of `length` starting `offset` after the epoch
+    /// # Arguments
+    /// * `length` - The length of each session in the units of the timestamps, must be greater than 0
+    /// * `offset` - The time after the epoch at which sessions start
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SessionBoundary;
+    ///
+    /// // start a new session every hour on the half hour for timestamps in seconds
+    /// let boundary = SessionBoundary::interval(3600, 1800);
+    /// assert!(matches!(boundary, SessionBoundary::Interval { length: 3600, offset: 1800 }));
+    /// ```
+    pub fn interval(length: i64, offset: i64) -> Self {
+        Self::Interval { length, offset }
+    }
+
+    /// Create a daily session boundary for timestamps in seconds
+    /// # Arguments
+    /// * `reset` - The number of seconds after midnight UTC at which each session starts
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SessionBoundary;
+    ///
+    /// // start a new session at 13:30 UTC, 09:30 New York time during daylight saving only
+    /// let boundary = SessionBoundary::daily(13 * 3600 + 30 * 60);
+    /// assert!(matches!(boundary, SessionBoundary::Interval { length: 86400, offset: 48600 }));
+    /// ```
+    pub fn daily(reset: i64) -> Self {
+        Self::interval(86400, reset)
+    }
+
+    /// Create a session boundary from a predicate given the (previous, current) timestamps, such as one following a
+    /// trading calendar with daylight saving, holidays and half days
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SessionBoundary;
+    ///
+    /// // sessions open at these timestamps, the third an hour earlier after the clocks change
+    /// let opens = [0, 86400, 169200];
+    /// let boundary = SessionBoundary::custom(move |previous, current| {
+    ///     opens.iter().any(|&open| previous < open && open <= current)
+    /// });
+    /// assert!(matches!(boundary, SessionBoundary::Custom(_)));
+    /// ```
+    pub fn custom(predicate: impl Fn(i64, i64) -> bool + Send + Sync + 'static) -> Self {
+        Self::Custom(Arc::new(predicate))
+    }
+
+    /// Checks a bar count or interval length is greater than 0.
+    fn validate(&self) -> Result<(), FinError> {
+        match *self {
+            Self::Bars(0) => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Bars must be greater than 0",
+            )),
+            Self::Interval { length, .. } if length <= 0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Length must be greater than 0",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether a new session starts between the previous and current timestamps.
+    fn fires(&self, previous: i64, current: i64) -> bool {
+        match self {
+            Self::Bars(_) => false,
+            Self::Interval { length, offset } => {
+                let session = |timestamp: i64| timestamp.checked_sub(*offset)?.checked_div_euclid(*length);
+                session(previous) != session(current)
+            }
+            Self::Custom(predicate) => predicate(previous, current),
+        }
+    }
+}
+
+/// The serializable session boundaries, checked as they are deserialized so an invalid bar count or interval length
+/// is an error rather than a panic on the next input.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(rename = "SessionBoundary")]
+enum CheckedSessionBoundary {
+    Bars(usize),
+    Interval { length: i64, offset: i64 },
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<CheckedSessionBoundary> for SessionBoundary {
+    type Error = FinError;
+
+    fn try_from(boundary: CheckedSessionBoundary) -> Result<Self, Self::Error> {
+        let boundary = match boundary {
+            CheckedSessionBoundary::Bars(bars) => Self::Bars(bars),
+            CheckedSessionBoundary::Interval { length, offset } => Self::Interval { length, offset },
+        };
+        boundary.validate()?;
+        Ok(boundary)
+    }
+}
+
+/// # Session Volume Weighted Average Price
+/// Container for session resetting Volume Weighted Average Price aggregation
+///
+/// The cumulative price volume and volume are summed from the start of the session, and reset whenever the session
+/// boundary fires so a continuous stream can produce a VWAP per session, the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mrow><mi>k</mi><mo>=</mo><mi>s</mi></mrow>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub><mi>i</mi><mi>k</mi></msub>
+///                 <msub><mi>v</mi><mi>k</mi></msub>
+///             </mrow>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mrow><mi>k</mi><mo>=</mo><mi>s</mi></mrow>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub><mi>v</mi><mi>k</mi></msub>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `s` is the first step of the current session, `i` is the price
+/// and `v` is the volume. If the volume over the session is 0 the output will be the current price.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{SessionBoundary, SessionVolumeWeightedAveragePrice};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Session VWAP which resets at midnight UTC each day for timestamps in seconds
+/// let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::daily(0)).unwrap();
+///
+/// // apply some (price, volume, timestamp) values and check their output
+/// assert_eq!(vwap.apply((2.0, 100.0, 0)), 2.0);
+/// assert_eq!(vwap.apply((4.0, 300.0, 3600)), 3.5);
+/// assert_eq!(vwap.apply((8.0, 100.0, 86400)), 8.0);
+///
+/// // evaluate some values, these won't affect the internal state of the Session VWAP
+/// assert_eq!(vwap.evaluate((4.0, 300.0, 90000)), 5.0);
+///
+/// // fetch the current value of the Session VWAP
+/// assert_eq!(vwap.current(), 8.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SessionVolumeWeightedAveragePrice {
+    boundary: SessionBoundary,
+    cumulative_price_volume: f64,
+    cumulative_volume: f64,
+    /// The number of bars in the current session.
+    bars: usize,
+    previous_timestamp: Option<i64>,
+    current: f64,
+}
+
+impl SessionVolumeWeightedAveragePrice {
+    /// Create a new Session Volume Weighted Average Price instance
+    /// # Arguments
+    /// * `boundary` - Decides when a new session starts, a bar count or interval length must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{SessionBoundary, SessionVolumeWeightedAveragePrice};
+    ///
+    /// let vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Bars(390));
+    /// assert!(vwap.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the bar count or interval length is not greater than 0
+    /// ```
+    /// use indicato_rs::signals::{SessionBoundary, SessionVolumeWeightedAveragePrice};
+    ///
+    /// let vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Bars(0));
+    /// assert!(vwap.is_err());
+    ///
+    /// let vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Interval { length: 0, offset: 0 });
+    /// assert!(vwap.is_err());
+    /// ```
+    pub fn new(boundary: SessionBoundary) -> Result<Self, FinError> {
+        boundary.validate()?;
+        Ok(Self {
+            boundary,
+            cumulative_price_volume: 0.0,
+            cumulative_volume: 0.0,
+            bars: 0,
+            previous_timestamp: None,
+            current: 0.0,
+        })
+    }
+
+    fn is_new_session(&self, timestamp: i64) -> bool {
+        match self.boundary {
+            SessionBoundary::Bars(bars) => self.bars >= bars,
+            _ => self
+                .previous_timestamp
+                .is_some_and(|previous| self.boundary.fires(previous, timestamp)),
+        }
+    }
+}
+
+impl IoState for SessionVolumeWeightedAveragePrice {
+    /// Input is a tuple of (price, volume, timestamp), the timestamp is only used by interval and custom session
+    /// boundaries
+    type Input = (f64, f64, i64);
+    type Output = f64;
+}
+
+impl Validate for SessionVolumeWeightedAveragePrice {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (price, volume, _) = *input;
+        validation::value(price, "Price")?;
+        validation::volume(volume)
+    }
+}
+
+impl Executable for SessionVolumeWeightedAveragePrice {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (price, volume, timestamp) = input;
+        let (cumulative_price_volume, cumulative_volume, bars) =
+            match self.is_new_session(timestamp) {
+                true => (price * volume, volume, 1),
+                false => (
+                    self.cumulative_price_volume + price * volume,
+                    self.cumulative_volume + volume,
+                    self.bars + 1,
+                ),
+            };
+        let result = match cumulative_volume == 0.0 {
+            true => price,
+            false => cumulative_price_volume / cumulative_volume,
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.cumulative_price_volume = cumulative_price_volume;
+                self.cumulative_volume = cumulative_volume;
+                self.bars = bars;
+                self.previous_timestamp = Some(timestamp);
+                self.current = result;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl Current for SessionVolumeWeightedAveragePrice {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for SessionVolumeWeightedAveragePrice {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.bars == 0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply_bars() {
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Bars(2)).unwrap();
+        assert_eq!(vwap.apply((2.0, 100.0, 0)), 2.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 0)), 3.5);
+        assert_eq!(vwap.apply((8.0, 100.0, 0)), 8.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 0)), 5.0);
+        assert_eq!(vwap.apply((1.0, 100.0, 0)), 1.0);
+    }
+
+    #[test]
+    fn test_apply_interval() {
+        let mut vwap =
+            SessionVolumeWeightedAveragePrice::new(SessionBoundary::Interval { length: 10, offset: 0 }).unwrap();
+        assert_eq!(vwap.apply((2.0, 100.0, 1)), 2.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 5)), 3.5);
+        assert_eq!(vwap.apply((6.0, 400.0, 9)), 4.75);
+        assert_eq!(vwap.apply((8.0, 100.0, 10)), 8.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 19)), 5.0);
+        assert_eq!(vwap.apply((1.0, 100.0, 35)), 1.0);
+    }
+
+    #[test]
+    fn test_interval_offset() {
+        let mut vwap =
+            SessionVolumeWeightedAveragePrice::new(SessionBoundary::Interval { length: 10, offset: 5 }).unwrap();
+        assert_eq!(vwap.apply((2.0, 100.0, -3)), 2.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 4)), 3.5);
+        assert_eq!(vwap.apply((8.0, 100.0, 5)), 8.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 14)), 5.0);
+        assert_eq!(vwap.apply((1.0, 100.0, 15)), 1.0);
+    }
+
+    #[test]
+    fn test_daily() {
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::daily(3600)).unwrap();
+        assert_eq!(vwap.apply((2.0, 100.0, 0)), 2.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 3599)), 3.5);
+        assert_eq!(vwap.apply((8.0, 100.0, 3600)), 8.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 86400)), 5.0);
+        assert_eq!(vwap.apply((1.0, 100.0, 90000)), 1.0);
+    }
+
+    #[test]
+    fn test_custom() {
+        // the second session opens an hour early, as an interval boundary can't follow
+        let opens = [10, 20, 25];
+        let boundary = SessionBoundary::custom(move |previous, current| {
+            opens.iter().any(|&open| previous < open && open <= current)
+        });
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(boundary).unwrap();
+        assert_eq!(vwap.apply((2.0, 100.0, 1)), 2.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 9)), 3.5);
+        assert_eq!(vwap.apply((8.0, 100.0, 10)), 8.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 19)), 5.0);
+        assert_eq!(vwap.evaluate((1.0, 100.0, 20)), 1.0);
+        assert_eq!(vwap.apply((1.0, 100.0, 24)), 1.0);
+        assert_eq!(vwap.apply((6.0, 100.0, 26)), 6.0);
+    }
+
+    #[test]
+    fn test_interval_overflow() {
+        // sessions past the range of the timestamps are not started rather than overflowing
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::interval(10, -10)).unwrap();
+        assert_eq!(vwap.apply((2.0, 100.0, i64::MAX - 5)), 2.0);
+        assert_eq!(vwap.apply((4.0, 300.0, i64::MAX)), 3.5);
+    }
+
+    #[test]
+    fn test_zero_volume() {
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Bars(2)).unwrap();
+        assert_eq!(vwap.apply((2.0, 0.0, 0)), 2.0);
+        assert_eq!(vwap.apply((4.0, 0.0, 0)), 4.0);
+        assert_eq!(vwap.apply((8.0, 0.0, 0)), 8.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Bars(2)).unwrap();
+        assert_eq!(vwap.evaluate((4.0, 100.0, 0)), 4.0);
+        assert_eq!(vwap.apply((2.0, 100.0, 0)), 2.0);
+        assert_eq!(vwap.evaluate((4.0, 300.0, 0)), 3.5);
+        assert_eq!(vwap.evaluate((8.0, 100.0, 0)), 5.0);
+        assert_eq!(vwap.apply((4.0, 300.0, 0)), 3.5);
+        assert_eq!(vwap.evaluate((8.0, 100.0, 0)), 8.0);
+        assert_eq!(vwap.apply((8.0, 100.0, 0)), 8.0);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Bars(2)).unwrap();
+        assert_eq!(vwap.current(), 0.0);
+        vwap.apply((2.0, 100.0, 0));
+        vwap.apply((4.0, 300.0, 0));
+        vwap.evaluate((8.0, 100.0, 0));
+        assert_eq!(vwap.current(), 3.5);
+    }
+
+    #[test]
+    fn test_invalid_bars() {
+        let vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::Bars(0));
+        assert!(vwap.is_err());
+    }
+
+    #[test]
+    fn test_invalid_length() {
+        assert!(SessionVolumeWeightedAveragePrice::new(SessionBoundary::Interval { length: 0, offset: 0 }).is_err());
+        assert!(SessionVolumeWeightedAveragePrice::new(SessionBoundary::Interval { length: -10, offset: 0 }).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_invalid() {
+        let json = r#"{"boundary":{"Interval":{"length":0,"offset":0}},"cumulative_price_volume":0.0,
+            "cumulative_volume":0.0,"bars":0,"previous_timestamp":null,"current":0.0}"#;
+        let error = serde_json::from_str::<SessionVolumeWeightedAveragePrice>(json).unwrap_err();
+        assert!(error.to_string().contains("Length must be greater than 0"));
+        assert!(serde_json::from_str::<SessionBoundary>(r#"{"Bars":0}"#).is_err());
+        assert!(serde_json::from_str::<SessionBoundary>(r#"{"Bars":390}"#).is_ok());
+
+        let vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::custom(|_, _| false)).unwrap();
+        assert!(serde_json::to_string(&vwap).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut vwap = SessionVolumeWeightedAveragePrice::new(SessionBoundary::daily(0)).unwrap();
+        vwap.apply((2.0, 100.0, 0));
+        let json = serde_json::to_string(&vwap).unwrap();
+        let mut restored: SessionVolumeWeightedAveragePrice = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.apply((4.0, 300.0, 3600)), vwap.apply((4.0, 300.0, 3600)));
+        assert_eq!(restored.apply((8.0, 100.0, 86400)), 8.0);
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::{Arc, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use crate::{
+    inspect::{SignalState, ToState},
+    traits::{Current, Executable, ExecutionContext, Inspect, IoState, WarmUp},
+};
+
+/// # Shared Signal
+/// Container for a signal shared between threads
+///
+/// The signal is held behind a read write lock, so it can be applied to through a shared reference. Cloning a Shared
+/// Signal creates another handle to the same signal rather than forking its state, so a feed handler thread can apply
+/// inputs while other threads read the current value.
+///
+/// A panic while the signal was locked doesn't make it unusable, the next access carries on with the signal as the
+/// panic left it.
+///
+/// # Example Usage
+/// ```
+/// use std::thread;
+///
+/// use indicato_rs::signals::{SharedSignal, SimpleMovingAverage};
+/// use indicato_rs::traits::Current;
+///
+/// let sma = SharedSignal::new(SimpleMovingAverage::new(2).unwrap());
+///
+/// // apply values from another thread
+/// let feed = sma.clone();
+/// thread::spawn(move || {
+///     feed.apply(1.0);
+///     feed.apply(3.0);
+/// })
+/// .join()
+/// .unwrap();
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(sma.evaluate(5.0), 4.0);
+///
+/// // fetch the current value of the signal
+/// assert_eq!(sma.current(), 2.0);
+/// ```
+#[derive(Debug)]
+pub struct SharedSignal<S> {
+    signal: Arc<RwLock<S>>,
+}
+
+impl<S> Clone for SharedSignal<S> {
+    fn clone(&self) -> Self {
+        Self {
+            signal: Arc::clone(&self.signal),
+        }
+    }
+}
+
+impl<S: Executable> IoState for SharedSignal<S> {
+    type Input = S::Input;
+    type Output = S::Output;
+}
+
+impl<S: Executable> SharedSignal<S> {
+    /// Create a new Shared Signal instance
+    /// # Arguments
+    /// * `signal` - The signal to share between threads
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{RelativeStrengthIndex, SharedSignal};
+    ///
+    /// let rsi = SharedSignal::new(RelativeStrengthIndex::standard());
+    /// ```
+    pub fn new(signal: S) -> Self {
+        Self {
+            signal: Arc::new(RwLock::new(signal)),
+        }
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, S> {
+        self.signal.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, S> {
+        self.signal.write().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Apply an input to the signal, blocking until no other thread is accessing it
+    pub fn apply(&self, input: S::Input) -> S::Output {
+        self.write().execute(input, &ExecutionContext::Apply)
+    }
+
+    /// Evaluate an input without affecting the internal state of the signal, blocking until no other thread is
+    /// accessing it
+    pub fn evaluate(&self, input: S::Input) -> S::Output {
+        self.write().execute(input, &ExecutionContext::Evaluate)
+    }
+
+    /// Run `f` with exclusive access to the signal, such as applying a batch of inputs without other threads
+    /// interleaving their own
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{SharedSignal, SimpleMovingAverage};
+    /// use indicato_rs::traits::ApplyBatch;
+    ///
+    /// let sma = SharedSignal::new(SimpleMovingAverage::new(2).unwrap());
+    /// let outputs = sma.with(|sma| sma.apply_slice(&[1.0, 3.0, 5.0]));
+    /// assert_eq!(outputs, vec![1.0, 2.0, 4.0]);
+    /// ```
+    pub fn with<R>(&self, f: impl FnOnce(&mut S) -> R) -> R {
+        f(&mut self.write())
+    }
+
+    /// A copy of the signal as it is now, which won't see inputs applied after it was taken
+    pub fn snapshot(&self) -> S
+    where
+        S: Clone,
+    {
+        self.read().clone()
+    }
+}
+
+impl<S: Executable + Current> Current for SharedSignal<S> {
+    fn current(&self) -> Self::Output {
+        self.read().current()
+    }
+}
+
+impl<S: Executable + WarmUp> WarmUp for SharedSignal<S> {
+    fn warmup_remaining(&self) -> usize {
+        self.read().warmup_remaining()
+    }
+}
+
+impl<S: Executable + Inspect> Inspect for SharedSignal<S> {
+    fn inspect(&self) -> SignalState {
+        SignalState::new("SharedSignal").field("signal", self.read().to_state())
+    }
+
+    fn name(&self) -> &'static str {
+        "SharedSignal"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+    use crate::inspect::StateValue;
+    use crate::signals::*;
+    use crate::traits::Apply;
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_signals_are_send_sync() {
+        assert_send_sync::<RelativeStrengthIndex>();
+        assert_send_sync::<SimpleMovingAverage>();
+        assert_send_sync::<ExponentialMovingAverage>();
+        assert_send_sync::<WildersSmoothing>();
+        assert_send_sync::<MovingAverageConvergenceDivergence>();
+        assert_send_sync::<MaximumPeriod>();
+        assert_send_sync::<MinimumPeriod>();
+        assert_send_sync::<StochasticMomentumOscillator>();
+        assert_send_sync::<BollingerBands>();
+        assert_send_sync::<BollingerBandsExtended>();
+        assert_send_sync::<IchimokuCloud>();
+        assert_send_sync::<Momentum>();
+        assert_send_sync::<WeightedMovingAverage>();
+        assert_send_sync::<ChaikinMoneyFlow>();
+        assert_send_sync::<MoneyFlowIndex>();
+        assert_send_sync::<ElderRay>();
+        assert_send_sync::<ForceIndex>();
+        assert_send_sync::<RateOfChange>();
+        assert_send_sync::<KnowSureThing>();
+        assert_send_sync::<LinearRegression>();
+        assert_send_sync::<MovingAverageEnvelope>();
+        assert_send_sync::<FractalAdaptiveMovingAverage>();
+        assert_send_sync::<EaseOfMovement>();
+        assert_send_sync::<NegativeVolumeIndex>();
+        assert_send_sync::<PositiveVolumeIndex>();
+        assert_send_sync::<PriceVolumeTrend>();
+        assert_send_sync::<StochasticOscillator>();
+        assert_send_sync::<SmiErgodic>();
+        assert_send_sync::<PivotDetector>();
+        assert_send_sync::<ZigZag>();
+        assert_send_sync::<VolumeWeightedMovingAverage>();
+        assert_send_sync::<HistoricalVolatility>();
+        assert_send_sync::<RollingBeta>();
+        assert_send_sync::<RollingCorrelation>();
+        assert_send_sync::<HistoricalVar>();
+        assert_send_sync::<PercentileRank>();
+        assert_send_sync::<RollingMedian>();
+        assert_send_sync::<SessionVolumeWeightedAveragePrice>();
+        assert_send_sync::<CumulativeSum>();
+        assert_send_sync::<KalmanTrend>();
+        assert_send_sync::<AverageDirectionalIndex>();
+        assert_send_sync::<Adxr>();
+        assert_send_sync::<MovingAverageRibbon>();
+        assert_send_sync::<HoltSmoothing>();
+        assert_send_sync::<HoltWinters>();
+        assert_send_sync::<HampelFilter>();
+        assert_send_sync::<SavitzkyGolay>();
+        assert_send_sync::<SuperSmoother>();
+        assert_send_sync::<TimeWeightedAveragePrice>();
+        assert_send_sync::<Returns>();
+        assert_send_sync::<CumulativeReturn>();
+        assert_send_sync::<HalfLife>();
+        assert_send_sync::<Crossover>();
+        assert_send_sync::<Alerts<RelativeStrengthIndex>>();
+        assert_send_sync::<Lagged<RelativeStrengthIndex>>();
+        assert_send_sync::<Difference<RelativeStrengthIndex>>();
+        assert_send_sync::<Normalized<RelativeStrengthIndex>>();
+        assert_send_sync::<Smoothed<RelativeStrengthIndex, ExponentialMovingAverage>>();
+        assert_send_sync::<Seeded<RelativeStrengthIndex>>();
+        assert_send_sync::<Filtered<RelativeStrengthIndex>>();
+        assert_send_sync::<RollingApply>();
+        assert_send_sync::<BoxedSignal<f64, Option<f64>>>();
+        assert_send_sync::<FixedSimpleMovingAverage<20>>();
+        assert_send_sync::<FixedMaximumPeriod<20>>();
+        assert_send_sync::<FixedMinimumPeriod<20>>();
+        assert_send_sync::<SharedSignal<SimpleMovingAverage>>();
+        assert_send_sync::<GapFilled<SimpleMovingAverage>>();
+        assert_send_sync::<SignalSet<BoxedSignal<f64, Option<f64>>>>();
+        #[cfg(feature = "chrono")]
+        assert_send_sync::<Timed<SimpleMovingAverage>>();
+        #[cfg(feature = "chrono")]
+        assert_send_sync::<TimeDecayedMovingAverage>();
+        assert_send_sync::<registry::SignalRegistry>();
+    }
+
+    #[test]
+    fn test_shared_between_threads() {
+        let shared = SharedSignal::new(CumulativeSum::new());
+        let handles = (0..4)
+            .map(|_| {
+                let feed = shared.clone();
+                thread::spawn(move || (0..100).for_each(|_| {
+                    feed.apply(1.0);
+                }))
+            })
+            .collect::<Vec<_>>();
+        handles.into_iter().for_each(|handle| handle.join().unwrap());
+        assert_eq!(shared.current(), 400.0);
+        assert_eq!(shared.evaluate(1.0), 401.0);
+        assert_eq!(shared.snapshot().current(), 400.0);
+    }
+
+    #[test]
+    fn test_warmup() {
+        let shared = SharedSignal::new(SimpleMovingAverage::new(2).unwrap());
+        assert_eq!(shared.warmup_remaining(), 2);
+        shared.with(|sma| sma.apply(1.0));
+        assert_eq!(shared.warmup_remaining(), 1);
+    }
+
+    #[test]
+    fn test_inspect() {
+        let shared = SharedSignal::new(SimpleMovingAverage::new(2).unwrap());
+        shared.apply(1.0);
+        let state = shared.inspect();
+        assert_eq!(state.name, "SharedSignal");
+        assert_eq!(
+            state.get("signal"),
+            Some(&StateValue::Nested(shared.snapshot().inspect()))
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, WarmUp};
+
+/// # Signal Set
+/// Container for many named signals driven by the same inputs
+///
+/// Each input is passed to every signal and the outputs are returned keyed by the name each signal was registered
+/// under, so a set of indicators can be calculated per bar with a single call. Signals with different types, such as
+/// an RSI and a MACD, can be stored together as [`BoxedSignal`](super::BoxedSignal)s with the same output type.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{
+///     BoxedSignal, MovingAverageConvergenceDivergence, RelativeStrengthIndex, SignalSet, SimpleMovingAverage,
+/// };
+/// use indicato_rs::traits::{Apply, Combine, Evaluate, Current};
+///
+/// // register an RSI, a MACD and an SMA, the outputs of signals without a warm up are wrapped in `Some`
+/// let mut set: SignalSet<BoxedSignal<f64, Option<f64>>> = SignalSet::new();
+/// set.register("rsi", BoxedSignal::new(RelativeStrengthIndex::new(2, 0).unwrap()));
+/// set.register("macd", BoxedSignal::new(MovingAverageConvergenceDivergence::new(2, 4).unwrap().map(Some)));
+/// set.register("sma", BoxedSignal::new(SimpleMovingAverage::new(2).unwrap().map(Some)));
+///
+/// // apply some values to every signal and check their output
+/// let outputs = set.apply(2.0);
+/// assert_eq!(outputs["rsi"], None);
+/// assert_eq!(outputs["sma"], Some(2.0));
+/// assert_eq!(set.apply(4.0)["sma"], Some(3.0));
+///
+/// // evaluate some values, these won't affect the internal state of the signals
+/// assert_eq!(set.evaluate(8.0)["sma"], Some(6.0));
+///
+/// // fetch the current value of every signal
+/// assert_eq!(set.current()["sma"], Some(3.0));
+/// assert_eq!(set.names(), vec!["rsi", "macd", "sma"]);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SignalSet<S>
+where
+    S: Executable,
+    S::Input: Clone,
+{
+    /// The signals and the names they were registered under, in the order they were registered.
+    signals: Vec<(String, S)>,
+}
+
+impl<S> IoState for SignalSet<S>
+where
+    S: Executable,
+    S::Input: Clone,
+{
+    type Input = S::Input;
+    /// Output is the output of each signal, keyed by the name it was registered under
+    type Output = HashMap<String, S::Output>;
+}
+
+impl<S> SignalSet<S>
+where
+    S: Executable,
+    S::Input: Clone,
+{
+    /// Create a new Signal Set instance, without any signals
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{SignalSet, SimpleMovingAverage};
+    ///
+    /// let set = SignalSet::<SimpleMovingAverage>::new();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            signals: Vec::new(),
+        }
+    }
+
+    /// Register a signal under a name, replacing any signal already registered under that name
+    /// # Arguments
+    /// * `name` - The name the output of the signal is keyed by
+    /// * `signal` - The signal to drive
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{SignalSet, SimpleMovingAverage};
+    /// use indicato_rs::traits::Apply;
+    ///
+    /// let mut set = SignalSet::new();
+    /// set.register("fast", SimpleMovingAverage::new(1).unwrap());
+    /// set.register("fast", SimpleMovingAverage::new(2).unwrap());
+    ///
+    /// set.apply(1.0);
+    /// assert_eq!(set.apply(3.0)["fast"], 2.0);
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    pub fn register(&mut self, name: &str, signal: S) {
+        match self.signals.iter_mut().find(|(registered, _)| registered == name) {
+            Some((_, registered)) => *registered = signal,
+            None => self.signals.push((name.to_string(), signal)),
+        }
+    }
+
+    /// Remove the signal registered under a name, returning it if there was one
+    pub fn remove(&mut self, name: &str) -> Option<S> {
+        let index = self.signals.iter().position(|(registered, _)| registered == name)?;
+        Some(self.signals.remove(index).1)
+    }
+
+    /// The signal registered under a name
+    pub fn get(&self, name: &str) -> Option<&S> {
+        self.signals
+            .iter()
+            .find(|(registered, _)| registered == name)
+            .map(|(_, signal)| signal)
+    }
+
+    /// The names of the signals in the set, in the order they were registered
+    pub fn names(&self) -> Vec<&str> {
+        self.signals.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    /// The number of signals in the set
+    pub fn len(&self) -> usize {
+        self.signals.len()
+    }
+
+    /// Whether the set has no signals
+    pub fn is_empty(&self) -> bool {
+        self.signals.is_empty()
+    }
+}
+
+impl<S> Default for SignalSet<S>
+where
+    S: Executable,
+    S::Input: Clone,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S> Executable for SignalSet<S>
+where
+    S: Executable,
+    S::Input: Clone,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        self.signals
+            .iter_mut()
+            .map(|(name, signal)| (name.clone(), signal.execute(input.clone(), execution_context)))
+            .collect()
+    }
+}
+
+impl<S> Current for SignalSet<S>
+where
+    S: Executable + Current,
+    S::Input: Clone,
+{
+    fn current(&self) -> Self::Output {
+        self.signals
+            .iter()
+            .map(|(name, signal)| (name.clone(), signal.current()))
+            .collect()
+    }
+}
+
+impl<S> WarmUp for SignalSet<S>
+where
+    S: Executable + WarmUp,
+    S::Input: Clone,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.signals
+            .iter()
+            .map(|(_, signal)| signal.warmup_remaining())
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::Momentum;
+
+    fn set() -> SignalSet<Momentum> {
+        let mut set = SignalSet::new();
+        set.register("one", Momentum::new(1).unwrap());
+        set.register("two", Momentum::new(2).unwrap());
+        set
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut set = set();
+        assert_eq!(set.apply(1.0), HashMap::from([("one".to_string(), None), ("two".to_string(), None)]));
+        set.apply(2.0);
+        assert_eq!(
+            set.apply(4.0),
+            HashMap::from([("one".to_string(), Some(2.0)), ("two".to_string(), Some(3.0))])
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut set = set();
+        set.apply(1.0);
+        set.apply(2.0);
+        assert_eq!(set.evaluate(5.0)["two"], Some(4.0));
+        assert_eq!(set.current()["one"], Some(1.0));
+        assert_eq!(set.apply(4.0)["two"], Some(3.0));
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut set = set();
+        assert_eq!(set.warmup_remaining(), Momentum::new(2).unwrap().warmup_remaining());
+        assert_eq!(SignalSet::<Momentum>::new().warmup_remaining(), 0);
+        set.apply(1.0);
+        set.apply(1.0);
+        set.apply(1.0);
+        assert_eq!(set.warmup_remaining(), 0);
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = set();
+        assert!(set.remove("one").is_some());
+        assert!(set.remove("one").is_none());
+        assert!(set.get("one").is_none());
+        assert!(set.get("two").is_some());
+        assert_eq!(set.names(), vec!["two"]);
+        assert!(set.apply(1.0).keys().eq(["two"].iter()));
+    }
+}
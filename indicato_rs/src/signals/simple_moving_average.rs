@@ -1,13 +1,16 @@
 use std::collections::VecDeque;
 
 use crate::{
-    deque_math::DequeMathExtF64,
+    deque_math::compensated_mean,
     fin_error::{FinError, FinErrorType},
-    traits::{Apply, Current, Evaluate},
+    traits::{Apply, Current, Evaluate, Numeric},
+    validation,
 };
-use indicato_rs_proc::{Apply, Evaluate};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+#[cfg(feature = "simd")]
+use crate::simd;
 
-use crate::traits::{Executable, ExecutionContext, IoState};
+use crate::traits::{Executable, ExecutionContext, Inspect, IoState, MovingAverage, Periodic, Validate, WarmUp};
 
 /// # Simple Moving Average
 /// Container for Simple Moving Average (SMA) aggregation
@@ -71,6 +74,10 @@ use crate::traits::{Executable, ExecutionContext, IoState};
 /// <br><br>
 /// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `p` is the period of the simple moving average and `i` is the input.
 ///
+/// The aggregation is generic over the [`Numeric`] type of its inputs and defaults to `f64`, with the `rust_decimal`
+/// feature it can also aggregate `rust_decimal::Decimal` prices exactly.
+/// `new` creates an `f64` aggregation, other types are created with [`for_type`](SimpleMovingAverage::for_type).
+///
 /// # Example Usage
 /// ```
 /// use indicato_rs::signals::SimpleMovingAverage;
@@ -93,19 +100,31 @@ use crate::traits::{Executable, ExecutionContext, IoState};
 /// assert_eq!(sma.current(), 3.0);
 /// ````
 ///
-#[derive(Apply, Evaluate)]
-pub struct SimpleMovingAverage {
+/// Other float types can be aggregated by naming the type parameter
+/// ```
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::traits::Apply;
+///
+/// let mut sma = SimpleMovingAverage::<f32>::for_type(2).unwrap();
+/// assert_eq!(sma.apply(1.0_f32), 1.0);
+/// assert_eq!(sma.apply(2.0_f32), 1.5);
+/// ```
+///
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimpleMovingAverage<T: Numeric = f64> {
     period: usize,
-    values: VecDeque<f64>,
+    values: VecDeque<T>,
 }
 
-impl IoState for SimpleMovingAverage {
-    type Input = f64;
-    type Output = f64;
+impl<T: Numeric> IoState for SimpleMovingAverage<T> {
+    type Input = T;
+    type Output = T;
 }
 
-impl SimpleMovingAverage {
-    /// Create a new Simple Moving Average instance
+impl<T: Numeric> SimpleMovingAverage<T> {
+    /// Create a new Simple Moving Average instance taking inputs of type `T`, such as `f32` or, with the
+    /// `rust_decimal` feature, `rust_decimal::Decimal`
     /// # Arguments
     /// * `period` - The period of the Simple Moving Average aggregation, must be greater than 0
     ///
@@ -114,7 +133,7 @@ impl SimpleMovingAverage {
     /// use indicato_rs::signals::SimpleMovingAverage;
     /// use indicato_rs::traits::{Apply, Evaluate, Current};
     ///
-    /// let sma = SimpleMovingAverage::new(3);
+    /// let sma = SimpleMovingAverage::<f32>::for_type(3);
     /// assert!(sma.is_ok());
     /// ```
     /// # Errors
@@ -122,11 +141,11 @@ impl SimpleMovingAverage {
     /// ```
     /// use indicato_rs::signals::SimpleMovingAverage;
     ///
-    /// let sma = SimpleMovingAverage::new(0);
+    /// let sma = SimpleMovingAverage::<f32>::for_type(0);
     ///
     /// assert!(sma.is_err());
     /// ```
-    pub fn new(period: usize) -> Result<Self, FinError> {
+    pub fn for_type(period: usize) -> Result<Self, FinError> {
         match period {
             0 => Err(FinError::new(
                 FinErrorType::InvalidInput,
@@ -140,7 +159,40 @@ impl SimpleMovingAverage {
     }
 }
 
-impl Executable for SimpleMovingAverage {
+impl SimpleMovingAverage {
+    /// Create a new Simple Moving Average instance
+    /// # Arguments
+    /// * `period` - The period of the Simple Moving Average aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SimpleMovingAverage;
+    /// use indicato_rs::traits::{Apply, Evaluate, Current};
+    ///
+    /// let sma = SimpleMovingAverage::new(3);
+    /// assert!(sma.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::SimpleMovingAverage;
+    ///
+    /// let sma = SimpleMovingAverage::new(0);
+    ///
+    /// assert!(sma.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
+impl<T: Numeric> Validate for SimpleMovingAverage<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<T: Numeric> Executable for SimpleMovingAverage<T> {
     fn execute(
         &mut self,
         input: Self::Input,
@@ -152,7 +204,7 @@ impl Executable for SimpleMovingAverage {
                 if self.values.len() > self.period {
                     self.values.pop_front();
                 }
-                self.values.mean()
+                compensated_mean(&self.values)
             },
             ExecutionContext::Evaluate => {
                 let mut values = self.values.clone();
@@ -160,21 +212,54 @@ impl Executable for SimpleMovingAverage {
                 if values.len() > self.period {
                     values.pop_front();
                 }
-                values.mean()
+                compensated_mean(&values)
             }
         }
     }
+
+    /// With the `simd` feature an `f64` average calculates four windows at a time.
+    fn execute_slice(&mut self, inputs: &[T]) -> Vec<T> {
+        #[cfg(feature = "simd")]
+        if let Some(outputs) =
+            simd::apply_f64_windows(&mut self.values, self.period, inputs, simd::window_means, compensated_mean)
+        {
+            return outputs;
+        }
+        inputs
+            .iter()
+            .map(|&input| self.execute(input, &ExecutionContext::Apply))
+            .collect()
+    }
 }
 
-impl Current for SimpleMovingAverage {
+impl<T: Numeric> Current for SimpleMovingAverage<T> {
     fn current(&self) -> Self::Output {
-        self.values.mean()
+        compensated_mean(&self.values)
+    }
+}
+
+impl<T: Numeric> WarmUp for SimpleMovingAverage<T> {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl<T: Numeric> Periodic for SimpleMovingAverage<T> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Numeric> MovingAverage for SimpleMovingAverage<T> {
+    fn with_period(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::traits::ApplyBatch;
 
     #[test]
     fn test_apply() {
@@ -212,4 +297,54 @@ mod tests {
         let sma = SimpleMovingAverage::new(0);
         assert!(sma.is_err());
     }
+
+    #[test]
+    fn test_clone() {
+        let mut sma = SimpleMovingAverage::new(2).unwrap();
+        sma.apply(1.0);
+        let mut fork = sma.clone();
+        assert_eq!(fork.apply(5.0), 3.0);
+        assert_eq!(sma.current(), 1.0);
+        assert_eq!(sma.apply(3.0), 2.0);
+    }
+
+    #[test]
+    fn test_f32() {
+        let mut sma = SimpleMovingAverage::<f32>::for_type(3).unwrap();
+        assert_eq!(sma.apply(1.0), 1.0);
+        assert_eq!(sma.apply(2.0), 1.5);
+        assert_eq!(sma.apply(3.0), 2.0);
+        assert_eq!(sma.evaluate(6.0), 11.0 / 3.0);
+        assert_eq!(sma.apply(4.0), 3.0);
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut sma = SimpleMovingAverage::<Decimal>::for_type(3).unwrap();
+        assert_eq!(sma.apply(Decimal::new(1, 1)), Decimal::new(1, 1));
+        assert_eq!(sma.apply(Decimal::new(2, 1)), Decimal::new(15, 2));
+        assert_eq!(sma.evaluate(Decimal::new(3, 1)), Decimal::new(2, 1));
+        assert_eq!(sma.apply(Decimal::new(3, 1)), Decimal::new(2, 1));
+        assert_eq!(sma.current(), Decimal::new(2, 1));
+    }
+
+    #[test]
+    fn test_apply_slice() {
+        let inputs = (0..37).map(|i| f64::from(i * 7 % 13) - 6.5).collect::<Vec<f64>>();
+        let mut sma = SimpleMovingAverage::new(5).unwrap();
+        let mut expected = sma.clone();
+        sma.apply(1.5);
+        expected.apply(1.5);
+        let outputs = inputs.iter().map(|&input| expected.apply(input)).collect::<Vec<f64>>();
+        assert_eq!(sma.apply_slice(&inputs), outputs);
+        assert_eq!(sma.evaluate(2.0), expected.evaluate(2.0));
+        assert!(sma.apply_slice(&[]).is_empty());
+
+        let mut sma = SimpleMovingAverage::<f32>::for_type(2).unwrap();
+        assert_eq!(sma.apply_slice(&[1.0, 3.0, 5.0]), vec![1.0, 2.0, 4.0]);
+        assert_eq!(sma.current(), 4.0);
+    }
 }
@@ -0,0 +1,345 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::FinError,
+    traits::{
+        Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate,
+        WarmUp,
+    },
+    validation,
+};
+
+use super::ExponentialMovingAverage;
+
+/// Output of the [`SmiErgodic`] signal.
+///
+/// Tuples of (smi, signal) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmiErgodicOutput {
+    /// The SMI Ergodic, the double smoothed momentum as a percentage of the double smoothed absolute momentum.
+    pub smi: f64,
+    /// The signal line, the Exponential Moving Average of the SMI Ergodic.
+    pub signal: f64,
+}
+
+impl From<(f64, f64)> for SmiErgodicOutput {
+    fn from((smi, signal): (f64, f64)) -> Self {
+        Self { smi, signal }
+    }
+}
+
+impl From<SmiErgodicOutput> for (f64, f64) {
+    fn from(output: SmiErgodicOutput) -> Self {
+        (output.smi, output.signal)
+    }
+}
+
+/// The SMI Ergodic and its signal line are passed on once they are available, such as to a
+/// [`Crossover`](super::Crossover).
+impl ChainValue<(f64, f64)> for Option<SmiErgodicOutput> {
+    fn into_input(self) -> Option<(f64, f64)> {
+        self.map(Into::into)
+    }
+}
+
+/// # SMI Ergodic
+/// Container for SMI Ergodic (Stochastic Momentum Index) aggregation
+///
+/// Unlike the [`StochasticMomentumOscillator`](super::StochasticMomentumOscillator), which is based on the highest
+/// and lowest values over a period, the SMI Ergodic is the ratio of the double smoothed momentum to the double
+/// smoothed absolute momentum. The first entry will produce `None` as there is no momentum yet, after which the
+/// following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mn>100</mn>
+///         <mo>×</mo>
+///         <mfrac>
+///             <mrow>
+///                 <msub>
+///                     <mi>EMA</mi>
+///                     <mi>s</mi>
+///                 </msub>
+///                 <mo>(</mo>
+///                 <msub>
+///                     <mi>EMA</mi>
+///                     <mi>l</mi>
+///                 </msub>
+///                 <mo>(</mo>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mn>n</mn>
+///                 </msub>
+///                 <mo>−</mo>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mn>n-1</mn>
+///                 </msub>
+///                 <mo>)</mo>
+///                 <mo>)</mo>
+///             </mrow>
+///             <mrow>
+///                 <msub>
+///                     <mi>EMA</mi>
+///                     <mi>s</mi>
+///                 </msub>
+///                 <mo>(</mo>
+///                 <msub>
+///                     <mi>EMA</mi>
+///                     <mi>l</mi>
+///                 </msub>
+///                 <mo>(</mo>
+///                 <mo>|</mo>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mn>n</mn>
+///                 </msub>
+///                 <mo>−</mo>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mn>n-1</mn>
+///                 </msub>
+///                 <mo>|</mo>
+///                 <mo>)</mo>
+///                 <mo>)</mo>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `EMA` is the Exponential Moving Average, `l` is the long
+/// smoothing period, `s` is the short smoothing period and `i` is the input. The signal line is an Exponential
+/// Moving Average of the output. If the smoothed absolute momentum is 0 the output will be 0.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{SmiErgodic, SmiErgodicOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new SMI Ergodic with long, short and signal periods of 2
+/// let mut smi = SmiErgodic::new(2, 2, 2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(smi.apply(1.0), None);
+/// assert_eq!(smi.apply(2.0), Some(SmiErgodicOutput { smi: 100.0, signal: 100.0 }));
+/// assert_eq!(smi.apply(4.0), Some((100.0, 100.0).into()));
+///
+/// // evaluate some values, these won't affect the internal state of the SMI Ergodic
+/// assert!(smi.evaluate(3.0).unwrap().smi < 100.0);
+///
+/// // fetch the current value of the SMI Ergodic
+/// assert_eq!(smi.current(), Some((100.0, 100.0).into()));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmiErgodic {
+    momentum_long: ExponentialMovingAverage,
+    momentum_short: ExponentialMovingAverage,
+    absolute_long: ExponentialMovingAverage,
+    absolute_short: ExponentialMovingAverage,
+    signal: ExponentialMovingAverage,
+    /// The previous input value.
+    previous: Option<f64>,
+    current: Option<SmiErgodicOutput>,
+}
+
+impl SmiErgodic {
+    /// Create a new SMI Ergodic instance
+    /// # Arguments
+    /// * `long_period` - The period of the first Exponential Moving Average smoothing, must be greater than 0
+    /// * `short_period` - The period of the second Exponential Moving Average smoothing, must be greater than 0
+    /// * `signal_period` - The period of the Exponential Moving Average of the signal line, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SmiErgodic;
+    ///
+    /// let smi = SmiErgodic::new(20, 5, 5);
+    /// assert!(smi.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    /// ```
+    /// use indicato_rs::signals::SmiErgodic;
+    ///
+    /// let smi = SmiErgodic::new(20, 0, 5);
+    ///
+    /// assert!(smi.is_err());
+    /// ```
+    pub fn new(
+        long_period: usize,
+        short_period: usize,
+        signal_period: usize,
+    ) -> Result<Self, FinError> {
+        Ok(Self {
+            momentum_long: ExponentialMovingAverage::new(long_period)?,
+            momentum_short: ExponentialMovingAverage::new(short_period)?,
+            absolute_long: ExponentialMovingAverage::new(long_period)?,
+            absolute_short: ExponentialMovingAverage::new(short_period)?,
+            signal: ExponentialMovingAverage::new(signal_period)?,
+            previous: None,
+            current: None,
+        })
+    }
+
+    /// Create a new SMI Ergodic instance with the conventional long, short and signal periods of 20, 5 and 5
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SmiErgodic;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let smi = SmiErgodic::standard();
+    /// assert_eq!(smi.period(), 20);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(20, 5, 5).unwrap()
+    }
+}
+
+impl Default for SmiErgodic {
+    /// Create a new SMI Ergodic instance with the conventional long, short and signal periods of 20, 5 and 5
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl IoState for SmiErgodic {
+    type Input = f64;
+    /// Output is the SMI Ergodic and its signal line, `None` until a second value has been applied
+    type Output = Option<SmiErgodicOutput>;
+}
+
+impl Validate for SmiErgodic {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for SmiErgodic {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let result = self.previous.map(|previous| {
+            let momentum = input - previous;
+            let numerator = self.momentum_short.execute(
+                self.momentum_long.execute(momentum, execution_context),
+                execution_context,
+            );
+            let denominator = self.absolute_short.execute(
+                self.absolute_long
+                    .execute(momentum.abs(), execution_context),
+                execution_context,
+            );
+            let smi = match denominator == 0.0 {
+                true => 0.0,
+                false => 100.0 * numerator / denominator,
+            };
+            SmiErgodicOutput {
+                smi,
+                signal: self.signal.execute(smi, execution_context),
+            }
+        });
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.previous = Some(input);
+                self.current = result;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl Current for SmiErgodic {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for SmiErgodic {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none()) + self.signal.warmup_remaining()
+    }
+}
+
+impl Periodic for SmiErgodic {
+    fn period(&self) -> usize {
+        self.momentum_long.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.momentum_long.period() + self.momentum_short.period() + self.signal.period() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply() {
+        let mut smi = SmiErgodic::new(2, 2, 2).unwrap();
+        let expected = [
+            (100.0, 100.0),
+            (100.0, 100.0),
+            (31.428571428571434, 54.28571428571429),
+            (15.789473684210526, 28.621553884711783),
+            (82.13399503722087, 64.29651465305118),
+        ];
+        assert_eq!(smi.apply(1.0), None);
+        for (input, (value, signal)) in [2.0, 4.0, 3.0, 3.0, 6.0].into_iter().zip(expected) {
+            let output = smi.apply(input).unwrap();
+            assert_abs_diff_eq!(output.smi, value, epsilon = 10e-7);
+            assert_abs_diff_eq!(output.signal, signal, epsilon = 10e-7);
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut smi = SmiErgodic::new(2, 2, 2).unwrap();
+        assert_eq!(smi.evaluate(1.0), None);
+        assert_eq!(smi.apply(1.0), None);
+        assert_eq!(smi.evaluate(2.0), Some((100.0, 100.0).into()));
+        assert_eq!(smi.evaluate(0.0), Some((-100.0, -100.0).into()));
+        assert_eq!(smi.apply(2.0), Some((100.0, 100.0).into()));
+        assert_eq!(smi.apply(4.0), Some((100.0, 100.0).into()));
+    }
+
+    #[test]
+    fn test_current() {
+        let mut smi = SmiErgodic::new(2, 2, 2).unwrap();
+        assert_eq!(smi.current(), None);
+        smi.apply(1.0);
+        assert_eq!(smi.current(), None);
+        smi.apply(2.0);
+        smi.evaluate(0.0);
+        assert_eq!(smi.current(), Some((100.0, 100.0).into()));
+    }
+
+    #[test]
+    fn test_flat_input() {
+        let mut smi = SmiErgodic::default();
+        assert_eq!(smi.apply(5.0), None);
+        assert_eq!(smi.apply(5.0), Some((0.0, 0.0).into()));
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(SmiErgodic::new(0, 5, 5).is_err());
+        assert!(SmiErgodic::new(20, 0, 5).is_err());
+        assert!(SmiErgodic::new(20, 5, 0).is_err());
+    }
+}
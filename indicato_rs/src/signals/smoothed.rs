@@ -0,0 +1,237 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::FinError,
+    traits::{
+        chained_warmup, Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState,
+        MovingAverage, Periodic, Validate, WarmUp,
+    },
+};
+
+/// # Smoothed
+/// Container for a signal and a moving average of its outputs
+///
+/// Each input is applied to the signal and its output is applied to a moving average of type `M`, such as a
+/// [`SimpleMovingAverage`](super::SimpleMovingAverage) or an
+/// [`ExponentialMovingAverage`](super::ExponentialMovingAverage), giving any signal a signal line. Outputs of `None`
+/// while the signal is warming up are not passed on, the moving average is left untouched and its output is `None`.
+///
+/// The pair of outputs can be fed into a [`Crossover`](super::Crossover) to find where the signal crosses its signal
+/// line.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{Momentum, SimpleMovingAverage, Smoothed};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a Momentum with a period of 1 and a 2 period Simple Moving Average signal line
+/// let mut smoothed: Smoothed<Momentum, SimpleMovingAverage> = Smoothed::new(Momentum::new(1).unwrap(), 2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(smoothed.apply(1.0), (None, None));
+/// assert_eq!(smoothed.apply(3.0), (Some(2.0), Some(2.0)));
+/// assert_eq!(smoothed.apply(4.0), (Some(1.0), Some(1.5)));
+/// assert_eq!(smoothed.apply(8.0), (Some(4.0), Some(2.5)));
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(smoothed.evaluate(8.0), (Some(0.0), Some(2.0)));
+///
+/// // fetch the current value of the signal
+/// assert_eq!(smoothed.current(), (Some(4.0), Some(2.5)));
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Smoothed<S, M>
+where
+    S: Executable,
+    M: MovingAverage,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    signal: S,
+    average: M,
+}
+
+impl<S, M> IoState for Smoothed<S, M>
+where
+    S: Executable,
+    M: MovingAverage,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    type Input = S::Input;
+    /// Output is a tuple of (signal output, moving average output)
+    type Output = (S::Output, Option<M::Output>);
+}
+
+impl<S, M> Smoothed<S, M>
+where
+    S: Executable,
+    M: MovingAverage,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    /// Create a new Smoothed instance
+    /// # Arguments
+    /// * `signal` - The signal to smooth the outputs of
+    /// * `period` - The period of the moving average
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{ExponentialMovingAverage, MovingAverageConvergenceDivergence, Smoothed};
+    ///
+    /// let macd = MovingAverageConvergenceDivergence::new(12, 26).unwrap();
+    /// let smoothed = Smoothed::<_, ExponentialMovingAverage>::new(macd, 9);
+    /// assert!(smoothed.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the moving average can't be created with the period
+    /// ```
+    /// use indicato_rs::signals::{ExponentialMovingAverage, MovingAverageConvergenceDivergence, Smoothed};
+    ///
+    /// let macd = MovingAverageConvergenceDivergence::new(12, 26).unwrap();
+    /// let smoothed = Smoothed::<_, ExponentialMovingAverage>::new(macd, 0);
+    ///
+    /// assert!(smoothed.is_err());
+    /// ```
+    pub fn new(signal: S, period: usize) -> Result<Self, FinError> {
+        Ok(Self {
+            signal,
+            average: M::with_period(period)?,
+        })
+    }
+
+    /// The signal being smoothed
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// The moving average of the outputs of the signal
+    pub fn average(&self) -> &M {
+        &self.average
+    }
+}
+
+impl<S, M> Validate for Smoothed<S, M>
+where
+    S: Executable + Validate,
+    M: MovingAverage,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        self.signal.validate(input)
+    }
+}
+
+impl<S, M> Executable for Smoothed<S, M>
+where
+    S: Executable,
+    M: MovingAverage,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let output = self.signal.execute(input, execution_context);
+        let smoothed = output
+            .clone()
+            .into_input()
+            .map(|value| self.average.execute(value, execution_context));
+        (output, smoothed)
+    }
+}
+
+impl<S, M> Current for Smoothed<S, M>
+where
+    S: Executable + Current,
+    M: MovingAverage + Current,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    fn current(&self) -> Self::Output {
+        let output = self.signal.current();
+        let smoothed = output.clone().into_input().map(|_| self.average.current());
+        (output, smoothed)
+    }
+}
+
+impl<S, M> WarmUp for Smoothed<S, M>
+where
+    S: Executable + WarmUp,
+    M: MovingAverage + WarmUp,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(self.signal.warmup_remaining(), self.average.warmup_remaining())
+    }
+}
+
+impl<S, M> Periodic for Smoothed<S, M>
+where
+    S: Executable + Periodic,
+    M: MovingAverage + Periodic,
+    S::Output: ChainValue<M::Input> + Clone,
+{
+    fn period(&self) -> usize {
+        self.signal.period().max(self.average.period())
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.signal.effective_lookback() + self.average.effective_lookback() - 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{
+        Crossover, CrossoverEvent, ExponentialMovingAverage, Momentum, RelativeStrengthIndex, SimpleMovingAverage,
+        WeightedMovingAverage, WildersSmoothing,
+    };
+    use crate::traits::Then;
+
+    #[test]
+    fn test_average_types() {
+        let mut ema: Smoothed<Momentum, ExponentialMovingAverage> =
+            Smoothed::new(Momentum::new(1).unwrap(), 3).unwrap();
+        let mut wma: Smoothed<Momentum, WeightedMovingAverage> = Smoothed::new(Momentum::new(1).unwrap(), 2).unwrap();
+        let mut wilders: Smoothed<Momentum, WildersSmoothing> = Smoothed::new(Momentum::new(1).unwrap(), 2).unwrap();
+        for value in [1.0, 3.0, 4.0] {
+            ema.apply(value);
+            wma.apply(value);
+            wilders.apply(value);
+        }
+        assert_eq!(ema.current(), (Some(1.0), Some(1.5)));
+        assert_eq!(wma.current(), (Some(1.0), Some(4.0 / 3.0)));
+        assert_eq!(wilders.current(), (Some(1.0), Some(Some(1.5))));
+    }
+
+    #[test]
+    fn test_warmup() {
+        let mut smoothed: Smoothed<Momentum, SimpleMovingAverage> =
+            Smoothed::new(Momentum::new(1).unwrap(), 3).unwrap();
+        assert_eq!(smoothed.warmup_remaining(), 4);
+        assert_eq!(smoothed.effective_lookback(), 4);
+        for _ in 0..4 {
+            smoothed.apply(1.0);
+        }
+        assert_eq!(smoothed.warmup_remaining(), 0);
+    }
+
+    #[test]
+    fn test_crossover() {
+        // the momentum crosses below its signal line as it slows
+        let smoothed: Smoothed<Momentum, ExponentialMovingAverage> =
+            Smoothed::new(Momentum::new(1).unwrap(), 3).unwrap();
+        let mut crossover = smoothed.then(Crossover::new());
+        let events: Vec<_> = [1.0, 3.0, 6.0, 7.0].into_iter().map(|value| crossover.apply(value)).collect();
+        assert_eq!(events[3], Some(CrossoverEvent::CrossBelow));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut smoothed: Smoothed<RelativeStrengthIndex, ExponentialMovingAverage> =
+            Smoothed::new(RelativeStrengthIndex::new(2, 0).unwrap(), 2).unwrap();
+        smoothed.apply(10.0);
+        assert_eq!(smoothed.evaluate(9.0), (None, None));
+        assert_eq!(smoothed.current(), (None, None));
+    }
+}
@@ -1,11 +1,17 @@
-use indicato_rs_proc::{Apply, Evaluate};
+use std::marker::PhantomData;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
 
 use crate::{
     fin_error::FinError,
-    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, IoState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
 };
 
-use super::{MaximumPeriod, MinimumPeriod};
+use super::{HighLowClose, MaximumPeriod, MinimumPeriod};
+use crate::types::{HasClose, HasHighLow};
 
 /// # Stochastic Momentum Oscillator
 ///
@@ -95,11 +101,39 @@ use super::{MaximumPeriod, MinimumPeriod};
 /// </math>
 /// <br>
 /// Where `o` is the output, `n` is the current step, `c` is the close value, `p` is the period, `H` is the Heaviside function, `h` is the high value, and `l` is the low value.
-#[derive(Apply, Evaluate)]
-pub struct StochasticMomentumOscillator {
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochasticMomentumOscillator<I: HasHighLow + HasClose = HighLowClose> {
     high: MaximumPeriod,
     low: MinimumPeriod,
     current: f64,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: HasHighLow + HasClose> StochasticMomentumOscillator<I> {
+    /// Create a new Stochastic Momentum Oscillator instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `period` - The period of the highest high and lowest low, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::StochasticMomentumOscillator;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let smo = StochasticMomentumOscillator::<Candle>::for_input(14);
+    /// assert!(smo.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    pub fn for_input(period: usize) -> Result<Self, FinError> {
+        Ok(Self {
+            high: MaximumPeriod::new(period)?,
+            low: MinimumPeriod::new(period)?,
+            current: 50.0,
+            input: PhantomData,
+        })
+    }
 }
 
 impl StochasticMomentumOscillator {
@@ -121,28 +155,53 @@ impl StochasticMomentumOscillator {
     /// assert!(smo.is_err());
     /// ```
     pub fn new(period: usize) -> Result<Self, FinError> {
-        Ok(Self {
-            high: MaximumPeriod::new(period)?,
-            low: MinimumPeriod::new(period)?,
-            current: 50.0,
-        })
+        Self::for_input(period)
+    }
+
+    /// Create a new Stochastic Momentum Oscillator instance with the conventional period of 14
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::StochasticMomentumOscillator;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let smo = StochasticMomentumOscillator::standard();
+    /// assert_eq!(smo.period(), 14);
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(14).unwrap()
     }
 }
 
-impl IoState for StochasticMomentumOscillator {
-    /// The input for the Stochastic Momentum Oscillator is a tuple of three f64 values, representing the high, low and close values.
-    type Input = (f64, f64, f64);
+impl Default for StochasticMomentumOscillator {
+    /// Create a new Stochastic Momentum Oscillator with the conventional period of 14
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for StochasticMomentumOscillator<I> {
+    /// The input for the Stochastic Momentum Oscillator is anything with high, low and close values, by default a
+    /// [`HighLowClose`].
+    type Input = I;
     /// The output for the Stochastic Momentum Oscillator is a single f64 value, representing the current value of the oscillator.
     type Output = f64;
 }
 
-impl Executable for StochasticMomentumOscillator {
+impl<I: HasHighLow + HasClose> Validate for StochasticMomentumOscillator<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for StochasticMomentumOscillator<I> {
     fn execute(
         &mut self,
         input: Self::Input,
         execution_context: &ExecutionContext,
     ) -> Self::Output {
-        let (high_i, low_i, close_i) = input;
+        let (high_i, low_i, close_i) = (input.high(), input.low(), input.close());
         match execution_context {
             ExecutionContext::Apply => {
                 let high = self.high.execute(high_i, execution_context);
@@ -167,40 +226,53 @@ impl Executable for StochasticMomentumOscillator {
     }
 }
 
-impl Current for StochasticMomentumOscillator {
+impl<I: HasHighLow + HasClose> Current for StochasticMomentumOscillator<I> {
     fn current(&self) -> Self::Output {
         self.current
     }
 }
 
+impl<I: HasHighLow + HasClose> WarmUp for StochasticMomentumOscillator<I> {
+    fn warmup_remaining(&self) -> usize {
+        self.high.warmup_remaining()
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for StochasticMomentumOscillator<I> {
+    fn period(&self) -> usize {
+        self.high.period()
+    }
+}
+
 
 #[cfg(test)]
 mod tests {
     use approx::assert_abs_diff_eq;
 
     use super::*;
+    use crate::types::Candle;
 
     #[test]
     fn test_stochastic_momentum_oscillator() {
         let mut smo = StochasticMomentumOscillator::new(3).unwrap();
-        assert_eq!(smo.apply((3.0, 1.0, 2.0)), 50.0);
-        assert_eq!(smo.evaluate((3.0, 1.0, 2.0)), 50.0);
-        assert_eq!(smo.apply((3.0, 1.0, 2.0)), 50.0);
-        assert_eq!(smo.evaluate((3.0, 1.0, 2.5)),75.0);
-        assert_eq!(smo.apply((3.0, 1.0, 2.5)), 75.0);
-        assert_abs_diff_eq!(smo.evaluate((3.0, 1.0, 2.8)), 90.0, epsilon = 10e-7);
+        assert_eq!(smo.apply((3.0, 1.0, 2.0).into()), 50.0);
+        assert_eq!(smo.evaluate((3.0, 1.0, 2.0).into()), 50.0);
+        assert_eq!(smo.apply((3.0, 1.0, 2.0).into()), 50.0);
+        assert_eq!(smo.evaluate((3.0, 1.0, 2.5).into()),75.0);
+        assert_eq!(smo.apply((3.0, 1.0, 2.5).into()), 75.0);
+        assert_abs_diff_eq!(smo.evaluate((3.0, 1.0, 2.8).into()), 90.0, epsilon = 10e-7);
         assert_eq!(smo.current(), 75.0);
     }
 
     #[test]
     fn test_flatline() {
         let mut smo = StochasticMomentumOscillator::new(3).unwrap();
-        assert_eq!(smo.apply((3.0, 3.0, 3.0)), 50.0);
-        assert_eq!(smo.evaluate((3.0, 3.0, 3.0)), 50.0);
-        assert_eq!(smo.apply((3.0, 3.0, 3.0)), 50.0);
-        assert_eq!(smo.evaluate((3.0, 3.0, 3.0)), 50.0);
-        assert_eq!(smo.apply((3.0, 3.0, 3.0)), 50.0);
-        assert_eq!(smo.evaluate((3.0, 3.0, 3.0)), 50.0);
+        assert_eq!(smo.apply((3.0, 3.0, 3.0).into()), 50.0);
+        assert_eq!(smo.evaluate((3.0, 3.0, 3.0).into()), 50.0);
+        assert_eq!(smo.apply((3.0, 3.0, 3.0).into()), 50.0);
+        assert_eq!(smo.evaluate((3.0, 3.0, 3.0).into()), 50.0);
+        assert_eq!(smo.apply((3.0, 3.0, 3.0).into()), 50.0);
+        assert_eq!(smo.evaluate((3.0, 3.0, 3.0).into()), 50.0);
         assert_eq!(smo.current(), 50.0);
     
     }
@@ -209,4 +281,14 @@ mod tests {
     fn test_invalid_period() {
         assert!(StochasticMomentumOscillator::new(0).is_err());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_for_input() {
+        let mut smo = StochasticMomentumOscillator::new(2).unwrap();
+        let mut candles = StochasticMomentumOscillator::<Candle>::for_input(2).unwrap();
+        for (high, low, close) in [(3.0, 1.0, 2.0), (5.0, 2.0, 4.0), (4.0, 0.0, 1.0)] {
+            let candle = Candle { open: close, high, low, close, volume: 0.0, timestamp: 0 };
+            assert_eq!(candles.apply(candle), smo.apply((high, low, close).into()));
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::FinError,
+    traits::{
+        Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate,
+        WarmUp,
+    },
+    validation,
+};
+
+use super::{HighLowClose, SimpleMovingAverage, StochasticMomentumOscillator};
+use crate::types::{HasClose, HasHighLow};
+
+/// Output of the [`StochasticOscillator`] signal.
+///
+/// Tuples of (%K, %D) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StochasticOscillatorOutput {
+    /// The %K line, the smoothed position of the close within the high low range of the period.
+    pub k: f64,
+    /// The %D line, the Simple Moving Average of the %K line.
+    pub d: f64,
+}
+
+impl From<(f64, f64)> for StochasticOscillatorOutput {
+    fn from((k, d): (f64, f64)) -> Self {
+        Self { k, d }
+    }
+}
+
+impl From<StochasticOscillatorOutput> for (f64, f64) {
+    fn from(output: StochasticOscillatorOutput) -> Self {
+        (output.k, output.d)
+    }
+}
+
+/// The %K and %D lines are passed on as a pair, such as to a [`Crossover`](super::Crossover).
+impl ChainValue<(f64, f64)> for StochasticOscillatorOutput {
+    fn into_input(self) -> Option<(f64, f64)> {
+        Some(self.into())
+    }
+}
+
+/// # Stochastic Oscillator
+/// Container for the full Stochastic Oscillator aggregation
+///
+/// The raw %K line is produced by a [`StochasticMomentumOscillator`], this is smoothed by a Simple Moving Average
+/// to produce the %K line, and the %K line is smoothed by a further Simple Moving Average to produce the %D line:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <mi>%K</mi>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>SMA</mi>
+///             <mi>k</mi>
+///         </msub>
+///         <mo>(</mo>
+///         <mi>SMO</mi>
+///         <mo>(</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>)</mo>
+///         <mo>)</mo>
+///         <mo>,</mo>
+///         <mspace width="1em"/>
+///         <mi>%D</mi>
+///         <mo>=</mo>
+///         <msub>
+///             <mi>SMA</mi>
+///             <mi>d</mi>
+///         </msub>
+///         <mo>(</mo>
+///         <mi>%K</mi>
+///         <mo>)</mo>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `n` is the current step, `SMA` is the Simple Moving Average, `k` is the %K smoothing period, `d` is the %D
+/// smoothing period, `SMO` is the Stochastic Momentum Oscillator and `i` is the input.
+///
+/// The fast stochastic has no %K smoothing, the slow stochastic has a %K smoothing period of 3 and the full stochastic
+/// allows both smoothing periods to be configured. The aggregation will begin producing values immediately.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{StochasticOscillator, StochasticOscillatorOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new fast Stochastic Oscillator with a %K period of 3 and %D period of 2
+/// let mut stochastic = StochasticOscillator::fast(3, 2).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(stochastic.apply((3.0, 1.0, 2.0).into()), StochasticOscillatorOutput { k: 50.0, d: 50.0 });
+/// assert_eq!(stochastic.apply((3.0, 1.0, 3.0).into()), (100.0, 75.0).into());
+///
+/// // evaluate some values, these won't affect the internal state of the Stochastic Oscillator
+/// assert_eq!(stochastic.evaluate((3.0, 1.0, 1.0).into()), (0.0, 50.0).into());
+///
+/// // fetch the current value of the Stochastic Oscillator
+/// assert_eq!(stochastic.current(), (100.0, 75.0).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound = ""))]
+pub struct StochasticOscillator<I: HasHighLow + HasClose = HighLowClose> {
+    raw_k: StochasticMomentumOscillator<I>,
+    k: SimpleMovingAverage,
+    d: SimpleMovingAverage,
+}
+
+impl<I: HasHighLow + HasClose> StochasticOscillator<I> {
+    /// Create a new full Stochastic Oscillator instance taking inputs of type `I`, such as a
+    /// [`Candle`](crate::types::Candle) or a bar type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `k_period` - The period of the highest high and lowest low used for the raw %K line, must be greater than 0
+    /// * `k_smoothing` - The period of the Simple Moving Average smoothing the raw %K line, must be greater than 0
+    /// * `d_smoothing` - The period of the Simple Moving Average smoothing the %K line, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::StochasticOscillator;
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let stochastic = StochasticOscillator::<Candle>::for_input(14, 3, 3);
+    /// assert!(stochastic.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    pub fn for_input(k_period: usize, k_smoothing: usize, d_smoothing: usize) -> Result<Self, FinError> {
+        Ok(Self {
+            raw_k: StochasticMomentumOscillator::for_input(k_period)?,
+            k: SimpleMovingAverage::new(k_smoothing)?,
+            d: SimpleMovingAverage::new(d_smoothing)?,
+        })
+    }
+}
+
+impl StochasticOscillator {
+    /// Create a new full Stochastic Oscillator instance
+    /// # Arguments
+    /// * `k_period` - The period of the highest high and lowest low used for the raw %K line, must be greater than 0
+    /// * `k_smoothing` - The period of the Simple Moving Average smoothing the raw %K line, must be greater than 0
+    /// * `d_smoothing` - The period of the Simple Moving Average smoothing the %K line, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::StochasticOscillator;
+    ///
+    /// let stochastic = StochasticOscillator::new(14, 3, 3);
+    /// assert!(stochastic.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if any of the periods are 0
+    /// ```
+    /// use indicato_rs::signals::StochasticOscillator;
+    ///
+    /// let stochastic = StochasticOscillator::new(14, 0, 3);
+    /// assert!(stochastic.is_err());
+    /// ```
+    pub fn new(k_period: usize, k_smoothing: usize, d_smoothing: usize) -> Result<Self, FinError> {
+        Self::for_input(k_period, k_smoothing, d_smoothing)
+    }
+
+    /// Create a new fast Stochastic Oscillator instance, where the %K line is not smoothed
+    /// # Arguments
+    /// * `k_period` - The period of the highest high and lowest low used for the %K line, must be greater than 0
+    /// * `d_smoothing` - The period of the Simple Moving Average smoothing the %K line, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::StochasticOscillator;
+    ///
+    /// let stochastic = StochasticOscillator::fast(14, 3);
+    /// assert!(stochastic.is_ok());
+    /// ```
+    pub fn fast(k_period: usize, d_smoothing: usize) -> Result<Self, FinError> {
+        Self::new(k_period, 1, d_smoothing)
+    }
+
+    /// Create a new slow Stochastic Oscillator instance, where the %K line is smoothed over 3 periods
+    /// # Arguments
+    /// * `k_period` - The period of the highest high and lowest low used for the raw %K line, must be greater than 0
+    /// * `d_smoothing` - The period of the Simple Moving Average smoothing the %K line, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::StochasticOscillator;
+    ///
+    /// let stochastic = StochasticOscillator::slow(14, 3);
+    /// assert!(stochastic.is_ok());
+    /// ```
+    pub fn slow(k_period: usize, d_smoothing: usize) -> Result<Self, FinError> {
+        Self::new(k_period, 3, d_smoothing)
+    }
+
+    /// Create a new Stochastic Oscillator instance with the conventional slow settings, a %K period of 14 smoothed over 3
+    /// periods and a %D smoothing of 3
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::StochasticOscillator;
+    /// use indicato_rs::traits::Periodic;
+    ///
+    /// let stochastic = StochasticOscillator::standard();
+    /// assert_eq!(stochastic.period(), 14);
+    /// ```
+    pub fn standard() -> Self {
+        Self::slow(14, 3).unwrap()
+    }
+}
+
+impl Default for StochasticOscillator {
+    /// Create a new Stochastic Oscillator with the conventional slow settings, a %K period of 14 smoothed over 3
+    /// periods and a %D smoothing of 3
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for StochasticOscillator<I> {
+    /// Input is anything with a high, low and close, by default a [`HighLowClose`]
+    type Input = I;
+    /// Output is the %K and %D lines
+    type Output = StochasticOscillatorOutput;
+}
+
+impl<I: HasHighLow + HasClose> Validate for StochasticOscillator<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for StochasticOscillator<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let raw_k = self.raw_k.execute(input, execution_context);
+        let k = self.k.execute(raw_k, execution_context);
+        let d = self.d.execute(k, execution_context);
+        StochasticOscillatorOutput { k, d }
+    }
+}
+
+impl<I: HasHighLow + HasClose> Current for StochasticOscillator<I> {
+    fn current(&self) -> Self::Output {
+        StochasticOscillatorOutput {
+            k: self.k.current(),
+            d: self.d.current(),
+        }
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for StochasticOscillator<I> {
+    fn warmup_remaining(&self) -> usize {
+        self.raw_k
+            .warmup_remaining()
+            .max(self.k.warmup_remaining())
+            .max(self.d.warmup_remaining())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Periodic for StochasticOscillator<I> {
+    fn period(&self) -> usize {
+        self.raw_k.period()
+    }
+
+    fn effective_lookback(&self) -> usize {
+        self.raw_k.period() + self.k.period() + self.d.period() - 2
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{Crossover, CrossoverEvent};
+    use crate::traits::Then;
+
+    #[test]
+    fn test_apply_fast() {
+        let mut stochastic = StochasticOscillator::fast(3, 2).unwrap();
+        assert_eq!(stochastic.apply((3.0, 1.0, 2.0).into()), (50.0, 50.0).into());
+        assert_eq!(stochastic.apply((3.0, 1.0, 3.0).into()), (100.0, 75.0).into());
+        assert_eq!(stochastic.apply((3.0, 1.0, 1.0).into()), (0.0, 50.0).into());
+    }
+
+    #[test]
+    fn test_apply_full() {
+        let mut stochastic = StochasticOscillator::new(3, 2, 2).unwrap();
+        assert_eq!(stochastic.apply((3.0, 1.0, 2.0).into()), (50.0, 50.0).into());
+        assert_eq!(stochastic.apply((3.0, 1.0, 3.0).into()), (75.0, 62.5).into());
+        assert_eq!(stochastic.apply((3.0, 1.0, 1.0).into()), (50.0, 62.5).into());
+        assert_eq!(stochastic.apply((5.0, 1.0, 5.0).into()), (50.0, 50.0).into());
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut stochastic = StochasticOscillator::slow(3, 2).unwrap();
+        assert_eq!(stochastic.apply((3.0, 1.0, 2.0).into()), (50.0, 50.0).into());
+        assert_eq!(stochastic.evaluate((3.0, 1.0, 3.0).into()), (75.0, 62.5).into());
+        assert_eq!(stochastic.evaluate((3.0, 1.0, 1.0).into()), (25.0, 37.5).into());
+        assert_eq!(stochastic.apply((3.0, 1.0, 3.0).into()), (75.0, 62.5).into());
+    }
+
+    #[test]
+    fn test_current() {
+        let mut stochastic = StochasticOscillator::fast(3, 2).unwrap();
+        stochastic.apply((3.0, 1.0, 2.0).into());
+        stochastic.apply((3.0, 1.0, 3.0).into());
+        stochastic.evaluate((3.0, 1.0, 1.0).into());
+        assert_eq!(stochastic.current(), (100.0, 75.0).into());
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        assert!(StochasticOscillator::new(0, 3, 3).is_err());
+        assert!(StochasticOscillator::new(14, 0, 3).is_err());
+        assert!(StochasticOscillator::new(14, 3, 0).is_err());
+        assert!(StochasticOscillator::fast(0, 3).is_err());
+        assert!(StochasticOscillator::slow(14, 0).is_err());
+    }
+
+    #[test]
+    fn test_periodic() {
+        let stochastic = StochasticOscillator::new(14, 3, 3).unwrap();
+        assert_eq!(stochastic.period(), 14);
+        assert_eq!(stochastic.effective_lookback(), 18);
+    }
+
+    #[test]
+    fn test_then_crossover() {
+        let mut cross = StochasticOscillator::fast(3, 2)
+            .unwrap()
+            .then(Crossover::new());
+        assert_eq!(cross.apply((3.0, 1.0, 2.0).into()), Some(CrossoverEvent::None));
+        assert_eq!(cross.apply((3.0, 1.0, 3.0).into()), Some(CrossoverEvent::None));
+        assert_eq!(cross.apply((3.0, 1.0, 1.0).into()), Some(CrossoverEvent::CrossBelow));
+    }
+}
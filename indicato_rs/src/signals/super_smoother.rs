@@ -0,0 +1,237 @@
+use std::f64::consts::{PI, SQRT_2};
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+/// # Super Smoother
+/// Container for Super Smoother aggregation
+///
+/// The Super Smoother is John Ehlers' two pole Butterworth style low pass filter, it removes the cycles shorter than
+/// the cutoff period with far less lag than a moving average that smooths as much. Being a recursive filter each
+/// output depends on the two previous outputs, so the first two entries are passed through unchanged to seed the
+/// filter. After which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <msub><mi>c</mi><mn>1</mn></msub>
+///         <mfrac>
+///             <mrow>
+///                 <msub><mi>i</mi><mn>n</mn></msub>
+///                 <mo>+</mo>
+///                 <msub><mi>i</mi><mn>n-1</mn></msub>
+///             </mrow>
+///             <mn>2</mn>
+///         </mfrac>
+///         <mo>+</mo>
+///         <msub><mi>c</mi><mn>2</mn></msub>
+///         <msub><mi>o</mi><mn>n-1</mn></msub>
+///         <mo>+</mo>
+///         <msub><mi>c</mi><mn>3</mn></msub>
+///         <msub><mi>o</mi><mn>n-2</mn></msub>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step and `i` is the input. The coefficients are derived from the cutoff
+/// period `p` as <code>a = e<sup>-√2π/p</sup></code>, <code>c<sub>2</sub> = 2a⋅cos(√2π/p)</code>,
+/// <code>c<sub>3</sub> = -a<sup>2</sup></code> and <code>c<sub>1</sub> = 1 - c<sub>2</sub> - c<sub>3</sub></code>.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::SuperSmoother;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+/// use approx::assert_abs_diff_eq;
+///
+/// // create a new Super Smoother with a cutoff period of 10
+/// let mut super_smoother = SuperSmoother::new(10).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(super_smoother.apply(1.0), 1.0);
+/// assert_eq!(super_smoother.apply(2.0), 2.0);
+/// assert_abs_diff_eq!(super_smoother.apply(3.0), 2.5378381, epsilon = 10e-7);
+///
+/// // evaluate some values, these won't affect the internal state of the Super Smoother
+/// assert_abs_diff_eq!(super_smoother.evaluate(4.0), 3.0026334, epsilon = 10e-7);
+///
+/// // fetch the current value of the Super Smoother
+/// assert_abs_diff_eq!(super_smoother.current(), 2.5378381, epsilon = 10e-7);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SuperSmoother {
+    period: usize,
+    c1: f64,
+    c2: f64,
+    c3: f64,
+    /// The number of inputs applied, saturating once the filter is seeded.
+    count: usize,
+    previous_input: f64,
+    /// The previous two outputs, the most recent first.
+    previous_outputs: (f64, f64),
+}
+
+impl SuperSmoother {
+    /// Create a new Super Smoother instance
+    /// # Arguments
+    /// * `period` - The cutoff period, cycles shorter than this are attenuated, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::SuperSmoother;
+    ///
+    /// let super_smoother = SuperSmoother::new(10);
+    /// assert!(super_smoother.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::SuperSmoother;
+    ///
+    /// let super_smoother = SuperSmoother::new(0);
+    ///
+    /// assert!(super_smoother.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => {
+                let angle = SQRT_2 * PI / period as f64;
+                let a = (-angle).exp();
+                let c2 = 2.0 * a * angle.cos();
+                let c3 = -a * a;
+                Ok(Self {
+                    period,
+                    c1: 1.0 - c2 - c3,
+                    c2,
+                    c3,
+                    count: 0,
+                    previous_input: 0.0,
+                    previous_outputs: (0.0, 0.0),
+                })
+            }
+        }
+    }
+}
+
+impl IoState for SuperSmoother {
+    type Input = f64;
+    type Output = f64;
+}
+
+impl Validate for SuperSmoother {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for SuperSmoother {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (previous, before_previous) = self.previous_outputs;
+        let result = match self.count < 2 {
+            true => input,
+            false => {
+                self.c1 * (input + self.previous_input) / 2.0
+                    + self.c2 * previous
+                    + self.c3 * before_previous
+            }
+        };
+        if let ExecutionContext::Apply = execution_context {
+            self.count = (self.count + 1).min(2);
+            self.previous_input = input;
+            self.previous_outputs = (result, previous);
+        }
+        result
+    }
+}
+
+impl Current for SuperSmoother {
+    fn current(&self) -> Self::Output {
+        self.previous_outputs.0
+    }
+}
+
+impl WarmUp for SuperSmoother {
+    fn warmup_remaining(&self) -> usize {
+        2 - self.count
+    }
+}
+
+impl Periodic for SuperSmoother {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_apply() {
+        let mut super_smoother = SuperSmoother::new(10).unwrap();
+        assert_eq!(super_smoother.apply(1.0), 1.0);
+        assert_eq!(super_smoother.apply(2.0), 2.0);
+        assert_abs_diff_eq!(super_smoother.apply(3.0), 2.5378381, epsilon = 10e-7);
+        assert_abs_diff_eq!(super_smoother.apply(4.0), 3.0026334, epsilon = 10e-7);
+        assert_abs_diff_eq!(super_smoother.apply(5.0), 3.5729016, epsilon = 10e-7);
+        assert_abs_diff_eq!(super_smoother.apply(10.0), 4.8017400, epsilon = 10e-7);
+    }
+
+    #[test]
+    fn test_constant_input() {
+        let mut super_smoother = SuperSmoother::new(7).unwrap();
+        for _ in 0..50 {
+            assert_abs_diff_eq!(super_smoother.apply(4.0), 4.0, epsilon = 10e-7);
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut super_smoother = SuperSmoother::new(10).unwrap();
+        assert_eq!(super_smoother.evaluate(1.0), 1.0);
+        assert_eq!(super_smoother.apply(1.0), 1.0);
+        assert_eq!(super_smoother.apply(2.0), 2.0);
+        let evaluated = super_smoother.evaluate(3.0);
+        assert_abs_diff_eq!(evaluated, 2.5378381, epsilon = 10e-7);
+        assert_eq!(super_smoother.evaluate(3.0), evaluated);
+        assert_eq!(super_smoother.apply(3.0), evaluated);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut super_smoother = SuperSmoother::new(10).unwrap();
+        assert_eq!(super_smoother.current(), 0.0);
+        super_smoother.apply(1.0);
+        super_smoother.apply(2.0);
+        super_smoother.evaluate(5.0);
+        assert_eq!(super_smoother.current(), 2.0);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let super_smoother = SuperSmoother::new(0);
+        assert!(super_smoother.is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+    validation,
+};
+
+/// # Time Decayed Moving Average
+/// Container for an exponential moving average of irregularly spaced inputs
+///
+/// Rather than weighting each input by a fixed period, the weight of the previous average decays with the time elapsed
+/// since the previous input, halving every half life. Inputs that arrive in quick succession move the average a little
+/// and an input after a long pause moves it a lot, so ticks and sparse bars can be averaged without resampling. The
+/// first value will be the input, after which the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mrow><mo stretchy="true" form="prefix">(</mo>
+///             <msub>
+///                 <mi>i</mi>
+///                 <mn>n</mn>
+///             </msub>
+///             <mo>−</mo>
+///             <msub>
+///                 <mi>o</mi>
+///                 <mn>n-1</mn>
+///             </msub>
+///         <mo stretchy="true" form="postfix">)</mo></mrow>
+///         <mo>⋅</mo>
+///         <mrow><mo stretchy="true" form="prefix">(</mo>
+///             <mn>1</mn>
+///             <mo>−</mo>
+///             <msup>
+///                 <mn>2</mn>
+///                 <mrow><mo>−</mo><mfrac><mrow><mi>Δ</mi><mi>t</mi></mrow><mi>h</mi></mfrac></mrow>
+///             </msup>
+///         <mo stretchy="true" form="postfix">)</mo></mrow>
+///         <mo>+</mo>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n-1</mn>
+///         </msub>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `i` is the input, `Δt` is the time
+/// since the previous input and `h` is the half life. An input with the same timestamp as the previous input has no
+/// weight.
+///
+/// # Example Usage
+/// ```
+/// use chrono::{TimeDelta, TimeZone, Utc};
+/// use indicato_rs::signals::TimeDecayedMovingAverage;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Time Decayed Moving Average with a half life of one minute
+/// let mut average = TimeDecayedMovingAverage::new(TimeDelta::minutes(1)).unwrap();
+/// let at = |minute| Utc.with_ymd_and_hms(2024, 1, 2, 9, minute, 0).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(average.apply((2.0, at(0))), 2.0);
+/// assert_eq!(average.apply((6.0, at(1))), 4.0);
+/// assert_eq!(average.apply((12.0, at(3))), 10.0);
+///
+/// // evaluate some values, these won't affect the internal state of the average
+/// assert_eq!(average.evaluate((14.0, at(4))), 12.0);
+///
+/// // fetch the current value of the average
+/// assert_eq!(average.current(), 10.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeDecayedMovingAverage {
+    /// The time for the weight of the previous average to halve.
+    half_life: TimeDelta,
+    current: f64,
+    /// The timestamp of the previous input.
+    previous: Option<DateTime<Utc>>,
+}
+
+impl TimeDecayedMovingAverage {
+    /// Create a new Time Decayed Moving Average instance
+    /// # Arguments
+    /// * `half_life` - The time for the weight of the previous average to halve, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use indicato_rs::signals::TimeDecayedMovingAverage;
+    ///
+    /// let average = TimeDecayedMovingAverage::new(TimeDelta::seconds(30));
+    /// assert!(average.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the half life is not greater than 0
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use indicato_rs::signals::TimeDecayedMovingAverage;
+    ///
+    /// let average = TimeDecayedMovingAverage::new(TimeDelta::zero());
+    ///
+    /// assert!(average.is_err());
+    /// ```
+    pub fn new(half_life: TimeDelta) -> Result<Self, FinError> {
+        match half_life > TimeDelta::zero() {
+            true => Ok(Self {
+                half_life,
+                current: 0.0,
+                previous: None,
+            }),
+            false => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Half life must be greater than 0",
+            )),
+        }
+    }
+
+    /// The time for the weight of the previous average to halve
+    pub fn half_life(&self) -> TimeDelta {
+        self.half_life
+    }
+}
+
+impl IoState for TimeDecayedMovingAverage {
+    /// Input is a tuple of (value, timestamp)
+    type Input = (f64, DateTime<Utc>);
+    type Output = f64;
+}
+
+impl Validate for TimeDecayedMovingAverage {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (value, timestamp) = *input;
+        validation::value(value, "Input")?;
+        match self.previous {
+            Some(previous) if timestamp < previous => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Timestamp must not be before the previous timestamp",
+            )),
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Executable for TimeDecayedMovingAverage {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (value, timestamp) = input;
+        let result = match self.previous {
+            Some(previous) => {
+                let elapsed = (timestamp - previous).max(TimeDelta::zero());
+                let weight = 1.0 - (-elapsed.as_seconds_f64() / self.half_life.as_seconds_f64()).exp2();
+                (value - self.current) * weight + self.current
+            }
+            None => value,
+        };
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.current = result;
+                self.previous = Some(timestamp);
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl Current for TimeDecayedMovingAverage {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl WarmUp for TimeDecayedMovingAverage {
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::TryApply;
+
+    fn at(second: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + second, 0).unwrap()
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut average = TimeDecayedMovingAverage::new(TimeDelta::seconds(10)).unwrap();
+        assert_eq!(average.warmup_remaining(), 1);
+        assert_eq!(average.apply((4.0, at(0))), 4.0);
+        assert_eq!(average.warmup_remaining(), 0);
+        assert_eq!(average.apply((8.0, at(10))), 6.0);
+        assert_eq!(average.apply((14.0, at(30))), 12.0);
+        // the same timestamp has no weight
+        assert_eq!(average.apply((100.0, at(30))), 12.0);
+    }
+
+    #[test]
+    fn test_irregular_spacing() {
+        // two steps of one half life are the same as one step of two half lives
+        let mut stepped = TimeDecayedMovingAverage::new(TimeDelta::seconds(5)).unwrap();
+        let mut skipped = stepped.clone();
+        stepped.apply((0.0, at(0)));
+        stepped.apply((1.0, at(5)));
+        skipped.apply((0.0, at(0)));
+        assert_eq!(stepped.apply((1.0, at(10))), skipped.apply((1.0, at(10))));
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut average = TimeDecayedMovingAverage::new(TimeDelta::seconds(10)).unwrap();
+        assert_eq!(average.evaluate((4.0, at(0))), 4.0);
+        assert_eq!(average.apply((2.0, at(0))), 2.0);
+        assert_eq!(average.evaluate((4.0, at(10))), 3.0);
+        assert_eq!(average.current(), 2.0);
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut average = TimeDecayedMovingAverage::new(TimeDelta::seconds(10)).unwrap();
+        assert_eq!(average.try_apply((2.0, at(5))).unwrap(), 2.0);
+        assert!(average.try_apply((2.0, at(4))).is_err());
+        assert!(average.try_apply((f64::NAN, at(6))).is_err());
+    }
+
+    #[test]
+    fn test_invalid_half_life() {
+        assert!(TimeDecayedMovingAverage::new(TimeDelta::seconds(-10)).is_err());
+    }
+}
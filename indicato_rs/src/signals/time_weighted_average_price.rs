@@ -0,0 +1,282 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequeMathExt,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Periodic, Validate, WarmUp,
+    },
+    validation,
+};
+
+fn time_weighted_mean(prices: &VecDeque<f64>, durations: &VecDeque<f64>) -> f64 {
+    let duration = durations.iter().sum::<f64>();
+    match duration == 0.0 {
+        true => prices.mean(),
+        false => {
+            prices
+                .iter()
+                .zip(durations.iter())
+                .map(|(price, duration)| price * duration)
+                .sum::<f64>()
+                / duration
+        }
+    }
+}
+
+/// # Time Weighted Average Price
+/// Container for Time Weighted Average Price (TWAP) aggregation
+///
+/// Each price is weighted by the duration it was in effect for, so prices that held for longer contribute more to
+/// the average. Inputs without a duration are weighted as a single unit of time, so a stream without durations is the
+/// bar count weighted mean of the prices. The aggregation will begin producing values immediately, with the period
+/// being filled as values are applied, the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mrow><mi>k</mi><mo>=</mo><mi>n</mi><mo>−</mo><mi>p</mi><mo>+</mo><mn>1</mn></mrow>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mi>k</mi>
+///                 </msub>
+///                 <msub>
+///                     <mi>d</mi>
+///                     <mi>k</mi>
+///                 </msub>
+///             </mrow>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mrow><mi>k</mi><mo>=</mo><mi>n</mi><mo>−</mo><mi>p</mi><mo>+</mo><mn>1</mn></mrow>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub>
+///                     <mi>d</mi>
+///                     <mi>k</mi>
+///                 </msub>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period, `i` is the price and `d` is the duration,
+/// or 1 when the input has no duration. If the duration over the period is 0 the output will be the simple mean of
+/// the prices over the period.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::TimeWeightedAveragePrice;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Time Weighted Average Price with a period of 2
+/// let mut twap = TimeWeightedAveragePrice::new(2).unwrap();
+///
+/// // apply some (price, duration) values and check their output
+/// assert_eq!(twap.apply((2.0, Some(10.0))), 2.0);
+/// assert_eq!(twap.apply((4.0, Some(30.0))), 3.5);
+/// assert_eq!(twap.apply((8.0, Some(10.0))), 5.0);
+///
+/// // evaluate some values, these won't affect the internal state of the TWAP
+/// assert_eq!(twap.evaluate((2.0, Some(90.0))), 2.6);
+///
+/// // inputs without a duration are weighted by bar count
+/// let mut bars = TimeWeightedAveragePrice::new(2).unwrap();
+/// bars.apply((2.0, None));
+/// assert_eq!(bars.apply((4.0, None)), 3.0);
+///
+/// // fetch the current value of the TWAP
+/// assert_eq!(twap.current(), 5.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeWeightedAveragePrice {
+    period: usize,
+    prices: VecDeque<f64>,
+    durations: VecDeque<f64>,
+}
+
+impl IoState for TimeWeightedAveragePrice {
+    /// Input is a tuple of (price, duration), where the duration is the time the price was in effect for
+    type Input = (f64, Option<f64>);
+    type Output = f64;
+}
+
+impl TimeWeightedAveragePrice {
+    /// Create a new Time Weighted Average Price instance
+    /// # Arguments
+    /// * `period` - The period of the Time Weighted Average Price aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::TimeWeightedAveragePrice;
+    ///
+    /// let twap = TimeWeightedAveragePrice::new(20);
+    /// assert!(twap.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::TimeWeightedAveragePrice;
+    ///
+    /// let twap = TimeWeightedAveragePrice::new(0);
+    ///
+    /// assert!(twap.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                prices: VecDeque::with_capacity(period + 1),
+                durations: VecDeque::with_capacity(period + 1),
+            }),
+        }
+    }
+}
+
+impl Validate for TimeWeightedAveragePrice {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (price, duration) = *input;
+        validation::value(price, "Price")?;
+        match duration {
+            Some(duration) => validation::non_negative(duration, "Duration"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Executable for TimeWeightedAveragePrice {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (price, duration) = input;
+        let duration = duration.unwrap_or(1.0);
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.prices.push_back(price);
+                self.durations.push_back(duration);
+                if self.prices.len() > self.period {
+                    self.prices.pop_front();
+                    self.durations.pop_front();
+                }
+                time_weighted_mean(&self.prices, &self.durations)
+            }
+            ExecutionContext::Evaluate => {
+                let mut prices = self.prices.clone();
+                let mut durations = self.durations.clone();
+                prices.push_back(price);
+                durations.push_back(duration);
+                if prices.len() > self.period {
+                    prices.pop_front();
+                    durations.pop_front();
+                }
+                time_weighted_mean(&prices, &durations)
+            }
+        }
+    }
+}
+
+impl Current for TimeWeightedAveragePrice {
+    fn current(&self) -> Self::Output {
+        time_weighted_mean(&self.prices, &self.durations)
+    }
+}
+
+impl WarmUp for TimeWeightedAveragePrice {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.prices.len())
+    }
+}
+
+impl Periodic for TimeWeightedAveragePrice {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::traits::TryApply;
+
+    #[test]
+    fn test_apply() {
+        let mut twap = TimeWeightedAveragePrice::new(2).unwrap();
+        assert_eq!(twap.apply((2.0, Some(10.0))), 2.0);
+        assert_eq!(twap.apply((4.0, Some(30.0))), 3.5);
+        assert_eq!(twap.apply((8.0, Some(10.0))), 5.0);
+        assert_eq!(twap.apply((2.0, Some(90.0))), 2.6);
+    }
+
+    #[test]
+    fn test_bar_count_fallback() {
+        let mut twap = TimeWeightedAveragePrice::new(3).unwrap();
+        assert_eq!(twap.apply((2.0, None)), 2.0);
+        assert_eq!(twap.apply((4.0, None)), 3.0);
+        assert_eq!(twap.apply((9.0, None)), 5.0);
+        assert_eq!(twap.apply((5.0, Some(3.0))), 5.6);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut twap = TimeWeightedAveragePrice::new(2).unwrap();
+        assert_eq!(twap.apply((2.0, Some(10.0))), 2.0);
+        assert_eq!(twap.evaluate((4.0, Some(30.0))), 3.5);
+        assert_eq!(twap.evaluate((8.0, Some(10.0))), 5.0);
+        assert_eq!(twap.apply((4.0, Some(30.0))), 3.5);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut twap = TimeWeightedAveragePrice::new(2).unwrap();
+        assert_eq!(twap.current(), 0.0);
+        twap.apply((2.0, Some(10.0)));
+        twap.apply((4.0, Some(30.0)));
+        twap.evaluate((8.0, Some(10.0)));
+        assert_eq!(twap.current(), 3.5);
+    }
+
+    #[test]
+    fn test_zero_duration() {
+        let mut twap = TimeWeightedAveragePrice::new(2).unwrap();
+        assert_eq!(twap.apply((2.0, Some(0.0))), 2.0);
+        assert_eq!(twap.apply((4.0, Some(0.0))), 3.0);
+        assert_eq!(twap.apply((8.0, Some(5.0))), 8.0);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let twap = TimeWeightedAveragePrice::new(0);
+        assert!(twap.is_err());
+    }
+
+    #[test]
+    fn test_try_apply() {
+        let mut twap = TimeWeightedAveragePrice::new(2).unwrap();
+        assert_eq!(twap.try_apply((2.0, Some(10.0))).unwrap(), 2.0);
+        assert!(twap.try_apply((4.0, Some(-30.0))).is_err());
+        assert_eq!(twap.current(), 2.0);
+        assert_eq!(twap.try_apply((4.0, Some(30.0))).unwrap(), 3.5);
+    }
+}
@@ -0,0 +1,218 @@
+use chrono::{DateTime, TimeDelta, Utc};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+};
+
+/// # Timed
+/// Container for a signal fed with timestamped inputs
+///
+/// Each input is the input of the signal and its timestamp. The timestamps are expected to never go backwards, an
+/// input before the previous timestamp is rejected by [`try_apply`](crate::traits::TryApply::try_apply). When a maximum
+/// gap is given, a step between timestamps longer than it is reported by [`last_gap`](Timed::last_gap) so stale
+/// history can be handled, such as by resetting the signal.
+///
+/// Signals that weight their inputs by the time between them, such as the
+/// [`TimeDecayedMovingAverage`](super::TimeDecayedMovingAverage), take the timestamps as part of their inputs instead.
+///
+/// # Example Usage
+/// ```
+/// use chrono::{TimeDelta, TimeZone, Utc};
+/// use indicato_rs::signals::{SimpleMovingAverage, Timed};
+/// use indicato_rs::traits::{Apply, Evaluate, Current, TryApply};
+///
+/// // create a Simple Moving Average with a period of 2 over values expected at least every minute
+/// let sma = SimpleMovingAverage::new(2).unwrap();
+/// let mut timed = Timed::new(sma, Some(TimeDelta::minutes(1))).unwrap();
+/// let at = |minute| Utc.with_ymd_and_hms(2024, 1, 2, 9, minute, 0).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(timed.apply((1.0, at(30))), 1.0);
+/// assert_eq!(timed.apply((3.0, at(31))), 2.0);
+/// assert_eq!(timed.last_gap(), None);
+///
+/// // a step of five minutes is a gap
+/// assert_eq!(timed.apply((5.0, at(36))), 4.0);
+/// assert_eq!(timed.last_gap(), Some(TimeDelta::minutes(5)));
+///
+/// // inputs from before the previous timestamp are rejected
+/// assert!(timed.try_apply((7.0, at(35))).is_err());
+///
+/// // evaluate some values, these won't affect the internal state of the signal
+/// assert_eq!(timed.evaluate((7.0, at(37))), 6.0);
+///
+/// // fetch the current value of the signal
+/// assert_eq!(timed.current(), 4.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timed<S: Executable> {
+    signal: S,
+    /// The longest step between timestamps that isn't a gap.
+    max_gap: Option<TimeDelta>,
+    /// The timestamp of the previous input.
+    previous: Option<DateTime<Utc>>,
+    /// The step to the last input applied, if it was a gap.
+    gap: Option<TimeDelta>,
+}
+
+impl<S: Executable> IoState for Timed<S> {
+    /// Input is a tuple of (signal input, timestamp)
+    type Input = (S::Input, DateTime<Utc>);
+    type Output = S::Output;
+}
+
+impl<S: Executable> Timed<S> {
+    /// Create a new Timed instance
+    /// # Arguments
+    /// * `signal` - The signal to feed
+    /// * `max_gap` - The longest step between timestamps that isn't a gap, must be greater than 0. Without a maximum
+    ///   gap no gaps are reported.
+    ///
+    /// # Example
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use indicato_rs::signals::{ExponentialMovingAverage, Timed};
+    ///
+    /// let timed = Timed::new(ExponentialMovingAverage::new(10).unwrap(), Some(TimeDelta::seconds(5)));
+    /// assert!(timed.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the maximum gap is not greater than 0
+    /// ```
+    /// use chrono::TimeDelta;
+    /// use indicato_rs::signals::{ExponentialMovingAverage, Timed};
+    ///
+    /// let timed = Timed::new(ExponentialMovingAverage::new(10).unwrap(), Some(TimeDelta::zero()));
+    ///
+    /// assert!(timed.is_err());
+    /// ```
+    pub fn new(signal: S, max_gap: Option<TimeDelta>) -> Result<Self, FinError> {
+        match max_gap {
+            Some(max_gap) if max_gap <= TimeDelta::zero() => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Maximum gap must be greater than 0",
+            )),
+            _ => Ok(Self {
+                signal,
+                max_gap,
+                previous: None,
+                gap: None,
+            }),
+        }
+    }
+
+    /// The signal being fed
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// The timestamp of the last input applied
+    pub fn previous_timestamp(&self) -> Option<DateTime<Utc>> {
+        self.previous
+    }
+
+    /// The step to the last input applied when it was longer than the maximum gap, `None` when it wasn't a gap
+    pub fn last_gap(&self) -> Option<TimeDelta> {
+        self.gap
+    }
+}
+
+impl<S: Executable + Validate> Validate for Timed<S> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        match self.previous {
+            Some(previous) if input.1 < previous => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Timestamp must not be before the previous timestamp",
+            )),
+            _ => self.signal.validate(&input.0),
+        }
+    }
+}
+
+impl<S: Executable> Executable for Timed<S> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (input, timestamp) = input;
+        if let ExecutionContext::Apply = execution_context {
+            let step = self.previous.map(|previous| timestamp - previous);
+            self.gap = step.filter(|step| self.max_gap.is_some_and(|max_gap| *step > max_gap));
+            self.previous = Some(timestamp);
+        }
+        self.signal.execute(input, execution_context)
+    }
+}
+
+impl<S: Executable + Current> Current for Timed<S> {
+    fn current(&self) -> Self::Output {
+        self.signal.current()
+    }
+}
+
+impl<S: Executable + WarmUp> WarmUp for Timed<S> {
+    fn warmup_remaining(&self) -> usize {
+        self.signal.warmup_remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::CumulativeSum;
+    use crate::traits::TryApply;
+
+    fn at(second: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + second, 0).unwrap()
+    }
+
+    #[test]
+    fn test_gaps() {
+        let mut sum = Timed::new(CumulativeSum::new(), Some(TimeDelta::seconds(10))).unwrap();
+        assert_eq!(sum.apply((1.0, at(0))), 1.0);
+        assert_eq!(sum.last_gap(), None);
+        assert_eq!(sum.apply((1.0, at(10))), 2.0);
+        assert_eq!(sum.last_gap(), None);
+        assert_eq!(sum.apply((1.0, at(25))), 3.0);
+        assert_eq!(sum.last_gap(), Some(TimeDelta::seconds(15)));
+        assert_eq!(sum.apply((1.0, at(25))), 4.0);
+        assert_eq!(sum.last_gap(), None);
+        assert_eq!(sum.previous_timestamp(), Some(at(25)));
+    }
+
+    #[test]
+    fn test_without_max_gap() {
+        let mut sum = Timed::new(CumulativeSum::new(), None).unwrap();
+        sum.apply((1.0, at(0)));
+        sum.apply((1.0, at(1_000_000)));
+        assert_eq!(sum.last_gap(), None);
+    }
+
+    #[test]
+    fn test_monotonic() {
+        let mut sum = Timed::new(CumulativeSum::new(), None).unwrap();
+        assert_eq!(sum.try_apply((1.0, at(5))).unwrap(), 1.0);
+        assert!(sum.try_apply((1.0, at(4))).is_err());
+        assert!(sum.try_apply((f64::NAN, at(6))).is_err());
+        assert_eq!(sum.try_apply((1.0, at(5))).unwrap(), 2.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut sum = Timed::new(CumulativeSum::new(), Some(TimeDelta::seconds(1))).unwrap();
+        sum.apply((1.0, at(0)));
+        assert_eq!(sum.evaluate((1.0, at(5))), 2.0);
+        assert_eq!(sum.last_gap(), None);
+        assert_eq!(sum.previous_timestamp(), Some(at(0)));
+        assert_eq!(sum.current(), 1.0);
+    }
+
+    #[test]
+    fn test_invalid_max_gap() {
+        assert!(Timed::new(CumulativeSum::new(), Some(TimeDelta::seconds(-1))).is_err());
+    }
+}
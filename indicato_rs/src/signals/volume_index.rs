@@ -0,0 +1,535 @@
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, ChainValue, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp,
+    },
+    validation,
+};
+
+use super::ExponentialMovingAverage;
+
+/// Output of the [`PositiveVolumeIndex`] and [`NegativeVolumeIndex`] signals.
+///
+/// Tuples of (index, signal) can be converted into it, and it can be converted back into a tuple.
+#[derive(Debug, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VolumeIndexOutput {
+    /// The volume index.
+    pub index: f64,
+    /// The signal line, the Exponential Moving Average of the index, `None` when the index has no signal line.
+    pub signal: Option<f64>,
+}
+
+impl From<(f64, Option<f64>)> for VolumeIndexOutput {
+    fn from((index, signal): (f64, Option<f64>)) -> Self {
+        Self { index, signal }
+    }
+}
+
+impl From<VolumeIndexOutput> for (f64, Option<f64>) {
+    fn from(output: VolumeIndexOutput) -> Self {
+        (output.index, output.signal)
+    }
+}
+
+/// The index and its signal line are passed on when the index has a signal line, such as to a
+/// [`Crossover`](super::Crossover).
+impl ChainValue<(f64, f64)> for VolumeIndexOutput {
+    fn into_input(self) -> Option<(f64, f64)> {
+        self.signal.map(|signal| (self.index, signal))
+    }
+}
+
+/// The change in volume that causes a volume index to be updated.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum VolumeDirection {
+    Rising,
+    Falling,
+}
+
+crate::inspect::debug_state!(VolumeDirection);
+
+/// Shared aggregation for the positive and negative volume indices.
+#[derive(Debug, Clone, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct VolumeIndex {
+    direction: VolumeDirection,
+    index: f64,
+    /// The previous close and volume.
+    previous: Option<(f64, f64)>,
+    signal: Option<ExponentialMovingAverage>,
+    current: VolumeIndexOutput,
+}
+
+impl VolumeIndex {
+    fn new(
+        direction: VolumeDirection,
+        initial_value: f64,
+        signal_period: Option<usize>,
+    ) -> Result<Self, FinError> {
+        if initial_value <= 0.0 {
+            return Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Initial value must be greater than 0",
+            ));
+        }
+        let signal = signal_period
+            .map(ExponentialMovingAverage::new)
+            .transpose()?;
+        Ok(Self {
+            direction,
+            index: initial_value,
+            previous: None,
+            current: (initial_value, None).into(),
+            signal,
+        })
+    }
+
+    fn warmup_remaining(&self) -> usize {
+        usize::from(self.previous.is_none())
+    }
+
+    fn execute(
+        &mut self,
+        input: (f64, f64),
+        execution_context: &ExecutionContext,
+    ) -> VolumeIndexOutput {
+        let (close, volume) = input;
+        let index = match self.previous {
+            Some((previous_close, previous_volume)) if previous_close != 0.0 => {
+                let is_active = match self.direction {
+                    VolumeDirection::Rising => volume > previous_volume,
+                    VolumeDirection::Falling => volume < previous_volume,
+                };
+                match is_active {
+                    true => self.index * close / previous_close,
+                    false => self.index,
+                }
+            }
+            _ => self.index,
+        };
+        let signal = self
+            .signal
+            .as_mut()
+            .map(|ema| ema.execute(index, execution_context));
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.index = index;
+                self.previous = Some((close, volume));
+                self.current = VolumeIndexOutput { index, signal };
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        VolumeIndexOutput { index, signal }
+    }
+}
+
+/// # Positive Volume Index
+/// Container for Positive Volume Index (PVI) aggregation
+///
+/// The Positive Volume Index is a cumulative index that only changes when the volume rises from the previous input,
+/// when it does the index changes by the percentage change in close:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mo>{</mo>
+///         <mtable>
+///             <mtr>
+///                 <mtd><mrow>
+///                     <msub><mi>o</mi><mn>n-1</mn></msub>
+///                     <mo>⋅</mo>
+///                     <mrow><mo stretchy="true" form="prefix">(</mo>
+///                         <mn>1</mn>
+///                         <mo>+</mo>
+///                         <mfrac>
+///                             <mrow>
+///                                 <msub><mi>c</mi><mn>n</mn></msub>
+///                                 <mo>−</mo>
+///                                 <msub><mi>c</mi><mn>n-1</mn></msub>
+///                             </mrow>
+///                             <msub><mi>c</mi><mn>n-1</mn></msub>
+///                         </mfrac>
+///                     <mo stretchy="true" form="postfix">)</mo></mrow>
+///                 </mrow></mtd>
+///                 <mtd>if</mtd>
+///                 <mtd><mrow><msub><mi>v</mi><mn>n</mn></msub><mo>></mo><msub><mi>v</mi><mn>n-1</mn></msub></mrow></mtd>
+///             </mtr>
+///             <mtr>
+///                 <mtd><msub><mi>o</mi><mn>n-1</mn></msub></mtd>
+///                 <mtd>otherwise</mtd>
+///             </mtr>
+///         </mtable>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `c` is the close value and `v` is the volume.
+///
+/// The index begins at the initial value, optionally an Exponential Moving Average of the index can be produced as a signal line.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{PositiveVolumeIndex, VolumeIndexOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Positive Volume Index starting at 1000 with a 3 period signal line
+/// let mut pvi = PositiveVolumeIndex::new(1000.0, Some(3)).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(pvi.apply((10.0, 100.0)), VolumeIndexOutput { index: 1000.0, signal: Some(1000.0) });
+/// assert_eq!(pvi.apply((11.0, 200.0)), (1100.0, Some(1050.0)).into());
+/// assert_eq!(pvi.apply((12.0, 100.0)), (1100.0, Some(1075.0)).into());
+///
+/// // evaluate some values, these won't affect the internal state of the PVI
+/// assert_eq!(pvi.evaluate((6.0, 200.0)), (550.0, Some(812.5)).into());
+///
+/// // fetch the current value of the PVI
+/// assert_eq!(pvi.current(), (1100.0, Some(1075.0)).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PositiveVolumeIndex {
+    index: VolumeIndex,
+}
+
+impl PositiveVolumeIndex {
+    /// Create a new Positive Volume Index instance
+    /// # Arguments
+    /// * `initial_value` - The value the index begins at, typically 1000, must be greater than 0
+    /// * `signal_period` - The period of the Exponential Moving Average signal line, or `None` for no signal line
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::PositiveVolumeIndex;
+    ///
+    /// let pvi = PositiveVolumeIndex::new(1000.0, Some(255));
+    /// assert!(pvi.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the initial value is not greater than 0 or the signal period is 0
+    /// ```
+    /// use indicato_rs::signals::PositiveVolumeIndex;
+    ///
+    /// assert!(PositiveVolumeIndex::new(0.0, None).is_err());
+    /// assert!(PositiveVolumeIndex::new(1000.0, Some(0)).is_err());
+    /// ```
+    pub fn new(initial_value: f64, signal_period: Option<usize>) -> Result<Self, FinError> {
+        Ok(Self {
+            index: VolumeIndex::new(VolumeDirection::Rising, initial_value, signal_period)?,
+        })
+    }
+
+    /// Create a new Positive Volume Index instance with the conventional initial value of 1000 and a 255 period signal line
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::PositiveVolumeIndex;
+    /// use indicato_rs::traits::Current;
+    ///
+    /// let pvi = PositiveVolumeIndex::standard();
+    /// assert_eq!(pvi.current(), (1000.0, None).into());
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(1000.0, Some(255)).unwrap()
+    }
+}
+
+impl Default for PositiveVolumeIndex {
+    /// Create a new Positive Volume Index with the conventional initial value of 1000 and a 255 period signal line
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl IoState for PositiveVolumeIndex {
+    /// Input is a tuple of (close, volume)
+    type Input = (f64, f64);
+    /// Output is the index and its signal line
+    type Output = VolumeIndexOutput;
+}
+
+impl Validate for PositiveVolumeIndex {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (close, volume) = *input;
+        validation::value(close, "Close")?;
+        validation::volume(volume)
+    }
+}
+
+impl Executable for PositiveVolumeIndex {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        self.index.execute(input, execution_context)
+    }
+}
+
+impl Current for PositiveVolumeIndex {
+    fn current(&self) -> Self::Output {
+        self.index.current
+    }
+}
+
+impl WarmUp for PositiveVolumeIndex {
+    fn warmup_remaining(&self) -> usize {
+        self.index.warmup_remaining()
+    }
+}
+
+/// # Negative Volume Index
+/// Container for Negative Volume Index (NVI) aggregation
+///
+/// The Negative Volume Index is a cumulative index that only changes when the volume falls from the previous input,
+/// when it does the index changes by the percentage change in close:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mo>{</mo>
+///         <mtable>
+///             <mtr>
+///                 <mtd><mrow>
+///                     <msub><mi>o</mi><mn>n-1</mn></msub>
+///                     <mo>⋅</mo>
+///                     <mrow><mo stretchy="true" form="prefix">(</mo>
+///                         <mn>1</mn>
+///                         <mo>+</mo>
+///                         <mfrac>
+///                             <mrow>
+///                                 <msub><mi>c</mi><mn>n</mn></msub>
+///                                 <mo>−</mo>
+///                                 <msub><mi>c</mi><mn>n-1</mn></msub>
+///                             </mrow>
+///                             <msub><mi>c</mi><mn>n-1</mn></msub>
+///                         </mfrac>
+///                     <mo stretchy="true" form="postfix">)</mo></mrow>
+///                 </mrow></mtd>
+///                 <mtd>if</mtd>
+///                 <mtd><mrow><msub><mi>v</mi><mn>n</mn></msub><mo><</mo><msub><mi>v</mi><mn>n-1</mn></msub></mrow></mtd>
+///             </mtr>
+///             <mtr>
+///                 <mtd><msub><mi>o</mi><mn>n-1</mn></msub></mtd>
+///                 <mtd>otherwise</mtd>
+///             </mtr>
+///         </mtable>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `n-1` is the previous step, `c` is the close value and `v` is the volume.
+///
+/// The index begins at the initial value, optionally an Exponential Moving Average of the index can be produced as a signal line.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{NegativeVolumeIndex, VolumeIndexOutput};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Negative Volume Index starting at 1000 without a signal line
+/// let mut nvi = NegativeVolumeIndex::new(1000.0, None).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(nvi.apply((10.0, 200.0)), VolumeIndexOutput { index: 1000.0, signal: None });
+/// assert_eq!(nvi.apply((11.0, 100.0)), (1100.0, None).into());
+/// assert_eq!(nvi.apply((12.0, 200.0)), (1100.0, None).into());
+///
+/// // evaluate some values, these won't affect the internal state of the NVI
+/// assert_eq!(nvi.evaluate((6.0, 100.0)), (550.0, None).into());
+///
+/// // fetch the current value of the NVI
+/// assert_eq!(nvi.current(), (1100.0, None).into());
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NegativeVolumeIndex {
+    index: VolumeIndex,
+}
+
+impl NegativeVolumeIndex {
+    /// Create a new Negative Volume Index instance
+    /// # Arguments
+    /// * `initial_value` - The value the index begins at, typically 1000, must be greater than 0
+    /// * `signal_period` - The period of the Exponential Moving Average signal line, or `None` for no signal line
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::NegativeVolumeIndex;
+    ///
+    /// let nvi = NegativeVolumeIndex::new(1000.0, Some(255));
+    /// assert!(nvi.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the initial value is not greater than 0 or the signal period is 0
+    /// ```
+    /// use indicato_rs::signals::NegativeVolumeIndex;
+    ///
+    /// assert!(NegativeVolumeIndex::new(-1.0, None).is_err());
+    /// assert!(NegativeVolumeIndex::new(1000.0, Some(0)).is_err());
+    /// ```
+    pub fn new(initial_value: f64, signal_period: Option<usize>) -> Result<Self, FinError> {
+        Ok(Self {
+            index: VolumeIndex::new(VolumeDirection::Falling, initial_value, signal_period)?,
+        })
+    }
+
+    /// Create a new Negative Volume Index instance with the conventional initial value of 1000 and a 255 period signal line
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::NegativeVolumeIndex;
+    /// use indicato_rs::traits::Current;
+    ///
+    /// let nvi = NegativeVolumeIndex::standard();
+    /// assert_eq!(nvi.current(), (1000.0, None).into());
+    /// ```
+    pub fn standard() -> Self {
+        Self::new(1000.0, Some(255)).unwrap()
+    }
+}
+
+impl Default for NegativeVolumeIndex {
+    /// Create a new Negative Volume Index with the conventional initial value of 1000 and a 255 period signal line
+    fn default() -> Self {
+        Self::standard()
+    }
+}
+
+impl IoState for NegativeVolumeIndex {
+    /// Input is a tuple of (close, volume)
+    type Input = (f64, f64);
+    /// Output is the index and its signal line
+    type Output = VolumeIndexOutput;
+}
+
+impl Validate for NegativeVolumeIndex {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (close, volume) = *input;
+        validation::value(close, "Close")?;
+        validation::volume(volume)
+    }
+}
+
+impl Executable for NegativeVolumeIndex {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        self.index.execute(input, execution_context)
+    }
+}
+
+impl Current for NegativeVolumeIndex {
+    fn current(&self) -> Self::Output {
+        self.index.current
+    }
+}
+
+impl WarmUp for NegativeVolumeIndex {
+    fn warmup_remaining(&self) -> usize {
+        self.index.warmup_remaining()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_positive_apply() {
+        let mut pvi = PositiveVolumeIndex::new(1000.0, Some(3)).unwrap();
+        assert_eq!(pvi.apply((10.0, 100.0)), (1000.0, Some(1000.0)).into());
+        assert_eq!(pvi.apply((11.0, 200.0)), (1100.0, Some(1050.0)).into());
+        assert_eq!(pvi.apply((12.0, 100.0)), (1100.0, Some(1075.0)).into());
+        assert_eq!(pvi.apply((6.0, 200.0)), (550.0, Some(812.5)).into());
+    }
+
+    #[test]
+    fn test_positive_evaluate() {
+        let mut pvi = PositiveVolumeIndex::new(1000.0, None).unwrap();
+        assert_eq!(pvi.evaluate((10.0, 100.0)), (1000.0, None).into());
+        assert_eq!(pvi.apply((10.0, 100.0)), (1000.0, None).into());
+        assert_eq!(pvi.evaluate((11.0, 200.0)), (1100.0, None).into());
+        assert_eq!(pvi.evaluate((11.0, 50.0)), (1000.0, None).into());
+        assert_eq!(pvi.apply((12.0, 200.0)), (1200.0, None).into());
+    }
+
+    #[test]
+    fn test_positive_current() {
+        let mut pvi = PositiveVolumeIndex::new(1000.0, Some(3)).unwrap();
+        assert_eq!(pvi.current(), (1000.0, None).into());
+        pvi.apply((10.0, 100.0));
+        pvi.apply((11.0, 200.0));
+        pvi.evaluate((6.0, 300.0));
+        assert_eq!(pvi.current(), (1100.0, Some(1050.0)).into());
+    }
+
+    #[test]
+    fn test_negative_apply() {
+        let mut nvi = NegativeVolumeIndex::new(1000.0, Some(3)).unwrap();
+        assert_eq!(nvi.apply((10.0, 200.0)), (1000.0, Some(1000.0)).into());
+        assert_eq!(nvi.apply((11.0, 100.0)), (1100.0, Some(1050.0)).into());
+        assert_eq!(nvi.apply((12.0, 200.0)), (1100.0, Some(1075.0)).into());
+        assert_eq!(nvi.apply((6.0, 100.0)), (550.0, Some(812.5)).into());
+    }
+
+    #[test]
+    fn test_negative_evaluate() {
+        let mut nvi = NegativeVolumeIndex::new(1000.0, None).unwrap();
+        assert_eq!(nvi.apply((10.0, 200.0)), (1000.0, None).into());
+        assert_eq!(nvi.evaluate((11.0, 100.0)), (1100.0, None).into());
+        assert_eq!(nvi.evaluate((11.0, 300.0)), (1000.0, None).into());
+        assert_eq!(nvi.apply((12.0, 100.0)), (1200.0, None).into());
+    }
+
+    #[test]
+    fn test_negative_current() {
+        let mut nvi = NegativeVolumeIndex::new(1000.0, None).unwrap();
+        assert_eq!(nvi.current(), (1000.0, None).into());
+        nvi.apply((10.0, 200.0));
+        nvi.apply((11.0, 100.0));
+        nvi.evaluate((6.0, 50.0));
+        assert_eq!(nvi.current(), (1100.0, None).into());
+    }
+
+    #[test]
+    fn test_zero_previous_close() {
+        let mut pvi = PositiveVolumeIndex::new(1000.0, None).unwrap();
+        assert_eq!(pvi.apply((0.0, 100.0)), (1000.0, None).into());
+        assert_eq!(pvi.apply((10.0, 200.0)), (1000.0, None).into());
+    }
+
+    #[test]
+    fn test_chain_value() {
+        let mut pvi = PositiveVolumeIndex::new(1000.0, Some(3)).unwrap();
+        assert_eq!(pvi.apply((10.0, 100.0)).into_input(), Some((1000.0, 1000.0)));
+        let mut pvi = PositiveVolumeIndex::new(1000.0, None).unwrap();
+        assert_eq!(pvi.apply((10.0, 100.0)).into_input(), None::<(f64, f64)>);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(PositiveVolumeIndex::new(0.0, None).is_err());
+        assert!(PositiveVolumeIndex::new(1000.0, Some(0)).is_err());
+        assert!(NegativeVolumeIndex::new(0.0, None).is_err());
+        assert!(NegativeVolumeIndex::new(1000.0, Some(0)).is_err());
+    }
+}
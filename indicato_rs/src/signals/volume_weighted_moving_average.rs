@@ -0,0 +1,301 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::{compensated_mean, compensated_sum},
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Numeric, Periodic,
+        Validate, WarmUp,
+    },
+    validation,
+};
+
+fn volume_weighted_mean<T: Numeric>(prices: &VecDeque<T>, volumes: &VecDeque<T>) -> T {
+    let volume = compensated_sum(volumes.iter().copied());
+    match volume == T::zero() {
+        true => compensated_mean(prices),
+        false => {
+            compensated_sum(
+                prices
+                    .iter()
+                    .zip(volumes.iter())
+                    .map(|(&price, &volume)| price * volume),
+            ) / volume
+        }
+    }
+}
+
+/// # Volume Weighted Moving Average
+/// Container for Volume Weighted Moving Average aggregation
+///
+/// The aggregation will begin producing values immediately, with the period being filled as values are applied,
+/// the following formula is applied:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>o</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>=</mo>
+///         <mfrac>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mrow><mi>k</mi><mo>=</mo><mi>n</mi><mo>−</mo><mi>p</mi><mo>+</mo><mn>1</mn></mrow>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub>
+///                     <mi>i</mi>
+///                     <mi>k</mi>
+///                 </msub>
+///                 <msub>
+///                     <mi>v</mi>
+///                     <mi>k</mi>
+///                 </msub>
+///             </mrow>
+///             <mrow>
+///                 <munderover>
+///                     <mo>∑</mo>
+///                     <mrow><mi>k</mi><mo>=</mo><mi>n</mi><mo>−</mo><mi>p</mi><mo>+</mo><mn>1</mn></mrow>
+///                     <mi>n</mi>
+///                 </munderover>
+///                 <msub>
+///                     <mi>v</mi>
+///                     <mi>k</mi>
+///                 </msub>
+///             </mrow>
+///         </mfrac>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `o` is the output, `n` is the current step, `p` is the period, `i` is the price and `v` is the volume. If
+/// the volume over the period is 0 the output will be the simple mean of the prices over the period.
+///
+/// The aggregation is generic over the [`Numeric`] type of its inputs and defaults to `f64`, with the `rust_decimal`
+/// feature it can also aggregate `rust_decimal::Decimal` prices and volumes exactly.
+/// `new` creates an `f64` aggregation, other types are created with
+/// [`for_type`](VolumeWeightedMovingAverage::for_type).
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::VolumeWeightedMovingAverage;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Volume Weighted Moving Average with a period of 2
+/// let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+///
+/// // apply some (price, volume) values and check their output
+/// assert_eq!(vwma.apply((2.0, 100.0)), 2.0);
+/// assert_eq!(vwma.apply((4.0, 300.0)), 3.5);
+/// assert_eq!(vwma.apply((8.0, 100.0)), 5.0);
+///
+/// // evaluate some values, these won't affect the internal state of the VWMA
+/// assert_eq!(vwma.evaluate((2.0, 900.0)), 2.6);
+///
+/// // fetch the current value of the VWMA
+/// assert_eq!(vwma.current(), 5.0);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VolumeWeightedMovingAverage<T: Numeric = f64> {
+    period: usize,
+    prices: VecDeque<T>,
+    volumes: VecDeque<T>,
+}
+
+impl<T: Numeric> IoState for VolumeWeightedMovingAverage<T> {
+    /// Input is a tuple of (price, volume)
+    type Input = (T, T);
+    type Output = T;
+}
+
+impl<T: Numeric> VolumeWeightedMovingAverage<T> {
+    /// Create a new Volume Weighted Moving Average instance taking inputs of type `T`, such as `f32` or, with the
+    /// `rust_decimal` feature, `rust_decimal::Decimal`
+    /// # Arguments
+    /// * `period` - The period of the Volume Weighted Moving Average aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::VolumeWeightedMovingAverage;
+    ///
+    /// let vwma = VolumeWeightedMovingAverage::<f32>::for_type(20);
+    /// assert!(vwma.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::VolumeWeightedMovingAverage;
+    ///
+    /// let vwma = VolumeWeightedMovingAverage::<f32>::for_type(0);
+    ///
+    /// assert!(vwma.is_err());
+    /// ```
+    pub fn for_type(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                prices: VecDeque::with_capacity(period + 1),
+                volumes: VecDeque::with_capacity(period + 1),
+            }),
+        }
+    }
+}
+
+impl VolumeWeightedMovingAverage {
+    /// Create a new Volume Weighted Moving Average instance
+    /// # Arguments
+    /// * `period` - The period of the Volume Weighted Moving Average aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::VolumeWeightedMovingAverage;
+    ///
+    /// let vwma = VolumeWeightedMovingAverage::new(20);
+    /// assert!(vwma.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::VolumeWeightedMovingAverage;
+    ///
+    /// let vwma = VolumeWeightedMovingAverage::new(0);
+    ///
+    /// assert!(vwma.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
+impl<T: Numeric> Validate for VolumeWeightedMovingAverage<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        let (price, volume) = *input;
+        validation::value(price, "Price")?;
+        validation::volume(volume)
+    }
+}
+
+impl<T: Numeric> Executable for VolumeWeightedMovingAverage<T> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (price, volume) = input;
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.prices.push_back(price);
+                self.volumes.push_back(volume);
+                if self.prices.len() > self.period {
+                    self.prices.pop_front();
+                    self.volumes.pop_front();
+                }
+                volume_weighted_mean(&self.prices, &self.volumes)
+            }
+            ExecutionContext::Evaluate => {
+                let mut prices = self.prices.clone();
+                let mut volumes = self.volumes.clone();
+                prices.push_back(price);
+                volumes.push_back(volume);
+                if prices.len() > self.period {
+                    prices.pop_front();
+                    volumes.pop_front();
+                }
+                volume_weighted_mean(&prices, &volumes)
+            }
+        }
+    }
+}
+
+impl<T: Numeric> Current for VolumeWeightedMovingAverage<T> {
+    fn current(&self) -> Self::Output {
+        volume_weighted_mean(&self.prices, &self.volumes)
+    }
+}
+
+impl<T: Numeric> WarmUp for VolumeWeightedMovingAverage<T> {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.prices.len())
+    }
+}
+
+impl<T: Numeric> Periodic for VolumeWeightedMovingAverage<T> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+        assert_eq!(vwma.apply((2.0, 100.0)), 2.0);
+        assert_eq!(vwma.apply((4.0, 300.0)), 3.5);
+        assert_eq!(vwma.apply((8.0, 100.0)), 5.0);
+        assert_eq!(vwma.apply((2.0, 900.0)), 2.6);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+        assert_eq!(vwma.apply((2.0, 100.0)), 2.0);
+        assert_eq!(vwma.evaluate((4.0, 300.0)), 3.5);
+        assert_eq!(vwma.evaluate((8.0, 100.0)), 5.0);
+        assert_eq!(vwma.apply((4.0, 300.0)), 3.5);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+        assert_eq!(vwma.current(), 0.0);
+        vwma.apply((2.0, 100.0));
+        vwma.apply((4.0, 300.0));
+        vwma.evaluate((8.0, 100.0));
+        assert_eq!(vwma.current(), 3.5);
+    }
+
+    #[test]
+    fn test_zero_volume() {
+        let mut vwma = VolumeWeightedMovingAverage::new(2).unwrap();
+        assert_eq!(vwma.apply((2.0, 0.0)), 2.0);
+        assert_eq!(vwma.apply((4.0, 0.0)), 3.0);
+        assert_eq!(vwma.apply((8.0, 100.0)), 8.0);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let vwma = VolumeWeightedMovingAverage::new(0);
+        assert!(vwma.is_err());
+    }
+
+    #[cfg(feature = "rust_decimal")]
+    #[test]
+    fn test_decimal() {
+        use rust_decimal::Decimal;
+
+        let mut vwma = VolumeWeightedMovingAverage::<Decimal>::for_type(2).unwrap();
+        assert_eq!(
+            vwma.apply((Decimal::new(101, 1), Decimal::ONE)),
+            Decimal::new(101, 1)
+        );
+        assert_eq!(
+            vwma.apply((Decimal::new(102, 1), Decimal::new(3, 0))),
+            Decimal::new(10175, 3)
+        );
+        assert_eq!(vwma.current(), Decimal::new(10175, 3));
+    }
+}
@@ -0,0 +1,234 @@
+use std::collections::VecDeque;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    deque_math::DequeMathExtF64,
+    fin_error::{FinError, FinErrorType},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, MovingAverage, Periodic, Validate,
+        WarmUp,
+    },
+    validation,
+};
+
+fn linear_weighted_mean(values: &VecDeque<f64>, weights: &[f64]) -> f64 {
+    // the values never outnumber the period, so the weights are sliced to their length and the mean is always defined
+    values.weighted_mean(&weights[..values.len()]).unwrap_or_default()
+}
+
+/// # Weighted Moving Average
+/// Container for linearly Weighted Moving Average (WMA) aggregation
+///
+/// The aggregation will begin producing values immediately, the most recent input is given a weight of `p` and
+/// each older input is given a weight one less than the input after it. Until the period has been filled the
+/// weights start at the oldest available input. The following formula is applied:
+/// <br><br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+/// <mrow>
+/// <msub>
+///     <mi>o</mi>
+///     <mn>n</mn>
+/// </msub>
+/// <mo>=</mo>
+/// <mfrac>
+///     <mrow>
+///         <munderover>
+///             <mo>∑</mo>
+///             <mi>k=1</mi>
+///             <mi>m</mi>
+///         </munderover>
+///         <mi>k</mi>
+///         <mo>⋅</mo>
+///         <msub>
+///             <mi>i</mi>
+///             <mn>n-m+k</mn>
+///         </msub>
+///     </mrow>
+///     <mrow>
+///         <munderover>
+///             <mo>∑</mo>
+///             <mi>k=1</mi>
+///             <mi>m</mi>
+///         </munderover>
+///         <mi>k</mi>
+///     </mrow>
+/// </mfrac>
+/// </mrow>
+/// </semantics>
+/// </math>
+/// <br><br>
+/// Where `o` is the output, `n` is the current step, `m` is the lesser of `p`, the period of the weighted moving average,
+/// and the number of inputs applied, and `i` is the input.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::WeightedMovingAverage;
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new Weighted Moving Average with a period of 3
+/// let mut wma = WeightedMovingAverage::new(3).unwrap();
+///
+/// // apply some values and check their output
+/// assert_eq!(wma.apply(3.0), 3.0);
+/// assert_eq!(wma.apply(6.0), 5.0);
+/// assert_eq!(wma.apply(3.0), 4.0);
+/// assert_eq!(wma.apply(9.0), 6.5);
+///
+/// // evaluate some values, these won't affect the internal state of the WMA
+/// assert_eq!(wma.evaluate(3.0), 5.0);
+/// assert_eq!(wma.evaluate(15.0), 11.0);
+///
+/// // fetch the current value of the WMA
+/// assert_eq!(wma.current(), 6.5);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WeightedMovingAverage {
+    period: usize,
+    values: VecDeque<f64>,
+    /// The weights from the oldest value, 1 up to the period, calculated once rather than for each input.
+    weights: Vec<f64>,
+}
+
+impl IoState for WeightedMovingAverage {
+    type Input = f64;
+    type Output = f64;
+}
+
+impl WeightedMovingAverage {
+    /// Create a new Weighted Moving Average instance
+    /// # Arguments
+    /// * `period` - The period of the Weighted Moving Average aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::WeightedMovingAverage;
+    ///
+    /// let wma = WeightedMovingAverage::new(3);
+    /// assert!(wma.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::WeightedMovingAverage;
+    ///
+    /// let wma = WeightedMovingAverage::new(0);
+    ///
+    /// assert!(wma.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        match period {
+            0 => Err(FinError::new(
+                FinErrorType::InvalidInput,
+                "Period must be greater than 0",
+            )),
+            _ => Ok(Self {
+                period,
+                values: VecDeque::with_capacity(period + 1),
+                weights: (1..=period).map(|weight| weight as f64).collect(),
+            }),
+        }
+    }
+}
+
+impl Validate for WeightedMovingAverage {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl Executable for WeightedMovingAverage {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.values.push_back(input);
+                if self.values.len() > self.period {
+                    self.values.pop_front();
+                }
+                linear_weighted_mean(&self.values, &self.weights)
+            }
+            ExecutionContext::Evaluate => {
+                let mut values = self.values.clone();
+                values.push_back(input);
+                if values.len() > self.period {
+                    values.pop_front();
+                }
+                linear_weighted_mean(&values, &self.weights)
+            }
+        }
+    }
+}
+
+impl Current for WeightedMovingAverage {
+    fn current(&self) -> Self::Output {
+        linear_weighted_mean(&self.values, &self.weights)
+    }
+}
+
+impl WarmUp for WeightedMovingAverage {
+    fn warmup_remaining(&self) -> usize {
+        self.period.saturating_sub(self.values.len())
+    }
+}
+
+impl Periodic for WeightedMovingAverage {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl MovingAverage for WeightedMovingAverage {
+    fn with_period(period: usize) -> Result<Self, FinError> {
+        Self::new(period)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apply() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!(wma.apply(3.0), 3.0);
+        assert_eq!(wma.apply(6.0), 5.0);
+        assert_eq!(wma.apply(3.0), 4.0);
+        assert_eq!(wma.apply(9.0), 6.5);
+        assert_eq!(wma.apply(3.0), 5.0);
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!(wma.apply(3.0), 3.0);
+        assert_eq!(wma.evaluate(6.0), 5.0);
+        assert_eq!(wma.apply(6.0), 5.0);
+        assert_eq!(wma.apply(3.0), 4.0);
+        assert_eq!(wma.evaluate(9.0), 6.5);
+        assert_eq!(wma.evaluate(15.0), 9.5);
+        assert_eq!(wma.apply(9.0), 6.5);
+    }
+
+    #[test]
+    fn test_current() {
+        let mut wma = WeightedMovingAverage::new(3).unwrap();
+        assert_eq!(wma.current(), 0.0);
+        assert_eq!(wma.apply(3.0), 3.0);
+        assert_eq!(wma.apply(6.0), 5.0);
+        assert_eq!(wma.apply(3.0), 4.0);
+        assert_eq!(wma.apply(9.0), 6.5);
+        assert_eq!(wma.current(), 6.5);
+    }
+
+    #[test]
+    fn test_invalid_period() {
+        let wma = WeightedMovingAverage::new(0);
+        assert!(wma.is_err());
+    }
+}
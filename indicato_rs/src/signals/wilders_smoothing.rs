@@ -1,12 +1,18 @@
-use indicato_rs_proc::{Apply, Evaluate};
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+use num_traits::Float;
 
 use crate::{
     fin_error::{FinError, FinErrorType},
-    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, IoState},
+    traits::{
+        Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, MovingAverage, Periodic, Validate,
+        WarmUp,
+    },
+    validation,
 };
 
-fn calculate_wilders(input: f64, previous: f64, period: usize) -> f64 {
-    (previous * (period as f64 - 1.0) + input) / period as f64
+fn calculate_wilders<T: Float>(input: T, previous: T, period: usize) -> T {
+    let period = T::from(period).unwrap_or_else(T::nan);
+    (previous * (period - T::one()) + input) / period
 }
 
 /// # Wilders Smoothing
@@ -81,6 +87,9 @@ fn calculate_wilders(input: f64, previous: f64, period: usize) -> f64 {
 /// </math>
 /// <br>
 ///
+/// The aggregation is generic over the float type of its inputs and defaults to `f64`.
+/// `new` creates an `f64` aggregation, other types are created with [`for_type`](WildersSmoothing::for_type).
+///
 /// # Example Usage
 /// ```
 /// use indicato_rs::signals::WildersSmoothing;
@@ -102,23 +111,24 @@ fn calculate_wilders(input: f64, previous: f64, period: usize) -> f64 {
 /// // check the current value of the Wilders Smoothing
 /// assert_eq!(ws.current(), Some(5.0));
 /// ```
-#[derive(Apply, Evaluate)]
-pub struct WildersSmoothing {
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WildersSmoothing<T: Float = f64> {
     /// The period of the Wilders Smoothing aggregation
     period: usize,
-    current: Option<f64>,
-    cumulative: f64,
-    previous: f64,
+    current: Option<T>,
+    cumulative: T,
+    previous: T,
     seed_count: usize,
 }
 
-impl IoState for WildersSmoothing {
-    type Input = f64;
-    type Output = Option<f64>;
+impl<T: Float> IoState for WildersSmoothing<T> {
+    type Input = T;
+    type Output = Option<T>;
 }
 
-impl WildersSmoothing {
-    /// Create a new WildersSmoothing instance
+impl<T: Float> WildersSmoothing<T> {
+    /// Create a new WildersSmoothing instance taking inputs of type `T`, such as `f32`
     /// # Arguments
     /// * `period` - The period of the Wilders Smoothing aggregation, must be greater than 0
     ///
@@ -127,7 +137,7 @@ impl WildersSmoothing {
     /// use indicato_rs::signals::WildersSmoothing;
     /// use indicato_rs::traits::{Apply, Evaluate, Current};
     /// 
-    /// let ws = WildersSmoothing::new(3)
+    /// let ws = WildersSmoothing::<f32>::for_type(3);
     /// 
     /// assert!(ws.is_ok());
     /// ```
@@ -135,11 +145,11 @@ impl WildersSmoothing {
     /// ```
     /// use indicato_rs::signals::WildersSmoothing;
     ///
-    /// let ws = WildersSmoothing::new(0);
+    /// let ws = WildersSmoothing::<f32>::for_type(0);
     ///
     /// assert!(ws.is_err());
     /// ```
-    pub fn new(period: usize) -> Result<Self, FinError> {
+    pub fn for_type(period: usize) -> Result<Self, FinError> {
         match period {
             0 => Err(FinError::new(
                 FinErrorType::InvalidInput,
@@ -147,16 +157,49 @@ impl WildersSmoothing {
             )),
             _ => Ok(Self {
                 period,
-                previous: 0.0,
+                previous: T::zero(),
                 current: None,
-                cumulative: 0.0,
+                cumulative: T::zero(),
                 seed_count: 1,
             }),
         }
     }
 }
 
-impl Executable for WildersSmoothing {
+impl WildersSmoothing {
+    /// Create a new WildersSmoothing instance
+    /// # Arguments
+    /// * `period` - The period of the Wilders Smoothing aggregation, must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::WildersSmoothing;
+    /// use indicato_rs::traits::{Apply, Evaluate, Current};
+    /// 
+    /// let ws = WildersSmoothing::new(3);
+    /// 
+    /// assert!(ws.is_ok());
+    /// ```
+    /// Will return an error if the period is 0
+    /// ```
+    /// use indicato_rs::signals::WildersSmoothing;
+    ///
+    /// let ws = WildersSmoothing::new(0);
+    ///
+    /// assert!(ws.is_err());
+    /// ```
+    pub fn new(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
+impl<T: Float> Validate for WildersSmoothing<T> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(*input, "Input")
+    }
+}
+
+impl<T: Float> Executable for WildersSmoothing<T> {
     fn execute(
         &mut self,
         input: Self::Input,
@@ -165,8 +208,9 @@ impl Executable for WildersSmoothing {
         match execution_context {
             ExecutionContext::Apply => {
                 if self.seed_count < self.period {
-                    self.cumulative += input;
-                    self.previous = self.cumulative / self.seed_count as f64;
+                    self.cumulative = self.cumulative + input;
+                    self.previous =
+                        self.cumulative / T::from(self.seed_count).unwrap_or_else(T::nan);
                     self.seed_count += 1;
                     None
                 } else {
@@ -188,7 +232,7 @@ impl Executable for WildersSmoothing {
     }
 }
 
-impl Current for WildersSmoothing {
+impl<T: Float> Current for WildersSmoothing<T> {
     fn current(&self) -> Self::Output {
         if self.seed_count < self.period {
             None
@@ -198,6 +242,27 @@ impl Current for WildersSmoothing {
     }
 }
 
+impl<T: Float> WarmUp for WildersSmoothing<T> {
+    fn warmup_remaining(&self) -> usize {
+        match self.current {
+            Some(_) => 0,
+            None => self.period + 1 - self.seed_count,
+        }
+    }
+}
+
+impl<T: Float> Periodic for WildersSmoothing<T> {
+    fn period(&self) -> usize {
+        self.period
+    }
+}
+
+impl<T: Float> MovingAverage for WildersSmoothing<T> {
+    fn with_period(period: usize) -> Result<Self, FinError> {
+        Self::for_type(period)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -234,4 +299,17 @@ mod tests {
         let ws = WildersSmoothing::new(0);
         assert!(ws.is_err());
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let mut ws = WildersSmoothing::new(3).unwrap();
+        ws.apply(1.0);
+        ws.apply(2.0);
+        ws.apply(3.0);
+        let snapshot = serde_json::to_string(&ws).unwrap();
+        let mut restored: WildersSmoothing = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(restored.current(), ws.current());
+        assert_eq!(restored.apply(5.0), ws.apply(5.0));
+    }
 }
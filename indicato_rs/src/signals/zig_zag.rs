@@ -0,0 +1,525 @@
+use std::marker::PhantomData;
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    inspect::{SignalState, StateValue, ToState},
+    traits::{Apply, Current, Evaluate, Executable, ExecutionContext, Inspect, IoState, Validate, WarmUp},
+    types::{HasClose, HasHighLow},
+    validation,
+};
+
+use super::WildersSmoothing;
+
+/// The reversal threshold of a [`ZigZag`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZigZagThreshold {
+    /// A reversal is confirmed when price moves this percentage away from the current extreme.
+    Percent(f64),
+    /// A reversal is confirmed when price moves this multiple of the Average True Range away from the current
+    /// extreme, the Average True Range is smoothed with [`WildersSmoothing`] over the period.
+    AverageTrueRange {
+        /// The period of the Average True Range smoothing.
+        period: usize,
+        /// The multiple of the Average True Range price must reverse by.
+        multiplier: f64,
+    },
+}
+
+/// The direction of a confirmed [`ZigZag`] leg.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ZigZagDirection {
+    /// A leg rising into a swing high.
+    Up,
+    /// A leg falling into a swing low.
+    Down,
+}
+
+crate::inspect::debug_state!(ZigZagDirection);
+
+/// A confirmed leg produced by the [`ZigZag`] signal.
+#[derive(Debug, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZigZagLeg {
+    /// The price of the pivot ending the leg, the high of a swing high or the low of a swing low.
+    pub price: f64,
+    /// The direction of the leg ending at the pivot.
+    pub direction: ZigZagDirection,
+    /// The number of steps from the previous pivot to this pivot, or from the first input for the first pivot.
+    pub length: usize,
+    /// The number of steps before the current step at which the pivot occurred.
+    pub offset: usize,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+enum ZigZagDistance {
+    Percent(f64),
+    AverageTrueRange {
+        smoothing: WildersSmoothing,
+        multiplier: f64,
+        previous_close: Option<f64>,
+    },
+}
+
+impl ToState for ZigZagDistance {
+    fn to_state(&self) -> StateValue {
+        match self {
+            Self::Percent(percent) => StateValue::Nested(
+                SignalState::new("Percent").field("percent", percent.to_state()),
+            ),
+            Self::AverageTrueRange {
+                smoothing,
+                multiplier,
+                previous_close,
+            } => StateValue::Nested(
+                SignalState::new("AverageTrueRange")
+                    .field("smoothing", smoothing.to_state())
+                    .field("multiplier", multiplier.to_state())
+                    .field("previous_close", previous_close.to_state()),
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+struct ZigZagState {
+    direction: Option<ZigZagDirection>,
+    /// The highest (high, step) since the last pivot.
+    high: (f64, usize),
+    /// The lowest (low, step) since the last pivot.
+    low: (f64, usize),
+    last_pivot: usize,
+    step: usize,
+}
+
+impl ZigZagState {
+    fn confirm(
+        &mut self,
+        (price, step): (f64, usize),
+        direction: ZigZagDirection,
+    ) -> Option<ZigZagLeg> {
+        let leg = ZigZagLeg {
+            price,
+            direction,
+            length: step - self.last_pivot,
+            offset: self.step - step,
+        };
+        self.last_pivot = step;
+        Some(leg)
+    }
+
+    fn update(
+        &mut self,
+        high: f64,
+        low: f64,
+        distance: Option<&dyn Fn(f64) -> f64>,
+    ) -> Option<ZigZagLeg> {
+        let step = self.step;
+        if step == 0 {
+            self.high = (high, step);
+            self.low = (low, step);
+            return None;
+        }
+        match self.direction {
+            None => {
+                if high > self.high.0 {
+                    self.high = (high, step);
+                }
+                if low < self.low.0 {
+                    self.low = (low, step);
+                }
+                let distance = distance?;
+                if self.low.1 < self.high.1 && self.high.0 >= self.low.0 + distance(self.low.0) {
+                    self.direction = Some(ZigZagDirection::Up);
+                    self.confirm(self.low, ZigZagDirection::Down)
+                } else if self.high.1 < self.low.1
+                    && self.low.0 <= self.high.0 - distance(self.high.0)
+                {
+                    self.direction = Some(ZigZagDirection::Down);
+                    self.confirm(self.high, ZigZagDirection::Up)
+                } else {
+                    None
+                }
+            }
+            Some(ZigZagDirection::Up) => {
+                if high > self.high.0 {
+                    self.high = (high, step);
+                    None
+                } else if distance.is_some_and(|d| low <= self.high.0 - d(self.high.0)) {
+                    self.direction = Some(ZigZagDirection::Down);
+                    self.low = (low, step);
+                    self.confirm(self.high, ZigZagDirection::Up)
+                } else {
+                    None
+                }
+            }
+            Some(ZigZagDirection::Down) => {
+                if low < self.low.0 {
+                    self.low = (low, step);
+                    None
+                } else if distance.is_some_and(|d| high >= self.low.0 + d(self.low.0)) {
+                    self.direction = Some(ZigZagDirection::Up);
+                    self.high = (high, step);
+                    self.confirm(self.low, ZigZagDirection::Down)
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// # ZigZag
+/// Container for ZigZag aggregation
+///
+/// The ZigZag tracks the highest high of a rising leg and the lowest low of a falling leg, when price reverses
+/// from the current extreme by more than the threshold the extreme is confirmed as a pivot and the opposite leg
+/// begins. For a rising leg a swing high is confirmed when:
+/// <br>
+/// <br>
+/// <math display="block" style="font-size: 20px;">
+/// <semantics>
+///     <mrow>
+///         <msub>
+///             <mi>l</mi>
+///             <mn>n</mn>
+///         </msub>
+///         <mo>≤</mo>
+///         <msub>
+///             <mi>h</mi>
+///             <mn>max</mn>
+///         </msub>
+///         <mo>−</mo>
+///         <mi>d</mi>
+///     </mrow>
+/// </semantics>
+/// </math>
+/// <br>
+/// Where `n` is the current step, `l` is the low, `h_max` is the highest high since the last pivot and `d` is the
+/// threshold distance, either a percentage of `h_max` or a multiple of the Average True Range. A falling leg
+/// confirms a swing low in the same way with the highs and lows reversed.
+///
+/// Pivots are only known once price has reversed, so each confirmed leg is reported retrospectively with the
+/// offset of its pivot from the current step. Steps where no leg is confirmed will produce `None`.
+///
+/// # Example Usage
+/// ```
+/// use indicato_rs::signals::{ZigZag, ZigZagDirection, ZigZagLeg, ZigZagThreshold};
+/// use indicato_rs::traits::{Apply, Evaluate, Current};
+///
+/// // create a new ZigZag with a reversal threshold of 10%
+/// let mut zig_zag = ZigZag::new(ZigZagThreshold::Percent(10.0)).unwrap();
+///
+/// // apply some (high, low, close) values and check their output
+/// assert_eq!(zig_zag.apply((10.0, 9.0, 9.5)), None);
+/// assert_eq!(
+///     zig_zag.apply((11.0, 10.0, 10.5)),
+///     Some(ZigZagLeg { price: 9.0, direction: ZigZagDirection::Down, length: 0, offset: 1 })
+/// );
+/// assert_eq!(zig_zag.apply((12.0, 11.0, 11.5)), None);
+///
+/// // evaluate some values, these won't affect the internal state of the ZigZag
+/// assert_eq!(
+///     zig_zag.evaluate((11.5, 10.5, 11.0)),
+///     Some(ZigZagLeg { price: 12.0, direction: ZigZagDirection::Up, length: 2, offset: 1 })
+/// );
+///
+/// // fetch the current value of the ZigZag
+/// assert_eq!(zig_zag.current(), None);
+/// ```
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ZigZag<I: HasHighLow + HasClose = (f64, f64, f64)> {
+    distance: ZigZagDistance,
+    state: ZigZagState,
+    current: Option<ZigZagLeg>,
+    input: PhantomData<fn(I)>,
+}
+
+impl<I: HasHighLow + HasClose> ZigZag<I> {
+    /// Create a new ZigZag instance taking inputs of type `I`, such as a [`Candle`](crate::types::Candle) or a bar
+    /// type implementing the [accessor traits](crate::types::HasClose)
+    /// # Arguments
+    /// * `threshold` - The reversal threshold, the percentage or multiplier must be greater than 0 and the Average
+    ///   True Range period must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{ZigZag, ZigZagThreshold};
+    /// use indicato_rs::types::Candle;
+    ///
+    /// let zig_zag = ZigZag::<Candle>::for_input(ZigZagThreshold::Percent(5.0));
+    /// assert!(zig_zag.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the percentage, multiplier or period are not greater than 0
+    pub fn for_input(threshold: ZigZagThreshold) -> Result<Self, FinError> {
+        let distance = match threshold {
+            ZigZagThreshold::Percent(percent) if percent > 0.0 => ZigZagDistance::Percent(percent),
+            ZigZagThreshold::AverageTrueRange { period, multiplier } if multiplier > 0.0 => {
+                ZigZagDistance::AverageTrueRange {
+                    smoothing: WildersSmoothing::new(period)?,
+                    multiplier,
+                    previous_close: None,
+                }
+            }
+            _ => {
+                return Err(FinError::new(
+                    FinErrorType::InvalidInput,
+                    "Threshold must be greater than 0",
+                ))
+            }
+        };
+        Ok(Self {
+            distance,
+            state: ZigZagState {
+                direction: None,
+                high: (0.0, 0),
+                low: (0.0, 0),
+                last_pivot: 0,
+                step: 0,
+            },
+            current: None,
+            input: PhantomData,
+        })
+    }
+}
+
+impl ZigZag {
+    /// Create a new ZigZag instance
+    /// # Arguments
+    /// * `threshold` - The reversal threshold, the percentage or multiplier must be greater than 0 and the Average
+    ///   True Range period must be greater than 0
+    ///
+    /// # Example
+    /// ```
+    /// use indicato_rs::signals::{ZigZag, ZigZagThreshold};
+    ///
+    /// let zig_zag = ZigZag::new(ZigZagThreshold::AverageTrueRange { period: 14, multiplier: 3.0 });
+    /// assert!(zig_zag.is_ok());
+    /// ```
+    /// # Errors
+    /// Will return an error if the percentage, multiplier or period are not greater than 0
+    /// ```
+    /// use indicato_rs::signals::{ZigZag, ZigZagThreshold};
+    ///
+    /// let zig_zag = ZigZag::new(ZigZagThreshold::Percent(0.0));
+    ///
+    /// assert!(zig_zag.is_err());
+    /// ```
+    pub fn new(threshold: ZigZagThreshold) -> Result<Self, FinError> {
+        Self::for_input(threshold)
+    }
+}
+
+impl<I: HasHighLow + HasClose> IoState for ZigZag<I> {
+    /// Input is anything with a high, low and close, by default a tuple of (high, low, close)
+    type Input = I;
+    type Output = Option<ZigZagLeg>;
+}
+
+impl<I: HasHighLow + HasClose> Validate for ZigZag<I> {
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError> {
+        validation::value(input.close(), "Close")?;
+        validation::high_low(input.high(), input.low())
+    }
+}
+
+impl<I: HasHighLow + HasClose> Executable for ZigZag<I> {
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (high, low, close) = (input.high(), input.low(), input.close());
+        let mut state = self.state;
+        let result = match &mut self.distance {
+            ZigZagDistance::Percent(percent) => {
+                let percent = *percent;
+                state.update(high, low, Some(&move |price| price * percent / 100.0))
+            }
+            ZigZagDistance::AverageTrueRange {
+                smoothing,
+                multiplier,
+                previous_close,
+            } => {
+                let true_range = match previous_close {
+                    Some(previous) => (high - low)
+                        .max((high - *previous).abs())
+                        .max((low - *previous).abs()),
+                    None => high - low,
+                };
+                let atr = smoothing.execute(true_range, execution_context);
+                if let ExecutionContext::Apply = execution_context {
+                    *previous_close = Some(close);
+                }
+                let multiplier = *multiplier;
+                match atr {
+                    Some(atr) => state.update(high, low, Some(&move |_| atr * multiplier)),
+                    None => state.update(high, low, None),
+                }
+            }
+        };
+        state.step += 1;
+        match execution_context {
+            ExecutionContext::Apply => {
+                self.state = state;
+                self.current = result;
+            }
+            ExecutionContext::Evaluate => {}
+        }
+        result
+    }
+}
+
+impl<I: HasHighLow + HasClose> Current for ZigZag<I> {
+    fn current(&self) -> Self::Output {
+        self.current
+    }
+}
+
+impl<I: HasHighLow + HasClose> WarmUp for ZigZag<I> {
+    fn warmup_remaining(&self) -> usize {
+        match &self.distance {
+            ZigZagDistance::Percent(_) => 0,
+            ZigZagDistance::AverageTrueRange { smoothing, .. } => smoothing.warmup_remaining(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::Candle;
+
+    fn leg(price: f64, direction: ZigZagDirection, length: usize) -> Option<ZigZagLeg> {
+        Some(ZigZagLeg {
+            price,
+            direction,
+            length,
+            offset: 1,
+        })
+    }
+
+    #[test]
+    fn test_apply_percent() {
+        let mut zig_zag = ZigZag::new(ZigZagThreshold::Percent(10.0)).unwrap();
+        assert_eq!(zig_zag.apply((10.0, 9.0, 9.5)), None);
+        assert_eq!(
+            zig_zag.apply((11.0, 10.0, 10.5)),
+            leg(9.0, ZigZagDirection::Down, 0)
+        );
+        assert_eq!(zig_zag.apply((12.0, 11.0, 11.5)), None);
+        assert_eq!(
+            zig_zag.apply((11.5, 10.5, 11.0)),
+            leg(12.0, ZigZagDirection::Up, 2)
+        );
+        assert_eq!(zig_zag.apply((11.0, 10.0, 10.2)), None);
+        assert_eq!(
+            zig_zag.apply((11.5, 10.8, 11.2)),
+            leg(10.0, ZigZagDirection::Down, 2)
+        );
+    }
+
+    #[test]
+    fn test_apply_average_true_range() {
+        let mut zig_zag = ZigZag::new(ZigZagThreshold::AverageTrueRange {
+            period: 1,
+            multiplier: 1.0,
+        })
+        .unwrap();
+        assert_eq!(zig_zag.apply((10.0, 9.0, 9.5)), None);
+        assert_eq!(
+            zig_zag.apply((11.0, 10.5, 11.0)),
+            leg(9.0, ZigZagDirection::Down, 0)
+        );
+        assert_eq!(zig_zag.apply((11.5, 11.0, 11.2)), None);
+        assert_eq!(
+            zig_zag.apply((11.4, 10.9, 11.0)),
+            leg(11.5, ZigZagDirection::Up, 2)
+        );
+    }
+
+    #[test]
+    fn test_average_true_range_warmup() {
+        let mut zig_zag = ZigZag::new(ZigZagThreshold::AverageTrueRange {
+            period: 3,
+            multiplier: 1.0,
+        })
+        .unwrap();
+        assert_eq!(zig_zag.apply((10.0, 9.0, 9.5)), None);
+        assert_eq!(zig_zag.apply((20.0, 19.0, 19.5)), None);
+        assert_eq!(
+            zig_zag.apply((21.0, 20.0, 20.5)),
+            Some(ZigZagLeg {
+                price: 9.0,
+                direction: ZigZagDirection::Down,
+                length: 0,
+                offset: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let mut zig_zag = ZigZag::new(ZigZagThreshold::Percent(10.0)).unwrap();
+        assert_eq!(zig_zag.evaluate((10.0, 9.0, 9.5)), None);
+        assert_eq!(zig_zag.apply((10.0, 9.0, 9.5)), None);
+        assert_eq!(
+            zig_zag.evaluate((11.0, 10.0, 10.5)),
+            leg(9.0, ZigZagDirection::Down, 0)
+        );
+        assert_eq!(
+            zig_zag.evaluate((8.0, 7.0, 7.5)),
+            leg(10.0, ZigZagDirection::Up, 0)
+        );
+        assert_eq!(
+            zig_zag.apply((11.0, 10.0, 10.5)),
+            leg(9.0, ZigZagDirection::Down, 0)
+        );
+    }
+
+    #[test]
+    fn test_current() {
+        let mut zig_zag = ZigZag::new(ZigZagThreshold::Percent(10.0)).unwrap();
+        assert_eq!(zig_zag.current(), None);
+        zig_zag.apply((10.0, 9.0, 9.5));
+        zig_zag.apply((11.0, 10.0, 10.5));
+        zig_zag.evaluate((12.0, 11.0, 11.5));
+        assert_eq!(zig_zag.current(), leg(9.0, ZigZagDirection::Down, 0));
+    }
+
+    #[test]
+    fn test_invalid_threshold() {
+        assert!(ZigZag::new(ZigZagThreshold::Percent(0.0)).is_err());
+        assert!(ZigZag::new(ZigZagThreshold::Percent(-1.0)).is_err());
+        assert!(ZigZag::new(ZigZagThreshold::AverageTrueRange {
+            period: 0,
+            multiplier: 1.0
+        })
+        .is_err());
+        assert!(ZigZag::new(ZigZagThreshold::AverageTrueRange {
+            period: 14,
+            multiplier: 0.0
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_for_input() {
+        let threshold = ZigZagThreshold::AverageTrueRange { period: 1, multiplier: 1.0 };
+        let mut zig_zag = ZigZag::new(threshold).unwrap();
+        let mut candles = ZigZag::<Candle>::for_input(threshold).unwrap();
+        for (high, low, close) in [(10.0, 9.0, 9.5), (11.0, 10.5, 11.0), (11.5, 11.0, 11.2), (11.4, 10.9, 11.0)] {
+            let candle = Candle { open: close, high, low, close, volume: 0.0, timestamp: 0 };
+            assert_eq!(candles.evaluate(candle), zig_zag.evaluate((high, low, close)));
+            assert_eq!(candles.apply(candle), zig_zag.apply((high, low, close)));
+        }
+        assert_eq!(candles.current(), leg(11.5, ZigZagDirection::Up, 2));
+    }
+}
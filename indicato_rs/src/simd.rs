@@ -0,0 +1,251 @@
+use std::{any::Any, collections::VecDeque};
+
+use wide::{f64x4, CmpGe};
+
+use crate::deque_math::compensated_sum;
+
+const LANES: usize = 4;
+
+/// Loads the four values from `start` into lanes.
+fn load(values: &[f64], start: usize) -> f64x4 {
+    let mut lanes = [0.0; LANES];
+    lanes.copy_from_slice(&values[start..start + LANES]);
+    f64x4::from(lanes)
+}
+
+/// Calculates every full window of `period` values, four windows at a time with lane `k` holding the window starting
+/// `k` values later. The windows left over are calculated one at a time by `scalar`.
+///
+/// Each lane sees the values of its window in the same order as the scalar calculation, so the results are identical
+/// to calculating every window with `scalar`.
+fn windows<R>(
+    values: &[f64],
+    period: usize,
+    lanes: impl Fn(usize) -> [R; LANES],
+    scalar: impl Fn(&[f64]) -> R,
+) -> Vec<R> {
+    let count = (values.len() + 1).saturating_sub(period);
+    let vectorised = count - count % LANES;
+    let mut results = Vec::with_capacity(count);
+    (0..vectorised)
+        .step_by(LANES)
+        .for_each(|start| results.extend(lanes(start)));
+    (vectorised..count).for_each(|start| results.push(scalar(&values[start..start + period])));
+    results
+}
+
+/// The compensated sum of each lane, matching [`compensated_sum`] lane by lane.
+fn compensated_lanes(values: &[f64], start: usize, period: usize, map: impl Fn(f64x4) -> f64x4) -> f64x4 {
+    let (sum, compensation) = (0..period).fold((f64x4::ZERO, f64x4::ZERO), |(sum, compensation), i| {
+        let x = map(load(values, start + i));
+        let total = sum + x;
+        let lost = sum.abs().cmp_ge(x.abs()).blend((sum - total) + x, (x - total) + sum);
+        (total, compensation + lost)
+    });
+    sum + compensation
+}
+
+/// The compensated mean of every full window of `period` values.
+pub(crate) fn window_means(values: &[f64], period: usize) -> Vec<f64> {
+    let length = period as f64;
+    windows(
+        values,
+        period,
+        |start| (compensated_lanes(values, start, period, |x| x) / f64x4::from(length)).to_array(),
+        |window| compensated_sum(window.iter().copied()) / length,
+    )
+}
+
+/// The extremum picked by `pick` of every full window of `period` values, using the van Herk/Gil-Werman algorithm so
+/// the work per window doesn't grow with the period.
+///
+/// The values are split into blocks of `period`, with the running extremum from the start of each block in `prefix`
+/// and to the end of each block in `suffix`. A window starting at `i` covers the end of one block and the start of
+/// the next, so its extremum is picked from `suffix[i]` and `prefix[i + period - 1]`, four windows at a time.
+fn window_extrema(
+    values: &[f64],
+    period: usize,
+    pick: impl Fn(f64, f64) -> f64,
+    pick_lanes: impl Fn(f64x4, f64x4) -> f64x4,
+) -> Vec<f64> {
+    let count = (values.len() + 1).saturating_sub(period);
+    if count == 0 {
+        return Vec::new();
+    }
+    let mut prefix = values.to_vec();
+    let mut suffix = values.to_vec();
+    (1..values.len())
+        .filter(|i| i % period != 0)
+        .for_each(|i| prefix[i] = pick(prefix[i - 1], values[i]));
+    (0..values.len() - 1)
+        .rev()
+        .filter(|i| (i + 1) % period != 0)
+        .for_each(|i| suffix[i] = pick(suffix[i + 1], values[i]));
+    let vectorised = count - count % LANES;
+    let mut results = Vec::with_capacity(count);
+    (0..vectorised).step_by(LANES).for_each(|start| {
+        results.extend(pick_lanes(load(&suffix, start), load(&prefix, start + period - 1)).to_array())
+    });
+    (vectorised..count).for_each(|start| results.push(pick(suffix[start], prefix[start + period - 1])));
+    results
+}
+
+/// The maximum of every full window of `period` values.
+pub(crate) fn window_maxima(values: &[f64], period: usize) -> Vec<f64> {
+    window_extrema(values, period, f64::max, f64x4::max)
+}
+
+/// The minimum of every full window of `period` values.
+pub(crate) fn window_minima(values: &[f64], period: usize) -> Vec<f64> {
+    window_extrema(values, period, f64::min, f64x4::min)
+}
+
+/// The compensated mean and population standard deviation of every full window of `period` values.
+pub(crate) fn window_mean_std_devs(values: &[f64], period: usize) -> Vec<(f64, f64)> {
+    let length = period as f64;
+    windows(
+        values,
+        period,
+        |start| {
+            let mean = compensated_lanes(values, start, period, |x| x) / f64x4::from(length);
+            let squares = compensated_lanes(values, start, period, |x| (x - mean) * (x - mean));
+            let means = mean.to_array();
+            let std_devs = (squares / f64x4::from(length)).sqrt().to_array();
+            [0, 1, 2, 3].map(|lane| (means[lane], std_devs[lane]))
+        },
+        |window| {
+            let mean = compensated_sum(window.iter().copied()) / length;
+            let variance = compensated_sum(window.iter().map(|x| (x - mean).powi(2))) / length;
+            (mean, variance.sqrt())
+        },
+    )
+}
+
+/// Applies a batch of inputs to a signal aggregating the last `period` values of `window`.
+///
+/// The outputs before the window is first filled are calculated by `partial` as each input is pushed, the rest are
+/// calculated from the full windows by `full`. The window is left holding the last `period` values, as if each input
+/// had been applied in turn.
+pub(crate) fn apply_windows<R>(
+    window: &mut VecDeque<f64>,
+    period: usize,
+    inputs: &[f64],
+    full: impl Fn(&[f64], usize) -> Vec<R>,
+    partial: impl Fn(&VecDeque<f64>) -> R,
+) -> Vec<R> {
+    let mut outputs = Vec::with_capacity(inputs.len());
+    let (filling, rest) = inputs.split_at((period - 1).saturating_sub(window.len()).min(inputs.len()));
+    filling.iter().for_each(|&input| {
+        window.push_back(input);
+        outputs.push(partial(window));
+    });
+    if !rest.is_empty() {
+        let mut values = window
+            .iter()
+            .skip(window.len() + 1 - period)
+            .copied()
+            .collect::<Vec<f64>>();
+        values.extend_from_slice(rest);
+        outputs.extend(full(&values, period));
+        window.clear();
+        window.extend(&values[values.len() - period..]);
+    }
+    outputs
+}
+
+/// Applies a batch of inputs to a signal generic over its numeric type with [`apply_windows`], returning `None` when
+/// the type isn't `f64` so the signal can fall back to applying each input in turn.
+pub(crate) fn apply_f64_windows<T: 'static>(
+    window: &mut VecDeque<T>,
+    period: usize,
+    inputs: &[T],
+    full: impl Fn(&[f64], usize) -> Vec<f64>,
+    partial: impl Fn(&VecDeque<f64>) -> f64,
+) -> Option<Vec<T>> {
+    let window = (window as &mut dyn Any).downcast_mut::<VecDeque<f64>>()?;
+    let inputs = inputs
+        .iter()
+        .map(|input| (input as &dyn Any).downcast_ref::<f64>().copied())
+        .collect::<Option<Vec<f64>>>()?;
+    let outputs = Box::new(apply_windows(window, period, &inputs, full, partial)) as Box<dyn Any>;
+    outputs.downcast::<Vec<T>>().ok().map(|outputs| *outputs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn values() -> Vec<f64> {
+        (0..23)
+            .map(|i| ((i * 37 % 11) as f64 - 5.0) * 1.7 + 1e12 * f64::from(i % 3 == 0))
+            .collect()
+    }
+
+    #[test]
+    fn test_window_means() {
+        let values = values();
+        let expected = values
+            .windows(5)
+            .map(|window| compensated_sum(window.iter().copied()) / 5.0)
+            .collect::<Vec<f64>>();
+        assert_eq!(window_means(&values, 5), expected);
+        assert!(window_means(&values[..3], 5).is_empty());
+    }
+
+    #[test]
+    fn test_window_extrema() {
+        let values = values();
+        let maxima = values
+            .windows(4)
+            .map(|window| window.iter().fold(f64::MIN, |acc, &x| acc.max(x)))
+            .collect::<Vec<f64>>();
+        let minima = values
+            .windows(4)
+            .map(|window| window.iter().fold(f64::MAX, |acc, &x| acc.min(x)))
+            .collect::<Vec<f64>>();
+        assert_eq!(window_maxima(&values, 4), maxima);
+        assert_eq!(window_minima(&values, 4), minima);
+        for period in [1, 3, 7, 23] {
+            let maxima = values
+                .windows(period)
+                .map(|window| window.iter().fold(f64::MIN, |acc, &x| acc.max(x)))
+                .collect::<Vec<f64>>();
+            assert_eq!(window_maxima(&values, period), maxima);
+        }
+        assert!(window_minima(&values, 24).is_empty());
+    }
+
+    #[test]
+    fn test_window_mean_std_devs() {
+        let values = values();
+        let expected = values
+            .windows(6)
+            .map(|window| {
+                let mean = compensated_sum(window.iter().copied()) / 6.0;
+                let variance = compensated_sum(window.iter().map(|x| (x - mean).powi(2))) / 6.0;
+                (mean, variance.sqrt())
+            })
+            .collect::<Vec<(f64, f64)>>();
+        assert_eq!(window_mean_std_devs(&values, 6), expected);
+    }
+
+    #[test]
+    fn test_apply_windows() {
+        let mut window = VecDeque::from(vec![1.0]);
+        let outputs = apply_windows(&mut window, 3, &[2.0, 3.0, 4.0, 5.0], window_maxima, |window| {
+            window.iter().fold(f64::MIN, |acc, &x| acc.max(x))
+        });
+        assert_eq!(outputs, vec![2.0, 3.0, 4.0, 5.0]);
+        assert_eq!(window, VecDeque::from(vec![3.0, 4.0, 5.0]));
+    }
+
+    #[test]
+    fn test_apply_f64_windows() {
+        let mut window = VecDeque::from(vec![1.0]);
+        let outputs = apply_f64_windows(&mut window, 2, &[3.0, 5.0], window_means, |window| window[0]);
+        assert_eq!(outputs, Some(vec![2.0, 4.0]));
+        let mut window = VecDeque::from(vec![1.0_f32]);
+        assert_eq!(apply_f64_windows(&mut window, 2, &[3.0], window_means, |window| window[0]), None);
+        assert_eq!(window, VecDeque::from(vec![1.0]));
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use futures_core::Stream;
+use pin_project_lite::pin_project;
+
+use crate::traits::{Executable, ExecutionContext};
+
+pin_project! {
+    /// A stream of the outputs of a signal applied to each item of an input stream, created with
+    /// [`SignalStreamExt::apply_signal`].
+    ///
+    /// Each input is applied to the signal as it arrives and the stream ends when the input stream ends.
+    #[derive(Debug, Clone)]
+    pub struct SignalStream<St, S> {
+        #[pin]
+        stream: St,
+        signal: S,
+    }
+}
+
+impl<St, S> SignalStream<St, S> {
+    /// The signal driven by the stream
+    pub fn signal(&self) -> &S {
+        &self.signal
+    }
+
+    /// Consumes the adapter, returning the input stream and the signal
+    pub fn into_inner(self) -> (St, S) {
+        (self.stream, self.signal)
+    }
+}
+
+impl<St, S> Stream for SignalStream<St, S>
+where
+    St: Stream<Item = S::Input>,
+    S: Executable,
+{
+    type Item = S::Output;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        this.stream
+            .poll_next(cx)
+            .map(|input| input.map(|input| this.signal.execute(input, &ExecutionContext::Apply)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.stream.size_hint()
+    }
+}
+
+/// Drives a signal from a stream, so indicators can be inserted into async pipelines.
+///
+/// # Example
+/// ```
+/// use futures::{executor::block_on, stream, StreamExt};
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::stream::SignalStreamExt;
+///
+/// let prices = stream::iter([1.0, 3.0, 5.0]);
+/// let averages = prices.apply_signal(SimpleMovingAverage::new(2).unwrap());
+///
+/// assert_eq!(block_on(averages.collect::<Vec<f64>>()), vec![1.0, 2.0, 4.0]);
+/// ```
+pub trait SignalStreamExt: Stream + Sized {
+    /// Creates a [`SignalStream`] applying each item of this stream to `signal` and yielding its outputs.
+    fn apply_signal<S>(self, signal: S) -> SignalStream<Self, S>
+    where
+        S: Executable<Input = Self::Item>,
+    {
+        SignalStream {
+            stream: self,
+            signal,
+        }
+    }
+}
+
+impl<St: Stream> SignalStreamExt for St {}
+
+#[cfg(test)]
+mod tests {
+    use futures::{executor::block_on, stream, StreamExt};
+
+    use super::*;
+    use crate::signals::RelativeStrengthIndex;
+    use crate::traits::{Apply, Current};
+
+    #[test]
+    fn test_apply_signal() {
+        let inputs = [1.0, 3.0, 2.0, 5.0, 4.0, 6.0, 8.0, 7.0];
+        let mut rsi = RelativeStrengthIndex::new(3, 0).unwrap();
+        let expected = inputs.iter().map(|&input| rsi.apply(input)).collect::<Vec<_>>();
+
+        let mut outputs = stream::iter(inputs).apply_signal(RelativeStrengthIndex::new(3, 0).unwrap());
+        assert_eq!(outputs.size_hint(), (8, Some(8)));
+        let collected = block_on((&mut outputs).collect::<Vec<_>>());
+        assert_eq!(collected, expected);
+        assert_eq!(outputs.signal().current(), rsi.current());
+    }
+
+    #[test]
+    fn test_into_inner() {
+        let outputs = stream::iter([2.0, 4.0]).apply_signal(RelativeStrengthIndex::new(3, 0).unwrap());
+        let (mut inputs, rsi) = outputs.into_inner();
+        assert_eq!(block_on(inputs.next()), Some(2.0));
+        assert_eq!(rsi.current(), None);
+    }
+}
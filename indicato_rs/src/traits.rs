@@ -1,3 +1,11 @@
+use std::{fmt, ops::Neg};
+
+use indicato_rs_proc::{Apply, Evaluate, Inspect};
+use num_traits::{FromPrimitive, Num};
+
+use crate::fin_error::FinError;
+use crate::inspect::{SignalState, ToState};
+
 /// Context enum to decided whether to apply or evaluate the signal.
 pub enum ExecutionContext {
     /// Next value will be calculated and the currrent input will be applied to the aggregation.
@@ -39,4 +47,693 @@ pub trait Executable: IoState {
     /// Executes the signal and returns the result applying the input to the aggregation as described by the `ExecutionContext`.
     fn execute(&mut self, input: Self::Input, execution_context: &ExecutionContext)
         -> Self::Output;
+
+    /// Applies each input in order and returns the output for each, the implementation behind
+    /// [`ApplyBatch::apply_slice`].
+    ///
+    /// By default each input is executed in turn, signals with a faster batch calculation, such as the SIMD kernels
+    /// enabled with the `simd` feature, override it with one giving identical outputs.
+    fn execute_slice(&mut self, inputs: &[Self::Input]) -> Vec<Self::Output>
+    where
+        Self::Input: Clone,
+    {
+        inputs
+            .iter()
+            .map(|input| self.execute(input.clone(), &ExecutionContext::Apply))
+            .collect()
+    }
+}
+
+/// Applies a slice of inputs to the aggregation in one call, such as pushing a whole history through a signal when
+/// backtesting.
+///
+/// The batch is applied with [`Executable::execute_slice`], so with the `simd` feature the `f64` Simple Moving
+/// Average, Maximum and Minimum Period and the Bollinger Bands calculate several windows at a time.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::traits::{ApplyBatch, Current};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// assert_eq!(sma.apply_slice(&[1.0, 3.0, 5.0]), vec![1.0, 2.0, 4.0]);
+/// assert_eq!(sma.current(), 4.0);
+/// ```
+pub trait ApplyBatch: Executable {
+    /// Applies each input in order and returns the output for each, the same as calling `apply` on every input.
+    fn apply_slice(&mut self, inputs: &[Self::Input]) -> Vec<Self::Output>;
+}
+
+impl<S> ApplyBatch for S
+where
+    S: Executable,
+    S::Input: Clone,
+{
+    fn apply_slice(&mut self, inputs: &[Self::Input]) -> Vec<Self::Output> {
+        self.execute_slice(inputs)
+    }
+}
+
+/// Checks an input is valid for the signal before it is aggregated, such as rejecting a negative volume, a high
+/// below the low or a NaN value.
+pub trait Validate: IoState {
+    /// Returns an error describing why the input is invalid, without affecting the aggregation.
+    fn validate(&self, input: &Self::Input) -> Result<(), FinError>;
+}
+
+/// Applies the input to the aggregation after validating it, an invalid input is rejected with an error and leaves
+/// the aggregation untouched.
+///
+/// [`Apply`] skips the validation and remains the fastest path for inputs that are already known to be clean.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::traits::{Current, TryApply};
+///
+/// let mut sma = SimpleMovingAverage::new(2).unwrap();
+/// assert_eq!(sma.try_apply(1.0).unwrap(), 1.0);
+///
+/// // the NaN is rejected and the moving average is unchanged
+/// assert!(sma.try_apply(f64::NAN).is_err());
+/// assert_eq!(sma.current(), 1.0);
+/// ```
+pub trait TryApply: Executable {
+    /// Validates the input and applies it to the aggregation, returning the result.
+    fn try_apply(&mut self, input: Self::Input) -> Result<Self::Output, FinError>;
+}
+
+impl<S: Executable + Validate> TryApply for S {
+    fn try_apply(&mut self, input: Self::Input) -> Result<Self::Output, FinError> {
+        self.validate(&input)?;
+        Ok(self.execute(input, &ExecutionContext::Apply))
+    }
+}
+
+/// Evaluates the input after validating it, the same as [`Evaluate`] but an invalid input is rejected with an error.
+pub trait TryEvaluate: Executable {
+    /// Validates the input and evaluates it without applying it to the aggregation, returning the result.
+    fn try_evaluate(&mut self, input: Self::Input) -> Result<Self::Output, FinError>;
+}
+
+impl<S: Executable + Validate> TryEvaluate for S {
+    fn try_evaluate(&mut self, input: Self::Input) -> Result<Self::Output, FinError> {
+        self.validate(&input)?;
+        Ok(self.execute(input, &ExecutionContext::Evaluate))
+    }
+}
+
+/// Reports how much history a signal still needs before its outputs can be trusted, so the number of bars to backfill
+/// can be known up front rather than by probing for `None`.
+///
+/// A signal is warmed up once every window and seed it aggregates over is filled, which is when signals that output an
+/// `Option` start producing `Some`. Signals that feed one aggregation into another only count the inner aggregation
+/// as warming up once the outer one is passing it values.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::SimpleMovingAverage;
+/// use indicato_rs::traits::{Apply, WarmUp};
+///
+/// let mut sma = SimpleMovingAverage::new(3).unwrap();
+/// assert_eq!(sma.warmup_remaining(), 3);
+///
+/// sma.apply(1.0);
+/// sma.apply(2.0);
+/// assert_eq!(sma.warmup_remaining(), 1);
+/// assert!(!sma.is_ready());
+///
+/// sma.apply(3.0);
+/// assert!(sma.is_ready());
+/// ```
+pub trait WarmUp {
+    /// The number of inputs that still need to be applied before the signal is warmed up, 0 once it is.
+    fn warmup_remaining(&self) -> usize;
+
+    /// Whether the signal is warmed up and its outputs can be trusted.
+    fn is_ready(&self) -> bool {
+        self.warmup_remaining() == 0
+    }
+}
+
+/// Exposes the period of a signal, so generic tooling can size history buffers and align the warm up of several
+/// signals without knowing their concrete types.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::{MovingAverageConvergenceDivergence, SimpleMovingAverage};
+/// use indicato_rs::traits::Periodic;
+///
+/// let sma = SimpleMovingAverage::new(20).unwrap();
+/// let macd = MovingAverageConvergenceDivergence::new(12, 26).unwrap();
+///
+/// // size a buffer for the longest history either signal needs
+/// let history = sma.effective_lookback().max(macd.effective_lookback());
+/// assert_eq!(history, 26);
+/// ```
+pub trait Periodic {
+    /// The period of the signal, the longest period for signals made up of several aggregations.
+    fn period(&self) -> usize;
+
+    /// The number of inputs of history the signal needs for its first complete output, including the history needed
+    /// by any aggregations it feeds into one another. This is the period unless the signal is a composite.
+    fn effective_lookback(&self) -> usize {
+        self.period()
+    }
+}
+
+/// A moving average that can be created from its period alone, so wrappers such as
+/// [`Smoothed`](crate::signals::Smoothed) can choose the moving average they apply by type parameter.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::ExponentialMovingAverage;
+/// use indicato_rs::traits::{MovingAverage, Periodic};
+///
+/// let ema = ExponentialMovingAverage::<f64>::with_period(9).unwrap();
+/// assert_eq!(ema.period(), 9);
+/// ```
+pub trait MovingAverage: Executable + Sized {
+    /// Creates the moving average with the period, returning an error if the period is invalid.
+    fn with_period(period: usize) -> Result<Self, FinError>;
+}
+
+/// Exposes the internal state of a signal, such as the contents of its windows, its seeds and the previous values it
+/// holds, for debugging a signal that disagrees with another source in production.
+///
+/// # Example
+/// ```
+/// use indicato_rs::inspect::StateValue;
+/// use indicato_rs::signals::RelativeStrengthIndex;
+/// use indicato_rs::traits::{Apply, Inspect};
+///
+/// let mut rsi = RelativeStrengthIndex::new(14, 0).unwrap();
+/// rsi.apply(1.0);
+///
+/// let state = rsi.inspect();
+/// assert_eq!(state.name, "RelativeStrengthIndex");
+/// assert_eq!(state.get("previous"), Some(&StateValue::Number(1.0)));
+/// ```
+pub trait Inspect {
+    /// A structured view of the internal state of the signal.
+    fn inspect(&self) -> SignalState;
+
+    /// The name of the signal type, the [`name`](SignalState::name) of its state, without building the state.
+    fn name(&self) -> &'static str {
+        self.inspect().name
+    }
+}
+
+/// The warm up remaining when the outputs of a signal with `first` remaining are fed into one with `second` remaining,
+/// the input producing the first output also counts towards warming up the second signal.
+pub(crate) fn chained_warmup(first: usize, second: usize) -> usize {
+    match first {
+        0 => second,
+        _ => first + second.saturating_sub(1),
+    }
+}
+
+/// Whether `signal` is warmed up once the input it was just executed with in `execution_context` is counted, an
+/// evaluated input leaves the warm up of the signal untouched so it counts as one more input.
+pub(crate) fn warmed_up<S: WarmUp>(signal: &S, execution_context: &ExecutionContext) -> bool {
+    match execution_context {
+        ExecutionContext::Apply => signal.warmup_remaining() == 0,
+        ExecutionContext::Evaluate => signal.warmup_remaining() <= 1,
+    }
+}
+
+/// The arithmetic required of the values aggregated by the signals that are generic over their numeric type.
+///
+/// This is implemented for `f32` and `f64`, and for `rust_decimal::Decimal` with the `rust_decimal` feature so
+/// price aggregations can be kept free of binary floating point error.
+pub trait Numeric: Copy + PartialOrd + Num + Neg<Output = Self> + FromPrimitive + 'static {
+    /// Converts a count, such as a period or the number of values in a window, into the numeric type.
+    fn from_count(count: usize) -> Self {
+        Self::from_usize(count).unwrap_or_else(Self::zero)
+    }
+}
+
+impl<T: Copy + PartialOrd + Num + Neg<Output = T> + FromPrimitive + 'static> Numeric for T {}
+
+/// A dyn compatible view of any signal with the input `I` and output `O`, implemented for every signal that is
+/// [`Executable`], [`Current`], [`WarmUp`], `Clone`, `Send` and `Sync`, so signals of different types can be driven
+/// uniformly as trait objects and moved between threads.
+pub trait DynSignal<I, O>: Executable<Input = I, Output = O> + Current + WarmUp + Send + Sync {
+    /// Clones the signal into a new trait object, forking its state.
+    fn clone_box(&self) -> Box<dyn DynSignal<I, O>>;
+}
+
+impl<S> DynSignal<S::Input, S::Output> for S
+where
+    S: Executable + Current + WarmUp + Clone + Send + Sync + 'static,
+{
+    fn clone_box(&self) -> Box<dyn DynSignal<S::Input, S::Output>> {
+        Box::new(self.clone())
+    }
+}
+
+/// Converts the output of a signal into the input of the next signal in a [`Chain`], `None` while the output is
+/// still warming up.
+pub trait ChainValue<T> {
+    /// The input for the next signal, or `None` when there is no value to pass on yet.
+    fn into_input(self) -> Option<T>;
+}
+
+impl<T> ChainValue<T> for T {
+    fn into_input(self) -> Option<T> {
+        Some(self)
+    }
+}
+
+impl<T> ChainValue<T> for Option<T> {
+    fn into_input(self) -> Option<T> {
+        self
+    }
+}
+
+/// A pair of outputs is passed on once both are available, such as the two lines of an indicator with a signal line.
+impl<A, B> ChainValue<(A, B)> for (Option<A>, Option<B>) {
+    fn into_input(self) -> Option<(A, B)> {
+        self.0.zip(self.1)
+    }
+}
+
+/// The value in the output of a signal once it is warmed up, so [`Seeded`](crate::signals::Seeded) can give the
+/// outputs of every signal as a [`SignalValue`](crate::types::SignalValue) of the same type whether the signal outputs
+/// `None` while warming up or not.
+///
+/// This is implemented for `f32`, `f64`, `rust_decimal::Decimal` with the `rust_decimal` feature, and any `Option` of
+/// them.
+pub trait ReadyValue {
+    /// The type of the value.
+    type Value;
+
+    /// The value, or `None` when there is no value.
+    fn into_ready(self) -> Option<Self::Value>;
+}
+
+impl ReadyValue for f64 {
+    type Value = f64;
+
+    fn into_ready(self) -> Option<f64> {
+        Some(self)
+    }
+}
+
+impl ReadyValue for f32 {
+    type Value = f32;
+
+    fn into_ready(self) -> Option<f32> {
+        Some(self)
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+impl ReadyValue for rust_decimal::Decimal {
+    type Value = rust_decimal::Decimal;
+
+    fn into_ready(self) -> Option<rust_decimal::Decimal> {
+        Some(self)
+    }
 }
+
+impl<T: ReadyValue> ReadyValue for Option<T> {
+    type Value = T::Value;
+
+    fn into_ready(self) -> Option<T::Value> {
+        self.and_then(ReadyValue::into_ready)
+    }
+}
+
+/// Feeds the output of one signal into another, created with [`Then::then`].
+///
+/// The execution context is passed through to both signals, so evaluating the chain doesn't affect either signal.
+/// While the first signal is warming up its `None` outputs are not passed on, the second signal is left untouched and
+/// the chain outputs `None`.
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chain<A, B>
+where
+    A: Executable,
+    B: Executable,
+    A::Output: ChainValue<B::Input>,
+{
+    first: A,
+    second: B,
+}
+
+impl<A, B> IoState for Chain<A, B>
+where
+    A: Executable,
+    B: Executable,
+    A::Output: ChainValue<B::Input>,
+{
+    type Input = A::Input;
+    type Output = Option<B::Output>;
+}
+
+impl<A, B> Executable for Chain<A, B>
+where
+    A: Executable,
+    B: Executable,
+    A::Output: ChainValue<B::Input>,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        self.first
+            .execute(input, execution_context)
+            .into_input()
+            .map(|value| self.second.execute(value, execution_context))
+    }
+}
+
+impl<A, B> Current for Chain<A, B>
+where
+    A: Executable + Current,
+    B: Executable + Current,
+    A::Output: ChainValue<B::Input>,
+{
+    fn current(&self) -> Self::Output {
+        self.first
+            .current()
+            .into_input()
+            .map(|_| self.second.current())
+    }
+}
+
+impl<A, B> WarmUp for Chain<A, B>
+where
+    A: Executable + WarmUp,
+    B: Executable + WarmUp,
+    A::Output: ChainValue<B::Input>,
+{
+    fn warmup_remaining(&self) -> usize {
+        chained_warmup(self.first.warmup_remaining(), self.second.warmup_remaining())
+    }
+}
+
+/// Composes signals by feeding the output of one signal into the next, such as smoothing an RSI with an EMA.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::{ExponentialMovingAverage, Momentum};
+/// use indicato_rs::traits::{Apply, Current, Evaluate, Then};
+///
+/// // smooth the momentum with an EMA, the EMA only receives values once the momentum is warmed up
+/// let mut smoothed = Momentum::new(1)
+///     .unwrap()
+///     .then(ExponentialMovingAverage::new(3).unwrap());
+///
+/// assert_eq!(smoothed.apply(1.0), None);
+/// assert_eq!(smoothed.apply(3.0), Some(2.0));
+/// assert_eq!(smoothed.evaluate(7.0), Some(3.0));
+/// assert_eq!(smoothed.apply(4.0), Some(1.5));
+/// assert_eq!(smoothed.current(), Some(1.5));
+/// ```
+pub trait Then: Executable + Sized {
+    /// Creates a [`Chain`] that feeds the output of this signal into `next`.
+    fn then<B>(self, next: B) -> Chain<Self, B>
+    where
+        B: Executable,
+        Self::Output: ChainValue<B::Input>,
+    {
+        Chain {
+            first: self,
+            second: next,
+        }
+    }
+}
+
+impl<S: Executable> Then for S {}
+
+/// Passes the same input to two signals and outputs both results, created with [`Combine::fanout`].
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FanOut<A, B>
+where
+    A: Executable,
+    B: Executable<Input = A::Input>,
+    A::Input: Clone,
+{
+    first: A,
+    second: B,
+}
+
+impl<A, B> IoState for FanOut<A, B>
+where
+    A: Executable,
+    B: Executable<Input = A::Input>,
+    A::Input: Clone,
+{
+    type Input = A::Input;
+    /// Output is a tuple of (first output, second output)
+    type Output = (A::Output, B::Output);
+}
+
+impl<A, B> Executable for FanOut<A, B>
+where
+    A: Executable,
+    B: Executable<Input = A::Input>,
+    A::Input: Clone,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        (
+            self.first.execute(input.clone(), execution_context),
+            self.second.execute(input, execution_context),
+        )
+    }
+}
+
+impl<A, B> Current for FanOut<A, B>
+where
+    A: Executable + Current,
+    B: Executable<Input = A::Input> + Current,
+    A::Input: Clone,
+{
+    fn current(&self) -> Self::Output {
+        (self.first.current(), self.second.current())
+    }
+}
+
+impl<A, B> WarmUp for FanOut<A, B>
+where
+    A: Executable + WarmUp,
+    B: Executable<Input = A::Input> + WarmUp,
+    A::Input: Clone,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.first.warmup_remaining().max(self.second.warmup_remaining())
+    }
+}
+
+/// Splits a paired input across two signals and outputs both results, created with [`Combine::zip`].
+#[derive(Debug, Clone, Apply, Evaluate, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Zip<A, B>
+where
+    A: Executable,
+    B: Executable,
+{
+    first: A,
+    second: B,
+}
+
+impl<A, B> IoState for Zip<A, B>
+where
+    A: Executable,
+    B: Executable,
+{
+    /// Input is a tuple of (first input, second input)
+    type Input = (A::Input, B::Input);
+    /// Output is a tuple of (first output, second output)
+    type Output = (A::Output, B::Output);
+}
+
+impl<A, B> Executable for Zip<A, B>
+where
+    A: Executable,
+    B: Executable,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        let (first, second) = input;
+        (
+            self.first.execute(first, execution_context),
+            self.second.execute(second, execution_context),
+        )
+    }
+}
+
+impl<A, B> Current for Zip<A, B>
+where
+    A: Executable + Current,
+    B: Executable + Current,
+{
+    fn current(&self) -> Self::Output {
+        (self.first.current(), self.second.current())
+    }
+}
+
+impl<A, B> WarmUp for Zip<A, B>
+where
+    A: Executable + WarmUp,
+    B: Executable + WarmUp,
+{
+    fn warmup_remaining(&self) -> usize {
+        self.first.warmup_remaining().max(self.second.warmup_remaining())
+    }
+}
+
+/// Post-processes the output of a signal with a closure, created with [`Combine::map`].
+///
+/// The closure can't be serialized, so unlike the other combinators this isn't serializable with the `serde` feature.
+#[derive(Clone)]
+pub struct Map<S, F> {
+    signal: S,
+    map: F,
+}
+
+impl<S: fmt::Debug, F> fmt::Debug for Map<S, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Map")
+            .field("signal", &self.signal)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, F, O> IoState for Map<S, F>
+where
+    S: Executable,
+    F: Fn(S::Output) -> O,
+{
+    type Input = S::Input;
+    type Output = O;
+}
+
+impl<S, F, O> Executable for Map<S, F>
+where
+    S: Executable,
+    F: Fn(S::Output) -> O,
+{
+    fn execute(
+        &mut self,
+        input: Self::Input,
+        execution_context: &ExecutionContext,
+    ) -> Self::Output {
+        (self.map)(self.signal.execute(input, execution_context))
+    }
+}
+
+impl<S, F, O> Apply for Map<S, F>
+where
+    S: Executable,
+    F: Fn(S::Output) -> O,
+{
+    fn apply(&mut self, input: Self::Input) -> Self::Output {
+        self.execute(input, &ExecutionContext::Apply)
+    }
+}
+
+impl<S, F, O> Evaluate for Map<S, F>
+where
+    S: Executable,
+    F: Fn(S::Output) -> O,
+{
+    fn evaluate(&mut self, input: Self::Input) -> Self::Output {
+        self.execute(input, &ExecutionContext::Evaluate)
+    }
+}
+
+impl<S, F, O> Current for Map<S, F>
+where
+    S: Executable + Current,
+    F: Fn(S::Output) -> O,
+{
+    fn current(&self) -> Self::Output {
+        (self.map)(self.signal.current())
+    }
+}
+
+impl<S: WarmUp, F> WarmUp for Map<S, F> {
+    fn warmup_remaining(&self) -> usize {
+        self.signal.warmup_remaining()
+    }
+}
+
+impl<S: Inspect, F> Inspect for Map<S, F> {
+    fn inspect(&self) -> SignalState {
+        SignalState::new("Map").field("signal", self.signal.to_state())
+    }
+
+    fn name(&self) -> &'static str {
+        "Map"
+    }
+}
+
+/// Combines signals side by side and post-processes their outputs, so ad-hoc composites don't need new types.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::ExponentialMovingAverage;
+/// use indicato_rs::traits::{Apply, Combine, Current, Evaluate};
+///
+/// // the EMA of the highs less the EMA of the lows, the input is a tuple of (high, low)
+/// let mut spread = ExponentialMovingAverage::new(3)
+///     .unwrap()
+///     .zip(ExponentialMovingAverage::new(3).unwrap())
+///     .map(|(high, low)| high - low);
+///
+/// assert_eq!(spread.apply((4.0, 2.0)), 2.0);
+/// assert_eq!(spread.evaluate((6.0, 2.0)), 3.0);
+/// assert_eq!(spread.apply((8.0, 4.0)), 3.0);
+/// assert_eq!(spread.current(), 3.0);
+///
+/// // the same input fanned out to a fast and a slow EMA
+/// let mut crossover = ExponentialMovingAverage::new(1)
+///     .unwrap()
+///     .fanout(ExponentialMovingAverage::new(3).unwrap());
+///
+/// assert_eq!(crossover.apply(2.0), (2.0, 2.0));
+/// assert_eq!(crossover.apply(4.0), (4.0, 3.0));
+/// ```
+pub trait Combine: Executable + Sized {
+    /// Creates a [`FanOut`] that passes each input to both this signal and `other`.
+    fn fanout<B>(self, other: B) -> FanOut<Self, B>
+    where
+        B: Executable<Input = Self::Input>,
+        Self::Input: Clone,
+    {
+        FanOut {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Creates a [`Zip`] that passes the first element of each input tuple to this signal and the second to `other`.
+    fn zip<B: Executable>(self, other: B) -> Zip<Self, B> {
+        Zip {
+            first: self,
+            second: other,
+        }
+    }
+
+    /// Creates a [`Map`] that passes each output of this signal through `map`.
+    fn map<F, O>(self, map: F) -> Map<Self, F>
+    where
+        F: Fn(Self::Output) -> O,
+    {
+        Map { signal: self, map }
+    }
+}
+
+impl<S: Executable> Combine for S {}
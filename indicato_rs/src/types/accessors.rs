@@ -0,0 +1,146 @@
+use super::{Candle, HighLowClose};
+
+/// Inputs with a closing price.
+///
+/// Signals that need the close of a period bound their input on this, so bar types from other crates can be applied
+/// directly by implementing it.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::MoneyFlowIndex;
+/// use indicato_rs::traits::Apply;
+/// use indicato_rs::types::{HasClose, HasHighLow, HasVolume};
+///
+/// struct Bar {
+///     high: f64,
+///     low: f64,
+///     close: f64,
+///     volume: f64,
+/// }
+///
+/// impl HasClose for Bar {
+///     fn close(&self) -> f64 {
+///         self.close
+///     }
+/// }
+///
+/// impl HasHighLow for Bar {
+///     fn high(&self) -> f64 {
+///         self.high
+///     }
+///
+///     fn low(&self) -> f64 {
+///         self.low
+///     }
+/// }
+///
+/// impl HasVolume for Bar {
+///     fn volume(&self) -> f64 {
+///         self.volume
+///     }
+/// }
+///
+/// let mut mfi = MoneyFlowIndex::<Bar>::for_input(1).unwrap();
+/// assert_eq!(mfi.apply(Bar { high: 3.0, low: 1.0, close: 2.0, volume: 100.0 }), None);
+/// assert_eq!(mfi.apply(Bar { high: 4.0, low: 2.0, close: 3.0, volume: 100.0 }), Some(100.0));
+/// ```
+pub trait HasClose {
+    /// The closing price
+    fn close(&self) -> f64;
+}
+
+/// Inputs with a high and low price.
+pub trait HasHighLow {
+    /// The highest price
+    fn high(&self) -> f64;
+
+    /// The lowest price
+    fn low(&self) -> f64;
+}
+
+/// Inputs with a traded volume.
+pub trait HasVolume {
+    /// The volume traded
+    fn volume(&self) -> f64;
+}
+
+impl HasClose for Candle {
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+
+impl HasHighLow for Candle {
+    fn high(&self) -> f64 {
+        self.high
+    }
+
+    fn low(&self) -> f64 {
+        self.low
+    }
+}
+
+impl HasVolume for Candle {
+    fn volume(&self) -> f64 {
+        self.volume
+    }
+}
+
+impl HasClose for HighLowClose {
+    fn close(&self) -> f64 {
+        self.close
+    }
+}
+
+impl HasHighLow for HighLowClose {
+    fn high(&self) -> f64 {
+        self.high
+    }
+
+    fn low(&self) -> f64 {
+        self.low
+    }
+}
+
+/// A tuple of (high, low, close)
+impl HasClose for (f64, f64, f64) {
+    fn close(&self) -> f64 {
+        self.2
+    }
+}
+
+/// A tuple of (high, low, close)
+impl HasHighLow for (f64, f64, f64) {
+    fn high(&self) -> f64 {
+        self.0
+    }
+
+    fn low(&self) -> f64 {
+        self.1
+    }
+}
+
+/// A tuple of (high, low, close, volume)
+impl HasClose for (f64, f64, f64, f64) {
+    fn close(&self) -> f64 {
+        self.2
+    }
+}
+
+/// A tuple of (high, low, close, volume)
+impl HasHighLow for (f64, f64, f64, f64) {
+    fn high(&self) -> f64 {
+        self.0
+    }
+
+    fn low(&self) -> f64 {
+        self.1
+    }
+}
+
+/// A tuple of (high, low, close, volume)
+impl HasVolume for (f64, f64, f64, f64) {
+    fn volume(&self) -> f64 {
+        self.3
+    }
+}
@@ -0,0 +1,96 @@
+use indicato_rs_proc::Inspect;
+
+use crate::traits::Inspect;
+
+/// The open, high, low, close and volume of a period, starting at `timestamp`.
+///
+/// Candles can be converted into the inputs of signals that take a subset of their fields, such as the
+/// [`HighLowClose`](super::HighLowClose) of the [`BollingerBands`](crate::signals::BollingerBands). They also implement the
+/// [accessor traits](super::HasClose), so signals created with `for_input` take them directly.
+///
+/// # Example
+/// ```
+/// use indicato_rs::signals::BollingerBands;
+/// use indicato_rs::traits::Apply;
+/// use indicato_rs::types::Candle;
+///
+/// let candle = Candle {
+///     open: 2.0,
+///     high: 4.0,
+///     low: 1.0,
+///     close: 4.0,
+///     volume: 1200.0,
+///     timestamp: 1_700_000_000,
+/// };
+/// assert_eq!(candle.typical_price(), 3.0);
+///
+/// let mut bollinger = BollingerBands::new(20, 2.0).unwrap();
+/// assert_eq!(bollinger.apply(candle.into()).middle, 3.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Inspect)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Candle {
+    /// The opening price of the period.
+    pub open: f64,
+    /// The highest price of the period.
+    pub high: f64,
+    /// The lowest price of the period.
+    pub low: f64,
+    /// The closing price of the period.
+    pub close: f64,
+    /// The volume traded during the period.
+    pub volume: f64,
+    /// The start of the period, in whatever unit the feed uses.
+    pub timestamp: i64,
+}
+
+impl Candle {
+    /// The mean of the high, low and close
+    pub fn typical_price(&self) -> f64 {
+        (self.high + self.low + self.close) / 3.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::signals::{HighLowClose, StochasticMomentumOscillator};
+    use crate::traits::Apply;
+
+    #[test]
+    fn test_into_high_low_close() {
+        let candle = Candle {
+            open: 2.0,
+            high: 5.0,
+            low: 1.0,
+            close: 3.0,
+            volume: 10.0,
+            timestamp: 60,
+        };
+        assert_eq!(
+            HighLowClose::from(candle),
+            HighLowClose { high: 5.0, low: 1.0, close: 3.0 }
+        );
+
+        let mut smo = StochasticMomentumOscillator::new(2).unwrap();
+        let mut expected = smo.clone();
+        assert_eq!(smo.apply(candle.into()), expected.apply((5.0, 1.0, 3.0).into()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_roundtrip() {
+        let candle = Candle {
+            open: 2.0,
+            high: 5.0,
+            low: 1.0,
+            close: 3.0,
+            volume: 10.0,
+            timestamp: 60,
+        };
+        let json = serde_json::to_string(&candle).unwrap();
+        assert_eq!(serde_json::from_str::<Candle>(&json).unwrap(), candle);
+        let hlc = HighLowClose::from(candle);
+        assert_eq!(serde_json::from_str::<HighLowClose>(&serde_json::to_string(&hlc).unwrap()).unwrap(), hlc);
+    }
+}
@@ -0,0 +1,48 @@
+use super::Candle;
+
+/// The high, low and close of a period, the input of signals such as the
+/// [`BollingerBands`](crate::signals::BollingerBands) and the
+/// [`StochasticMomentumOscillator`](crate::signals::StochasticMomentumOscillator).
+///
+/// Tuples of (high, low, close) and [`Candle`]s can be converted into it, and it can be converted back into a tuple.
+///
+/// # Example
+/// ```
+/// use indicato_rs::types::HighLowClose;
+///
+/// let input: HighLowClose = (3.0, 1.0, 2.0).into();
+/// assert_eq!(input, HighLowClose { high: 3.0, low: 1.0, close: 2.0 });
+/// assert_eq!(<(f64, f64, f64)>::from(input), (3.0, 1.0, 2.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HighLowClose {
+    /// The highest price of the period.
+    pub high: f64,
+    /// The lowest price of the period.
+    pub low: f64,
+    /// The closing price of the period.
+    pub close: f64,
+}
+
+impl From<(f64, f64, f64)> for HighLowClose {
+    fn from((high, low, close): (f64, f64, f64)) -> Self {
+        Self { high, low, close }
+    }
+}
+
+impl From<HighLowClose> for (f64, f64, f64) {
+    fn from(input: HighLowClose) -> Self {
+        (input.high, input.low, input.close)
+    }
+}
+
+impl From<Candle> for HighLowClose {
+    fn from(candle: Candle) -> Self {
+        Self {
+            high: candle.high,
+            low: candle.low,
+            close: candle.close,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+mod accessors;
+pub use accessors::{HasClose, HasHighLow, HasVolume};
+
+mod candle;
+pub use candle::Candle;
+
+mod high_low_close;
+pub use high_low_close::HighLowClose;
+
+mod signal_value;
+pub use signal_value::SignalValue;
@@ -0,0 +1,69 @@
+/// The output of a signal that is either still seeding its aggregations or ready, output by
+/// [`Seeded`](crate::signals::Seeded).
+///
+/// Signals report their warm up differently, some output `None` until they are warmed up, such as the
+/// [`RelativeStrengthIndex`](crate::signals::RelativeStrengthIndex), and others output a value from the first input,
+/// such as the [`SimpleMovingAverage`](crate::signals::SimpleMovingAverage). A signal value handles the warm up of
+/// every signal the same way.
+///
+/// # Example
+/// ```
+/// use indicato_rs::types::SignalValue;
+///
+/// let seeding: SignalValue<f64> = SignalValue::Seeding { remaining: 2 };
+/// assert!(!seeding.is_ready());
+/// assert_eq!(seeding.remaining(), 2);
+/// assert_eq!(seeding.ready(), None);
+///
+/// let ready = SignalValue::Ready(4.0);
+/// assert_eq!(ready.map(|value| value / 2.0), SignalValue::Ready(2.0));
+/// assert_eq!(Option::from(ready), Some(4.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SignalValue<T> {
+    /// The signal is warming up and needs `remaining` more inputs before its output can be trusted.
+    Seeding {
+        /// The number of inputs still needed.
+        remaining: usize,
+    },
+    /// The signal is warmed up and this is its output.
+    Ready(T),
+}
+
+impl<T> SignalValue<T> {
+    /// Whether the signal was warmed up
+    pub fn is_ready(&self) -> bool {
+        matches!(self, Self::Ready(_))
+    }
+
+    /// The number of inputs still needed before the signal is warmed up, 0 once it is
+    pub fn remaining(&self) -> usize {
+        match self {
+            Self::Seeding { remaining } => *remaining,
+            Self::Ready(_) => 0,
+        }
+    }
+
+    /// The output of the signal, if it was warmed up
+    pub fn ready(self) -> Option<T> {
+        match self {
+            Self::Seeding { .. } => None,
+            Self::Ready(value) => Some(value),
+        }
+    }
+
+    /// Maps the output of a warmed up signal, keeping the remaining warm up of a seeding one
+    pub fn map<U>(self, map: impl FnOnce(T) -> U) -> SignalValue<U> {
+        match self {
+            Self::Seeding { remaining } => SignalValue::Seeding { remaining },
+            Self::Ready(value) => SignalValue::Ready(map(value)),
+        }
+    }
+}
+
+impl<T> From<SignalValue<T>> for Option<T> {
+    fn from(value: SignalValue<T>) -> Self {
+        value.ready()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::{
+    fin_error::{FinError, FinErrorType},
+    traits::Numeric,
+};
+
+/// Checks the value is a number, NaN is the only value that can't be ordered against itself.
+pub(crate) fn value<T: PartialOrd>(value: T, name: &str) -> Result<(), FinError> {
+    match value.partial_cmp(&value) {
+        Some(_) => Ok(()),
+        None => Err(FinError::new(
+            FinErrorType::InvalidInput,
+            &format!("{} must not be NaN", name),
+        )),
+    }
+}
+
+/// Checks the value is a number that isn't negative.
+pub(crate) fn non_negative<T: Numeric>(value: T, name: &str) -> Result<(), FinError> {
+    self::value(value, name)?;
+    match value < T::zero() {
+        true => Err(FinError::new(
+            FinErrorType::InvalidInput,
+            &format!("{} must not be negative", name),
+        )),
+        false => Ok(()),
+    }
+}
+
+/// Checks the volume is a number that isn't negative.
+pub(crate) fn volume<T: Numeric>(volume: T) -> Result<(), FinError> {
+    non_negative(volume, "Volume")
+}
+
+/// Checks the high and low are numbers and the high isn't below the low.
+pub(crate) fn high_low(high: f64, low: f64) -> Result<(), FinError> {
+    value(high, "High")?;
+    value(low, "Low")?;
+    match high < low {
+        true => Err(FinError::new(
+            FinErrorType::InvalidInput,
+            "High must not be less than the low",
+        )),
+        false => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_value() {
+        assert!(value(1.0, "Input").is_ok());
+        assert!(value(f64::INFINITY, "Input").is_ok());
+        assert_eq!(
+            value(f64::NAN, "Input").err().unwrap().message,
+            "Input must not be NaN"
+        );
+    }
+
+    #[test]
+    fn test_volume() {
+        assert!(volume(0.0).is_ok());
+        assert!(volume(f64::NAN).is_err());
+        assert_eq!(
+            volume(-1.0).err().unwrap().message,
+            "Volume must not be negative"
+        );
+    }
+
+    #[test]
+    fn test_high_low() {
+        assert!(high_low(2.0, 2.0).is_ok());
+        assert!(high_low(f64::NAN, 2.0).is_err());
+        assert_eq!(
+            high_low(1.0, 2.0).err().unwrap().message,
+            "High must not be less than the low"
+        );
+    }
+}
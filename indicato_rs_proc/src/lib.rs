@@ -1,13 +1,13 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn;
 
 #[proc_macro_derive(Apply)]
 pub fn apply_derive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
     let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let gen = quote! {
-        impl Apply for #name {
+        impl #impl_generics Apply for #name #ty_generics #where_clause {
             fn apply(&mut self, input: Self::Input) -> Self::Output {
                 self.execute(input, &ExecutionContext::Apply)
             }
@@ -20,8 +20,9 @@ pub fn apply_derive(input: TokenStream) -> TokenStream {
 pub fn evaluate_derive(input: TokenStream) -> TokenStream {
     let ast: syn::DeriveInput = syn::parse(input).unwrap();
     let name = &ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
     let gen = quote! {
-        impl Evaluate for #name {
+        impl #impl_generics Evaluate for #name #ty_generics #where_clause {
             fn evaluate(&mut self, input: Self::Input) -> Self::Output {
                 self.execute(input, &ExecutionContext::Evaluate)
             }
@@ -29,3 +30,60 @@ pub fn evaluate_derive(input: TokenStream) -> TokenStream {
     };
     gen.into()
 }
+
+/// Whether the type names any of the type parameters, so the field needs a bound to be inspected.
+fn uses_type_params(ty: &syn::Type, params: &[String]) -> bool {
+    quote!(#ty)
+        .to_string()
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .any(|ident| params.iter().any(|param| param == ident))
+}
+
+#[proc_macro_derive(Inspect)]
+pub fn inspect_derive(input: TokenStream) -> TokenStream {
+    let ast: syn::DeriveInput = syn::parse(input).unwrap();
+    let name = &ast.ident;
+    let fields = match &ast.data {
+        syn::Data::Struct(syn::DataStruct {
+            fields: syn::Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => panic!("Inspect can only be derived for structs with named fields"),
+    };
+    let params = ast
+        .generics
+        .type_params()
+        .map(|param| param.ident.to_string())
+        .collect::<Vec<String>>();
+    let mut generics = ast.generics.clone();
+    let mut entries = Vec::new();
+    for field in fields {
+        let ty = &field.ty;
+        if quote!(#ty).to_string().starts_with("PhantomData") {
+            continue;
+        }
+        if uses_type_params(ty, &params) {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(syn::parse_quote!(#ty: crate::inspect::ToState));
+        }
+        let ident = field.ident.as_ref().unwrap();
+        entries.push(quote! {
+            .field(stringify!(#ident), crate::inspect::ToState::to_state(&self.#ident))
+        });
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let gen = quote! {
+        impl #impl_generics Inspect for #name #ty_generics #where_clause {
+            fn inspect(&self) -> crate::inspect::SignalState {
+                crate::inspect::SignalState::new(stringify!(#name)) #(#entries)*
+            }
+
+            fn name(&self) -> &'static str {
+                stringify!(#name)
+            }
+        }
+    };
+    gen.into()
+}